@@ -0,0 +1,52 @@
+//! Minimal CSV writing for the table exports.
+
+use std::fmt::Display;
+
+/// Builds CSV text one row at a time, quoting fields as RFC 4180 requires.
+#[derive(Default)]
+pub(crate) struct CsvWriter {
+    out: String,
+    row_started: bool,
+}
+
+impl CsvWriter {
+    /// Append a field to the current row.
+    pub(crate) fn field(&mut self, value: impl Display) -> &mut Self {
+        if self.row_started {
+            self.out.push(',');
+        }
+        self.row_started = true;
+        let value = value.to_string();
+        if value.contains([',', '"', '\n', '\r']) {
+            self.out.push('"');
+            self.out.push_str(&value.replace('"', "\"\""));
+            self.out.push('"');
+        } else {
+            self.out.push_str(&value);
+        }
+        self
+    }
+
+    /// Terminate the current row.
+    pub(crate) fn end_row(&mut self) {
+        self.out.push('\n');
+        self.row_started = false;
+    }
+
+    pub(crate) fn finish(self) -> String {
+        self.out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotes_only_when_needed() {
+        let mut csv = CsvWriter::default();
+        csv.field("plain").field("a,b").field("say \"hi\"").field(3);
+        csv.end_row();
+        assert_eq!(csv.finish(), "plain,\"a,b\",\"say \"\"hi\"\"\",3\n");
+    }
+}
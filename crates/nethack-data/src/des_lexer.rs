@@ -5,7 +5,11 @@
 
 use std::fmt;
 
-#[derive(Debug, Clone, PartialEq)]
+use nethack_types::sp_lev::HumidityFlags;
+use strum::EnumDiscriminants;
+
+#[derive(Debug, Clone, PartialEq, EnumDiscriminants)]
+#[strum_discriminants(name(TokenKind), derive(Hash))]
 pub enum Token {
     // Structure
     Maze,
@@ -196,11 +200,7 @@ pub enum Token {
     Square,
 
     // Humidity
-    Dry,
-    Wet,
-    Hot,
-    Solid,
-    Any,
+    Humidity(HumidityFlags),
 
     // Comparison
     CompareEq,
@@ -250,6 +250,14 @@ pub enum Token {
     Eof,
 }
 
+impl Token {
+    /// The variant without its payload, for comparing tokens without
+    /// cloning strings.
+    pub fn kind(&self) -> TokenKind {
+        self.into()
+    }
+}
+
 impl fmt::Display for Token {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -450,20 +458,25 @@ pub fn lex(input: &str) -> Result<Vec<Located<Token>>, LexError> {
 
         // Percent in brackets: [50%]
         if ch == '[' {
-            // Peek ahead: could be [N%] or just [
-            let saved: Vec<char> = chars.clone().skip(1).take(20).collect();
-            let s: std::string::String = saved.iter().collect();
-            if let Some(pct_end) = s.find('%') {
-                let num_str: std::string::String = s[..pct_end]
-                    .chars()
+            // Peek ahead: could be [N%] or just [. The bracket never spans
+            // lines, so only the rest of this line is scanned, which keeps
+            // the one-column-per-char advance below in step with `line`.
+            let ahead: Vec<char> = chars
+                .clone()
+                .skip(1)
+                .take_while(|&c| c != '\n' && c != '\r')
+                .take(20)
+                .collect();
+            if let Some(pct_end) = ahead.iter().position(|&c| c == '%') {
+                let num_str: std::string::String = ahead[..pct_end]
+                    .iter()
                     .filter(|c| !c.is_whitespace())
                     .collect();
                 // Check if there's a ] after the %
-                let rest = &s[pct_end + 1..];
-                let close_pos = rest.find(']');
-                if let (Ok(n), Some(_close)) = (num_str.parse::<i64>(), close_pos) {
+                let close_pos = ahead[pct_end + 1..].iter().position(|&c| c == ']');
+                if let (Ok(n), Some(close)) = (num_str.parse::<i64>(), close_pos) {
                     // It's [N%] — consume all of it
-                    let total = pct_end + 1 + close_pos.expect("close_pos already matched") + 1;
+                    let total = pct_end + 1 + close + 1;
                     chars.next(); // [
                     col += 1;
                     for _ in 0..total {
@@ -1081,11 +1094,11 @@ pub fn lex(input: &str) -> Result<Vec<Located<Token>>, LexError> {
                 "square" => Token::Square,
 
                 // Humidity
-                "dry" => Token::Dry,
-                "wet" => Token::Wet,
-                "hot" => Token::Hot,
-                "solid" => Token::Solid,
-                "any" => Token::Any,
+                "dry" => Token::Humidity(HumidityFlags::DRY),
+                "wet" => Token::Humidity(HumidityFlags::WET),
+                "hot" => Token::Humidity(HumidityFlags::HOT),
+                "solid" => Token::Humidity(HumidityFlags::SOLID),
+                "any" => Token::Humidity(HumidityFlags::ANY_LOC),
 
                 // Trapped state
                 "trapped" => Token::Trapped,
@@ -1134,6 +1147,40 @@ mod tests {
         assert!(matches!(tokens[4].value, Token::Random));
     }
 
+    #[test]
+    fn percent_bracket_stays_on_its_line() {
+        let tokens = lex("[\n50%]:\nFOUNTAIN [ 25% ]").expect("lex");
+        let found: Vec<_> = tokens.iter().map(|t| (&t.value, t.line, t.col)).collect();
+        assert_eq!(
+            found,
+            [
+                (&Token::LBracket, 1, 1),
+                (&Token::Percent(50), 2, 1),
+                (&Token::RBracket, 2, 4),
+                (&Token::Colon, 2, 5),
+                (&Token::Fountain, 3, 1),
+                (&Token::Percent(25), 3, 10),
+                (&Token::Eof, 3, 17),
+            ]
+        );
+    }
+
+    #[test]
+    fn kind_ignores_payload() {
+        assert_eq!(
+            Token::String("castle".into()).kind(),
+            Token::String(String::new()).kind()
+        );
+        assert_eq!(Token::Integer(1).kind(), Token::Integer(-7).kind());
+        assert_eq!(
+            Token::Dice { num: 1, die: 6 }.kind(),
+            Token::Dice { num: 3, die: 4 }.kind()
+        );
+        assert_eq!(Token::Variable("x".into()).kind(), TokenKind::Variable);
+        assert_ne!(Token::Integer(1).kind(), Token::Percent(1).kind());
+        assert_ne!(Token::Maze.kind(), Token::Level.kind());
+    }
+
     #[test]
     fn lex_map_block() {
         let input = "MAP\n.|.|\n-+-+\nENDMAP\n";
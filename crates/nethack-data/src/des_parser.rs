@@ -3,11 +3,13 @@
 //! Consumes tokens from [`des_lexer`] and emits [`SpLevOpcode`] bytecode
 //! matching the semantics of C's `lev_comp` (`nethack/util/lev_comp.y`).
 
-use crate::des_lexer::{Located, Token};
+use crate::des_lexer::{Located, Token, TokenKind};
 use crate::monsters::MONSTERS;
 use crate::objects::OBJECTS;
+use nethack_types::RoomType;
 use nethack_types::sp_lev::{
-    DesFile, LevelFlags, SpLevOpcode, SpMonVarFlag, SpObjVarFlag, SpOpcode, SpOperand, SpecialLevel,
+    DesFile, HumidityFlags, LevelFlags, SpLevOpcode, SpMonVarFlag, SpObjVarFlag, SpOpcode,
+    SpOperand, SpecialLevel,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -16,6 +18,135 @@ pub enum DesParseError {
     Parse { line: usize, msg: String },
 }
 
+/// Optional parser behaviour. The defaults match C's `lev_comp` output.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParseOptions {
+    /// Record [`SourceHint`]s for constructs whose lowering loses the
+    /// original source form.
+    pub source_hints: bool,
+}
+
+/// Source-level information attached to an opcode offset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceHint {
+    /// A `[N%]` chance gate; the offset is the first opcode of the lowered
+    /// `rn2(100)` comparison.
+    Percent(i64),
+}
+
+/// A problem in the source that still compiles, such as dead code.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("line {line}: {msg}")]
+pub struct DesWarning {
+    pub line: usize,
+    pub msg: String,
+}
+
+/// Result of [`parse_des_with_options`].
+#[derive(Debug, Clone)]
+pub struct ParsedDes {
+    pub des: DesFile,
+    /// Per-level `(opcode offset, hint)` tables, parallel to `des.levels`.
+    /// Empty unless [`ParseOptions::source_hints`] is set.
+    pub hints: Vec<Vec<(usize, SourceHint)>>,
+    /// Warnings for the whole file, in source order.
+    pub warnings: Vec<DesWarning>,
+}
+
+/// A monster or object specifier as written in the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DesSpec {
+    /// `random`
+    Random,
+    /// `'c'`: any member of a class.
+    Class(char),
+    /// `('c', "name")`
+    Named { class: char, name: String },
+    /// `$var` or `$var[i]`, holding the name without the `$`.
+    Variable(String),
+}
+
+/// A coordinate argument as written in the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DesCoord {
+    /// `random`
+    Random,
+    /// `(x, y)`
+    At { x: i16, y: i16 },
+    /// `$var` or `$var[i]`, holding the name without the `$`.
+    Variable(String),
+    /// `rndcoord(selection)`
+    RndCoord,
+}
+
+/// A `MAP` block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapStatement {
+    pub line: usize,
+    /// The map text, exactly as it appears between `MAP` and `ENDMAP`.
+    pub map: String,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// A `MONSTER` statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonsterStatement {
+    pub line: usize,
+    pub spec: DesSpec,
+    pub coord: DesCoord,
+}
+
+/// An `OBJECT` statement, including those nested in a `CONTAINER`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectStatement {
+    pub line: usize,
+    pub spec: DesSpec,
+    /// `None` when no coordinate was written.
+    pub coord: Option<DesCoord>,
+}
+
+/// A `DOOR` statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoorStatement {
+    pub line: usize,
+    /// `D_*` state bits, or -1 for `random`.
+    pub state: i64,
+}
+
+/// A FUNCTION body, compiled on its own with offsets from its start.
+#[derive(Debug, Clone)]
+struct FunctionDef {
+    code: Vec<SpLevOpcode>,
+    hints: Vec<(usize, SourceHint)>,
+    /// Where the body was emitted, once the function has been called.
+    addr: Option<usize>,
+}
+
+/// The alignment of a MAP from its GEOMETRY statement.
+#[derive(Debug, Clone, Copy)]
+struct MapGeometry {
+    halign: i16,
+    valign: i16,
+    roomfill: i64,
+}
+
+/// `CENTER` from `sp_lev.h`, for both alignments.
+const ALIGN_CENTER: i16 = 3;
+
+/// Callbacks invoked by [`parse_des_visit`] as each statement is parsed.
+///
+/// Every method defaults to doing nothing, so implementors only override the
+/// statements they care about.
+pub trait StatementVisitor {
+    /// A `MAZE` or `LEVEL` header starts a new level.
+    fn on_level(&mut self, _name: &str) {}
+    fn on_map(&mut self, _map: &MapStatement) {}
+    fn on_monster(&mut self, _monster: &MonsterStatement) {}
+    fn on_object(&mut self, _object: &ObjectStatement) {}
+    fn on_door(&mut self, _door: &DoorStatement) {}
+}
+
 /// Variable type tracking for the symbol table (used for future type checking).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(dead_code)]
@@ -35,6 +166,8 @@ enum VarType {
 struct VarDef {
     typ: VarType,
     is_array: bool,
+    /// The value of an integer assigned once, from a literal.
+    constant: Option<i64>,
 }
 
 /// Convert a display character to a terrain type, matching C's `what_map_char()`.
@@ -79,13 +212,17 @@ struct ScanMapResult {
     data: String,
     height: usize,
     width: usize,
+    /// `(row, first invalid char, count)` for each row with characters
+    /// that were replaced by STONE.
+    invalid_rows: Vec<(usize, char, usize)>,
 }
 
 /// Replicate C's `scan_map()` from `lev_main.c`.
 ///
 /// 1. Strip digits 0-9 (C uses these for line numbering in some maps)
 /// 2. Find max row width
-/// 3. Convert each character through `what_map_char()`, add +1
+/// 3. Convert each character through `what_map_char()`, add +1; invalid
+///    characters become STONE, as C does after its `lc_warning()`
 /// 4. Pad shorter rows with `STONE + 1 = 1`
 fn scan_map(raw: &str) -> ScanMapResult {
     // Strip digits
@@ -98,16 +235,23 @@ fn scan_map(raw: &str) -> ScanMapResult {
 
     // Convert each character and pad
     let mut buf = Vec::with_capacity(max_hig * max_len);
-    for row in &rows {
+    let mut invalid_rows = Vec::new();
+    for (y, row) in rows.iter().enumerate() {
+        let mut invalid = None;
         for ch in row.chars() {
-            let terrain = what_map_char(ch);
-            let terrain = if terrain == INVALID_TYPE { 0 } else { terrain }; // STONE for invalid
+            let mut terrain = what_map_char(ch);
+            if terrain == INVALID_TYPE {
+                let (_, count) = invalid.get_or_insert((ch, 0));
+                *count += 1;
+                terrain = 0; // STONE
+            }
             buf.push((terrain as u8).wrapping_add(1));
         }
-        // Pad to max_len with STONE+1 = 1
-        for _ in row.len()..max_len {
-            buf.push(1); // STONE + 1
+        if let Some((ch, count)) = invalid {
+            invalid_rows.push((y, ch, count));
         }
+        // Pad to max_len with STONE+1 = 1
+        buf.extend(std::iter::repeat_n(1, max_len.saturating_sub(row.len())));
     }
 
     // Convert to string (C stores as char array)
@@ -117,6 +261,7 @@ fn scan_map(raw: &str) -> ScanMapResult {
         data,
         height: max_hig,
         width: max_len,
+        invalid_rows,
     }
 }
 
@@ -165,10 +310,10 @@ fn get_object_id(name: &str, class_char: char) -> Option<i16> {
 
     // Exact match
     for (i, o) in OBJECTS.iter().enumerate() {
-        if let Some(fc) = filter_class {
-            if o.class != fc {
-                continue;
-            }
+        if let Some(fc) = filter_class
+            && o.class != fc
+        {
+            continue;
         }
         if o.name == name {
             return Some(i as i16);
@@ -177,10 +322,10 @@ fn get_object_id(name: &str, class_char: char) -> Option<i16> {
     // Case-insensitive fallback
     let name_lower = name.to_lowercase();
     for (i, o) in OBJECTS.iter().enumerate() {
-        if let Some(fc) = filter_class {
-            if o.class != fc {
-                continue;
-            }
+        if let Some(fc) = filter_class
+            && o.class != fc
+        {
+            continue;
         }
         if o.name.to_lowercase() == name_lower {
             return Some(i as i16);
@@ -220,34 +365,118 @@ fn get_trap_type(name: &str) -> Option<i64> {
 }
 
 /// Parser state for compiling a `.des` file.
-struct Parser {
+struct Parser<'v> {
     tokens: Vec<Located<Token>>,
     pos: usize,
     /// Per-level opcode accumulator.
     opcodes: Vec<SpLevOpcode>,
     /// Variable symbol table (per level, reset on each MAZE/LEVEL).
     vars: std::collections::HashMap<String, VarDef>,
+    /// How many times each variable is assigned anywhere in the source,
+    /// FOR headers included.
+    assignments: std::collections::HashMap<String, usize>,
     /// Container nesting depth.
     container_depth: u32,
+    /// Jumps to patch for the BREAKs of each enclosing SWITCH, LOOP or FOR,
+    /// innermost last (lev_comp's `break_stmt_start()` scopes).
+    break_targets: Vec<Vec<usize>>,
+    /// Inside a SWITCH; lev_comp does not allow them to nest.
+    in_switch: bool,
+    /// FUNCTION definitions of the current level, by name.
+    functions: std::collections::HashMap<String, FunctionDef>,
+    /// Whether a FUNCTION body is being parsed.
+    in_function: bool,
     /// Collected levels.
     levels: Vec<SpecialLevel>,
     /// Current level name.
     level_name: String,
-    /// Roomfill value from GEOMETRY (C default = 1).
-    roomfill: i64,
+    /// Placement from a GEOMETRY, waiting for the MAP it applies to.
+    pending_geometry: Option<MapGeometry>,
+    options: ParseOptions,
+    /// Source hints for the current level.
+    hints: Vec<(usize, SourceHint)>,
+    /// Collected per-level source hints, parallel to `levels`.
+    level_hints: Vec<Vec<(usize, SourceHint)>>,
+    warnings: Vec<DesWarning>,
+    visitor: Option<&'v mut dyn StatementVisitor>,
 }
 
-impl Parser {
-    fn new(tokens: Vec<Located<Token>>) -> Self {
+impl<'v> Parser<'v> {
+    fn new(tokens: Vec<Located<Token>>, options: ParseOptions) -> Self {
+        let mut assignments = std::collections::HashMap::new();
+        for pair in tokens.windows(2) {
+            if let (Token::Variable(name), Token::Equals) = (&pair[0].value, &pair[1].value) {
+                *assignments.entry(name.clone()).or_insert(0) += 1;
+            }
+        }
         Self {
             tokens,
             pos: 0,
             opcodes: Vec::new(),
             vars: std::collections::HashMap::new(),
+            assignments,
             container_depth: 0,
+            break_targets: Vec::new(),
+            in_switch: false,
+            functions: std::collections::HashMap::new(),
+            in_function: false,
             levels: Vec::new(),
             level_name: String::new(),
-            roomfill: 1,
+            pending_geometry: None,
+            options,
+            hints: Vec::new(),
+            level_hints: Vec::new(),
+            warnings: Vec::new(),
+            visitor: None,
+        }
+    }
+
+    fn visit(&mut self, f: impl FnOnce(&mut dyn StatementVisitor)) {
+        if let Some(visitor) = self.visitor.as_deref_mut() {
+            f(visitor);
+        }
+    }
+
+    fn hint(&mut self, offset: usize, hint: SourceHint) {
+        if self.options.source_hints {
+            self.hints.push((offset, hint));
+        }
+    }
+
+    /// Warn once about a statement that follows an unconditional `EXIT`
+    /// in the same block. `exit_line` is the block's pending `EXIT`, taken
+    /// so that a run of dead statements warns only at its start.
+    fn check_reachable(&mut self, exit_line: &mut Option<usize>) {
+        if let Some(exit) = exit_line.take() {
+            self.warnings.push(DesWarning {
+                line: self.current_line(),
+                msg: format!("statement is unreachable after EXIT on line {exit}"),
+            });
+        }
+    }
+
+    /// Parse one statement of a block, with its optional `[N%]:` prefix,
+    /// tracking unconditional `EXIT`s for [`Self::check_reachable`].
+    fn parse_block_statement(
+        &mut self,
+        exit_line: &mut Option<usize>,
+    ) -> Result<(), DesParseError> {
+        let line = self.current_line();
+        let pct = self.try_percent_prefix()?;
+        let kind = self.peek_kind();
+        // A function body only runs when called.
+        if kind != TokenKind::Function {
+            self.check_reachable(exit_line);
+        }
+        if let Some(pct_val) = pct {
+            self.expect_colon()?;
+            self.parse_pct_statement(pct_val)
+        } else {
+            self.parse_statement()?;
+            if kind == TokenKind::Exit {
+                *exit_line = Some(line);
+            }
+            Ok(())
         }
     }
 
@@ -274,13 +503,17 @@ impl Parser {
         tok
     }
 
+    fn peek_kind(&self) -> TokenKind {
+        self.peek().kind()
+    }
+
     fn expect(&mut self, expected: &Token) -> Result<(), DesParseError> {
-        let tok = self.peek().clone();
-        if std::mem::discriminant(&tok) == std::mem::discriminant(expected) {
+        if self.peek_kind() == expected.kind() {
             self.advance();
             Ok(())
         } else {
-            Err(self.err(&format!("expected {expected:?}, got {tok:?}")))
+            let msg = format!("expected {expected:?}, got {:?}", self.peek());
+            Err(self.err(&msg))
         }
     }
 
@@ -320,7 +553,7 @@ impl Parser {
         });
     }
 
-    fn emit_push_coord(&mut self, x: i16, y: i16, is_random: bool, flags: u32) {
+    fn emit_push_coord(&mut self, x: i16, y: i16, is_random: bool, flags: HumidityFlags) {
         self.opcodes.push(SpLevOpcode {
             opcode: SpOpcode::Push,
             operand: Some(SpOperand::Coord {
@@ -412,39 +645,43 @@ impl Parser {
                 name: std::mem::take(&mut self.level_name),
                 opcodes,
             });
+            self.level_hints.push(std::mem::take(&mut self.hints));
             self.vars.clear();
             self.container_depth = 0;
-            self.roomfill = 1;
+            self.pending_geometry = None;
+            self.functions.clear();
         }
     }
 
     // ---- Top-level parsing ----
 
-    fn parse(mut self) -> Result<DesFile, DesParseError> {
+    fn parse(mut self) -> Result<ParsedDes, DesParseError> {
+        let mut exit_line = None;
         while *self.peek() != Token::Eof {
-            // Handle optional percent prefix: [75%]: statement
-            let pct = self.try_percent_prefix()?;
-            if pct.is_some() {
-                // Expect colon after percent
-                self.expect_colon()?;
+            match self.peek_kind() {
+                TokenKind::Maze | TokenKind::Level => self.check_geometry_used()?,
+                _ => {}
             }
-
-            match self.peek().clone() {
-                Token::Maze => self.parse_maze()?,
-                Token::Level => self.parse_level_def()?,
-                Token::Eof => break,
-                _ => {
-                    if let Some(pct_val) = pct {
-                        self.parse_pct_statement(pct_val)?;
-                    } else {
-                        self.parse_statement()?;
-                    }
+            match self.peek_kind() {
+                TokenKind::Maze => {
+                    exit_line = None;
+                    self.parse_maze()?;
                 }
+                TokenKind::Level => {
+                    exit_line = None;
+                    self.parse_level_def()?;
+                }
+                _ => self.parse_block_statement(&mut exit_line)?,
             }
         }
+        self.check_geometry_used()?;
         self.finish_level();
-        Ok(DesFile {
-            levels: self.levels,
+        Ok(ParsedDes {
+            des: DesFile {
+                levels: self.levels,
+            },
+            hints: self.level_hints,
+            warnings: self.warnings,
         })
     }
 
@@ -468,15 +705,15 @@ impl Parser {
     /// Emits a percent-chance condition. Returns the index of the jump target
     /// PUSH that needs to be patched after the body.
     fn emit_percent_condition(&mut self, pct: i64) -> usize {
+        self.hint(self.current_offset(), SourceHint::Percent(pct));
         self.emit_push_int(pct);
         self.emit_push_int(100);
         self.emit(SpOpcode::Rn2);
-        self.emit_push_int(0);
         self.emit(SpOpcode::Cmp);
         let jmp_idx = self.current_offset();
         self.emit_push_int(jmp_idx as i64 + 1);
-        // JG = jump if greater (i.e., skip if rn2 result > 0 → pct failed)
-        self.emit(SpOpcode::Jg);
+        // JLE = skip the body when pct <= rn2(100), i.e. the roll failed
+        self.emit(SpOpcode::Jle);
         jmp_idx
     }
 
@@ -502,6 +739,7 @@ impl Parser {
         self.expect_colon()?;
 
         let name = self.parse_string()?;
+        self.visit(|v| v.on_level(&name));
         self.level_name = name;
         self.expect_comma()?;
 
@@ -553,6 +791,7 @@ impl Parser {
         self.advance(); // LEVEL
         self.expect_colon()?;
         let name = self.parse_string()?;
+        self.visit(|v| v.on_level(&name));
         self.level_name = name;
         // C's LEVEL_ID rule only calls start_level_def() — no opcodes emitted.
         // But the `flags` production always fires next in the grammar.
@@ -575,56 +814,74 @@ impl Parser {
         }
     }
 
+    /// A GEOMETRY only places the MAP or NOMAP right after it.
+    fn check_geometry_used(&self) -> Result<(), DesParseError> {
+        match (self.pending_geometry, self.peek_kind()) {
+            (None, _) | (Some(_), TokenKind::Map | TokenKind::Nomap) => Ok(()),
+            (Some(_), _) => Err(self.err("GEOMETRY must be followed by MAP")),
+        }
+    }
+
     fn parse_statement(&mut self) -> Result<(), DesParseError> {
-        match self.peek().clone() {
-            Token::Flags => self.parse_flags(),
-            Token::InitMap => self.parse_init_map(),
-            Token::Geometry => self.parse_geometry(),
-            Token::Nomap => self.parse_nomap(),
-            Token::Map => self.parse_map_statement(),
-            Token::Message => self.parse_message(),
-            Token::Monster => self.parse_monster(),
-            Token::Object => self.parse_object(),
-            Token::Container => self.parse_container(),
-            Token::Trap => self.parse_trap(),
-            Token::Door => self.parse_door(),
-            Token::RoomDoor => self.parse_roomdoor(),
-            Token::Drawbridge => self.parse_drawbridge(),
-            Token::Fountain => self.parse_fountain(),
-            Token::Sink => self.parse_sink(),
-            Token::Pool => self.parse_pool(),
-            Token::Ladder => self.parse_ladder(),
-            Token::Stair => self.parse_stair(),
-            Token::Altar => self.parse_altar(),
-            Token::TeleportRegion => self.parse_teleport_region(),
-            Token::Branch => self.parse_branch_region(),
-            Token::Portal => self.parse_portal_region(),
-            Token::Gold => self.parse_gold(),
-            Token::Engraving => self.parse_engraving(),
-            Token::Grave => self.parse_grave(),
-            Token::MazeWalk => self.parse_mazewalk(),
-            Token::Wallify => self.parse_wallify(),
-            Token::Mineralize => self.parse_mineralize(),
-            Token::NonDiggable => self.parse_non_diggable(),
-            Token::NonPasswall => self.parse_non_passwall(),
-            Token::Terrain => self.parse_terrain(),
-            Token::ReplaceTerrain => self.parse_replace_terrain(),
-            Token::Region => self.parse_region(),
-            Token::Room => self.parse_room(false),
-            Token::Subroom => self.parse_room(true),
-            Token::Corridor => self.parse_corridor(),
-            Token::RandomCorridors => self.parse_random_corridors(),
-            Token::If => self.parse_if(),
-            Token::For => self.parse_for(),
-            Token::Loop => self.parse_loop(),
-            Token::Switch => self.parse_switch(),
-            Token::Function => self.parse_function(),
-            Token::Exit => self.parse_exit(),
-            Token::Shuffle => self.parse_shuffle(),
-            Token::Variable(_) => self.parse_variable_assignment(),
+        self.check_geometry_used()?;
+        match self.peek_kind() {
+            TokenKind::Flags => self.parse_flags(),
+            TokenKind::InitMap => self.parse_init_map(),
+            TokenKind::Geometry => self.parse_geometry(),
+            TokenKind::Nomap => self.parse_nomap(),
+            TokenKind::Map => self.parse_map_statement(),
+            TokenKind::Message => self.parse_message(),
+            TokenKind::Monster => self.parse_monster(),
+            TokenKind::Object => self.parse_object(),
+            TokenKind::Container => self.parse_container(),
+            TokenKind::Trap => self.parse_trap(),
+            TokenKind::Door => self.parse_door(),
+            TokenKind::RoomDoor => self.parse_roomdoor(),
+            TokenKind::Drawbridge => self.parse_drawbridge(),
+            TokenKind::Fountain => self.parse_fountain(),
+            TokenKind::Sink => self.parse_sink(),
+            TokenKind::Pool => self.parse_pool(),
+            TokenKind::Ladder => self.parse_ladder(),
+            TokenKind::Stair => self.parse_stair(),
+            TokenKind::Altar => self.parse_altar(),
+            TokenKind::TeleportRegion => self.parse_teleport_region(),
+            TokenKind::Branch => self.parse_branch_region(),
+            TokenKind::Portal => self.parse_portal_region(),
+            TokenKind::Gold => self.parse_gold(),
+            TokenKind::Engraving => self.parse_engraving(),
+            TokenKind::Grave => self.parse_grave(),
+            TokenKind::MazeWalk => self.parse_mazewalk(),
+            TokenKind::Wallify => self.parse_wallify(),
+            TokenKind::Mineralize => self.parse_mineralize(),
+            TokenKind::NonDiggable => self.parse_non_diggable(),
+            TokenKind::NonPasswall => self.parse_non_passwall(),
+            TokenKind::Terrain => self.parse_terrain(),
+            TokenKind::ReplaceTerrain => self.parse_replace_terrain(),
+            TokenKind::Region => self.parse_region(),
+            TokenKind::Room => self.parse_room(false),
+            TokenKind::Subroom => self.parse_room(true),
+            TokenKind::Corridor => self.parse_corridor(),
+            TokenKind::RandomCorridors => self.parse_random_corridors(),
+            TokenKind::If => self.parse_if(),
+            TokenKind::For => self.parse_for(),
+            TokenKind::Loop => self.parse_loop(),
+            TokenKind::Switch => self.parse_switch(),
+            TokenKind::Function => self.parse_function(),
+            TokenKind::Exit => self.parse_exit(),
+            TokenKind::Break => self.parse_break(),
+            TokenKind::Shuffle => self.parse_shuffle(),
+            TokenKind::Variable => self.parse_variable_assignment(),
+            TokenKind::String
+                if self
+                    .tokens
+                    .get(self.pos + 1)
+                    .is_some_and(|t| t.value == Token::LParen) =>
+            {
+                self.parse_function_call()
+            }
             _ => {
-                let tok = self.peek().clone();
-                Err(self.err(&format!("unexpected token: {tok:?}")))
+                let msg = format!("unexpected token: {:?}", self.peek());
+                Err(self.err(&msg))
             }
         }
     }
@@ -731,21 +988,21 @@ impl Parser {
     }
 
     /// Parse a coordinate: `(x,y)`, `random`, or `$var` / `rndcoord($sel)`.
-    fn parse_coord_or_var(&mut self) -> Result<(), DesParseError> {
+    fn parse_coord_or_var(&mut self) -> Result<DesCoord, DesParseError> {
         match self.peek().clone() {
             Token::Random => {
                 self.advance();
-                self.emit_push_coord(-1, -1, true, 0);
-                Ok(())
+                self.emit_push_coord(-1, -1, true, HumidityFlags::empty());
+                Ok(DesCoord::Random)
             }
             Token::LParen => {
                 self.advance();
-                let x = self.parse_integer()? as i16;
+                let x = self.parse_coord_component()?;
                 self.expect_comma()?;
-                let y = self.parse_integer()? as i16;
+                let y = self.parse_coord_component()?;
                 self.expect(&Token::RParen)?;
-                self.emit_push_coord(x, y, false, 0);
-                Ok(())
+                self.emit_push_coord(x, y, false, HumidityFlags::empty());
+                Ok(DesCoord::At { x, y })
             }
             Token::Variable(name) => {
                 self.advance();
@@ -756,7 +1013,7 @@ impl Parser {
                     self.emit_push_int(idx);
                 }
                 self.emit_push_var(&name);
-                Ok(())
+                Ok(DesCoord::Variable(name))
             }
             Token::RndCoord => {
                 self.advance();
@@ -764,12 +1021,39 @@ impl Parser {
                 self.parse_ter_selection()?;
                 self.expect(&Token::RParen)?;
                 self.emit(SpOpcode::SelRndCoord);
-                Ok(())
+                Ok(DesCoord::RndCoord)
             }
             _ => Err(self.err("expected coordinate, random, or variable")),
         }
     }
 
+    /// One component of a literal `(x,y)`: an integer, or an integer
+    /// variable assigned exactly once, from a literal. sp_lev has no opcode
+    /// that builds a coordinate from run-time values, so the variable is
+    /// folded into the constant coordinate lev_comp would see. A variable
+    /// assigned anywhere else, such as later in a LOOP body, may hold a
+    /// different value when the coordinate is used, so it is rejected.
+    fn parse_coord_component(&mut self) -> Result<i16, DesParseError> {
+        let n =
+            match self.peek().clone() {
+                Token::Variable(name) => match self.vars.get(&name) {
+                    Some(VarDef {
+                        constant: Some(n), ..
+                    }) if self.assignments.get(&name) == Some(&1) => {
+                        let n = *n;
+                        self.advance();
+                        n
+                    }
+                    Some(_) => return Err(self.err(&format!(
+                        "{name} is not a constant integer, so it cannot be a coordinate component"
+                    ))),
+                    None => return Err(self.err(&format!("undefined variable {name}"))),
+                },
+                _ => self.parse_integer()?,
+            };
+        i16::try_from(n).map_err(|_| self.err(&format!("coordinate component {n} is out of range")))
+    }
+
     /// Parse a region: `(x1,y1,x2,y2)` or `$var`.
     fn parse_region_or_var(&mut self) -> Result<(), DesParseError> {
         match self.peek().clone() {
@@ -867,7 +1151,7 @@ impl Parser {
     /// C resolves monster names at compile time via `get_monster_id()` and packs
     /// the result with `SP_MONST_PACK(id, class_char)`. Named monsters emit NO
     /// string push — just the packed Monst operand.
-    fn parse_monster_or_var(&mut self) -> Result<(), DesParseError> {
+    fn parse_monster_or_var(&mut self) -> Result<DesSpec, DesParseError> {
         match self.peek().clone() {
             Token::LParen => {
                 self.advance();
@@ -883,18 +1167,21 @@ impl Parser {
                 self.expect(&Token::RParen)?;
                 let id = get_monster_id(&name, class_char).unwrap_or(-1);
                 self.emit_push_monst(class_char as i16, id);
-                Ok(())
+                Ok(DesSpec::Named {
+                    class: class_char,
+                    name,
+                })
             }
             Token::Char(c) => {
                 self.advance();
                 self.emit_push_monst(c as i16, -1);
-                Ok(())
+                Ok(DesSpec::Class(c))
             }
             Token::Random => {
                 self.advance();
                 // C: -1 unpacks via SP_MONST_CLASS/PM to class=255, id=-11
                 self.emit_push_monst(255, -11);
-                Ok(())
+                Ok(DesSpec::Random)
             }
             Token::Variable(name) => {
                 self.advance();
@@ -905,7 +1192,7 @@ impl Parser {
                     self.emit_push_int(idx);
                 }
                 self.emit_push_var(&name);
-                Ok(())
+                Ok(DesSpec::Variable(name))
             }
             _ => Err(self.err("expected monster spec, random, or variable")),
         }
@@ -916,7 +1203,7 @@ impl Parser {
     /// C resolves object names at compile time via `get_object_id()` and packs
     /// with `SP_OBJ_PACK(id, class_char)`. For name-only objects, C uses class=1
     /// to force specific item generation. Named objects emit NO string push.
-    fn parse_object_or_var(&mut self) -> Result<(), DesParseError> {
+    fn parse_object_or_var(&mut self) -> Result<DesSpec, DesParseError> {
         match self.peek().clone() {
             Token::LParen => {
                 self.advance();
@@ -932,18 +1219,21 @@ impl Parser {
                 self.expect(&Token::RParen)?;
                 let id = get_object_id(&name, class_char).unwrap_or(-1);
                 self.emit_push_obj(class_char as i16, id);
-                Ok(())
+                Ok(DesSpec::Named {
+                    class: class_char,
+                    name,
+                })
             }
             Token::Char(c) => {
                 self.advance();
                 self.emit_push_obj(c as i16, -1);
-                Ok(())
+                Ok(DesSpec::Class(c))
             }
             Token::Random => {
                 self.advance();
                 // C: -1 unpacks via SP_OBJ_CLASS/TYP to class=255, id=-11
                 self.emit_push_obj(255, -11);
-                Ok(())
+                Ok(DesSpec::Random)
             }
             Token::Variable(name) => {
                 self.advance();
@@ -954,7 +1244,7 @@ impl Parser {
                     self.emit_push_int(idx);
                 }
                 self.emit_push_var(&name);
-                Ok(())
+                Ok(DesSpec::Variable(name))
             }
             _ => Err(self.err("expected object spec, random, or variable")),
         }
@@ -1118,16 +1408,33 @@ impl Parser {
                     }
                     _ => return Err(self.err("expected gradient type")),
                 };
+                // `(mindist - maxdist [limited])`
                 self.expect_comma()?;
-                self.parse_math_expr()?; // range
+                self.expect(&Token::LParen)?;
+                self.parse_integer_or_var()?;
+                self.expect(&Token::Minus)?;
+                self.parse_integer_or_var()?;
+                let limited = match self.peek() {
+                    Token::Limited => {
+                        self.advance();
+                        1
+                    }
+                    Token::Unlimited => {
+                        self.advance();
+                        0
+                    }
+                    _ => 0,
+                };
+                self.expect(&Token::RParen)?;
                 self.expect_comma()?;
-                self.parse_coord_or_var()?; // center
-                let limited = if self.peek() == &Token::Comma {
+                self.parse_coord_or_var()?;
+                // Without a second coordinate, lev_comp copies the first.
+                if self.peek() == &Token::Comma {
                     self.advance();
-                    self.parse_integer()?
+                    self.parse_coord_or_var()?;
                 } else {
-                    0
-                };
+                    self.emit(SpOpcode::Copy);
+                }
                 self.expect(&Token::RParen)?;
                 self.emit_push_int(limited);
                 self.emit_push_int(grad_type);
@@ -1147,7 +1454,7 @@ impl Parser {
             }
             Token::Random => {
                 self.advance();
-                self.emit_push_coord(-1, -1, true, 0);
+                self.emit_push_coord(-1, -1, true, HumidityFlags::empty());
                 self.emit(SpOpcode::SelPoint);
                 Ok(())
             }
@@ -1291,20 +1598,20 @@ impl Parser {
         self.advance(); // INIT_MAP
         self.expect_colon()?;
 
-        let style = match self.peek().clone() {
-            Token::Mines => {
+        let style = match self.peek_kind() {
+            TokenKind::Mines => {
                 self.advance();
                 3i64
             }
-            Token::SolidFill => {
+            TokenKind::SolidFill => {
                 self.advance();
                 1
             }
-            Token::MazeGrid => {
+            TokenKind::MazeGrid => {
                 self.advance();
                 2
             }
-            Token::RogueLev => {
+            TokenKind::RogueLev => {
                 self.advance();
                 4
             }
@@ -1318,12 +1625,8 @@ impl Parser {
             // INIT_MAP: mines, '.' , ' ' , true , true , random , true
             self.expect_comma()?;
             let fg = self.parse_char_or_random()?;
-            self.emit_push_int(fg);
             self.expect_comma()?;
             let bg = self.parse_char_or_random()?;
-            // For mines: params order is style, filling(fg), walled, lit, joined, smoothed, bg, fg
-            // Actually looking at the C: LVLINIT_MINES, filling, walled, lit, joined, smoothed, bg, fg
-            // Let me just push the remaining params
             self.expect_comma()?;
             let smoothed = self.parse_bool_or_random()?;
             self.expect_comma()?;
@@ -1332,24 +1635,19 @@ impl Parser {
             let lit = self.parse_bool_or_random()?;
             self.expect_comma()?;
             let walled = self.parse_bool_or_random()?;
-            // Push remaining 6 params: filling=fg, walled, lit, joined, smoothed, bg
-            // Actually the C order for INITLEVEL is 8 pushes total:
-            // style, filling, smoothed, lit, joined, hushed, bg, fg
-            // We already pushed style. Now push the rest.
-            // Wait - we already pushed style and fg... Let me re-read the pattern.
-            // Actually in C it's: add_opvars(splev, "iiiiiiiio",
-            //   LVLINIT_MINES, filling, walled, lit, joined, smoothed, bg, fg, SPO_INITLEVEL)
-            // So we push: style, filling, walled, lit, joined, smoothed, bg, fg
-            // We pushed style. Now filling=fg_char above. Then the rest.
-            // But we already pushed fg as the filling. Let me emit the remaining 5.
+            // C: add_opvars(splev, "iiiiiiiio", LVLINIT_MINES, filling, walled,
+            //   lit, joined, smoothed, bg, fg, SPO_INITLEVEL). The optional
+            //   trailing fill char isn't supported, so filling is always -1.
+            self.emit_push_int(-1);
             self.emit_push_int(walled);
             self.emit_push_int(lit);
             self.emit_push_int(joined);
             self.emit_push_int(smoothed);
             self.emit_push_int(bg);
+            self.emit_push_int(fg);
         } else if style == 4 {
             // rogue: no extra params
-            for _ in 0..6 {
+            for _ in 0..7 {
                 self.emit_push_int(0);
             }
         } else {
@@ -1357,7 +1655,7 @@ impl Parser {
             self.expect_comma()?;
             let filling = self.parse_char_or_random()?;
             self.emit_push_int(filling);
-            for _ in 0..5 {
+            for _ in 0..6 {
                 self.emit_push_int(0);
             }
         }
@@ -1414,11 +1712,13 @@ impl Parser {
         let h = self.parse_halign()?;
         self.expect_comma()?;
         let v = self.parse_valign()?;
-        // C's `roomfill` production defaults to 1 when not explicitly specified
-        self.roomfill = 1;
-        self.emit_push_coord(h, v, false, 0);
-        self.emit_push_int(1); // has geometry
-        self.emit_push_int(self.roomfill);
+        // lev_comp reads GEOMETRY and MAP as one rule, so the placement is
+        // emitted with the map. C's `roomfill` defaults to 1.
+        self.pending_geometry = Some(MapGeometry {
+            halign: h,
+            valign: v,
+            roomfill: 1,
+        });
         Ok(())
     }
 
@@ -1476,9 +1776,10 @@ impl Parser {
 
     fn parse_nomap(&mut self) -> Result<(), DesParseError> {
         self.advance(); // NOMAP
+        self.pending_geometry = None;
         // C: add_opvars(splev, "ciisiio",
         //     VA_PASS7(0, 0, 1, (char *) 0, 0, 0, SPO_MAP));
-        self.emit_push_coord(0, 0, false, 0);
+        self.emit_push_coord(0, 0, false, HumidityFlags::empty());
         self.emit_push_int(0); // not has_geom
         self.emit_push_int(1); // nomap marker
         self.emit_push_str("");
@@ -1489,8 +1790,10 @@ impl Parser {
     }
 
     fn parse_map_statement(&mut self) -> Result<(), DesParseError> {
+        let line = self.current_line();
         self.advance(); // Map token
         // Next token should be MapData
+        let data_line = self.current_line();
         let map_data = match self.peek().clone() {
             Token::MapData(s) => {
                 self.advance();
@@ -1499,33 +1802,80 @@ impl Parser {
             _ => return Err(self.err("expected map data after MAP")),
         };
 
+        // C: GEOMETRY pushes coord(halign, valign), 1, roomfill. A MAP with
+        // no GEOMETRY is centred, as in later versions' des.map().
+        let geometry = self.pending_geometry.take().unwrap_or(MapGeometry {
+            halign: ALIGN_CENTER,
+            valign: ALIGN_CENTER,
+            roomfill: 1,
+        });
+        self.emit_push_coord(
+            geometry.halign,
+            geometry.valign,
+            false,
+            HumidityFlags::empty(),
+        );
+        self.emit_push_int(1); // has geometry
+        self.emit_push_int(geometry.roomfill);
+
         // Replicate C's scan_map(): strip digits, convert chars, pad rows
         let converted = scan_map(&map_data);
+        // Vanilla has no comments inside MAP: a `#` line is corridor, and
+        // its words are invalid characters that silently become stone.
+        for &(y, ch, count) in &converted.invalid_rows {
+            let row = map_data.split('\n').nth(y).unwrap_or_default();
+            let mut msg =
+                format!("{count} invalid MAP character(s), first {ch:?}, replaced with stone");
+            if row.trim_start().starts_with('#') {
+                msg.push_str(" (comments are not allowed inside MAP)");
+            }
+            self.warnings.push(DesWarning {
+                line: data_line + y,
+                msg,
+            });
+        }
         self.emit_push_str(&converted.data);
         self.emit_push_int(converted.height as i64);
         self.emit_push_int(converted.width as i64);
         self.emit(SpOpcode::Map);
+        self.visit(|v| {
+            v.on_map(&MapStatement {
+                line,
+                map: map_data,
+                width: converted.width,
+                height: converted.height,
+            })
+        });
         Ok(())
     }
 
     fn parse_message(&mut self) -> Result<(), DesParseError> {
         self.advance(); // MESSAGE
         self.expect_colon()?;
-        self.parse_string_expr()?;
-        self.emit(SpOpcode::Message);
+        // `MESSAGE:"a","b"` queues one message per string.
+        loop {
+            self.parse_string_expr()?;
+            self.emit(SpOpcode::Message);
+            if self.peek() != &Token::Comma {
+                break;
+            }
+            self.advance();
+        }
         Ok(())
     }
 
     fn parse_monster(&mut self) -> Result<(), DesParseError> {
+        let line = self.current_line();
         self.advance(); // MONSTER
         self.expect_colon()?;
 
         // C: monster_desc = monster_or_var ',' coord_or_var monster_infos
         // monster_or_var pushes monster spec first
-        self.parse_monster_or_var()?;
+        let spec = self.parse_monster_or_var()?;
         self.expect_comma()?;
         // coord_or_var pushes coord
-        self.parse_coord_or_var()?;
+        let coord = self.parse_coord_or_var()?;
+        self.visit(|v| v.on_monster(&MonsterStatement { line, spec, coord }));
 
         // monster_infos base case pushes End sentinel
         self.emit_push_int(SpMonVarFlag::End as i64);
@@ -1644,44 +1994,44 @@ impl Parser {
     }
 
     fn parse_object(&mut self) -> Result<(), DesParseError> {
+        let line = self.current_line();
         self.advance(); // OBJECT
         self.expect_colon()?;
 
         // C: object_desc = object_or_var object_infos
         // object_or_var pushes the object spec FIRST
-        self.parse_object_or_var()?;
+        let spec = self.parse_object_or_var()?;
+        let mut coord = None;
 
         // C: object_infos base case pushes End sentinel
         self.emit_push_int(SpObjVarFlag::End as i64);
 
         // Coordinate is a modifier in C's grammar (object_info: coord_or_var)
-        if self.peek() == &Token::Comma {
-            // Peek past comma to see if next looks like a coord or modifier
-            let next_pos = self.pos + 1;
-            let next_is_coord = if next_pos < self.tokens.len() {
+        let next_is_coord = self.peek() == &Token::Comma
+            && self.tokens.get(self.pos + 1).is_some_and(|t| {
                 matches!(
-                    self.tokens[next_pos].value,
+                    t.value,
                     Token::LParen | Token::Random | Token::Variable(_) | Token::RndCoord
                 )
-            } else {
-                false
-            };
-
-            if next_is_coord {
+            });
+        match (next_is_coord, self.is_in_container()) {
+            // Contents go inside the container, so lev_comp rejects a coord.
+            (true, true) => return Err(self.err("object cannot have a coord when contained")),
+            (true, false) => {
                 self.advance(); // consume comma
-                self.parse_coord_or_var()?;
+                coord = Some(self.parse_coord_or_var()?);
                 self.emit_push_int(SpObjVarFlag::Coord as i64);
-            } else if !self.is_in_container() {
-                // No coord and not in container — push random coord
-                self.emit_push_coord(-1, -1, true, 0);
+            }
+            (false, true) => {}
+            // lev_comp requires a coord here; default it to a random one.
+            (false, false) => {
+                self.emit_push_coord(-1, -1, true, HumidityFlags::empty());
                 self.emit_push_int(SpObjVarFlag::Coord as i64);
             }
-        } else if !self.is_in_container() {
-            // No coord and not in container — push random coord
-            self.emit_push_coord(-1, -1, true, 0);
-            self.emit_push_int(SpObjVarFlag::Coord as i64);
         }
 
+        self.visit(|v| v.on_object(&ObjectStatement { line, spec, coord }));
+
         // Parse optional modifiers
         self.parse_object_modifiers()?;
 
@@ -1709,15 +2059,17 @@ impl Parser {
                 Token::MonType => {
                     self.advance();
                     self.expect_colon()?;
-                    // montype can be "string" or 'c' (char class)
-                    match self.peek().clone() {
-                        Token::Char(c) => {
-                            self.advance();
-                            self.emit_push_str(&c.to_string());
-                        }
-                        _ => {
-                            self.parse_string_expr()?;
-                        }
+                    // Like lev_comp's encodemonster: a bare name packs the
+                    // monster with its own class symbol, anything else is a
+                    // monster spec.
+                    if let Token::String(name) = self.peek().clone() {
+                        let Some(id) = get_monster_id(&name, '\0') else {
+                            return Err(self.err(&format!("Unknown monster \"{name}\"")));
+                        };
+                        self.advance();
+                        self.emit_push_monst(MONSTERS[id as usize].symbol as i16, id);
+                    } else {
+                        self.parse_monster_or_var()?;
                     }
                     self.emit_push_int(SpObjVarFlag::CorpseNm as i64);
                 }
@@ -1730,7 +2082,14 @@ impl Parser {
                 Token::Quantity => {
                     self.advance();
                     self.expect_colon()?;
-                    self.parse_integer_or_var()?;
+                    if self.peek() == &Token::Random {
+                        // -1 is sp_lev.c's "no quantity given" default, which
+                        // keeps the normally generated stack size.
+                        self.advance();
+                        self.emit_push_int(-1);
+                    } else {
+                        self.parse_integer_or_var()?;
+                    }
                     self.emit_push_int(SpObjVarFlag::Quan as i64);
                 }
                 Token::Buried => {
@@ -1880,12 +2239,18 @@ impl Parser {
         };
         self.expect_comma()?;
         self.parse_coord_or_var()?;
+        // SPO_TRAP has no victim operand: mktrap() makes a statue trap's
+        // statue itself, from a random monster.
+        if self.peek() == &Token::Comma {
+            return Err(self.err("TRAP takes only a trap type and a location"));
+        }
         self.emit_push_int(trap_id);
         self.emit(SpOpcode::Trap);
         Ok(())
     }
 
     fn parse_door(&mut self) -> Result<(), DesParseError> {
+        let line = self.current_line();
         self.advance(); // DOOR
         self.expect_colon()?;
         let state = self.parse_door_state()?;
@@ -1894,6 +2259,7 @@ impl Parser {
         self.parse_ter_selection()?;
         self.emit_push_int(state);
         self.emit(SpOpcode::Door);
+        self.visit(|v| v.on_door(&DoorStatement { line, state }));
         Ok(())
     }
 
@@ -1901,11 +2267,11 @@ impl Parser {
         match self.peek().clone() {
             Token::DoorState(ref s) => {
                 let val = match s.as_str() {
-                    "open" => 1i64,
-                    "closed" => 2,
-                    "locked" => 4,
-                    "nodoor" => 8,
-                    "broken" => 16,
+                    "nodoor" => 0i64,
+                    "broken" => 1,
+                    "open" => 2,
+                    "closed" => 4,
+                    "locked" => 8,
                     "secret" => 32,
                     _ => return Err(self.err(&format!("unknown door state: {s}"))),
                 };
@@ -2000,23 +2366,29 @@ impl Parser {
         self.expect_colon()?;
         self.parse_coord_or_var()?;
         self.expect_comma()?;
-        let dir = self.parse_direction()?;
+        // C's DIRECTION token is a single cardinal; combinations, random
+        // and horizontal/vertical are all rejected.
+        let db_dir = match self.peek() {
+            Token::North => 0, // DB_NORTH
+            Token::South => 1, // DB_SOUTH
+            Token::East => 2,  // DB_EAST
+            Token::West => 3,  // DB_WEST
+            _ => return Err(self.err("Invalid drawbridge direction.")),
+        };
+        self.advance();
+        if self.peek() == &Token::Pipe {
+            return Err(self.err("Invalid drawbridge direction."));
+        }
         self.expect_comma()?;
         let raw_state = self.parse_door_state()?;
-        // C normalizes: D_ISOPEN(1)→1, D_CLOSED(2)→0, random(-1)→-1
+        // C normalizes: D_ISOPEN(2)→1, D_CLOSED(4)→0, random(-1)→-1
         let state = match raw_state {
-            1 => 1,   // open
-            2 => 0,   // closed
+            2 => 1,   // open
+            4 => 0,   // closed
             -1 => -1, // random
-            _ => 0,
-        };
-        // C converts W_NORTH/S/E/W(1/2/4/8) → DB_NORTH/S/E/W(0/1/2/3)
-        let db_dir = match dir {
-            1 => 0, // W_NORTH → DB_NORTH
-            2 => 1, // W_SOUTH → DB_SOUTH
-            4 => 2, // W_EAST → DB_EAST
-            8 => 3, // W_WEST → DB_WEST
-            _ => dir,
+            _ => {
+                return Err(self.err("A drawbridge can only be open, closed or random!"));
+            }
         };
         self.emit_push_int(state);
         self.emit_push_int(db_dir);
@@ -2477,21 +2849,21 @@ impl Parser {
 
     fn parse_terrain_selection(&mut self) -> Result<(), DesParseError> {
         // Check for selection function keywords
-        match self.peek().clone() {
-            Token::FillRect
-            | Token::Rect
-            | Token::Line
-            | Token::RandLine
-            | Token::Grow
-            | Token::FloodFill
-            | Token::Filter
-            | Token::Complement
-            | Token::Ellipse
-            | Token::Circle
-            | Token::Gradient => {
+        match self.peek_kind() {
+            TokenKind::FillRect
+            | TokenKind::Rect
+            | TokenKind::Line
+            | TokenKind::RandLine
+            | TokenKind::Grow
+            | TokenKind::FloodFill
+            | TokenKind::Filter
+            | TokenKind::Complement
+            | TokenKind::Ellipse
+            | TokenKind::Circle
+            | TokenKind::Gradient => {
                 self.parse_ter_selection_x()?;
             }
-            Token::LParen => {
+            TokenKind::LParen => {
                 // Could be a coord (2 values) or a selection function in parens
                 // like (randline (37,7),(62,02),7)
                 let next_pos = self.pos + 1;
@@ -2521,12 +2893,12 @@ impl Parser {
                 self.parse_coord_or_var()?;
                 self.emit(SpOpcode::SelPoint);
             }
-            Token::Variable(_) => {
+            TokenKind::Variable => {
                 self.parse_coord_or_var()?;
                 // If it's a selection var, no SelPoint needed; if coord, we need it
                 // For now, just push the variable (runtime resolves type)
             }
-            Token::Random => {
+            TokenKind::Random => {
                 self.parse_coord_or_var()?;
                 self.emit(SpOpcode::SelPoint);
             }
@@ -2540,7 +2912,12 @@ impl Parser {
     fn parse_replace_terrain(&mut self) -> Result<(), DesParseError> {
         self.advance(); // REPLACE_TERRAIN
         self.expect_colon()?;
-        self.parse_region_or_var()?;
+        // The area is a plain region or variable, or any selection expression.
+        if self.is_region_4_ahead() || matches!(self.peek(), Token::Variable(_)) {
+            self.parse_region_or_var()?;
+        } else {
+            self.parse_ter_selection()?;
+        }
         self.expect_comma()?;
         self.parse_mapchar_or_var()?; // from terrain
         self.expect_comma()?;
@@ -2585,17 +2962,18 @@ impl Parser {
             _ => -1,
         };
         self.expect_comma()?;
-        let room_type_str = self.parse_string()?;
-        let room_type = room_type_to_int(&room_type_str);
+        let mut room_type = self.parse_room_type()?;
 
-        // Optional modifiers: filled/unfilled, irregular, joined
-        let mut region_flags = 0i64;
+        // Optional modifiers: filled/unfilled, irregular, joined. Without
+        // any, lev_comp defaults to filled.
+        let mut region_flags = None;
         while self.peek() == &Token::Comma {
             self.advance();
+            let flags = region_flags.get_or_insert(0i64);
             match self.peek() {
                 Token::Filled => {
                     self.advance();
-                    region_flags |= 1;
+                    *flags |= 1;
                 }
                 Token::Unfilled => {
                     self.advance();
@@ -2603,7 +2981,7 @@ impl Parser {
                 }
                 Token::Irregular => {
                     self.advance();
-                    region_flags |= 2;
+                    *flags |= 2;
                 }
                 Token::Regular => {
                     self.advance();
@@ -2613,33 +2991,59 @@ impl Parser {
                 }
                 Token::Unjoined => {
                     self.advance();
-                    region_flags |= 4;
+                    *flags |= 4;
+                }
+                Token::Limited | Token::Unlimited => {
+                    return Err(self.err("limited/unlimited is only valid in a gradient selection"));
                 }
                 _ => break,
             }
         }
+        let region_flags = region_flags.unwrap_or(1);
+        // An unfilled region is marked by offsetting its type past
+        // MAXRTYPE, which tells the interpreter not to stock it.
+        if region_flags & 1 == 0 {
+            room_type += i64::from(RoomType::MAX) + 1;
+        }
 
         self.emit_push_int(lit);
         self.emit_push_int(room_type);
         self.emit_push_int(region_flags);
         self.emit(SpOpcode::Region);
 
-        // Optional block with inline statements (e.g. ROOMDOOR inside REGION)
+        // Optional block with the region's contents (e.g. ROOMDOOR). Every
+        // region lev_comp accepts is "permanent" (irregular, filled, or with
+        // an offset type), so its room is always closed with ENDROOM.
         if self.peek() == &Token::LBrace {
             self.advance();
             self.parse_block()?;
             self.expect(&Token::RBrace)?;
         }
+        self.emit(SpOpcode::EndRoom);
         Ok(())
     }
 
+    /// A quoted room type name, as its `RoomType` value. Like lev_comp's
+    /// `get_room_type()`, an unknown name warns and makes an ordinary room.
+    fn parse_room_type(&mut self) -> Result<i64, DesParseError> {
+        let line = self.current_line();
+        let name = self.parse_string()?;
+        let rt = RoomType::from_des_name(&name).unwrap_or_else(|| {
+            self.warnings.push(DesWarning {
+                line,
+                msg: format!("unknown room type \"{name}\", making an ordinary room"),
+            });
+            RoomType::Ordinary
+        });
+        Ok(rt as i64)
+    }
+
     fn parse_room(&mut self, is_sub: bool) -> Result<(), DesParseError> {
         self.advance(); // ROOM or SUBROOM
         self.expect_colon()?;
 
         // room_begin: type [pct%], lit
-        let room_type_str = self.parse_string()?;
-        let room_type = room_type_to_int(&room_type_str);
+        let room_type = self.parse_room_type()?;
 
         let chance = if let Token::Percent(n) = *self.peek() {
             self.advance();
@@ -2764,6 +3168,13 @@ impl Parser {
                     | Token::Unjoined => {
                         self.advance(); // comma
                     }
+                    // C has no room flag for these; they only bound a gradient.
+                    Token::Limited | Token::Unlimited => {
+                        self.advance(); // comma
+                        return Err(
+                            self.err("limited/unlimited is only valid in a gradient selection")
+                        );
+                    }
                     _ => break,
                 }
             } else {
@@ -2897,6 +3308,16 @@ impl Parser {
         self.expect_colon()?;
         match self.peek().clone() {
             Token::Variable(name) => {
+                // lev_comp rejects unknown and non-array variables here.
+                match self.vars.get(&name) {
+                    None => {
+                        return Err(self.err(&format!("SHUFFLE of undeclared variable ${name}")));
+                    }
+                    Some(var) if !var.is_array => {
+                        return Err(self.err(&format!("SHUFFLE of non-array variable ${name}")));
+                    }
+                    Some(_) => {}
+                }
                 self.advance();
                 // C preserves $ prefix in variable names
                 let var_name = if name.starts_with('$') {
@@ -3013,6 +3434,7 @@ impl Parser {
 
         let loop_start = self.current_offset();
 
+        self.break_targets.push(Vec::new());
         self.expect(&Token::LBrace)?;
         self.parse_block()?;
         self.expect(&Token::RBrace)?;
@@ -3030,6 +3452,7 @@ impl Parser {
         let jmp_offset = loop_start as i64 - self.current_offset() as i64 - 1;
         self.emit_push_int(jmp_offset);
         self.emit(SpOpcode::Jne);
+        self.patch_breaks();
 
         // Track variable
         self.vars.insert(
@@ -3037,6 +3460,7 @@ impl Parser {
             VarDef {
                 typ: VarType::Int,
                 is_array: false,
+                constant: None,
             },
         );
 
@@ -3055,6 +3479,7 @@ impl Parser {
         let loop_top = self.current_offset();
         self.emit(SpOpcode::Dec);
 
+        self.break_targets.push(Vec::new());
         self.expect(&Token::LBrace)?;
         self.parse_block()?;
         self.expect(&Token::RBrace)?;
@@ -3066,12 +3491,17 @@ impl Parser {
         let jmp_offset = loop_top as i64 - self.current_offset() as i64 - 1;
         self.emit_push_int(jmp_offset);
         self.emit(SpOpcode::Jg);
+        // Breaks land on the POP too, so they do not leave the count behind.
+        self.patch_breaks();
         self.emit(SpOpcode::Pop); // discard counter
 
         Ok(())
     }
 
     fn parse_switch(&mut self) -> Result<(), DesParseError> {
+        if self.in_switch {
+            return Err(self.err("Cannot nest switch-statements."));
+        }
         self.advance(); // SWITCH
         self.expect(&Token::LBracket)?;
         self.parse_math_expr()?;
@@ -3085,32 +3515,39 @@ impl Parser {
         // Collect case bodies and default
         let mut case_addresses: Vec<(i64, usize)> = Vec::new(); // (case_value, body_start_offset)
         let mut default_address: Option<usize> = None;
-        let mut break_targets: Vec<usize> = Vec::new();
+        self.break_targets.push(Vec::new());
+        self.in_switch = true;
 
         self.expect(&Token::LBrace)?;
 
         loop {
-            match self.peek().clone() {
-                Token::Case => {
+            match self.peek_kind() {
+                TokenKind::Case => {
                     self.advance();
                     let val = self.parse_integer()?;
                     self.expect_colon()?;
                     case_addresses.push((val, self.current_offset()));
-                    self.parse_case_body(&mut break_targets)?;
+                    self.parse_case_body()?;
                 }
-                Token::Default => {
+                TokenKind::Default => {
                     self.advance();
                     self.expect_colon()?;
                     default_address = Some(self.current_offset());
-                    self.parse_case_body(&mut break_targets)?;
+                    self.parse_case_body()?;
                 }
-                Token::RBrace => {
+                TokenKind::RBrace => {
                     self.advance();
                     break;
                 }
                 _ => return Err(self.err("expected CASE, DEFAULT, or '}'")),
             }
         }
+        self.in_switch = false;
+
+        // The last body falls through past the case checks
+        let end_jmp_idx = self.current_offset();
+        self.emit_push_int(end_jmp_idx as i64 + 1);
+        self.emit(SpOpcode::Jmp);
 
         // Now emit the case-checking code
         self.patch_jump(check_jmp_idx);
@@ -3130,65 +3567,128 @@ impl Parser {
             self.emit(SpOpcode::Jmp);
         }
 
-        // Pop the switch value
+        // Breaks and the fall-through land on the POP of the switch value
+        self.patch_jump(end_jmp_idx);
+        self.patch_breaks();
         self.emit(SpOpcode::Pop);
 
-        // Patch all break targets to here
-        let end_offset = self.current_offset();
-        for idx in break_targets {
-            if let Some(SpLevOpcode {
-                operand: Some(SpOperand::Int(val)),
-                ..
-            }) = self.opcodes.get_mut(idx)
-            {
-                *val = end_offset as i64 - *val;
-            }
+        Ok(())
+    }
+
+    /// Close the innermost break scope, sending its BREAKs here.
+    fn patch_breaks(&mut self) {
+        for idx in self.break_targets.pop().unwrap_or_default() {
+            self.patch_jump(idx);
         }
+    }
 
+    fn parse_case_body(&mut self) -> Result<(), DesParseError> {
+        let mut exit_line = None;
+        while !matches!(self.peek(), Token::Case | Token::Default | Token::RBrace) {
+            self.parse_block_statement(&mut exit_line)?;
+        }
         Ok(())
     }
 
-    fn parse_case_body(&mut self, break_targets: &mut Vec<usize>) -> Result<(), DesParseError> {
-        loop {
-            match self.peek() {
-                Token::Case | Token::Default | Token::RBrace => break,
-                Token::Break => {
-                    self.advance();
-                    let idx = self.current_offset();
-                    self.emit_push_int(idx as i64 + 1);
-                    self.emit(SpOpcode::Jmp);
-                    break_targets.push(idx);
-                }
-                _ => {
-                    // Check for percent prefix
-                    let pct = self.try_percent_prefix()?;
-                    if let Some(pct_val) = pct {
-                        self.expect_colon()?;
-                        self.parse_pct_statement(pct_val)?;
-                    } else {
-                        self.parse_statement()?;
-                    }
-                }
-            }
+    /// BREAK leaves the innermost SWITCH, LOOP or FOR, from any depth
+    /// inside it.
+    fn parse_break(&mut self) -> Result<(), DesParseError> {
+        if self.break_targets.is_empty() {
+            return Err(self.err("BREAK outside of SWITCH, LOOP or FOR"));
+        }
+        self.advance(); // BREAK
+        let idx = self.current_offset();
+        self.emit_push_int(idx as i64 + 1);
+        self.emit(SpOpcode::Jmp);
+        if let Some(targets) = self.break_targets.last_mut() {
+            targets.push(idx);
         }
         Ok(())
     }
 
+    /// `FUNCTION name() { body }`. As in lev_comp, the body is compiled
+    /// aside, ending in `RETURN`, and only emitted at the first call.
     fn parse_function(&mut self) -> Result<(), DesParseError> {
         self.advance(); // FUNCTION
-        // Function definitions not commonly used in standard .des files;
-        // skip to end of function body
-        // FUNCTION name ( params ) { body }
-        let _name = self.parse_string()?;
+        if self.in_function {
+            return Err(self.err("cannot define a FUNCTION inside another"));
+        }
+        let name = self.parse_string()?;
+        if self.functions.contains_key(&name) {
+            return Err(self.err(&format!("FUNCTION {name} is already defined")));
+        }
         self.expect(&Token::LParen)?;
-        // Skip params
-        while self.peek() != &Token::RParen && self.peek() != &Token::Eof {
-            self.advance();
+        if self.peek() != &Token::RParen {
+            return Err(self.err("FUNCTION parameters are not supported"));
         }
-        self.expect(&Token::RParen)?;
+        self.advance(); // )
         self.expect(&Token::LBrace)?;
-        self.parse_block()?;
+
+        let opcodes = std::mem::take(&mut self.opcodes);
+        let hints = std::mem::take(&mut self.hints);
+        let break_targets = std::mem::take(&mut self.break_targets);
+        self.in_function = true;
+        let body = self.parse_block();
+        self.in_function = false;
+        let code = std::mem::replace(&mut self.opcodes, opcodes);
+        let body_hints = std::mem::replace(&mut self.hints, hints);
+        self.break_targets = break_targets;
+        body?;
         self.expect(&Token::RBrace)?;
+
+        let mut def = FunctionDef {
+            code,
+            hints: body_hints,
+            addr: None,
+        };
+        // C: add_opvars(splev, "io", VA_PASS2(0, SPO_RETURN))
+        def.code.push(SpLevOpcode {
+            opcode: SpOpcode::Push,
+            operand: Some(SpOperand::Int(0)),
+        });
+        def.code.push(SpLevOpcode {
+            opcode: SpOpcode::Return,
+            operand: None,
+        });
+        self.functions.insert(name, def);
+        Ok(())
+    }
+
+    /// `name()`. The first call jumps over a copy of the body, which every
+    /// call then reaches through `CALL`.
+    fn parse_function_call(&mut self) -> Result<(), DesParseError> {
+        let name = self.parse_string()?;
+        self.expect(&Token::LParen)?;
+        self.expect(&Token::RParen)?;
+        let base = self.current_offset();
+        let Some(def) = self.functions.get_mut(&name) else {
+            return Err(self.err(&format!("FUNCTION {name} is not defined")));
+        };
+        let addr = match def.addr {
+            Some(addr) => addr,
+            None => {
+                // Body offsets move past the PUSH/JMP that skips it.
+                let addr = base + 2;
+                def.addr = Some(addr);
+                let code = def.code.clone();
+                let hints: Vec<_> = def
+                    .hints
+                    .iter()
+                    .map(|(o, h)| (o + addr, h.clone()))
+                    .collect();
+                self.emit_push_int(base as i64 + 1);
+                self.emit(SpOpcode::Jmp);
+                self.opcodes.extend(code);
+                self.hints.extend(hints);
+                self.patch_jump(base);
+                addr
+            }
+        };
+        // C: add_opvars(splev, "iio", VA_PASS3(nparams, l, SPO_CALL))
+        let call = self.current_offset() + 2;
+        self.emit_push_int(0);
+        self.emit_push_int(addr as i64 - call as i64);
+        self.emit(SpOpcode::Call);
         Ok(())
     }
 
@@ -3223,20 +3723,20 @@ impl Parser {
                             break;
                         }
                     }
-                    match self.peek().clone() {
-                        Token::LParen => {
+                    match self.peek_kind() {
+                        TokenKind::LParen => {
                             self.parse_coord_or_var()?;
                             var_type = VarType::Coord;
                         }
-                        Token::Char(_) => {
+                        TokenKind::Char => {
                             self.parse_mapchar_or_var()?;
                             var_type = VarType::MapChar;
                         }
-                        Token::Integer(_) | Token::Dice { .. } => {
+                        TokenKind::Integer | TokenKind::Dice => {
                             self.parse_math_expr()?;
                             var_type = VarType::Int;
                         }
-                        Token::String(_) => {
+                        TokenKind::String => {
                             self.parse_string_expr()?;
                             var_type = VarType::String;
                         }
@@ -3253,6 +3753,7 @@ impl Parser {
                     VarDef {
                         typ: var_type,
                         is_array: true,
+                        constant: None,
                     },
                 );
             }
@@ -3267,6 +3768,7 @@ impl Parser {
                     VarDef {
                         typ: VarType::Sel,
                         is_array: false,
+                        constant: None,
                     },
                 );
             }
@@ -3309,6 +3811,7 @@ impl Parser {
                     VarDef {
                         typ: VarType::MapChar,
                         is_array: true,
+                        constant: None,
                     },
                 );
             }
@@ -3324,13 +3827,20 @@ impl Parser {
             }
             _ => {
                 // Scalar: math expr, string, coord, etc.
+                let start = self.pos;
+                let first = self.peek().clone();
                 self.parse_math_expr()?;
+                let constant = match first {
+                    Token::Integer(n) if self.pos == start + 1 => Some(n),
+                    _ => None,
+                };
                 self.emit_var_init(&name, 0);
                 self.vars.insert(
                     name,
                     VarDef {
                         typ: VarType::Int,
                         is_array: false,
+                        constant,
                     },
                 );
             }
@@ -3368,6 +3878,7 @@ impl Parser {
             VarDef {
                 typ: var_type,
                 is_array: true,
+                constant: None,
             },
         );
         Ok(())
@@ -3375,19 +3886,9 @@ impl Parser {
 
     /// Parse a block of statements (inside { }).
     fn parse_block(&mut self) -> Result<(), DesParseError> {
-        loop {
-            match self.peek() {
-                Token::RBrace | Token::Eof => break,
-                _ => {
-                    let pct = self.try_percent_prefix()?;
-                    if let Some(pct_val) = pct {
-                        self.expect_colon()?;
-                        self.parse_pct_statement(pct_val)?;
-                    } else {
-                        self.parse_statement()?;
-                    }
-                }
-            }
+        let mut exit_line = None;
+        while !matches!(self.peek(), Token::RBrace | Token::Eof) {
+            self.parse_block_statement(&mut exit_line)?;
         }
         Ok(())
     }
@@ -3395,43 +3896,180 @@ impl Parser {
 
 /// Parse a `.des` file from its token stream.
 pub fn parse_des(tokens: Vec<Located<Token>>) -> Result<DesFile, DesParseError> {
-    Parser::new(tokens).parse()
+    Ok(parse_des_with_options(tokens, &ParseOptions::default())?.des)
+}
+
+/// Parse a `.des` file from its token stream with non-default [`ParseOptions`].
+pub fn parse_des_with_options(
+    tokens: Vec<Located<Token>>,
+    options: &ParseOptions,
+) -> Result<ParsedDes, DesParseError> {
+    Parser::new(tokens, *options).parse()
+}
+
+/// Parse a `.des` file, reporting each statement to `visitor` as it is parsed.
+pub fn parse_des_visit(
+    tokens: Vec<Located<Token>>,
+    visitor: &mut impl StatementVisitor,
+) -> Result<DesFile, DesParseError> {
+    let mut parser = Parser::new(tokens, ParseOptions::default());
+    parser.visitor = Some(visitor);
+    Ok(parser.parse()?.des)
 }
 
 /// Parse a `.des` file from source text (lex + parse).
-pub fn parse_des_file(input: &str) -> Result<DesFile, Box<dyn std::error::Error>> {
+pub fn parse_des_file(input: &str) -> Result<DesFile, crate::Error> {
     let tokens = crate::des_lexer::lex(input)?;
     let des = parse_des(tokens)?;
     Ok(des)
 }
 
-fn room_type_to_int(s: &str) -> i64 {
-    match s {
-        "ordinary" => 0,
-        "throne" => 2,
-        "swamp" => 3,
-        "vault" => 4,
-        "beehive" => 5,
-        "morgue" => 6,
-        "barracks" => 7,
-        "zoo" => 8,
-        "delphi" => 9,
-        "temple" => 10,
-        "anthole" => 11,
-        "cocknest" => 12,
-        "leprehall" => 13,
-        "shop" => 14,
-        "armor shop" => 14,
-        "scroll shop" => 14,
-        "potion shop" => 14,
-        "weapon shop" => 14,
-        "food shop" => 14,
-        "ring shop" => 14,
-        "wand shop" => 14,
-        "tool shop" => 14,
-        "book shop" => 14,
-        "candle shop" => 14,
-        _ => 0,
+/// How serious a [`Lint`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The file compiles, but probably not as intended.
+    Warning,
+    /// The file does not compile.
+    Error,
+}
+
+/// One finding of [`lint`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("line {line}: {severity:?}: {msg}")]
+pub struct Lint {
+    pub line: usize,
+    pub severity: Severity,
+    pub msg: String,
+}
+
+/// Check a `.des` source for likely mistakes, sorted by line.
+///
+/// Reports unbalanced braces, lexer and parser errors (such as a GEOMETRY
+/// with no MAP), unreachable statements, monster and object names that do
+/// not resolve, and literal coordinates outside the current MAP, or outside
+/// the level when there is none. Parsing stops at the first error, so only
+/// the problems before it are found.
+pub fn lint(input: &str) -> Vec<Lint> {
+    let tokens = match crate::des_lexer::lex(input) {
+        Ok(tokens) => tokens,
+        Err(crate::des_lexer::LexError::Error { line, msg, .. }) => {
+            return vec![Lint {
+                line,
+                severity: Severity::Error,
+                msg,
+            }];
+        }
+    };
+
+    let mut lints = Vec::new();
+    let mut open = Vec::new();
+    for tok in &tokens {
+        match tok.value {
+            Token::LBrace => open.push(tok.line),
+            Token::RBrace if open.pop().is_none() => lints.push(Lint {
+                line: tok.line,
+                severity: Severity::Error,
+                msg: "'}' without a matching '{'".into(),
+            }),
+            _ => {}
+        }
+    }
+    let unbalanced = !lints.is_empty() || !open.is_empty();
+    lints.extend(open.into_iter().map(|line| Lint {
+        line,
+        severity: Severity::Error,
+        msg: "'{' is never closed".into(),
+    }));
+
+    let mut linter = Linter::default();
+    let mut parser = Parser::new(tokens, ParseOptions::default());
+    parser.visitor = Some(&mut linter);
+    match parser.parse() {
+        Ok(parsed) => lints.extend(parsed.warnings.into_iter().map(|w| Lint {
+            line: w.line,
+            severity: Severity::Warning,
+            msg: w.msg,
+        })),
+        // The brace lints already say where the real problem is.
+        Err(_) if unbalanced => {}
+        Err(DesParseError::Parse { line, msg }) => lints.push(Lint {
+            line,
+            severity: Severity::Error,
+            msg,
+        }),
+    }
+    lints.append(&mut linter.lints);
+    lints.sort_by_key(|l| l.line);
+    lints
+}
+
+/// The [`lint`] checks that need statement details.
+#[derive(Default)]
+struct Linter {
+    /// Width and height of the level's last MAP.
+    bounds: Option<(usize, usize)>,
+    lints: Vec<Lint>,
+}
+
+impl Linter {
+    fn warn(&mut self, line: usize, msg: String) {
+        self.lints.push(Lint {
+            line,
+            severity: Severity::Warning,
+            msg,
+        });
+    }
+
+    fn check_coord(&mut self, line: usize, coord: &DesCoord) {
+        let &DesCoord::At { x, y } = coord else {
+            return;
+        };
+        let (w, h) = self
+            .bounds
+            .unwrap_or((nethack_types::COLNO, nethack_types::ROWNO));
+        let inside =
+            usize::try_from(x).is_ok_and(|x| x < w) && usize::try_from(y).is_ok_and(|y| y < h);
+        if !inside {
+            let area = if self.bounds.is_some() {
+                "MAP"
+            } else {
+                "level"
+            };
+            self.warn(line, format!("({x},{y}) is outside the {w}x{h} {area}"));
+        }
+    }
+}
+
+impl StatementVisitor for Linter {
+    fn on_level(&mut self, _name: &str) {
+        self.bounds = None;
+    }
+
+    fn on_map(&mut self, map: &MapStatement) {
+        self.bounds = Some((map.width, map.height));
+    }
+
+    fn on_monster(&mut self, monster: &MonsterStatement) {
+        if let DesSpec::Named { class, name } = &monster.spec
+            && get_monster_id(name, *class).is_none()
+        {
+            self.warn(
+                monster.line,
+                format!("unknown monster '{class}' \"{name}\""),
+            );
+        }
+        self.check_coord(monster.line, &monster.coord);
+    }
+
+    fn on_object(&mut self, object: &ObjectStatement) {
+        if let DesSpec::Named { class, name } = &object.spec
+            && get_object_id(name, *class).is_none()
+        {
+            self.warn(object.line, format!("unknown object '{class}' \"{name}\""));
+        }
+        if let Some(coord) = &object.coord {
+            self.check_coord(object.line, coord);
+        }
     }
 }
 
@@ -3447,6 +4085,787 @@ mod tests {
         parse_des(tokens).unwrap_or_else(|e| panic!("parse {name}: {e}"))
     }
 
+    fn parse_src(src: &str) -> DesFile {
+        let tokens = des_lexer::lex(src).unwrap_or_else(|e| panic!("lex: {e}"));
+        parse_des(tokens).unwrap_or_else(|e| panic!("parse: {e}"))
+    }
+
+    fn opcodes(des: &DesFile) -> Vec<SpOpcode> {
+        des.levels[0].opcodes.iter().map(|o| o.opcode).collect()
+    }
+
+    /// The three opcodes ending at the first `opcode` in `ops`.
+    fn feature_ops(ops: &[SpLevOpcode], opcode: SpOpcode) -> &[SpLevOpcode] {
+        let i = ops.iter().position(|o| o.opcode == opcode).unwrap();
+        &ops[i - 2..=i]
+    }
+
+    /// lev_comp compiles `ter_selection` features to PUSH coord, SEL_POINT
+    /// and the feature opcode, which pops the selection.
+    #[test]
+    fn features_match_lev_comp() {
+        let fixture = |name: &str| {
+            let path = format!("{}/tests/fixtures/lev/{name}", env!("CARGO_MANIFEST_DIR"));
+            crate::lev_reader::read_lev(&std::fs::read(&path).unwrap()).unwrap()
+        };
+        let castle = fixture("castle.lev");
+        let minetown = fixture("minetn-7.lev");
+        let coord = |x, y| SpLevOpcode {
+            opcode: SpOpcode::Push,
+            operand: Some(SpOperand::Coord {
+                x,
+                y,
+                is_random: false,
+                flags: HumidityFlags::empty(),
+            }),
+        };
+        let op = |opcode| SpLevOpcode {
+            opcode,
+            operand: None,
+        };
+
+        let des = parse_src("MAZE:\"test\",' '\nFOUNTAIN:(10,8)\nSINK:(0,0)\nPOOL:(4,2)\n");
+        let ops = &des.levels[0].opcodes;
+        assert_eq!(
+            feature_ops(ops, SpOpcode::Fountain),
+            feature_ops(&castle, SpOpcode::Fountain)
+        );
+        assert_eq!(
+            feature_ops(ops, SpOpcode::Sink),
+            feature_ops(&minetown, SpOpcode::Sink)
+        );
+        // No fixture has a POOL; it shares the grammar rule.
+        assert_eq!(
+            feature_ops(ops, SpOpcode::Pool),
+            [coord(4, 2), op(SpOpcode::SelPoint), op(SpOpcode::Pool)]
+        );
+    }
+
+    #[test]
+    fn percent_hint_recorded() {
+        let src = "MAZE:\"test\",' '\n[75%]: FOUNTAIN:(5,5)\n";
+        let tokens = des_lexer::lex(src).unwrap();
+        let options = ParseOptions { source_hints: true };
+        let parsed = parse_des_with_options(tokens, &options).unwrap();
+        assert_eq!(parsed.hints.len(), 1);
+        let hints = &parsed.hints[0];
+        assert_eq!(hints.len(), 1);
+        let (offset, hint) = &hints[0];
+        assert_eq!(hint, &SourceHint::Percent(75));
+        assert_eq!(
+            parsed.des.levels[0].opcodes[*offset].operand,
+            Some(SpOperand::Int(75))
+        );
+    }
+
+    #[test]
+    fn percent_terrain_skips_statement() {
+        let src = "MAZE:\"test\",' '\n[50%]: TERRAIN:(5,5),'.'\nFOUNTAIN:(6,6)\n";
+        let des = parse_src(src);
+        let ops = &des.levels[0].opcodes;
+        let rn2 = ops.iter().position(|o| o.opcode == SpOpcode::Rn2).unwrap();
+        assert_eq!(ops[rn2 - 2].operand, Some(SpOperand::Int(50)));
+        assert_eq!(ops[rn2 - 1].operand, Some(SpOperand::Int(100)));
+        // Same shape as lev_comp: the roll is compared directly against pct,
+        // leaving nothing on the stack.
+        assert_eq!(
+            opcodes(&des)[rn2..rn2 + 8],
+            [
+                SpOpcode::Rn2,
+                SpOpcode::Cmp,
+                SpOpcode::Push,
+                SpOpcode::Jle,
+                SpOpcode::Push,
+                SpOpcode::SelPoint,
+                SpOpcode::Push,
+                SpOpcode::Terrain,
+            ]
+        );
+        // The jump lands just past TERRAIN, at the start of the fountain.
+        let Some(SpOperand::Int(offset)) = ops[rn2 + 2].operand else {
+            panic!("jump offset is not an int");
+        };
+        let target = (rn2 as i64 + 3 + offset) as usize;
+        assert_eq!(target, rn2 + 8);
+        assert_eq!(
+            ops[target].operand,
+            Some(SpOperand::Coord {
+                x: 6,
+                y: 6,
+                is_random: false,
+                flags: HumidityFlags::empty()
+            })
+        );
+    }
+
+    #[test]
+    fn init_map_mines_pushes_all_args() {
+        let src = "MAZE:\"test\",' '\nINIT_MAP: mines, '.', ' ', true, true, random, true\n";
+        let ops = &parse_src(src).levels[0].opcodes;
+        let init = ops
+            .iter()
+            .rposition(|o| o.opcode == SpOpcode::InitLevel)
+            .unwrap();
+        let args: Vec<_> = ops[init - 8..init]
+            .iter()
+            .map(|o| o.operand.clone().unwrap())
+            .collect();
+        // style, filling, walled, lit, joined, smoothed, bg, fg (minefill.lev)
+        let expected = [3, -1, 1, -1, 1, 1, 0, 24].map(SpOperand::Int);
+        assert_eq!(args, expected);
+    }
+
+    #[test]
+    fn region_pushes_room_type_and_fill() {
+        let region_args = |line: &str| {
+            let des = parse_src(&format!("MAZE:\"test\",' '\n{line}\n"));
+            let ops = &des.levels[0].opcodes;
+            let at = ops
+                .iter()
+                .position(|o| o.opcode == SpOpcode::Region)
+                .unwrap();
+            ops[at - 3..at]
+                .iter()
+                .map(|o| match o.operand {
+                    Some(SpOperand::Int(n)) => n,
+                    ref other => panic!("{other:?}"),
+                })
+                .collect::<Vec<_>>()
+        };
+        // lit, room type, flags
+        assert_eq!(
+            region_args("REGION:(1,1,5,5),lit,\"weapon shop\""),
+            [1, RoomType::WeaponShop as i64, 1]
+        );
+        assert_eq!(
+            region_args("REGION:(1,1,5,5),unlit,\"ordinary\",unfilled"),
+            [0, i64::from(RoomType::MAX) + 1, 0]
+        );
+        assert_eq!(
+            region_args("REGION:(1,1,5,5),lit,\"zoo\",filled,irregular"),
+            [1, RoomType::Zoo as i64, 3]
+        );
+    }
+
+    #[test]
+    fn break_leaves_innermost_loop() {
+        // Where the BREAK at the first JMP after `after` lands.
+        let break_target = |src: &str, after: SpOpcode| {
+            let ops = parse_src(src).levels[0].opcodes.clone();
+            let start = ops.iter().position(|o| o.opcode == after).unwrap();
+            let jmp = start
+                + ops[start..]
+                    .iter()
+                    .position(|o| o.opcode == SpOpcode::Jmp)
+                    .unwrap();
+            let Some(SpOperand::Int(offset)) = ops[jmp - 1].operand else {
+                panic!("{:?}", ops[jmp - 1]);
+            };
+            (ops.clone(), (jmp as i64 + offset) as usize)
+        };
+
+        // A LOOP's BREAK lands on its own POP of the count, not the SWITCH's.
+        let (ops, target) = break_target(
+            "MAZE:\"test\",' '\nSWITCH [1] {\nCASE 1:\nLOOP [3] {\nBREAK\n}\nFOUNTAIN:(1,1)\n}\n",
+            SpOpcode::Dec,
+        );
+        assert_eq!(ops[target].opcode, SpOpcode::Pop);
+        assert_eq!(ops[target - 1].opcode, SpOpcode::Jg);
+        assert_eq!(ops[target + 2].opcode, SpOpcode::SelPoint);
+
+        // A FOR's lands just past its closing JNE.
+        let (ops, target) = break_target(
+            "MAZE:\"test\",' '\nFOR $i = 1 TO 3 {\nBREAK\n}\n",
+            SpOpcode::MathSign,
+        );
+        assert_eq!(ops[target - 1].opcode, SpOpcode::Jne);
+
+        let nested =
+            "MAZE:\"test\",' '\nSWITCH [1] {\nCASE 1:\nSWITCH [2] {\nCASE 2:\nBREAK\n}\n}\n";
+        let err = parse_des(des_lexer::lex(nested).unwrap()).unwrap_err();
+        assert!(err.to_string().contains("Cannot nest switch"), "{err}");
+        let err = parse_des(des_lexer::lex("MAZE:\"test\",' '\nBREAK\n").unwrap()).unwrap_err();
+        assert!(err.to_string().contains("BREAK outside"), "{err}");
+    }
+
+    #[test]
+    fn shuffle_requires_declared_array() {
+        let header = "MAZE:\"test\",' '\n";
+        let ok = format!("{header}$places = {{ (1,1), (2,2) }}\nSHUFFLE: $places\n");
+        assert!(opcodes(&parse_src(&ok)).contains(&SpOpcode::ShuffleArray));
+
+        let parse = |src: String| parse_des(des_lexer::lex(&src).unwrap());
+        let err = parse(format!("{header}SHUFFLE: $nowhere\n")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: SHUFFLE of undeclared variable $nowhere"
+        );
+        let err = parse(format!("{header}$n = 3\nSHUFFLE: $n\n")).unwrap_err();
+        assert!(err.to_string().contains("non-array variable $n"), "{err}");
+    }
+
+    #[test]
+    fn statue_trap_has_no_monster() {
+        let header = "MAZE:\"test\",' '\n";
+        let des = parse_src(&format!("{header}TRAP:\"statue\",(3,4)\n"));
+        let ops = &des.levels[0].opcodes;
+        let [.., coord, typ, trap] = &ops[..] else {
+            panic!("too few opcodes: {ops:?}");
+        };
+        assert!(matches!(
+            coord.operand,
+            Some(SpOperand::Coord { x: 3, y: 4, .. })
+        ));
+        assert_eq!(typ.operand, Some(SpOperand::Int(19)));
+        assert_eq!(trap.opcode, SpOpcode::Trap);
+
+        let src = format!("{header}TRAP:\"statue\",(3,4),('C',\"forest centaur\")\n");
+        let err = parse_des(des_lexer::lex(&src).unwrap()).unwrap_err();
+        assert!(err.to_string().contains("only a trap type"), "{err}");
+    }
+
+    #[test]
+    fn statements_after_exit_warn() {
+        let src = "MAZE:\"test\",' '\n\
+                   IF [50%] {\n\
+                   EXIT\n\
+                   FOUNTAIN:(1,1)\n\
+                   SINK:(2,2)\n\
+                   }\n\
+                   [50%]: EXIT\n\
+                   FOUNTAIN:(3,3)\n\
+                   EXIT\n\
+                   FOUNTAIN:(4,4)\n";
+        let parsed =
+            parse_des_with_options(des_lexer::lex(src).unwrap(), &ParseOptions::default()).unwrap();
+        // One warning for the dead run inside IF, none after the chance
+        // EXIT, and one at the top level.
+        let lines: Vec<_> = parsed.warnings.iter().map(|w| w.line).collect();
+        assert_eq!(lines, [4, 10]);
+        assert_eq!(
+            parsed.warnings[0].to_string(),
+            "line 4: statement is unreachable after EXIT on line 3"
+        );
+        assert_eq!(parsed.des, parse_src(src));
+    }
+
+    #[test]
+    fn unknown_room_type_warns_and_is_ordinary() {
+        let src = "MAZE:\"test\",' '\n\
+                   REGION:(1,1,5,5),lit,\"bogus\"\n";
+        let parsed =
+            parse_des_with_options(des_lexer::lex(src).unwrap(), &ParseOptions::default()).unwrap();
+        assert_eq!(
+            parsed.warnings[..],
+            [DesWarning {
+                line: 2,
+                msg: "unknown room type \"bogus\", making an ordinary room".into(),
+            }]
+        );
+        let ordinary = src.replace("bogus", "ordinary");
+        assert_eq!(parsed.des, parse_src(&ordinary));
+    }
+
+    #[test]
+    fn comment_line_in_map_warns() {
+        let src = "MAZE:\"test\",' '\n\
+                   MAP\n\
+                   ---\n\
+                   # a comment\n\
+                   |.|\n\
+                   ---\n\
+                   ENDMAP\n";
+        let parsed =
+            parse_des_with_options(des_lexer::lex(src).unwrap(), &ParseOptions::default()).unwrap();
+        assert_eq!(parsed.warnings.len(), 1);
+        assert_eq!(
+            parsed.warnings[0].to_string(),
+            "line 4: 8 invalid MAP character(s), first 'a', replaced with stone \
+             (comments are not allowed inside MAP)"
+        );
+        // The row is still map data: `#` is corridor, the rest stone.
+        let map = &parsed.des.levels[0].opcodes;
+        let data = map.iter().find_map(|op| match &op.operand {
+            Some(SpOperand::String(s)) if s.len() == 4 * 11 => Some(s.clone()),
+            _ => None,
+        });
+        let corr = (what_map_char('#') + 1) as u8 as char;
+        assert_eq!(data.unwrap().chars().nth(11), Some(corr));
+    }
+
+    #[test]
+    fn object_coord_depends_on_container() {
+        let header = "MAZE:\"test\",' '\n";
+        let random_coord = Some(SpOperand::Coord {
+            x: -1,
+            y: -1,
+            is_random: true,
+            flags: HumidityFlags::empty(),
+        });
+        let src =
+            format!("{header}CONTAINER:('(',\"chest\"),(2,2) {{\nOBJECT:'!'\n}}\nOBJECT:'?'\n");
+        let des = parse_src(&src);
+        let ops = &des.levels[0].opcodes;
+        let objects: Vec<_> = ops
+            .iter()
+            .enumerate()
+            .filter(|(_, o)| o.opcode == SpOpcode::Object)
+            .map(|(i, _)| i)
+            .collect();
+        let [_chest, contents, loose] = objects[..] else {
+            panic!("expected three objects, got {objects:?}");
+        };
+        // OBJECT:'!' pushes the spec, the End sentinel and the content count.
+        assert_eq!(ops[contents - 1].operand, Some(SpOperand::Int(1)));
+        assert_eq!(
+            ops[contents - 2].operand,
+            Some(SpOperand::Int(SpObjVarFlag::End as i64))
+        );
+        // OBJECT:'?' gets a random coord.
+        assert_eq!(
+            ops[loose - 2].operand,
+            Some(SpOperand::Int(SpObjVarFlag::Coord as i64))
+        );
+        assert_eq!(ops[loose - 3].operand, random_coord);
+
+        let src = format!("{header}CONTAINER:('(',\"chest\"),(2,2) {{\nOBJECT:'!',(1,1)\n}}\n");
+        let err = parse_des(des_lexer::lex(&src).unwrap()).unwrap_err();
+        assert!(err.to_string().contains("when contained"), "{err}");
+    }
+
+    #[test]
+    fn geometry_places_the_following_map() {
+        let map = "MAP\n---\n|.|\n---\nENDMAP\n";
+        let placement = |src: String| {
+            let des = parse_src(&src);
+            let ops = &des.levels[0].opcodes;
+            let map = ops.iter().position(|o| o.opcode == SpOpcode::Map).unwrap();
+            ops[map - 6..map - 3]
+                .iter()
+                .map(|o| o.operand.clone().unwrap())
+                .collect::<Vec<_>>()
+        };
+        let coord = |x, y| SpOperand::Coord {
+            x,
+            y,
+            is_random: false,
+            flags: HumidityFlags::empty(),
+        };
+        let header = "MAZE:\"test\",' '\n";
+        assert_eq!(
+            placement(format!("{header}GEOMETRY:right,bottom\n{map}")),
+            [coord(5, 5), SpOperand::Int(1), SpOperand::Int(1)]
+        );
+        assert_eq!(
+            placement(format!("{header}{map}")),
+            [coord(3, 3), SpOperand::Int(1), SpOperand::Int(1)]
+        );
+    }
+
+    #[test]
+    fn geometry_without_map_is_an_error() {
+        let header = "MAZE:\"test\",' '\n";
+        for tail in ["FOUNTAIN:(1,1)\n", "GEOMETRY:left,top\n", ""] {
+            let src = format!("{header}GEOMETRY:center,center\n{tail}");
+            let err = parse_des(des_lexer::lex(&src).unwrap()).unwrap_err();
+            assert!(
+                err.to_string().contains("GEOMETRY must be followed by MAP"),
+                "{tail:?}: {err}"
+            );
+        }
+        let src = format!("{header}GEOMETRY:center,center\nNOMAP\n");
+        assert!(parse_des(des_lexer::lex(&src).unwrap()).is_ok());
+    }
+
+    #[test]
+    fn function_body_is_emitted_at_its_first_call() {
+        let header = "MAZE:\"test\",' '\n";
+        let base = parse_src(header).levels[0].opcodes.len();
+        let func = "FUNCTION fill() { TERRAIN:(1,1),'.' }\n";
+        let defined = parse_src(&format!("{header}{func}"));
+        // Like lev_comp, a definition alone emits nothing.
+        assert_eq!(defined.levels[0].opcodes.len(), base);
+
+        let called = parse_src(&format!("{header}{func}fill()\nfill()\n"));
+        let ops = &called.levels[0].opcodes[base..];
+        let kinds: Vec<_> = ops.iter().map(|o| o.opcode).collect();
+        let body = kinds.iter().position(|&k| k == SpOpcode::Terrain).unwrap();
+        let ret = body + 2;
+        assert_eq!(&kinds[..2], [SpOpcode::Push, SpOpcode::Jmp]);
+        assert_eq!(
+            &kinds[body..],
+            [
+                SpOpcode::Terrain,
+                SpOpcode::Push,
+                SpOpcode::Return,
+                SpOpcode::Push,
+                SpOpcode::Push,
+                SpOpcode::Call,
+                SpOpcode::Push,
+                SpOpcode::Push,
+                SpOpcode::Call,
+            ]
+        );
+        let int = |i: usize| match ops[i].operand {
+            Some(SpOperand::Int(n)) => n,
+            ref other => panic!("{other:?}"),
+        };
+        // The JMP skips to the first CALL's arguments; both CALLs land on
+        // the start of the body.
+        assert_eq!(1 + int(0), ret as i64 + 1);
+        for call in [ret + 3, ret + 6] {
+            assert_eq!(int(call - 2), 0);
+            assert_eq!(call as i64 + int(call - 1), 2);
+        }
+
+        for (src, msg) in [
+            (format!("{header}fill()\n"), "not defined"),
+            (format!("{header}{func}{func}"), "already defined"),
+            (format!("{header}FUNCTION f(a) {{ }}\n"), "parameters"),
+        ] {
+            let err = parse_des(des_lexer::lex(&src).unwrap()).unwrap_err();
+            assert!(err.to_string().contains(msg), "{err}");
+        }
+    }
+
+    #[test]
+    fn coord_components_from_constant_variables() {
+        let header = "MAZE:\"test\",' '\n$x = 2\n$y = 3\n";
+        let des = parse_src(&format!("{header}TERRAIN: line ($x,$y),(5,5), '.'\n"));
+        let ops = &des.levels[0].opcodes;
+        let line = ops
+            .iter()
+            .position(|o| o.opcode == SpOpcode::SelLine)
+            .unwrap();
+        let coord = |x, y| SpOperand::Coord {
+            x,
+            y,
+            is_random: false,
+            flags: HumidityFlags::empty(),
+        };
+        assert_eq!(ops[line - 2].operand, Some(coord(2, 3)));
+        assert_eq!(ops[line - 1].operand, Some(coord(5, 5)));
+
+        // A reassigned or computed variable has no single value to fold,
+        // even when the reassignment comes after the use.
+        for (before, after) in [
+            ("$x = 4\n", ""),
+            ("$x = 1 + 1\n", ""),
+            ("", "$x = 4\n"),
+            ("LOOP [3] {\n", "$x = 4\n}\n"),
+        ] {
+            let src = format!("{header}{before}TERRAIN: line ($x,$y),(5,5), '.'\n{after}");
+            let err = parse_des(des_lexer::lex(&src).unwrap()).unwrap_err();
+            assert!(err.to_string().contains("not a constant"), "{src}: {err}");
+        }
+
+        let src = format!("{header}TERRAIN: line (70000,$y),(5,5), '.'\n");
+        let err = parse_des(des_lexer::lex(&src).unwrap()).unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{err}");
+    }
+
+    #[test]
+    fn percent_hints_off_by_default() {
+        let src = "MAZE:\"test\",' '\n[75%]: FOUNTAIN:(5,5)\n";
+        let tokens = des_lexer::lex(src).unwrap();
+        let parsed = parse_des_with_options(tokens, &ParseOptions::default()).unwrap();
+        assert!(parsed.hints.iter().all(Vec::is_empty));
+        assert_eq!(parsed.des, parse_src(src));
+    }
+
+    #[test]
+    fn gradient_limited_keyword() {
+        let limited =
+            parse_src("MAZE:\"test\",' '\nTERRAIN:gradient(radial,(1 - 3 limited),(10,10)),'T'\n");
+        let unlimited = parse_src(
+            "MAZE:\"test\",' '\nTERRAIN:gradient(radial,(1 - 3 unlimited),(10,10)),'T'\n",
+        );
+        for (des, expected) in [(limited, 1), (unlimited, 0)] {
+            let ops = &des.levels[0].opcodes;
+            let idx = ops
+                .iter()
+                .position(|o| o.opcode == SpOpcode::SelGradient)
+                .expect("gradient emitted");
+            assert_eq!(ops[idx - 2].operand, Some(SpOperand::Int(expected)));
+        }
+    }
+
+    /// mindist, maxdist, both coordinates (the second a COPY of the first
+    /// when omitted), limited and type: the six operands SEL_GRADIENT pops.
+    #[test]
+    fn gradient_pushes_what_sel_gradient_pops() {
+        let gradient = |args: &str| {
+            let des = parse_src(&format!(
+                "MAZE:\"test\",' '\nTERRAIN:gradient({args}),'T'\n"
+            ));
+            des.levels[0].validate_stack().unwrap();
+            let ops = des.levels[0].opcodes.clone();
+            let end = ops
+                .iter()
+                .position(|o| o.opcode == SpOpcode::SelGradient)
+                .unwrap();
+            let start = ops
+                .iter()
+                .position(|o| o.operand == Some(SpOperand::Int(2)))
+                .unwrap();
+            ops[start..end]
+                .iter()
+                .map(|o| (o.opcode, o.operand.clone()))
+                .collect::<Vec<_>>()
+        };
+        let coord = |x, y| {
+            Some(SpOperand::Coord {
+                x,
+                y,
+                is_random: false,
+                flags: HumidityFlags::empty(),
+            })
+        };
+        let int = |n| (SpOpcode::Push, Some(SpOperand::Int(n)));
+        assert_eq!(
+            gradient("square,(2 - 5),(10,10)"),
+            [
+                int(2),
+                int(5),
+                (SpOpcode::Push, coord(10, 10)),
+                (SpOpcode::Copy, None),
+                int(0),
+                int(1),
+            ]
+        );
+        assert_eq!(
+            gradient("radial,(2 - 5 limited),(10,10),(20,12)"),
+            [
+                int(2),
+                int(5),
+                (SpOpcode::Push, coord(10, 10)),
+                (SpOpcode::Push, coord(20, 12)),
+                int(1),
+                int(0),
+            ]
+        );
+    }
+
+    #[test]
+    fn limited_room_flag_rejected() {
+        let src =
+            "LEVEL:\"test\"\nROOM:\"ordinary\",lit,(1,1),(left,top),(5,5),irregular,limited {\n}\n";
+        let tokens = des_lexer::lex(src).unwrap();
+        let err = parse_des(tokens).unwrap_err();
+        assert!(err.to_string().contains("gradient"), "{err}");
+
+        let src = "MAZE:\"test\",' '\nREGION:(1,1,5,5),lit,\"ordinary\",irregular,limited\n";
+        let err = parse_des(des_lexer::lex(src).unwrap()).unwrap_err();
+        assert!(err.to_string().contains("gradient"), "{err}");
+    }
+
+    #[test]
+    fn replace_terrain_region() {
+        let des = parse_src("MAZE:\"test\",' '\nREPLACE_TERRAIN:(1,1,10,5),'.','T',25%\n");
+        let ops = &des.levels[0].opcodes;
+        let idx = ops
+            .iter()
+            .position(|o| o.opcode == SpOpcode::ReplaceTerrain)
+            .expect("REPLACE_TERRAIN emitted");
+        assert_eq!(
+            ops[idx - 4].operand,
+            Some(SpOperand::Region {
+                x1: 1,
+                y1: 1,
+                x2: 10,
+                y2: 5
+            })
+        );
+        assert_eq!(ops[idx - 1].operand, Some(SpOperand::Int(25)));
+    }
+
+    #[test]
+    fn replace_terrain_circle_selection() {
+        let des = parse_src("MAZE:\"test\",' '\nREPLACE_TERRAIN:circle((10,10),4),'.','T',50%\n");
+        let ops = opcodes(&des);
+        let ellipse = ops
+            .iter()
+            .position(|&o| o == SpOpcode::SelEllipse)
+            .expect("circle selection emitted");
+        let replace = ops
+            .iter()
+            .position(|&o| o == SpOpcode::ReplaceTerrain)
+            .expect("REPLACE_TERRAIN emitted");
+        assert!(ellipse < replace);
+        // selection, from, to, percent
+        assert_eq!(replace - ellipse, 4);
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        levels: Vec<String>,
+        maps: Vec<MapStatement>,
+        monsters: Vec<MonsterStatement>,
+        objects: Vec<ObjectStatement>,
+        doors: Vec<DoorStatement>,
+    }
+
+    impl StatementVisitor for Recorder {
+        fn on_level(&mut self, name: &str) {
+            self.levels.push(name.to_string());
+        }
+        fn on_map(&mut self, map: &MapStatement) {
+            self.maps.push(map.clone());
+        }
+        fn on_monster(&mut self, monster: &MonsterStatement) {
+            self.monsters.push(monster.clone());
+        }
+        fn on_object(&mut self, object: &ObjectStatement) {
+            self.objects.push(object.clone());
+        }
+        fn on_door(&mut self, door: &DoorStatement) {
+            self.doors.push(door.clone());
+        }
+    }
+
+    #[test]
+    fn visitor_reports_statements() {
+        let src = "MAZE:\"test\",' '\nMAP\n---\n|.|\n---\nENDMAP\n\
+                   MONSTER:('D',\"red dragon\"),(1,1)\n\
+                   MONSTER:random,$place\n\
+                   OBJECT:'(',random\n\
+                   OBJECT:('%',\"food ration\")\n\
+                   DOOR:locked,(2,1)\n";
+        let mut rec = Recorder::default();
+        let des = parse_des_visit(des_lexer::lex(src).unwrap(), &mut rec).unwrap();
+        assert_eq!(des, parse_src(src));
+
+        assert_eq!(rec.levels, vec!["test"]);
+        assert_eq!(rec.maps.len(), 1);
+        assert_eq!((rec.maps[0].width, rec.maps[0].height), (3, 3));
+        assert_eq!(
+            rec.monsters[0].spec,
+            DesSpec::Named {
+                class: 'D',
+                name: "red dragon".into()
+            }
+        );
+        assert_eq!(rec.monsters[0].coord, DesCoord::At { x: 1, y: 1 });
+        assert_eq!(rec.monsters[1].spec, DesSpec::Random);
+        assert_eq!(rec.monsters[1].coord, DesCoord::Variable("place".into()));
+        assert_eq!(rec.objects[0].spec, DesSpec::Class('('));
+        assert_eq!(rec.objects[0].coord, Some(DesCoord::Random));
+        assert_eq!(rec.objects[1].coord, None);
+        assert_eq!(rec.doors.len(), 1);
+        assert_eq!(rec.doors[0].state, 8);
+        assert_eq!(rec.doors[0].line, 11);
+    }
+
+    #[test]
+    fn lint_reports_each_issue() {
+        let src = "MAZE:\"test\",' '\n\
+                   MAP\n---\n|.|\n---\nENDMAP\n\
+                   MONSTER:('D',\"pink dragon\"),(1,1)\n\
+                   OBJECT:('(',\"chest\"),(5,1)\n\
+                   EXIT\n\
+                   MONSTER:random,random\n";
+        let lints = lint(src);
+        let found: Vec<_> = lints.iter().map(|l| (l.line, l.severity)).collect();
+        assert_eq!(
+            found,
+            [
+                (7, Severity::Warning),
+                (8, Severity::Warning),
+                (10, Severity::Warning)
+            ]
+        );
+        assert!(lints[0].msg.contains("pink dragon"), "{}", lints[0]);
+        assert_eq!(
+            lints[1].to_string(),
+            "line 8: Warning: (5,1) is outside the 3x3 MAP"
+        );
+        assert!(lints[2].msg.contains("unreachable"), "{}", lints[2]);
+
+        // The unclosed brace, not the parse error it causes.
+        let lints = lint("MAZE:\"test\",' '\nIF [50%] {\nEXIT\n");
+        assert_eq!(lints.len(), 1);
+        assert_eq!((lints[0].line, lints[0].severity), (2, Severity::Error));
+
+        let lints = lint("MAZE:\"test\",' '\nGEOMETRY:center,center\nEXIT\n");
+        assert_eq!(lints.len(), 1);
+        assert!(lints[0].msg.contains("GEOMETRY"), "{}", lints[0]);
+    }
+
+    #[test]
+    fn visit_castle_doors() {
+        let path = format!(
+            "{}/../../nethack/dat/castle.des",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let input = std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("read {path}"));
+        let mut rec = Recorder::default();
+        parse_des_visit(des_lexer::lex(&input).unwrap(), &mut rec).unwrap();
+        // Matches the DOOR opcodes in tests/fixtures/lev/castle.lev.
+        assert_eq!(rec.doors.len(), 18);
+        assert_eq!(rec.levels, vec!["castle"]);
+    }
+
+    #[test]
+    fn message_with_multiple_strings() {
+        let des = parse_src("MAZE:\"test\",' '\nMESSAGE:\"first\",\"second\"\n");
+        let ops = &des.levels[0].opcodes;
+        let messages: Vec<_> = ops
+            .iter()
+            .enumerate()
+            .filter(|(_, o)| o.opcode == SpOpcode::Message)
+            .map(|(i, _)| &ops[i - 1].operand)
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                &Some(SpOperand::String("first".into())),
+                &Some(SpOperand::String("second".into()))
+            ]
+        );
+    }
+
+    /// Opcodes between the QUAN flag's value and the flag itself.
+    fn quantity_ops(src: &str) -> Vec<SpLevOpcode> {
+        let des = parse_src(src);
+        let ops = &des.levels[0].opcodes;
+        // ..., value, QUAN, count, OBJECT
+        let object = ops
+            .iter()
+            .position(|o| o.opcode == SpOpcode::Object)
+            .expect("object emitted");
+        let flag = object - 2;
+        assert_eq!(
+            ops[flag].operand,
+            Some(SpOperand::Int(SpObjVarFlag::Quan as i64))
+        );
+        let start = ops[..flag]
+            .iter()
+            .rposition(|o| o.operand == Some(SpOperand::Int(SpObjVarFlag::Coord as i64)))
+            .expect("coord flag precedes quantity");
+        ops[start + 1..flag].to_vec()
+    }
+
+    #[test]
+    fn object_quantity_dice() {
+        let ops = quantity_ops("MAZE:\"test\",' '\nOBJECT:'(',random,quantity:2d4\n");
+        let ops: Vec<_> = ops.iter().map(|o| (o.opcode, o.operand.clone())).collect();
+        assert_eq!(
+            ops,
+            vec![
+                (SpOpcode::Push, Some(SpOperand::Int(2))),
+                (SpOpcode::Push, Some(SpOperand::Int(4))),
+                (SpOpcode::Dice, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn object_quantity_random() {
+        let ops = quantity_ops("MAZE:\"test\",' '\nOBJECT:'(',random,quantity:random\n");
+        assert_eq!(ops.len(), 1);
+        assert_eq!(ops[0].operand, Some(SpOperand::Int(-1)));
+    }
+
     #[test]
     fn parse_mines() {
         let des = parse_file("mines.des");
@@ -3471,6 +4890,201 @@ mod tests {
         );
     }
 
+    #[test]
+    fn delphi_subroom_matches_oracle() {
+        // oracle.des gives the Oracle's centre a room type through SUBROOM.
+        let src = "LEVEL:\"oracle\"\n\
+                   ROOM:\"ordinary\",lit,(3,3),(center,center),(11,9) {\n\
+                   SUBROOM:\"delphi\",lit,(4,3),(3,3) {\n\
+                   FOUNTAIN:(0,1)\nFOUNTAIN:(1,0)\nFOUNTAIN:(1,2)\nFOUNTAIN:(2,1)\n\
+                   MONSTER:('@',\"Oracle\"),(1,1)\n\
+                   ROOMDOOR:false,nodoor,random,random\n\
+                   }\n}\n";
+        let delphi = |ops: &[SpLevOpcode]| {
+            let start = ops
+                .iter()
+                .position(|o| o.opcode == SpOpcode::Subroom)
+                .unwrap();
+            let end = start
+                + ops[start..]
+                    .iter()
+                    .position(|o| o.opcode == SpOpcode::EndRoom)
+                    .unwrap();
+            ops[start - 10..=end].to_vec()
+        };
+        let oracle_fixture = |name: &str| {
+            let path = format!("{}/tests/fixtures/lev/{name}", env!("CARGO_MANIFEST_DIR"));
+            crate::lev_reader::read_lev(&std::fs::read(&path).unwrap()).unwrap()
+        };
+        let oracle = oracle_fixture("oracle.lev");
+        let ours = delphi(&parse_src(src).levels[0].opcodes);
+        assert_eq!(ours, delphi(&oracle));
+        assert_eq!(
+            ours[0].operand,
+            Some(SpOperand::Int(RoomType::Delphi as i64))
+        );
+
+        // The same room type on a REGION, with its contents in a block and
+        // the region's room closed after them.
+        let src = "MAZE:\"test\",' '\n\
+                   REGION:(4,3,6,5),lit,\"delphi\" {\n\
+                   FOUNTAIN:(0,1)\n\
+                   }\n";
+        let ops = &parse_src(src).levels[0].opcodes;
+        let i = ops
+            .iter()
+            .position(|o| o.opcode == SpOpcode::Region)
+            .unwrap();
+        assert_eq!(
+            ops[i - 3..i]
+                .iter()
+                .map(|o| o.operand.clone().unwrap())
+                .collect::<Vec<_>>(),
+            [
+                SpOperand::Int(1),
+                SpOperand::Int(RoomType::Delphi as i64),
+                SpOperand::Int(1)
+            ]
+        );
+        assert_eq!(ops[i + 3].opcode, SpOpcode::Fountain);
+        assert_eq!(ops[i + 4].opcode, SpOpcode::EndRoom);
+
+        // castle.des: REGION:(27,05,37,11),lit,"throne",unfilled
+        let throne = |ops: &[SpLevOpcode]| {
+            let i = ops
+                .iter()
+                .position(|o| o.opcode == SpOpcode::Region)
+                .unwrap();
+            ops[i - 4..=i + 1].to_vec()
+        };
+        let src = "MAZE:\"test\",' '\nREGION:(27,05,37,11),lit,\"throne\",unfilled\n";
+        let castle = &oracle_fixture("castle.lev");
+        let castle_throne = castle
+            .windows(6)
+            .find(|w| w[4].opcode == SpOpcode::Region && w[2].operand != Some(SpOperand::Int(0)))
+            .unwrap();
+        assert_eq!(throne(&parse_src(src).levels[0].opcodes), castle_throne);
+    }
+
+    #[test]
+    fn statue_with_montype_and_name() {
+        let src = "MAZE:\"test\",' '\n\
+                   OBJECT:('`',\"statue\"),montype:\"knight\",name:\"Perseus\"\n";
+        let ops = &parse_src(src).levels[0].opcodes;
+        let end = ops
+            .iter()
+            .rposition(|o| o.opcode == SpOpcode::Object)
+            .unwrap();
+        let start = ops
+            .iter()
+            .position(|o| o.operand == Some(SpOperand::Int(SpObjVarFlag::End as i64)))
+            .unwrap();
+        let operands: Vec<_> = ops[start + 1..end]
+            .iter()
+            .map(|o| o.operand.clone().unwrap())
+            .collect();
+        // Each modifier pushes its value then its flag, in source order,
+        // after the defaulted random coord.
+        assert_eq!(
+            operands[2..],
+            [
+                SpOperand::Monst {
+                    class: '@' as i16,
+                    id: nethack_types::MonsterId::Knight as i16,
+                },
+                SpOperand::Int(SpObjVarFlag::CorpseNm as i64),
+                SpOperand::String("Perseus".into()),
+                SpOperand::Int(SpObjVarFlag::Name as i64),
+                SpOperand::Int(0),
+            ]
+        );
+    }
+
+    /// medusa-1.des: the Perseus statue's `montype:"knight"` packs the knight
+    /// with its own class, as lev_comp's encodemonster does.
+    #[test]
+    fn montype_matches_lev_comp() {
+        let corpsenm = |ops: &[SpLevOpcode]| {
+            ops.windows(2)
+                .find(|w| {
+                    matches!(w[0].operand, Some(SpOperand::Monst { .. }))
+                        && w[1].operand == Some(SpOperand::Int(SpObjVarFlag::CorpseNm as i64))
+                })
+                .map(|w| w[0].clone())
+                .unwrap()
+        };
+        let path = format!(
+            "{}/tests/fixtures/lev/medusa-1.lev",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let medusa = crate::lev_reader::read_lev(&std::fs::read(&path).unwrap()).unwrap();
+        let statue = |montype: &str| {
+            let src =
+                format!("MAZE:\"test\",' '\nOBJECT:('`',\"statue\"),(36,10),montype:{montype}\n");
+            parse_des(des_lexer::lex(&src).unwrap()).map(|des| corpsenm(&des.levels[0].opcodes))
+        };
+        assert_eq!(statue("\"knight\"").unwrap(), corpsenm(&medusa));
+        assert_eq!(
+            statue("'D'").unwrap().operand,
+            Some(SpOperand::Monst {
+                class: 'D' as i16,
+                id: -1
+            })
+        );
+        let err = statue("\"dragon\"").unwrap_err();
+        assert!(err.to_string().contains("Unknown monster"), "{err}");
+    }
+
+    #[test]
+    fn drawbridge_directions() {
+        let compile = |dir: &str, state: &str| {
+            let src = format!("MAZE:\"test\",' '\nDRAWBRIDGE:(5,8),{dir},{state}\n");
+            parse_des(des_lexer::lex(&src).unwrap()).map(|des| {
+                let ops = &des.levels[0].opcodes;
+                let i = ops
+                    .iter()
+                    .rposition(|o| o.opcode == SpOpcode::Drawbridge)
+                    .unwrap();
+                ops[i - 3..=i].to_vec()
+            })
+        };
+        let int = |ops: &[SpLevOpcode], i: usize| match ops[i].operand {
+            Some(SpOperand::Int(n)) => n,
+            ref other => panic!("{other:?}"),
+        };
+        for (dir, db) in [("north", 0), ("south", 1), ("east", 2), ("west", 3)] {
+            let ops = compile(dir, "open").unwrap();
+            assert_eq!((int(&ops, 1), int(&ops, 2)), (1, db), "{dir}");
+        }
+
+        // castle.des: DRAWBRIDGE:(05,08),east,closed
+        let path = format!(
+            "{}/tests/fixtures/lev/castle.lev",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let castle = crate::lev_reader::read_lev(&std::fs::read(&path).unwrap()).unwrap();
+        let i = castle
+            .iter()
+            .position(|o| o.opcode == SpOpcode::Drawbridge)
+            .unwrap();
+        assert_eq!(compile("east", "closed").unwrap(), castle[i - 3..=i]);
+        assert_eq!(int(&compile("west", "random").unwrap(), 1), -1);
+
+        for (dir, state) in [
+            ("north|south", "open"),
+            ("random", "open"),
+            ("vertical", "open"),
+        ] {
+            let err = compile(dir, state).unwrap_err();
+            assert!(
+                err.to_string().contains("Invalid drawbridge direction"),
+                "{err}"
+            );
+        }
+        let err = compile("east", "locked").unwrap_err();
+        assert!(err.to_string().contains("open, closed or random"), "{err}");
+    }
+
     #[test]
     fn parse_bigroom() {
         let des = parse_file("bigroom.des");
@@ -3484,10 +5098,8 @@ mod tests {
         // Should contain PREMAPPED flag
         let has_premapped = des.levels.iter().any(|l| {
             l.opcodes.iter().any(|op| {
-                op.opcode == SpOpcode::LevelFlags
-                    && matches!(
-                        op.operand, None // flags are pushed separately
-                    )
+                // flags are pushed separately
+                op.opcode == SpOpcode::LevelFlags && op.operand.is_none()
             })
         });
         // The flag value is in a preceding PUSH, check for that
@@ -0,0 +1,92 @@
+//! Text listings of compiled special levels, for inspection and diffs.
+
+use std::fmt::Write;
+
+use nethack_types::sp_lev::{SpLevOpcode, SpOperand};
+
+use crate::monsters::MONSTERS;
+use crate::objects::OBJECTS;
+
+/// How [`disassemble`] and [`format_opcode`] print operands.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisasmOptions {
+    /// Print monster and object operands by name, e.g. `Monst(giant ant)`,
+    /// instead of as raw class and id numbers. Random ones stay numeric.
+    pub resolve_names: bool,
+}
+
+/// One opcode as `Opcode` or `Opcode operand`.
+pub fn format_opcode(op: &SpLevOpcode, options: &DisasmOptions) -> String {
+    match &op.operand {
+        None => format!("{:?}", op.opcode),
+        Some(operand) => format!("{:?} {}", op.opcode, format_operand(operand, options)),
+    }
+}
+
+/// Every opcode on its own line, prefixed with its index (the base that
+/// jump offsets are relative to).
+pub fn disassemble(ops: &[SpLevOpcode], options: &DisasmOptions) -> String {
+    let mut out = String::new();
+    for (i, op) in ops.iter().enumerate() {
+        let _ = writeln!(out, "{i:5}: {}", format_opcode(op, options));
+    }
+    out
+}
+
+fn format_operand(operand: &SpOperand, options: &DisasmOptions) -> String {
+    let name = match *operand {
+        _ if !options.resolve_names => None,
+        SpOperand::Monst { id, .. } => usize::try_from(id)
+            .ok()
+            .and_then(|i| MONSTERS.get(i))
+            .map(|m| format!("Monst({})", m.name)),
+        SpOperand::Obj { id, .. } => usize::try_from(id)
+            .ok()
+            .and_then(|i| OBJECTS.get(i))
+            .filter(|o| !o.name.is_empty())
+            .map(|o| format!("Obj({})", o.name)),
+        _ => None,
+    };
+    name.unwrap_or_else(|| format!("{operand:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nethack_types::SpOpcode;
+    use nethack_types::{MonsterId, ObjectId};
+
+    fn push(operand: SpOperand) -> SpLevOpcode {
+        SpLevOpcode {
+            opcode: SpOpcode::Push,
+            operand: Some(operand),
+        }
+    }
+
+    #[test]
+    fn resolves_monster_and_object_names() {
+        let ops = [
+            push(SpOperand::Monst {
+                class: 'a' as i16,
+                id: MonsterId::GiantAnt as i16,
+            }),
+            push(SpOperand::Obj {
+                class: ')' as i16,
+                id: ObjectId::LongSword as i16,
+            }),
+            push(SpOperand::Monst { class: -1, id: -1 }),
+        ];
+        let resolve = DisasmOptions {
+            resolve_names: true,
+        };
+        let text = disassemble(&ops, &resolve);
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines[0], "    0: Push Monst(giant ant)");
+        assert_eq!(lines[1], "    1: Push Obj(long sword)");
+        assert_eq!(lines[2], "    2: Push Monst { class: -1, id: -1 }");
+
+        let raw = disassemble(&ops, &DisasmOptions::default());
+        assert!(!raw.contains("giant ant"), "{raw}");
+        assert!(raw.contains("Monst { class: 97, id: 0 }"), "{raw}");
+    }
+}
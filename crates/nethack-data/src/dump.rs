@@ -0,0 +1,131 @@
+//! JSON dump of the monster and object tables, for diffing a data import
+//! against a reference.
+
+use crate::monsters::MONSTERS;
+use crate::objects::OBJECTS;
+
+/// Both tables as `{"monsters":[...],"objects":[...]}`, one entry per line
+/// so dumps diff well as text. Flags are written by name.
+pub fn dump_tables_json() -> Result<String, serde_json::Error> {
+    let mut out = String::from("{\"monsters\":[\n");
+    write_entries(&mut out, MONSTERS.iter().map(serde_json::to_string))?;
+    out.push_str("],\"objects\":[\n");
+    write_entries(&mut out, OBJECTS.iter().map(serde_json::to_string))?;
+    out.push_str("]}\n");
+    Ok(out)
+}
+
+fn write_entries(
+    out: &mut String,
+    entries: impl Iterator<Item = Result<String, serde_json::Error>>,
+) -> Result<(), serde_json::Error> {
+    for (i, entry) in entries.enumerate() {
+        if i > 0 {
+            out.push_str(",\n");
+        }
+        out.push_str(&entry?);
+    }
+    out.push('\n');
+    Ok(())
+}
+
+/// A leaf value that differs between a reference dump and the tables.
+#[cfg(test)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FieldDiff {
+    /// Where the value is, e.g. `monsters[3].attacks[0].dice_num`.
+    pub path: String,
+    /// The JSON text in the reference, `None` if it has no such field.
+    pub reference: Option<String>,
+    /// The JSON text in the current dump, `None` if it has no such field.
+    pub current: Option<String>,
+}
+
+/// Compare `reference`, a [`dump_tables_json`] from another build, with the
+/// current tables, field by field in path order.
+#[cfg(test)]
+pub(crate) fn diff_tables(reference: &str) -> Result<Vec<FieldDiff>, String> {
+    use std::collections::BTreeMap;
+
+    let parse = |json: &str| {
+        let value: serde_json::Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let mut leaves = BTreeMap::new();
+        flatten(String::new(), &value, &mut leaves);
+        Ok::<_, String>(leaves)
+    };
+    let reference = parse(reference)?;
+    let mut current = parse(&dump_tables_json().map_err(|e| e.to_string())?)?;
+    let mut diffs = Vec::new();
+    for (path, value) in reference {
+        match current.remove(&path) {
+            Some(now) if now == value => {}
+            now => diffs.push(FieldDiff {
+                path,
+                reference: Some(value.to_string()),
+                current: now.map(|v| v.to_string()),
+            }),
+        }
+    }
+    diffs.extend(current.into_iter().map(|(path, now)| FieldDiff {
+        path,
+        reference: None,
+        current: Some(now.to_string()),
+    }));
+    diffs.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(diffs)
+}
+
+/// Collect every scalar under `value` into `leaves`, keyed by its path.
+#[cfg(test)]
+fn flatten(
+    path: String,
+    value: &serde_json::Value,
+    leaves: &mut std::collections::BTreeMap<String, serde_json::Value>,
+) {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(fields) => {
+            for (key, child) in fields {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                flatten(child_path, child, leaves);
+            }
+        }
+        Value::Array(items) => {
+            for (i, child) in items.iter().enumerate() {
+                flatten(format!("{path}[{i}]"), child, leaves);
+            }
+        }
+        scalar => {
+            leaves.insert(path, scalar.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dump_matches_itself() {
+        let dump = dump_tables_json().unwrap();
+        assert!(dump.starts_with("{\"monsters\":[\n{\"name\":\"giant ant\""));
+        assert_eq!(diff_tables(&dump), Ok(vec![]));
+
+        let changed = dump.replacen("\"name\":\"giant ant\"", "\"name\":\"giant aunt\"", 1);
+        let diffs = diff_tables(&changed).unwrap();
+        assert_eq!(
+            diffs,
+            [FieldDiff {
+                path: "monsters[0].name".into(),
+                reference: Some("\"giant aunt\"".into()),
+                current: Some("\"giant ant\"".into()),
+            }]
+        );
+        assert!(diff_tables("{\"monsters\":[").is_err());
+    }
+}
@@ -115,11 +115,56 @@ pub fn parse_dungeon_def(input: &str) -> Result<DungeonTopology, DungeonParseErr
     Ok(DungeonTopology { dungeons })
 }
 
+/// Render the dungeons and their branches as a Graphviz digraph. Stair
+/// branches are solid edges, portals dashed; each edge is labelled with the
+/// level the branch leaves from.
+pub fn to_dot(topo: &DungeonTopology) -> String {
+    let mut out = String::from("digraph dungeons {\n");
+    for d in &topo.dungeons {
+        out.push_str(&format!("    {};\n", dot_id(&d.name)));
+    }
+    for d in &topo.dungeons {
+        for b in &d.branches {
+            let at = match &b.chain {
+                Some(chain) => format!("{chain} + ({}, {})", b.offset_base, b.offset_rand),
+                None => format!("({}, {})", b.offset_base, b.offset_rand),
+            };
+            let style = match b.branch_type {
+                BranchType::Portal => "dashed",
+                BranchType::Stair | BranchType::NoUp | BranchType::NoDown => "solid",
+            };
+            let dir = match b.direction {
+                Some(BranchDirection::Up) => ", dir=back",
+                Some(BranchDirection::Down) | None => "",
+            };
+            out.push_str(&format!(
+                "    {} -> {} [label={}, style={style}{dir}];\n",
+                dot_id(&d.name),
+                dot_id(&b.name),
+                dot_id(&at),
+            ));
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// A quoted DOT identifier.
+fn dot_id(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Drop a `#` comment, unless the `#` is inside a quoted string.
 fn strip_comment(line: &str) -> &str {
-    match line.find('#') {
-        Some(pos) => &line[..pos],
-        None => line,
+    let mut quoted = false;
+    for (pos, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..pos],
+            _ => {}
+        }
     }
+    line
 }
 
 fn split_keyword(line: &str) -> Option<(&str, &str)> {
@@ -490,6 +535,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn dot_shows_branch_edges() {
+        let topo = parse_dungeon_def(
+            r#"
+DUNGEON: "The Dungeons of Doom" "D" (25, 5)
+BRANCH: "The Gnomish Mines" @ (2, 3)
+BRANCH: "Fort Ludios" @ (18, 4) portal
+DUNGEON: "The Gnomish Mines" "M" (8, 2)
+DUNGEON: "Fort Ludios" "K" (1, 0)
+"#,
+        )
+        .unwrap();
+        let dot = to_dot(&topo);
+        assert!(dot.starts_with("digraph dungeons {"));
+        assert!(dot.contains(
+            r#""The Dungeons of Doom" -> "The Gnomish Mines" [label="(2, 3)", style=solid];"#
+        ));
+        assert!(dot.contains(r#"-> "Fort Ludios" [label="(18, 4)", style=dashed];"#));
+        assert!(dot.contains("    \"Fort Ludios\";\n"));
+    }
+
     #[test]
     fn empty_input() {
         let topo = parse_dungeon_def("").expect("empty input");
@@ -501,4 +567,59 @@ mod tests {
         let result = parse_dungeon_def("DUNGEON: \"Test\" \"T\" (1, 0)\nFOOBAR: baz\n");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn quoted_names_keep_punctuation() {
+        let topo = parse_dungeon_def(
+            r#"
+DUNGEON: "Doom, (the) #1 @ +" "D" (25, 5) # comment
+LEVEL: "a,b" "(" @ (5, 5)
+"#,
+        )
+        .unwrap();
+        let doom = &topo.dungeons[0];
+        assert_eq!(doom.name, "Doom, (the) #1 @ +");
+        assert_eq!((doom.base, doom.rand), (25, 5));
+        assert_eq!(doom.levels[0].name, "a,b");
+        assert_eq!(doom.levels[0].boneschar, "(");
+        assert_eq!(
+            tokenize(r#""x(1,2)" ( 3 )"#),
+            [r#""x(1,2)""#, "(", "3", ")"]
+        );
+    }
+
+    #[test]
+    fn level_descriptions_apply_to_last_level() {
+        // dgn_comp has no inline flags on LEVEL lines; LEVELDESC and
+        // LEVALIGN after a level are the only way to set them.
+        let topo = parse_dungeon_def(
+            r#"
+DUNGEON: "The Dungeons of Doom" "D" (25, 5)
+LEVEL: "oracle" "O" @ (5, 5)
+LEVALIGN: neutral
+LEVEL: "bigrm" "B" @ (10, 3) 40
+CHAINLEVEL: "town" "T" "oracle" + (1, 0)
+LEVELDESC: town
+LEVELDESC: mazelike
+"#,
+        )
+        .unwrap();
+        let levels = &topo.dungeons[0].levels;
+        assert_eq!(levels[0].flags.align, DungeonAlignment::Neutral);
+        assert!(!levels[0].flags.town);
+        assert_eq!(levels[1].flags, DungeonFlags::default());
+        assert!(levels[2].flags.town && levels[2].flags.maze_like);
+        assert!(!levels[2].flags.hellish);
+        assert_eq!(topo.dungeons[0].flags, DungeonFlags::default());
+    }
+
+    #[test]
+    fn level_description_needs_a_level() {
+        let err =
+            parse_dungeon_def("DUNGEON: \"Test\" \"T\" (1, 0)\nLEVELDESC: town\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: LEVELDESC before any LEVEL");
+        let err =
+            parse_dungeon_def("DUNGEON: \"Test\" \"T\" (1, 0)\nLEVALIGN: lawful\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: LEVALIGN before any LEVEL");
+    }
 }
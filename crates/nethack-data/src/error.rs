@@ -0,0 +1,44 @@
+//! Crate-wide error type, for callers that chain several parsers with `?`.
+
+use crate::des_lexer::LexError;
+use crate::des_parser::DesParseError;
+use crate::dungeon_parser::DungeonParseError;
+use crate::lev_reader::LevReadError;
+
+/// Any error from this crate. Each module's own error stays public and
+/// converts into this with `From`.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Lex(#[from] LexError),
+    #[error(transparent)]
+    DesParse(#[from] DesParseError),
+    #[error(transparent)]
+    DungeonParse(#[from] DungeonParseError),
+    #[error(transparent)]
+    LevRead(#[from] LevReadError),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{des_lexer, des_parser};
+
+    fn compile(src: &str) -> Result<usize, Error> {
+        let tokens = des_lexer::lex(src)?;
+        let des = des_parser::parse_des(tokens)?;
+        Ok(des.levels.len())
+    }
+
+    #[test]
+    fn propagates_lex_and_parse_errors() {
+        assert_eq!(compile("MAZE:\"test\",' '\n").unwrap(), 1);
+        let err = compile("MAZE:\"test\n").unwrap_err();
+        assert!(matches!(err, Error::Lex(_)), "{err:?}");
+        let err = compile("MAZE:\"test\",' '\nFOUNTAIN 3\n").unwrap_err();
+        assert!(matches!(err, Error::DesParse(_)), "{err:?}");
+        assert!(err.to_string().starts_with("line 2:"), "{err}");
+    }
+}
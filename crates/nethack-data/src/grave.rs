@@ -0,0 +1,68 @@
+//! Grave epitaphs used when a `.des` `GRAVE` has no text of its own.
+
+use nethack_rng::NhRng;
+
+/// Epitaphs from `engrave.c`'s `epitaphs[]` table.
+///
+/// NetHack 3.6 moved these into `dat/epitaph` and reads a random line via
+/// `get_rnd_text()`, which seeks by byte offset; selection here is a plain
+/// `rn2` over the table, so the draw does not reproduce C's choice.
+pub const EPITAPHS: &[&str] = &[
+    "Rest in peace",
+    "R.I.P.",
+    "Rest In Pieces",
+    "Note -- there are NO valid items in this grave",
+    "1994-1995. The Longest-Lived Hacker Ever",
+    "The Grave of the Unknown Hacker",
+    "We weren't sure who this was, but we buried him here anyway",
+    "Sparky -- he was a very good dog",
+    "Beware of Electric Third Rail",
+    "Made in Taiwan",
+    "Og friend. Og good dude. Og died. Og now food",
+    "Beetlejuice Beetlejuice Beetlejuice",
+    "Look out below!",
+    "Please don't dig me up. I'm perfectly happy down here. -- Resident",
+    "Postman, please note forwarding address: Gehennom, Asmodeus's Fortress, fifth lemure on the left",
+    "Mary had a little lamb/Its fleece was white as snow/When Mary was in trouble/The lamb was first to go",
+    "Be careful, or this could happen to you!",
+    "Soon you'll join this fellow in hell! -- the Wizard of Yendor",
+    "Caution! This grave contains toxic waste",
+    "Sum quod eris",
+    "Here lies an Atheist, all dressed up and no place to go",
+    "Here lies Ezekiel, age 102.  The good die young.",
+    "Here lies my wife: Here let her lie! Now she's at rest and so am I.",
+    "Here lies Johnny Yeast. Pardon me for not rising.",
+    "He always lied while on the earth and now he's lying in it",
+    "I made an ash of myself",
+    "Soon ripe. Soon rotten. Soon gone. But not forgotten.",
+    "Here lies the body of Jonathan Blake. Stepped on the gas instead of the brake.",
+    "Go away!",
+];
+
+/// Pick a random epitaph on the core stream (one `rn2` draw).
+pub fn random_epitaph(rng: &mut NhRng) -> &'static str {
+    EPITAPHS[rng.rn2(EPITAPHS.len() as i32) as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_epitaph_is_stable() {
+        let mut rng = NhRng::new(42);
+        let first = random_epitaph(&mut rng);
+        assert_eq!(first, "Caution! This grave contains toxic waste");
+        let mut rng = NhRng::new(42);
+        assert_eq!(random_epitaph(&mut rng), first);
+    }
+
+    #[test]
+    fn consumes_one_draw() {
+        let mut a = NhRng::new(7);
+        let mut b = NhRng::new(7);
+        random_epitaph(&mut a);
+        b.rn2(EPITAPHS.len() as i32);
+        assert_eq!(a.rn2(1000), b.rn2(1000));
+    }
+}
@@ -3,46 +3,61 @@
 //! Parses the binary opcode stream into the same [`SpLevOpcode`] representation
 //! used by the Rust `.des` parser, enabling comparison between the two.
 
-use nethack_types::sp_lev::{SpLevOpcode, SpOpcode, SpOperand};
+use nethack_types::Selection;
+use nethack_types::sp_lev::{HumidityFlags, SpLevOpcode, SpOpcode, SpOperand};
 
 /// Version header size: 5 × `unsigned long` (8 bytes each on 64-bit Linux).
-const VERSION_HEADER_SIZE: usize = 40;
-
-const SPOVAR_NULL: u8 = 0x00;
-const SPOVAR_INT: u8 = 0x01;
-const SPOVAR_STRING: u8 = 0x02;
-const SPOVAR_VARIABLE: u8 = 0x03;
-const SPOVAR_COORD: u8 = 0x04;
-const SPOVAR_REGION: u8 = 0x05;
-const SPOVAR_MAPCHAR: u8 = 0x06;
-const SPOVAR_MONST: u8 = 0x07;
-const SPOVAR_OBJ: u8 = 0x08;
-const SPOVAR_SEL: u8 = 0x09;
+pub(crate) const VERSION_HEADER_SIZE: usize = 40;
+
+pub(crate) const SPOVAR_NULL: u8 = 0x00;
+pub(crate) const SPOVAR_INT: u8 = 0x01;
+pub(crate) const SPOVAR_STRING: u8 = 0x02;
+pub(crate) const SPOVAR_VARIABLE: u8 = 0x03;
+pub(crate) const SPOVAR_COORD: u8 = 0x04;
+pub(crate) const SPOVAR_REGION: u8 = 0x05;
+pub(crate) const SPOVAR_MAPCHAR: u8 = 0x06;
+pub(crate) const SPOVAR_MONST: u8 = 0x07;
+pub(crate) const SPOVAR_OBJ: u8 = 0x08;
+pub(crate) const SPOVAR_SEL: u8 = 0x09;
 
 /// Bit that marks a coord as random in the packed i64 representation.
-const SP_COORD_IS_RANDOM: i64 = 0x0100_0000;
+pub(crate) const SP_COORD_IS_RANDOM: i64 = 0x0100_0000;
 
 #[derive(Debug, thiserror::Error)]
 pub enum LevReadError {
-    #[error("unexpected end of data at offset {offset}")]
-    UnexpectedEof { offset: usize },
-    #[error("unknown opcode {value} at offset {offset}")]
-    UnknownOpcode { value: i32, offset: usize },
-    #[error("unknown spovartyp {value} at offset {offset}")]
-    UnknownSpovartyp { value: u8, offset: usize },
-    #[error("invalid UTF-8 string at offset {offset}")]
-    InvalidUtf8 { offset: usize },
+    #[error("unexpected end of data at opcode {index} (offset {offset})")]
+    UnexpectedEof { index: usize, offset: usize },
+    #[error("unknown opcode {value} at opcode {index} (offset {offset})")]
+    UnknownOpcode {
+        value: i32,
+        index: usize,
+        offset: usize,
+    },
+    #[error("unknown spovartyp {value} at opcode {index} (offset {offset})")]
+    UnknownSpovartyp {
+        value: u8,
+        index: usize,
+        offset: usize,
+    },
+    #[error("invalid UTF-8 string at opcode {index} (offset {offset})")]
+    InvalidUtf8 { index: usize, offset: usize },
 }
 
 /// Cursor for reading little-endian binary data.
 struct Reader<'a> {
     data: &'a [u8],
     pos: usize,
+    /// Index of the opcode being read, for error context.
+    index: usize,
 }
 
 impl<'a> Reader<'a> {
     fn new(data: &'a [u8]) -> Self {
-        Self { data, pos: 0 }
+        Self {
+            data,
+            pos: 0,
+            index: 0,
+        }
     }
 
     fn remaining(&self) -> usize {
@@ -51,7 +66,10 @@ impl<'a> Reader<'a> {
 
     fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], LevReadError> {
         if self.remaining() < n {
-            return Err(LevReadError::UnexpectedEof { offset: self.pos });
+            return Err(LevReadError::UnexpectedEof {
+                index: self.index,
+                offset: self.pos,
+            });
         }
         let slice = &self.data[self.pos..self.pos + n];
         self.pos += n;
@@ -74,7 +92,10 @@ impl<'a> Reader<'a> {
 
     fn skip(&mut self, n: usize) -> Result<(), LevReadError> {
         if self.remaining() < n {
-            return Err(LevReadError::UnexpectedEof { offset: self.pos });
+            return Err(LevReadError::UnexpectedEof {
+                index: self.index,
+                offset: self.pos,
+            });
         }
         self.pos += n;
         Ok(())
@@ -85,7 +106,7 @@ impl<'a> Reader<'a> {
 fn unpack_coord(packed: i64) -> SpOperand {
     if packed & SP_COORD_IS_RANDOM != 0 {
         // Random coord: lower bits are humidity flags
-        let flags = (packed & 0xFF) as u32;
+        let flags = HumidityFlags::from_bits_retain((packed & 0xFF) as u8);
         SpOperand::Coord {
             x: -1,
             y: -1,
@@ -99,7 +120,7 @@ fn unpack_coord(packed: i64) -> SpOperand {
             x,
             y,
             is_random: false,
-            flags: 0,
+            flags: HumidityFlags::empty(),
         }
     }
 }
@@ -142,83 +163,201 @@ fn unpack_obj(packed: i64) -> SpOperand {
 /// - `n_opcodes: i64`
 /// - For each opcode: `opcode: i32`, then if `Push`: `spovartyp: u8` + payload
 pub fn read_lev(data: &[u8]) -> Result<Vec<SpLevOpcode>, LevReadError> {
+    match read_lev_partial(data) {
+        (opcodes, None) => Ok(opcodes),
+        (_, Some(err)) => Err(err),
+    }
+}
+
+/// Like [`read_lev`], but on a damaged or truncated file also returns the
+/// opcodes decoded before the error, for debugging.
+pub fn read_lev_partial(data: &[u8]) -> (Vec<SpLevOpcode>, Option<LevReadError>) {
     let mut r = Reader::new(data);
+    let mut opcodes = Vec::new();
 
     // Skip version_info header
-    r.skip(VERSION_HEADER_SIZE)?;
-
-    let n_opcodes = r.read_i64()?;
-    let mut opcodes = Vec::with_capacity(n_opcodes as usize);
-
-    for _ in 0..n_opcodes {
-        let op_offset = r.pos;
-        let raw_opcode = r.read_i32()?;
-        let opcode = SpOpcode::from_repr(raw_opcode as u8).ok_or(LevReadError::UnknownOpcode {
-            value: raw_opcode,
-            offset: op_offset,
-        })?;
-
-        let operand = if opcode == SpOpcode::Push {
-            let typ_offset = r.pos;
-            let spovartyp = r.read_u8()?;
-            match spovartyp {
-                SPOVAR_NULL => None,
-                SPOVAR_INT => {
-                    let val = r.read_i64()?;
-                    Some(SpOperand::Int(val))
-                }
-                SPOVAR_STRING => {
-                    let len = r.read_i32()? as usize;
-                    let bytes = r.read_bytes(len)?;
-                    let s = std::str::from_utf8(bytes)
-                        .map_err(|_| LevReadError::InvalidUtf8 { offset: r.pos })?;
-                    Some(SpOperand::String(s.to_string()))
-                }
-                SPOVAR_VARIABLE => {
-                    let len = r.read_i32()? as usize;
-                    let bytes = r.read_bytes(len)?;
-                    let s = std::str::from_utf8(bytes)
-                        .map_err(|_| LevReadError::InvalidUtf8 { offset: r.pos })?;
-                    Some(SpOperand::Variable(s.to_string()))
-                }
-                SPOVAR_COORD => {
-                    let packed = r.read_i64()?;
-                    Some(unpack_coord(packed))
-                }
-                SPOVAR_REGION => {
-                    let packed = r.read_i64()?;
-                    Some(unpack_region(packed))
-                }
-                SPOVAR_MAPCHAR => {
-                    let packed = r.read_i64()?;
-                    Some(unpack_mapchar(packed))
-                }
-                SPOVAR_MONST => {
-                    let packed = r.read_i64()?;
-                    Some(unpack_monst(packed))
-                }
-                SPOVAR_OBJ => {
-                    let packed = r.read_i64()?;
-                    Some(unpack_obj(packed))
-                }
-                SPOVAR_SEL => {
-                    let len = r.read_i32()? as usize;
-                    let bytes = r.read_bytes(len)?;
-                    Some(SpOperand::Sel(bytes.to_vec()))
-                }
-                _ => {
-                    return Err(LevReadError::UnknownSpovartyp {
-                        value: spovartyp,
-                        offset: typ_offset,
-                    });
-                }
+    let n_opcodes = match r.skip(VERSION_HEADER_SIZE).and_then(|()| r.read_i64()) {
+        Ok(n) => n,
+        Err(err) => return (opcodes, Some(err)),
+    };
+    opcodes.reserve((n_opcodes as usize).min(r.remaining() / 4));
+
+    for index in 0..n_opcodes as usize {
+        r.index = index;
+        match read_opcode(&mut r) {
+            Ok(op) => opcodes.push(op),
+            Err(err) => return (opcodes, Some(err)),
+        }
+    }
+
+    (opcodes, None)
+}
+
+/// Read the opcode at the cursor and, for `Push`, its operand.
+fn read_opcode(r: &mut Reader) -> Result<SpLevOpcode, LevReadError> {
+    let index = r.index;
+    let op_offset = r.pos;
+    let raw_opcode = r.read_i32()?;
+    let opcode = SpOpcode::from_repr(raw_opcode as u8).ok_or(LevReadError::UnknownOpcode {
+        value: raw_opcode,
+        index,
+        offset: op_offset,
+    })?;
+
+    let operand = if opcode == SpOpcode::Push {
+        let typ_offset = r.pos;
+        let spovartyp = r.read_u8()?;
+        match spovartyp {
+            SPOVAR_NULL => None,
+            SPOVAR_INT => {
+                let val = r.read_i64()?;
+                Some(SpOperand::Int(val))
+            }
+            SPOVAR_STRING => {
+                let len = r.read_i32()? as usize;
+                let bytes = r.read_bytes(len)?;
+                let s = std::str::from_utf8(bytes).map_err(|_| LevReadError::InvalidUtf8 {
+                    index,
+                    offset: r.pos,
+                })?;
+                Some(SpOperand::String(s.to_string()))
+            }
+            SPOVAR_VARIABLE => {
+                let len = r.read_i32()? as usize;
+                let bytes = r.read_bytes(len)?;
+                let s = std::str::from_utf8(bytes).map_err(|_| LevReadError::InvalidUtf8 {
+                    index,
+                    offset: r.pos,
+                })?;
+                Some(SpOperand::Variable(s.to_string()))
+            }
+            SPOVAR_COORD => {
+                let packed = r.read_i64()?;
+                Some(unpack_coord(packed))
+            }
+            SPOVAR_REGION => {
+                let packed = r.read_i64()?;
+                Some(unpack_region(packed))
+            }
+            SPOVAR_MAPCHAR => {
+                let packed = r.read_i64()?;
+                Some(unpack_mapchar(packed))
+            }
+            SPOVAR_MONST => {
+                let packed = r.read_i64()?;
+                Some(unpack_monst(packed))
+            }
+            SPOVAR_OBJ => {
+                let packed = r.read_i64()?;
+                Some(unpack_obj(packed))
+            }
+            SPOVAR_SEL => {
+                let len = r.read_i32()? as usize;
+                let bytes = r.read_bytes(len)?;
+                Some(SpOperand::Sel {
+                    sel: Selection::from_lev_bytes(bytes),
+                    raw: Some(bytes.to_vec()),
+                })
             }
-        } else {
-            None
-        };
+            _ => {
+                return Err(LevReadError::UnknownSpovartyp {
+                    value: spovartyp,
+                    index,
+                    offset: typ_offset,
+                });
+            }
+        }
+    } else {
+        None
+    };
+
+    Ok(SpLevOpcode { opcode, operand })
+}
 
-        opcodes.push(SpLevOpcode { opcode, operand });
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a `.lev` image from raw `(opcode, payload)` records.
+    fn lev_image(records: &[(i32, &[u8])]) -> Vec<u8> {
+        let mut data = vec![0u8; VERSION_HEADER_SIZE];
+        data.extend_from_slice(&(records.len() as i64).to_le_bytes());
+        for (opcode, payload) in records {
+            data.extend_from_slice(&opcode.to_le_bytes());
+            data.extend_from_slice(payload);
+        }
+        data
     }
 
-    Ok(opcodes)
+    fn push_int(val: i64) -> Vec<u8> {
+        let mut payload = vec![SPOVAR_INT];
+        payload.extend_from_slice(&val.to_le_bytes());
+        payload
+    }
+
+    #[test]
+    fn reads_simple_stream() {
+        let one = push_int(1);
+        let data = lev_image(&[(SpOpcode::Push as i32, &one), (SpOpcode::Exit as i32, &[])]);
+        let ops = read_lev(&data).unwrap();
+        assert_eq!(ops.len(), 2);
+        assert_eq!(ops[0].operand, Some(SpOperand::Int(1)));
+        assert_eq!(ops[1].opcode, SpOpcode::Exit);
+    }
+
+    #[test]
+    fn unknown_opcode_reports_index() {
+        let one = push_int(1);
+        let data = lev_image(&[
+            (SpOpcode::Push as i32, &one),
+            (SpOpcode::Push as i32, &one),
+            (SpOpcode::Pop as i32, &[]),
+            (250, &[]),
+            (SpOpcode::Exit as i32, &[]),
+        ]);
+        let err = read_lev(&data).unwrap_err();
+        match err {
+            LevReadError::UnknownOpcode { value, index, .. } => {
+                assert_eq!(value, 250);
+                assert_eq!(index, 3);
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+        assert!(err.to_string().contains("opcode 3"));
+    }
+
+    #[test]
+    fn truncated_push_reports_index() {
+        let one = push_int(1);
+        let mut data = lev_image(&[(SpOpcode::Push as i32, &one), (SpOpcode::Push as i32, &one)]);
+        data.truncate(data.len() - 4);
+        assert!(matches!(
+            read_lev(&data),
+            Err(LevReadError::UnexpectedEof { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn partial_read_keeps_decoded_opcodes() {
+        let one = push_int(1);
+        let two = push_int(2);
+        let mut data = lev_image(&[
+            (SpOpcode::Push as i32, &one),
+            (SpOpcode::Push as i32, &two),
+            (SpOpcode::Exit as i32, &[]),
+        ]);
+        data.truncate(data.len() - 8);
+        let (ops, err) = read_lev_partial(&data);
+        assert_eq!(ops.len(), 1);
+        assert_eq!(ops[0].operand, Some(SpOperand::Int(1)));
+        assert!(matches!(
+            err,
+            Some(LevReadError::UnexpectedEof { index: 1, .. })
+        ));
+
+        let whole = lev_image(&[(SpOpcode::Exit as i32, &[])]);
+        let (ops, err) = read_lev_partial(&whole);
+        assert_eq!(ops.len(), 1);
+        assert!(err.is_none());
+    }
 }
@@ -0,0 +1,267 @@
+//! Writer for the `.lev` binary format read by [`lev_reader`](crate::lev_reader).
+//!
+//! Produces the same layout as C's `lev_comp`, except that the version header
+//! is zero-filled; `read_lev` skips it.
+
+use nethack_types::sp_lev::{HumidityFlags, SpLevOpcode, SpOperand};
+
+use crate::lev_reader::{
+    SP_COORD_IS_RANDOM, SPOVAR_COORD, SPOVAR_INT, SPOVAR_MAPCHAR, SPOVAR_MONST, SPOVAR_NULL,
+    SPOVAR_OBJ, SPOVAR_REGION, SPOVAR_SEL, SPOVAR_STRING, SPOVAR_VARIABLE, VERSION_HEADER_SIZE,
+};
+
+fn pack_coord(x: i16, y: i16, is_random: bool, flags: HumidityFlags) -> i64 {
+    if is_random {
+        SP_COORD_IS_RANDOM | flags.bits() as i64
+    } else {
+        (x as i64 & 0xFF) | ((y as i64 & 0xFF) << 16)
+    }
+}
+
+fn pack_region(x1: i16, y1: i16, x2: i16, y2: i16) -> i64 {
+    (x1 as i64 & 0xFF)
+        | ((y1 as i64 & 0xFF) << 8)
+        | ((x2 as i64 & 0xFF) << 16)
+        | ((y2 as i64 & 0xFF) << 24)
+}
+
+/// Shared packing for `SP_MAPCHAR_PACK`, `SP_MONST_PACK` and `SP_OBJ_PACK`.
+///
+/// `lev_comp` stores a fully random monster or object as a bare `-1`, which
+/// unpacks to the all-ones pair; write that back as `-1` so the high bits
+/// survive.
+fn pack_pair(low: i16, high: i16) -> i64 {
+    let packed = (low as i64 & 0xFF) | (((high as i64 + 10) & 0xFFFF) << 8);
+    if packed == 0xFF_FFFF { -1 } else { packed }
+}
+
+fn write_bytes(out: &mut Vec<u8>, typ: u8, bytes: &[u8]) {
+    out.push(typ);
+    out.extend_from_slice(&(bytes.len() as i32).to_le_bytes());
+    out.extend_from_slice(bytes);
+}
+
+fn write_packed(out: &mut Vec<u8>, typ: u8, val: i64) {
+    out.push(typ);
+    out.extend_from_slice(&val.to_le_bytes());
+}
+
+fn write_operand(out: &mut Vec<u8>, operand: Option<&SpOperand>) {
+    match operand {
+        None => out.push(SPOVAR_NULL),
+        Some(SpOperand::Int(v)) => write_packed(out, SPOVAR_INT, *v),
+        Some(SpOperand::String(s)) => write_bytes(out, SPOVAR_STRING, s.as_bytes()),
+        Some(SpOperand::Variable(s)) => write_bytes(out, SPOVAR_VARIABLE, s.as_bytes()),
+        Some(&SpOperand::Coord {
+            x,
+            y,
+            is_random,
+            flags,
+        }) => write_packed(out, SPOVAR_COORD, pack_coord(x, y, is_random, flags)),
+        Some(&SpOperand::Region { x1, y1, x2, y2 }) => {
+            write_packed(out, SPOVAR_REGION, pack_region(x1, y1, x2, y2))
+        }
+        Some(&SpOperand::MapChar { typ, lit }) => {
+            write_packed(out, SPOVAR_MAPCHAR, pack_pair(typ, lit))
+        }
+        Some(&SpOperand::Monst { class, id }) => {
+            write_packed(out, SPOVAR_MONST, pack_pair(class, id))
+        }
+        Some(&SpOperand::Obj { class, id }) => write_packed(out, SPOVAR_OBJ, pack_pair(class, id)),
+        Some(SpOperand::Sel { raw: Some(raw), .. }) => write_bytes(out, SPOVAR_SEL, raw),
+        Some(SpOperand::Sel { sel, raw: None }) => {
+            write_bytes(out, SPOVAR_SEL, &sel.to_lev_bytes())
+        }
+    }
+}
+
+/// Serialize an opcode stream in the `.lev` binary format.
+///
+/// Operands are only written for `Push` opcodes, matching what `read_lev`
+/// (and C's `sp_level_loader`) expects.
+pub fn write_lev(opcodes: &[SpLevOpcode]) -> Vec<u8> {
+    let mut out = vec![0u8; VERSION_HEADER_SIZE];
+    out.extend_from_slice(&(opcodes.len() as i64).to_le_bytes());
+    for op in opcodes {
+        out.extend_from_slice(&(op.opcode as i32).to_le_bytes());
+        if op.opcode == nethack_types::SpOpcode::Push {
+            write_operand(&mut out, op.operand.as_ref());
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lev_reader::read_lev;
+    use nethack_types::SpOpcode;
+    use nethack_types::{COLNO, ROWNO, Selection};
+
+    const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/lev");
+
+    fn push(operand: SpOperand) -> SpLevOpcode {
+        SpLevOpcode {
+            opcode: SpOpcode::Push,
+            operand: Some(operand),
+        }
+    }
+
+    #[test]
+    fn selection_bytes_round_trip() {
+        let mut sel = Selection::new();
+        sel.set(0, 0, true);
+        sel.set(10, 5, true);
+        sel.set(79, 20, true);
+        let raw = sel.to_lev_bytes();
+        assert_eq!(raw.len(), COLNO * ROWNO);
+        assert_eq!(raw[5 * COLNO + 10], 2);
+        assert_eq!(raw[1], 1);
+        assert_eq!(Selection::from_lev_bytes(&raw), sel);
+    }
+
+    /// No `lev_comp` fixture pushes a selection, so the expected file is
+    /// assembled the way C's writer lays out a `SPOVAR_SEL` string.
+    #[test]
+    fn selection_matches_c_layout() {
+        let mut sel = Selection::new();
+        sel.set(3, 2, true);
+        sel.set(4, 2, true);
+
+        let mut cells = [1u8; COLNO * ROWNO];
+        cells[2 * COLNO + 3] = 2;
+        cells[2 * COLNO + 4] = 2;
+        let mut expected = vec![0u8; VERSION_HEADER_SIZE];
+        expected.extend_from_slice(&1i64.to_le_bytes());
+        expected.extend_from_slice(&(SpOpcode::Push as i32).to_le_bytes());
+        expected.push(SPOVAR_SEL);
+        expected.extend_from_slice(&((COLNO * ROWNO) as i32).to_le_bytes());
+        expected.extend_from_slice(&cells);
+
+        let ops = [push(SpOperand::Sel {
+            sel: sel.clone(),
+            raw: None,
+        })];
+        assert_eq!(write_lev(&ops), expected);
+        let read = [push(SpOperand::Sel {
+            sel,
+            raw: Some(cells.to_vec()),
+        })];
+        assert_eq!(read_lev(&expected).unwrap(), read);
+    }
+
+    #[test]
+    fn selection_round_trips_through_lev() {
+        let mut sel = Selection::new();
+        for x in 5..15 {
+            sel.set(x, 3, true);
+        }
+        let ops = vec![
+            push(SpOperand::Sel {
+                sel: sel.clone(),
+                raw: Some(sel.to_lev_bytes()),
+            }),
+            SpLevOpcode {
+                opcode: SpOpcode::Pop,
+                operand: None,
+            },
+        ];
+        let read = read_lev(&write_lev(&ops)).unwrap();
+        assert_eq!(read, ops);
+        assert!(matches!(&read[0].operand, Some(SpOperand::Sel { sel: s, .. }) if *s == sel));
+    }
+
+    /// Bytes that decode to the same selection but are not what
+    /// `to_lev_bytes` would write are kept, so the file round-trips.
+    #[test]
+    fn raw_selection_bytes_are_kept() {
+        let raw = vec![1, 3, 2, 1];
+        let mut file = vec![0u8; VERSION_HEADER_SIZE];
+        file.extend_from_slice(&1i64.to_le_bytes());
+        file.extend_from_slice(&(SpOpcode::Push as i32).to_le_bytes());
+        file.push(SPOVAR_SEL);
+        file.extend_from_slice(&(raw.len() as i32).to_le_bytes());
+        file.extend_from_slice(&raw);
+
+        let read = read_lev(&file).unwrap();
+        let Some(SpOperand::Sel { sel, raw: kept }) = &read[0].operand else {
+            panic!("expected a selection, got {:?}", read[0].operand);
+        };
+        assert_eq!(kept.as_deref(), Some(&raw[..]));
+        assert_eq!(sel.iter().collect::<Vec<_>>(), vec![(1, 0), (2, 0)]);
+        assert_eq!(write_lev(&read), file);
+    }
+
+    #[test]
+    fn every_operand_kind_round_trips() {
+        let ops = vec![
+            push(SpOperand::Int(-42)),
+            push(SpOperand::String("hello".into())),
+            push(SpOperand::Variable("$place".into())),
+            push(SpOperand::Coord {
+                x: 12,
+                y: 7,
+                is_random: false,
+                flags: HumidityFlags::empty(),
+            }),
+            push(SpOperand::Coord {
+                x: -1,
+                y: -1,
+                is_random: true,
+                flags: HumidityFlags::DRY | HumidityFlags::ANY_LOC,
+            }),
+            push(SpOperand::Region {
+                x1: 1,
+                y1: 2,
+                x2: 30,
+                y2: 15,
+            }),
+            push(SpOperand::MapChar { typ: 24, lit: -1 }),
+            push(SpOperand::Monst {
+                class: 'a' as i16,
+                id: 0,
+            }),
+            push(SpOperand::Obj {
+                class: 255,
+                id: -11,
+            }),
+            SpLevOpcode {
+                opcode: SpOpcode::Exit,
+                operand: None,
+            },
+        ];
+        assert_eq!(read_lev(&write_lev(&ops)).unwrap(), ops);
+    }
+
+    #[test]
+    fn humidity_flags_round_trip() {
+        let flags = HumidityFlags::WET | HumidityFlags::SPACELOC;
+        let ops = vec![push(SpOperand::Coord {
+            x: -1,
+            y: -1,
+            is_random: true,
+            flags,
+        })];
+        let bytes = write_lev(&ops);
+        assert_eq!(read_lev(&bytes).unwrap(), ops);
+        // Packed as SP_COORD_PACK_RANDOM(WET | SPACELOC).
+        let packed = SP_COORD_IS_RANDOM | 0x42;
+        assert_eq!(bytes[bytes.len() - 8..], packed.to_le_bytes());
+    }
+
+    #[test]
+    fn c_fixtures_round_trip() {
+        for name in ["castle", "minefill", "bigrm-1", "oracle"] {
+            let data = std::fs::read(format!("{FIXTURES_DIR}/{name}.lev")).unwrap();
+            let ops = read_lev(&data).unwrap();
+            let rewritten = write_lev(&ops);
+            assert_eq!(read_lev(&rewritten).unwrap(), ops, "{name}");
+            // Everything after the version header is byte-identical.
+            assert_eq!(
+                rewritten[VERSION_HEADER_SIZE..],
+                data[VERSION_HEADER_SIZE..],
+                "{name}"
+            );
+        }
+    }
+}
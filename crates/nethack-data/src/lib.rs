@@ -1,9 +1,20 @@
+mod csv;
 pub mod des_lexer;
 pub mod des_parser;
+pub mod disasm;
+mod dump;
 pub mod dungeon_parser;
+mod error;
+pub mod grave;
 pub mod lev_reader;
+pub mod lev_writer;
 pub mod monsters;
 pub mod objects;
+pub mod roles;
+pub mod worn;
+
+pub use dump::dump_tables_json;
+pub use error::Error;
 
 #[cfg(test)]
 mod tests {
@@ -115,6 +126,81 @@ mod tests {
         assert_eq!(ls.color, Color::Cyan); // HI_METAL
     }
 
+    #[test]
+    fn steeds() {
+        let rideable = |id: MonsterId| MONSTERS[id as usize].can_be_ridden();
+        assert!(rideable(MonsterId::Horse));
+        assert!(rideable(MonsterId::Pony));
+        assert!(rideable(MonsterId::ForestCentaur));
+        assert!(rideable(MonsterId::RedDragon));
+        assert!(!rideable(MonsterId::GiantAnt));
+        assert!(!rideable(MonsterId::Aleax));
+    }
+
+    #[test]
+    fn giant_ant_summary() {
+        let summary = MONSTERS[MonsterId::GiantAnt as usize].summary();
+        assert!(summary.starts_with("giant ant (a)"), "{summary}");
+        assert!(summary.contains("lvl 2"), "{summary}");
+        assert!(summary.contains("AC 3"), "{summary}");
+        assert!(summary.contains("Bite 1d4 Physical"), "{summary}");
+    }
+
+    #[test]
+    fn giant_ant_sacrifice_value() {
+        let ant = &MONSTERS[MonsterId::GiantAnt as usize];
+        let base = ant.difficulty as i32 + 1;
+        assert_eq!(ant.sacrifice_value(Some(Alignment::Lawful)), base);
+        assert_eq!(ant.sacrifice_value(None), base);
+    }
+
+    #[test]
+    fn unicorn_sacrifice_value() {
+        let white = &MONSTERS[MonsterId::WhiteUnicorn as usize];
+        let base = white.difficulty as i32 + 1;
+        // Cross-aligned unicorn on the hero's own altar.
+        assert_eq!(white.sacrifice_value(Some(Alignment::Chaotic)), base + 3);
+        // Co-aligned unicorn.
+        assert_eq!(white.sacrifice_value(Some(Alignment::Lawful)), -5);
+    }
+
+    #[test]
+    fn cross_aligned_unique_sacrifice_value() {
+        // C gives uniques no special treatment: Medusa is just her difficulty.
+        let medusa = &MONSTERS[MonsterId::Medusa as usize];
+        assert_eq!(medusa.alignment, Alignment::Chaotic);
+        assert_eq!(
+            medusa.sacrifice_value(Some(Alignment::Lawful)),
+            medusa.difficulty as i32 + 1
+        );
+    }
+
+    #[test]
+    fn undead_sacrifice_value() {
+        let mummy = &MONSTERS[MonsterId::HumanMummy as usize];
+        let base = mummy.difficulty as i32 + 1;
+        assert_eq!(mummy.sacrifice_value(Some(Alignment::Lawful)), base + 1);
+        assert_eq!(mummy.sacrifice_value(Some(Alignment::Chaotic)), base);
+    }
+
+    #[test]
+    fn long_sword_summary() {
+        let summary = OBJECTS[ObjectId::LongSword as usize].summary();
+        assert!(summary.starts_with("long sword ())"), "{summary}");
+        assert!(summary.contains("Weapon Iron"), "{summary}");
+        assert!(summary.contains("wt 40 cost 15"), "{summary}");
+        assert!(summary.contains("dmg d8/d12"), "{summary}");
+    }
+
+    #[test]
+    fn light_radii() {
+        let radius = |id: ObjectId| OBJECTS[id as usize].light_radius();
+        assert_eq!(radius(ObjectId::OilLamp), 3);
+        assert_eq!(radius(ObjectId::WaxCandle), 2);
+        assert_eq!(radius(ObjectId::LongSword), 0);
+        assert_eq!(OBJECTS.iter().filter(|o| o.light_radius() > 0).count(), 7);
+    }
+
     #[test]
     fn plate_mail_spot_check() {
         let pm = &OBJECTS[ObjectId::PlateMail as usize];
@@ -0,0 +1,11086 @@
+// @generated by tools/extract_data.py — do not edit manually
+
+#![allow(clippy::unreadable_literal)]
+
+use nethack_types::*;
+
+pub static MONSTERS: [MonsterType; 382] = [
+    MonsterType {
+        name: "giant ant",
+        symbol: 'a',
+        level: 2,
+        move_speed: 18,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A3),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 10,
+        nutrition: 10,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20442000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 4,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "killer bee",
+        symbol: 'a',
+        level: 1,
+        move_speed: 18,
+        ac: -1,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0062),
+        attacks: [
+            Attack::new(AttackType::Sting, DamageType::DrainStr, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1,
+        nutrition: 5,
+        sound: MonsterSound::Buzz,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x10042001),
+        flags2: MonsterFlags2::from_bits_truncate(0x00120000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 5,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "soldier ant",
+        symbol: 'a',
+        level: 3,
+        move_speed: 18,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A2),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Sting, DamageType::DrainStr, 3, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 20,
+        nutrition: 5,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x30442000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "fire ant",
+        symbol: 'a',
+        level: 3,
+        move_speed: 18,
+        ac: 3,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Bite, DamageType::Fire, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 30,
+        nutrition: 10,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0x01),
+        conveys: Resistance::from_bits_truncate(0x01),
+        flags1: MonsterFlags1::from_bits_truncate(0x20442000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 6,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "giant beetle",
+        symbol: 'a',
+        level: 5,
+        move_speed: 6,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0023),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 10,
+        nutrition: 10,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x30042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "queen bee",
+        symbol: 'a',
+        level: 9,
+        move_speed: 24,
+        ac: -4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0220),
+        attacks: [
+            Attack::new(AttackType::Sting, DamageType::DrainStr, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1,
+        nutrition: 5,
+        sound: MonsterSound::Buzz,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x10442001),
+        flags2: MonsterFlags2::from_bits_truncate(0x00120800),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 12,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "acid blob",
+        symbol: 'b',
+        level: 1,
+        move_speed: 3,
+        ac: 8,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::None, DamageType::Acid, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 30,
+        nutrition: 10,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0xE4),
+        conveys: Resistance::from_bits_truncate(0x80),
+        flags1: MonsterFlags1::from_bits_truncate(0x0801F404),
+        flags2: MonsterFlags2::from_bits_truncate(0x00840000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 2,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "quivering blob",
+        symbol: 'b',
+        level: 5,
+        move_speed: 1,
+        ac: 8,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 200,
+        nutrition: 100,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x24),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001F000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00940000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "gelatinous cube",
+        symbol: 'b',
+        level: 6,
+        move_speed: 6,
+        ac: 8,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Paralyze, 2, 4),
+            Attack::new(AttackType::None, DamageType::Paralyze, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 150,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0xF7),
+        conveys: Resistance::from_bits_truncate(0x17),
+        flags1: MonsterFlags1::from_bits_truncate(0x6801F000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00940000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 8,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "chickatrice",
+        symbol: 'c',
+        level: 4,
+        move_speed: 4,
+        ac: 8,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 2),
+            Attack::new(AttackType::Touch, DamageType::Stone, 0, 0),
+            Attack::new(AttackType::None, DamageType::Stone, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 10,
+        nutrition: 10,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0xA0),
+        conveys: Resistance::from_bits_truncate(0xA0),
+        flags1: MonsterFlags1::from_bits_truncate(0x60042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "cockatrice",
+        symbol: 'c',
+        level: 5,
+        move_speed: 6,
+        ac: 6,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0025),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Touch, DamageType::Stone, 0, 0),
+            Attack::new(AttackType::None, DamageType::Stone, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 30,
+        nutrition: 30,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0xA0),
+        conveys: Resistance::from_bits_truncate(0xA0),
+        flags1: MonsterFlags1::from_bits_truncate(0x60442000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "pyrolisk",
+        symbol: 'c',
+        level: 6,
+        move_speed: 6,
+        ac: 6,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Gaze, DamageType::Fire, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 30,
+        nutrition: 30,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::from_bits_truncate(0x21),
+        flags1: MonsterFlags1::from_bits_truncate(0x60442000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "jackal",
+        symbol: 'd',
+        level: 0,
+        move_speed: 12,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A3),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 2),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 300,
+        nutrition: 250,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 1,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "fox",
+        symbol: 'd',
+        level: 0,
+        move_speed: 15,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 300,
+        nutrition: 250,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 1,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "coyote",
+        symbol: 'd',
+        level: 1,
+        move_speed: 12,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 300,
+        nutrition: 250,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 2,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "werejackal",
+        symbol: 'd',
+        level: 2,
+        move_speed: 12,
+        ac: 7,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0210),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Were, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 300,
+        nutrition: 250,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x30802000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100005),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 4,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "little dog",
+        symbol: 'd',
+        level: 2,
+        move_speed: 18,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 150,
+        nutrition: 150,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00400000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 3,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "dingo",
+        symbol: 'd',
+        level: 4,
+        move_speed: 16,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 200,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 5,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "dog",
+        symbol: 'd',
+        level: 4,
+        move_speed: 16,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 200,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00400000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 5,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "large dog",
+        symbol: 'd',
+        level: 6,
+        move_speed: 15,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 250,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04400000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "wolf",
+        symbol: 'd',
+        level: 5,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A2),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 500,
+        nutrition: 250,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 6,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "werewolf",
+        symbol: 'd',
+        level: 5,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0210),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Were, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 500,
+        nutrition: 250,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x30802000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100005),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "winter wolf cub",
+        symbol: 'd',
+        level: 5,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x08A2),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Breath, DamageType::Cold, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 250,
+        nutrition: 200,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x02),
+        conveys: Resistance::from_bits_truncate(0x02),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 7,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "warg",
+        symbol: 'd',
+        level: 7,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00A2),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 850,
+        nutrition: 350,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "winter wolf",
+        symbol: 'd',
+        level: 7,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0821),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Breath, DamageType::Cold, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 700,
+        nutrition: 300,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x02),
+        conveys: Resistance::from_bits_truncate(0x02),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 9,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "hell hound pup",
+        symbol: 'd',
+        level: 7,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x04A1),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Breath, DamageType::Fire, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 200,
+        nutrition: 200,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x01),
+        conveys: Resistance::from_bits_truncate(0x01),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 9,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "hell hound",
+        symbol: 'd',
+        level: 12,
+        move_speed: 14,
+        ac: 2,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0421),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 6),
+            Attack::new(AttackType::Breath, DamageType::Fire, 3, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 300,
+        sound: MonsterSound::Bark,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x01),
+        conveys: Resistance::from_bits_truncate(0x01),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 14,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "gas spore",
+        symbol: 'e',
+        level: 1,
+        move_speed: 3,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Boom, DamageType::Physical, 4, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 10,
+        nutrition: 10,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001E401),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 2,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "floating eye",
+        symbol: 'e',
+        level: 2,
+        move_speed: 1,
+        ac: 9,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0025),
+        attacks: [
+            Attack::new(AttackType::None, DamageType::Paralyze, 0, 70),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 10,
+        nutrition: 10,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0000EA01),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 3,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "freezing sphere",
+        symbol: 'e',
+        level: 6,
+        move_speed: 13,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0832),
+        attacks: [
+            Attack::new(AttackType::Explode, DamageType::Cold, 4, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 10,
+        nutrition: 10,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x02),
+        conveys: Resistance::from_bits_truncate(0x02),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001EC01),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "flaming sphere",
+        symbol: 'e',
+        level: 6,
+        move_speed: 13,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0032),
+        attacks: [
+            Attack::new(AttackType::Explode, DamageType::Fire, 4, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 10,
+        nutrition: 10,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x01),
+        conveys: Resistance::from_bits_truncate(0x01),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001EC01),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "shocking sphere",
+        symbol: 'e',
+        level: 6,
+        move_speed: 13,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0032),
+        attacks: [
+            Attack::new(AttackType::Explode, DamageType::Electric, 4, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 10,
+        nutrition: 10,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x10),
+        conveys: Resistance::from_bits_truncate(0x10),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001EC01),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::BrightBlue,
+    },
+    MonsterType {
+        name: "kitten",
+        symbol: 'f',
+        level: 2,
+        move_speed: 18,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 150,
+        nutrition: 150,
+        sound: MonsterSound::Mew,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00C00000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 3,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "housecat",
+        symbol: 'f',
+        level: 4,
+        move_speed: 16,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 200,
+        nutrition: 200,
+        sound: MonsterSound::Mew,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00400000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 5,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "jaguar",
+        symbol: 'f',
+        level: 4,
+        move_speed: 15,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 300,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 6,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "lynx",
+        symbol: 'f',
+        level: 5,
+        move_speed: 15,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 300,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "panther",
+        symbol: 'f',
+        level: 5,
+        move_speed: 15,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 300,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "large cat",
+        symbol: 'f',
+        level: 6,
+        move_speed: 15,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 250,
+        nutrition: 250,
+        sound: MonsterSound::Mew,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04400000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "tiger",
+        symbol: 'f',
+        level: 6,
+        move_speed: 12,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 300,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "gremlin",
+        symbol: 'g',
+        level: 5,
+        move_speed: 12,
+        ac: 2,
+        magic_resistance: 25,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Curse, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 100,
+        nutrition: 20,
+        sound: MonsterSound::Laugh,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x10020002),
+        flags2: MonsterFlags2::from_bits_truncate(0x01000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "gargoyle",
+        symbol: 'g',
+        level: 6,
+        move_speed: 10,
+        ac: -4,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1000,
+        nutrition: 200,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x80),
+        conveys: Resistance::from_bits_truncate(0x80),
+        flags1: MonsterFlags1::from_bits_truncate(0x00220400),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 8,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "winged gargoyle",
+        symbol: 'g',
+        level: 9,
+        move_speed: 15,
+        ac: -2,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 300,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x80),
+        conveys: Resistance::from_bits_truncate(0x80),
+        flags1: MonsterFlags1::from_bits_truncate(0x00620401),
+        flags2: MonsterFlags2::from_bits_truncate(0x84100400),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 11,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "hobbit",
+        symbol: 'h',
+        level: 1,
+        move_speed: 9,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 500,
+        nutrition: 200,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 2,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "dwarf",
+        symbol: 'h',
+        level: 2,
+        move_speed: 6,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0023),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 900,
+        nutrition: 300,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020060),
+        flags2: MonsterFlags2::from_bits_truncate(0x74000021),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 4,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "bugbear",
+        symbol: 'h',
+        level: 3,
+        move_speed: 9,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1250,
+        nutrition: 250,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 5,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "dwarf lord",
+        symbol: 'h',
+        level: 4,
+        move_speed: 6,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 900,
+        nutrition: 300,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020060),
+        flags2: MonsterFlags2::from_bits_truncate(0x74010420),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 6,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "dwarf king",
+        symbol: 'h',
+        level: 6,
+        move_speed: 6,
+        ac: 10,
+        magic_resistance: 20,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 900,
+        nutrition: 300,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020060),
+        flags2: MonsterFlags2::from_bits_truncate(0x74010820),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 8,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "mind flayer",
+        symbol: 'h',
+        level: 9,
+        move_speed: 12,
+        ac: 5,
+        magic_resistance: 90,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Tentacle, DamageType::DrainInt, 2, 1),
+            Attack::new(AttackType::Tentacle, DamageType::DrainInt, 2, 1),
+            Attack::new(AttackType::Tentacle, DamageType::DrainInt, 2, 1),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020001),
+        flags2: MonsterFlags2::from_bits_truncate(0x72100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 13,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "master mind flayer",
+        symbol: 'h',
+        level: 13,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 90,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Tentacle, DamageType::DrainInt, 2, 1),
+            Attack::new(AttackType::Tentacle, DamageType::DrainInt, 2, 1),
+            Attack::new(AttackType::Tentacle, DamageType::DrainInt, 2, 1),
+            Attack::new(AttackType::Tentacle, DamageType::DrainInt, 2, 1),
+            Attack::new(AttackType::Tentacle, DamageType::DrainInt, 2, 1),
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020001),
+        flags2: MonsterFlags2::from_bits_truncate(0x72100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 19,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "manes",
+        symbol: 'i',
+        level: 1,
+        move_speed: 3,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0071),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 100,
+        nutrition: 100,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x24),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10000000),
+        flags2: MonsterFlags2::from_bits_truncate(0x01100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 3,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "homunculus",
+        symbol: 'i',
+        level: 2,
+        move_speed: 12,
+        ac: 6,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Sleep, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 60,
+        nutrition: 100,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0x24),
+        conveys: Resistance::from_bits_truncate(0x24),
+        flags1: MonsterFlags1::from_bits_truncate(0x10000001),
+        flags2: MonsterFlags2::from_bits_truncate(0x01000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 3,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "imp",
+        symbol: 'i',
+        level: 3,
+        move_speed: 12,
+        ac: 2,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 20,
+        nutrition: 10,
+        sound: MonsterSound::Cuss,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00800000),
+        flags2: MonsterFlags2::from_bits_truncate(0x01800000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 4,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "lemure",
+        symbol: 'i',
+        level: 3,
+        move_speed: 3,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0471),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 150,
+        nutrition: 100,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x24),
+        conveys: Resistance::from_bits_truncate(0x04),
+        flags1: MonsterFlags1::from_bits_truncate(0x10800000),
+        flags2: MonsterFlags2::from_bits_truncate(0x01940000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 5,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "quasit",
+        symbol: 'i',
+        level: 3,
+        move_speed: 15,
+        ac: 2,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::DrainDex, 1, 2),
+            Attack::new(AttackType::Claw, DamageType::DrainDex, 1, 2),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 200,
+        nutrition: 200,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x00800000),
+        flags2: MonsterFlags2::from_bits_truncate(0x01000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 7,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "tengu",
+        symbol: 'i',
+        level: 6,
+        move_speed: 13,
+        ac: 5,
+        magic_resistance: 30,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0023),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 7),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 300,
+        nutrition: 200,
+        sound: MonsterSound::Squawk,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x06000000),
+        flags2: MonsterFlags2::from_bits_truncate(0x01000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 7,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "blue jelly",
+        symbol: 'j',
+        level: 4,
+        move_speed: 0,
+        ac: 8,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::None, DamageType::Cold, 0, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 50,
+        nutrition: 20,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x22),
+        conveys: Resistance::from_bits_truncate(0x22),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001FC04),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 5,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "spotted jelly",
+        symbol: 'j',
+        level: 5,
+        move_speed: 0,
+        ac: 8,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::None, DamageType::Acid, 0, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 50,
+        nutrition: 20,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xC0),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0801FC04),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "ochre jelly",
+        symbol: 'j',
+        level: 6,
+        move_speed: 3,
+        ac: 8,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Engulf, DamageType::Acid, 3, 6),
+            Attack::new(AttackType::None, DamageType::Acid, 3, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 50,
+        nutrition: 20,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xC0),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0801FC04),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 8,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "kobold",
+        symbol: 'k',
+        level: 0,
+        move_speed: 6,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 100,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x70020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 1,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "large kobold",
+        symbol: 'k',
+        level: 1,
+        move_speed: 6,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 450,
+        nutrition: 150,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x70020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 2,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "kobold lord",
+        symbol: 'k',
+        level: 2,
+        move_speed: 6,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 500,
+        nutrition: 200,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x70020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40110400),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 3,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "kobold shaman",
+        symbol: 'k',
+        level: 2,
+        move_speed: 6,
+        ac: 6,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 450,
+        nutrition: 150,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x70020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x80100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 4,
+        color: Color::BrightBlue,
+    },
+    MonsterType {
+        name: "leprechaun",
+        symbol: 'l',
+        level: 5,
+        move_speed: 15,
+        ac: 8,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0024),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::StealGold, 1, 2),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 60,
+        nutrition: 30,
+        sound: MonsterSound::Laugh,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x02020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x10100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 4,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "small mimic",
+        symbol: 'm',
+        level: 7,
+        move_speed: 3,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 300,
+        nutrition: 200,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x40),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x2024F504),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 8,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "large mimic",
+        symbol: 'm',
+        level: 8,
+        move_speed: 3,
+        ac: 7,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Stick, 3, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 400,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x40),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x2024F514),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 9,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "giant mimic",
+        symbol: 'm',
+        level: 9,
+        move_speed: 3,
+        ac: 7,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Stick, 3, 6),
+            Attack::new(AttackType::Claw, DamageType::Stick, 3, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 500,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x40),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x2024F514),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 11,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "wood nymph",
+        symbol: 'n',
+        level: 3,
+        move_speed: 12,
+        ac: 9,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::StealItem, 0, 0),
+            Attack::new(AttackType::Claw, DamageType::Seduce, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 300,
+        sound: MonsterSound::SeduceSound,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x02020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40120000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 5,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "water nymph",
+        symbol: 'n',
+        level: 3,
+        move_speed: 12,
+        ac: 9,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::StealItem, 0, 0),
+            Attack::new(AttackType::Claw, DamageType::Seduce, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 300,
+        sound: MonsterSound::SeduceSound,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x02020002),
+        flags2: MonsterFlags2::from_bits_truncate(0x40120000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 5,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "mountain nymph",
+        symbol: 'n',
+        level: 3,
+        move_speed: 12,
+        ac: 9,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::StealItem, 0, 0),
+            Attack::new(AttackType::Claw, DamageType::Seduce, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 300,
+        sound: MonsterSound::SeduceSound,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x02020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40120000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 5,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "goblin",
+        symbol: 'o',
+        level: 0,
+        move_speed: 6,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 100,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40000080),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 1,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "hobgoblin",
+        symbol: 'o',
+        level: 1,
+        move_speed: 9,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1000,
+        nutrition: 200,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44000080),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 3,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "orc",
+        symbol: 'o',
+        level: 1,
+        move_speed: 9,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0260),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 850,
+        nutrition: 150,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x74000081),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 3,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "hill orc",
+        symbol: 'o',
+        level: 2,
+        move_speed: 9,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0062),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1000,
+        nutrition: 200,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x74000080),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 4,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "Mordor orc",
+        symbol: 'o',
+        level: 3,
+        move_speed: 5,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0061),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 200,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x74000080),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 5,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "Uruk-hai",
+        symbol: 'o',
+        level: 3,
+        move_speed: 7,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0061),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1300,
+        nutrition: 300,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x74000080),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 5,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "orc shaman",
+        symbol: 'o',
+        level: 3,
+        move_speed: 9,
+        ac: 5,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1000,
+        nutrition: 300,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xB4000080),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 5,
+        color: Color::BrightBlue,
+    },
+    MonsterType {
+        name: "orc-captain",
+        symbol: 'o',
+        level: 5,
+        move_speed: 5,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1350,
+        nutrition: 350,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x74000080),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 7,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "rock piercer",
+        symbol: 'p',
+        level: 3,
+        move_speed: 1,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0024),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 200,
+        nutrition: 200,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20047910),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 4,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "iron piercer",
+        symbol: 'p',
+        level: 5,
+        move_speed: 1,
+        ac: 0,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 300,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20047910),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "glass piercer",
+        symbol: 'p',
+        level: 7,
+        move_speed: 1,
+        ac: 0,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 4, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 300,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x40),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20047910),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 9,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "rothe",
+        symbol: 'q',
+        level: 2,
+        move_speed: 9,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A4),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 100,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 4,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "mumak",
+        symbol: 'q',
+        level: 5,
+        move_speed: 9,
+        ac: 0,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Butt, DamageType::Physical, 4, 12),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2500,
+        nutrition: 500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x40242000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "leocrotta",
+        symbol: 'q',
+        level: 6,
+        move_speed: 18,
+        ac: 4,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 500,
+        sound: MonsterSound::Imitate,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "wumpus",
+        symbol: 'q',
+        level: 8,
+        move_speed: 3,
+        ac: 2,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2500,
+        nutrition: 500,
+        sound: MonsterSound::Burble,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60042010),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 9,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "titanothere",
+        symbol: 'q',
+        level: 12,
+        move_speed: 12,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2650,
+        nutrition: 650,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x40242000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 13,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "baluchitherium",
+        symbol: 'q',
+        level: 14,
+        move_speed: 12,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 5, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 5, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 3800,
+        nutrition: 800,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x40242000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 15,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "mastodon",
+        symbol: 'q',
+        level: 20,
+        move_speed: 12,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Butt, DamageType::Physical, 4, 8),
+            Attack::new(AttackType::Butt, DamageType::Physical, 4, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 3800,
+        nutrition: 800,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x40242000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 22,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "sewer rat",
+        symbol: 'r',
+        level: 0,
+        move_speed: 12,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 20,
+        nutrition: 12,
+        sound: MonsterSound::Squeak,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 1,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "giant rat",
+        symbol: 'r',
+        level: 1,
+        move_speed: 10,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A2),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 30,
+        nutrition: 30,
+        sound: MonsterSound::Squeak,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 2,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "rabid rat",
+        symbol: 'r',
+        level: 2,
+        move_speed: 12,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::DrainCon, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 30,
+        nutrition: 5,
+        sound: MonsterSound::Squeak,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x30042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 4,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "wererat",
+        symbol: 'r',
+        level: 2,
+        move_speed: 12,
+        ac: 6,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0210),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Were, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 40,
+        nutrition: 30,
+        sound: MonsterSound::Squeak,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x30802000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100005),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 4,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "rock mole",
+        symbol: 'r',
+        level: 3,
+        move_speed: 3,
+        ac: 0,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 30,
+        nutrition: 30,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x80042020),
+        flags2: MonsterFlags2::from_bits_truncate(0x70100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 4,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "woodchuck",
+        symbol: 'r',
+        level: 3,
+        move_speed: 3,
+        ac: 0,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0220),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 30,
+        nutrition: 30,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x40042022),
+        flags2: MonsterFlags2::from_bits_truncate(0x00900000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 4,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "cave spider",
+        symbol: 's',
+        level: 1,
+        move_speed: 12,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A2),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 2),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 50,
+        nutrition: 50,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x20442080),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 3,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "centipede",
+        symbol: 's',
+        level: 2,
+        move_speed: 4,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::DrainStr, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 50,
+        nutrition: 50,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x20442080),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 4,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "giant spider",
+        symbol: 's',
+        level: 5,
+        move_speed: 15,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::DrainStr, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 100,
+        nutrition: 100,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x30442000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 7,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "scorpion",
+        symbol: 's',
+        level: 5,
+        move_speed: 15,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 2),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 2),
+            Attack::new(AttackType::Sting, DamageType::DrainStr, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 50,
+        nutrition: 100,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x30442080),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 8,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "lurker above",
+        symbol: 't',
+        level: 10,
+        move_speed: 3,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Engulf, DamageType::Digest, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 350,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x2004F101),
+        flags2: MonsterFlags2::from_bits_truncate(0x05100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 12,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "trapper",
+        symbol: 't',
+        level: 12,
+        move_speed: 3,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Engulf, DamageType::Digest, 1, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 350,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x2004F100),
+        flags2: MonsterFlags2::from_bits_truncate(0x05100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 14,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "pony",
+        symbol: 'u',
+        level: 3,
+        move_speed: 16,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 2),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1300,
+        nutrition: 250,
+        sound: MonsterSound::Neigh,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x40042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04C00000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 4,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "white unicorn",
+        symbol: 'u',
+        level: 4,
+        move_speed: 24,
+        ac: 2,
+        magic_resistance: 70,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Butt, DamageType::Physical, 1, 12),
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1300,
+        nutrition: 300,
+        sound: MonsterSound::Neigh,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x40002000),
+        flags2: MonsterFlags2::from_bits_truncate(0x24800000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 6,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "gray unicorn",
+        symbol: 'u',
+        level: 4,
+        move_speed: 24,
+        ac: 2,
+        magic_resistance: 70,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Butt, DamageType::Physical, 1, 12),
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1300,
+        nutrition: 300,
+        sound: MonsterSound::Neigh,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x40002000),
+        flags2: MonsterFlags2::from_bits_truncate(0x24800000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 6,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "black unicorn",
+        symbol: 'u',
+        level: 4,
+        move_speed: 24,
+        ac: 2,
+        magic_resistance: 70,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Butt, DamageType::Physical, 1, 12),
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1300,
+        nutrition: 300,
+        sound: MonsterSound::Neigh,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x40002000),
+        flags2: MonsterFlags2::from_bits_truncate(0x24800000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 6,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "horse",
+        symbol: 'u',
+        level: 5,
+        move_speed: 20,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 300,
+        sound: MonsterSound::Neigh,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x40042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04C00000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "warhorse",
+        symbol: 'u',
+        level: 7,
+        move_speed: 24,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 10),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1800,
+        nutrition: 350,
+        sound: MonsterSound::Neigh,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x40042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04C00000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 9,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "fog cloud",
+        symbol: 'v',
+        level: 3,
+        move_speed: 1,
+        ac: 0,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0032),
+        attacks: [
+            Attack::new(AttackType::Engulf, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 0,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0xA4),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0011F405),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 4,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "dust vortex",
+        symbol: 'v',
+        level: 4,
+        move_speed: 20,
+        ac: 2,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0032),
+        attacks: [
+            Attack::new(AttackType::Engulf, DamageType::Blind, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 0,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0xA4),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001F401),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "ice vortex",
+        symbol: 'v',
+        level: 5,
+        move_speed: 20,
+        ac: 2,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0831),
+        attacks: [
+            Attack::new(AttackType::Engulf, DamageType::Cold, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 0,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0xA6),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001F401),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "energy vortex",
+        symbol: 'v',
+        level: 6,
+        move_speed: 20,
+        ac: 2,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Engulf, DamageType::Electric, 1, 6),
+            Attack::new(AttackType::Engulf, DamageType::DrainEnergy, 2, 6),
+            Attack::new(AttackType::None, DamageType::Electric, 0, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 0,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0xBC),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0011F401),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 9,
+        color: Color::BrightBlue,
+    },
+    MonsterType {
+        name: "steam vortex",
+        symbol: 'v',
+        level: 7,
+        move_speed: 22,
+        ac: 2,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0432),
+        attacks: [
+            Attack::new(AttackType::Engulf, DamageType::Fire, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 0,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0xA5),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0011F401),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 9,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "fire vortex",
+        symbol: 'v',
+        level: 8,
+        move_speed: 22,
+        ac: 2,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0431),
+        attacks: [
+            Attack::new(AttackType::Engulf, DamageType::Fire, 1, 10),
+            Attack::new(AttackType::None, DamageType::Fire, 0, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 0,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0xA5),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0011F401),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 10,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "baby long worm",
+        symbol: 'w',
+        level: 5,
+        move_speed: 3,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 250,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x200C6800),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "baby purple worm",
+        symbol: 'w',
+        level: 8,
+        move_speed: 3,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 250,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x200C6000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 9,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "long worm",
+        symbol: 'w',
+        level: 9,
+        move_speed: 3,
+        ac: 5,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x204C6800),
+        flags2: MonsterFlags2::from_bits_truncate(0x06100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 10,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "purple worm",
+        symbol: 'w',
+        level: 15,
+        move_speed: 9,
+        ac: 6,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Engulf, DamageType::Digest, 1, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2700,
+        nutrition: 700,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x204C6000),
+        flags2: MonsterFlags2::from_bits_truncate(0x06100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 17,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "grid bug",
+        symbol: 'x',
+        level: 0,
+        move_speed: 12,
+        ac: 9,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00B3),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Electric, 1, 1),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 15,
+        nutrition: 10,
+        sound: MonsterSound::Buzz,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0x30),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00040000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 1,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "xan",
+        symbol: 'x',
+        level: 7,
+        move_speed: 18,
+        ac: -4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0023),
+        attacks: [
+            Attack::new(AttackType::Sting, DamageType::Legs, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 300,
+        nutrition: 300,
+        sound: MonsterSound::Buzz,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x10042001),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 9,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "yellow light",
+        symbol: 'y',
+        level: 3,
+        move_speed: 15,
+        ac: 0,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0034),
+        attacks: [
+            Attack::new(AttackType::Explode, DamageType::Blind, 10, 20),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 0,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0xFF),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0011FC05),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 5,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "black light",
+        symbol: 'y',
+        level: 5,
+        move_speed: 15,
+        ac: 0,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0032),
+        attacks: [
+            Attack::new(AttackType::Explode, DamageType::Hallucination, 10, 12),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 0,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0xFF),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0111FC05),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 7,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "zruty",
+        symbol: 'z',
+        level: 9,
+        move_speed: 8,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 4),
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 600,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20060000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 11,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "couatl",
+        symbol: 'A',
+        level: 8,
+        move_speed: 10,
+        ac: 5,
+        magic_resistance: 30,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0891),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::DrainStr, 2, 4),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Hugs, DamageType::Wrap, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 900,
+        nutrition: 400,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10082001),
+        flags2: MonsterFlags2::from_bits_truncate(0x07001000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 11,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "Aleax",
+        symbol: 'A',
+        level: 10,
+        move_speed: 8,
+        ac: 0,
+        magic_resistance: 30,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0811),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Imitate,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x36),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x01020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x43001000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 12,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "Angel",
+        symbol: 'A',
+        level: 14,
+        move_speed: 10,
+        ac: -4,
+        magic_resistance: 55,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0811),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Magic, DamageType::MagicMissile, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Cuss,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x36),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x01020001),
+        flags2: MonsterFlags2::from_bits_truncate(0x47001001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 19,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "ki-rin",
+        symbol: 'A',
+        level: 16,
+        move_speed: 18,
+        ac: -5,
+        magic_resistance: 90,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0811),
+        attacks: [
+            Attack::new(AttackType::Kick, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Kick, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Butt, DamageType::Physical, 3, 6),
+            Attack::new(AttackType::Magic, DamageType::Spell, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Neigh,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x01042001),
+        flags2: MonsterFlags2::from_bits_truncate(0x07001401),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 21,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "Archon",
+        symbol: 'A',
+        level: 19,
+        move_speed: 16,
+        ac: -6,
+        magic_resistance: 80,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0811),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Gaze, DamageType::Blind, 2, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Magic, DamageType::Spell, 4, 6),
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Cuss,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x37),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x01820001),
+        flags2: MonsterFlags2::from_bits_truncate(0xC7001401),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 26,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "bat",
+        symbol: 'B',
+        level: 0,
+        move_speed: 22,
+        ac: 8,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 20,
+        nutrition: 20,
+        sound: MonsterSound::Squeak,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042001),
+        flags2: MonsterFlags2::from_bits_truncate(0x00800000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 2,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "giant bat",
+        symbol: 'B',
+        level: 2,
+        move_speed: 22,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 30,
+        nutrition: 30,
+        sound: MonsterSound::Squeak,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042001),
+        flags2: MonsterFlags2::from_bits_truncate(0x00900000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 3,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "raven",
+        symbol: 'B',
+        level: 4,
+        move_speed: 20,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Claw, DamageType::Blind, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 40,
+        nutrition: 20,
+        sound: MonsterSound::Squawk,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042001),
+        flags2: MonsterFlags2::from_bits_truncate(0x00900000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 6,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "vampire bat",
+        symbol: 'B',
+        level: 5,
+        move_speed: 20,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Bite, DamageType::DrainStr, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 30,
+        nutrition: 20,
+        sound: MonsterSound::Squeak,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x24),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x70842001),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "plains centaur",
+        symbol: 'C',
+        level: 4,
+        move_speed: 18,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2500,
+        nutrition: 500,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x54000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 6,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "forest centaur",
+        symbol: 'C',
+        level: 5,
+        move_speed: 18,
+        ac: 3,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2550,
+        nutrition: 600,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x54000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "mountain centaur",
+        symbol: 'C',
+        level: 6,
+        move_speed: 20,
+        ac: 2,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 10),
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2550,
+        nutrition: 500,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x54000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 9,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "baby gray dragon",
+        symbol: 'D',
+        level: 12,
+        move_speed: 9,
+        ac: 2,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Huge,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20202001),
+        flags2: MonsterFlags2::from_bits_truncate(0x34100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 13,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "baby silver dragon",
+        symbol: 'D',
+        level: 12,
+        move_speed: 9,
+        ac: 2,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Huge,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20202001),
+        flags2: MonsterFlags2::from_bits_truncate(0x34100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 13,
+        color: Color::BrightCyan,
+    },
+    MonsterType {
+        name: "baby red dragon",
+        symbol: 'D',
+        level: 12,
+        move_speed: 9,
+        ac: 2,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x01),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20202001),
+        flags2: MonsterFlags2::from_bits_truncate(0x34100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 13,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "baby white dragon",
+        symbol: 'D',
+        level: 12,
+        move_speed: 9,
+        ac: 2,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x02),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20202001),
+        flags2: MonsterFlags2::from_bits_truncate(0x34100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 13,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "baby orange dragon",
+        symbol: 'D',
+        level: 12,
+        move_speed: 9,
+        ac: 2,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x04),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20202001),
+        flags2: MonsterFlags2::from_bits_truncate(0x34100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 13,
+        color: Color::Orange,
+    },
+    MonsterType {
+        name: "baby black dragon",
+        symbol: 'D',
+        level: 12,
+        move_speed: 9,
+        ac: 2,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x08),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20202001),
+        flags2: MonsterFlags2::from_bits_truncate(0x34100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 13,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "baby blue dragon",
+        symbol: 'D',
+        level: 12,
+        move_speed: 9,
+        ac: 2,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x10),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20202001),
+        flags2: MonsterFlags2::from_bits_truncate(0x34100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 13,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "baby green dragon",
+        symbol: 'D',
+        level: 12,
+        move_speed: 9,
+        ac: 2,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x30202001),
+        flags2: MonsterFlags2::from_bits_truncate(0x34100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 13,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "baby yellow dragon",
+        symbol: 'D',
+        level: 12,
+        move_speed: 9,
+        ac: 2,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0xC0),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x28202001),
+        flags2: MonsterFlags2::from_bits_truncate(0x34100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 13,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "gray dragon",
+        symbol: 'D',
+        level: 15,
+        move_speed: 9,
+        ac: -1,
+        magic_resistance: 20,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Breath, DamageType::MagicMissile, 4, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 4500,
+        nutrition: 1500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x21602001),
+        flags2: MonsterFlags2::from_bits_truncate(0xB6100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 20,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "silver dragon",
+        symbol: 'D',
+        level: 15,
+        move_speed: 9,
+        ac: -1,
+        magic_resistance: 20,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Breath, DamageType::Cold, 4, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 4500,
+        nutrition: 1500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::from_bits_truncate(0x02),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x21602001),
+        flags2: MonsterFlags2::from_bits_truncate(0xB6100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 20,
+        color: Color::BrightCyan,
+    },
+    MonsterType {
+        name: "red dragon",
+        symbol: 'D',
+        level: 15,
+        move_speed: 9,
+        ac: -1,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Breath, DamageType::Fire, 6, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 4500,
+        nutrition: 1500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::from_bits_truncate(0x01),
+        conveys: Resistance::from_bits_truncate(0x01),
+        flags1: MonsterFlags1::from_bits_truncate(0x21602001),
+        flags2: MonsterFlags2::from_bits_truncate(0xB6100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 20,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "white dragon",
+        symbol: 'D',
+        level: 15,
+        move_speed: 9,
+        ac: -1,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Breath, DamageType::Cold, 4, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 4500,
+        nutrition: 1500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::from_bits_truncate(0x02),
+        conveys: Resistance::from_bits_truncate(0x02),
+        flags1: MonsterFlags1::from_bits_truncate(0x21602001),
+        flags2: MonsterFlags2::from_bits_truncate(0xB6100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 20,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "orange dragon",
+        symbol: 'D',
+        level: 15,
+        move_speed: 9,
+        ac: -1,
+        magic_resistance: 20,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Breath, DamageType::Sleep, 4, 25),
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 4500,
+        nutrition: 1500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::from_bits_truncate(0x04),
+        conveys: Resistance::from_bits_truncate(0x04),
+        flags1: MonsterFlags1::from_bits_truncate(0x21602001),
+        flags2: MonsterFlags2::from_bits_truncate(0xB6100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 20,
+        color: Color::Orange,
+    },
+    MonsterType {
+        name: "black dragon",
+        symbol: 'D',
+        level: 15,
+        move_speed: 9,
+        ac: -1,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Breath, DamageType::Disintegration, 1, 255),
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 4500,
+        nutrition: 1500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::from_bits_truncate(0x08),
+        conveys: Resistance::from_bits_truncate(0x08),
+        flags1: MonsterFlags1::from_bits_truncate(0x21602001),
+        flags2: MonsterFlags2::from_bits_truncate(0xB6100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 20,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "blue dragon",
+        symbol: 'D',
+        level: 15,
+        move_speed: 9,
+        ac: -1,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Breath, DamageType::Electric, 4, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 4500,
+        nutrition: 1500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::from_bits_truncate(0x10),
+        conveys: Resistance::from_bits_truncate(0x10),
+        flags1: MonsterFlags1::from_bits_truncate(0x21602001),
+        flags2: MonsterFlags2::from_bits_truncate(0xB6100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 20,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "green dragon",
+        symbol: 'D',
+        level: 15,
+        move_speed: 9,
+        ac: -1,
+        magic_resistance: 20,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Breath, DamageType::DrainStr, 4, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 4500,
+        nutrition: 1500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x31602001),
+        flags2: MonsterFlags2::from_bits_truncate(0xB6100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 20,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "yellow dragon",
+        symbol: 'D',
+        level: 15,
+        move_speed: 9,
+        ac: -1,
+        magic_resistance: 20,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Breath, DamageType::Acid, 4, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 4500,
+        nutrition: 1500,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::from_bits_truncate(0xC0),
+        conveys: Resistance::from_bits_truncate(0x80),
+        flags1: MonsterFlags1::from_bits_truncate(0x29602001),
+        flags2: MonsterFlags2::from_bits_truncate(0xB6100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 20,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "stalker",
+        symbol: 'E',
+        level: 8,
+        move_speed: 12,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0023),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 4, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 900,
+        nutrition: 400,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x01040001),
+        flags2: MonsterFlags2::from_bits_truncate(0x05900000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 9,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "air elemental",
+        symbol: 'E',
+        level: 8,
+        move_speed: 36,
+        ac: 2,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Engulf, DamageType::Physical, 1, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 0,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0xA0),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0011F401),
+        flags2: MonsterFlags2::from_bits_truncate(0x04040000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 10,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "fire elemental",
+        symbol: 'E',
+        level: 8,
+        move_speed: 12,
+        ac: 2,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Fire, 3, 6),
+            Attack::new(AttackType::None, DamageType::Fire, 0, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 0,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0xA1),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0011FC01),
+        flags2: MonsterFlags2::from_bits_truncate(0x04040000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 10,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "earth elemental",
+        symbol: 'E',
+        level: 8,
+        move_speed: 6,
+        ac: 2,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 4, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2500,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0xA3),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0021F408),
+        flags2: MonsterFlags2::from_bits_truncate(0x04040000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 10,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "water elemental",
+        symbol: 'E',
+        level: 8,
+        move_speed: 6,
+        ac: 2,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 5, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2500,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0xA0),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0011F602),
+        flags2: MonsterFlags2::from_bits_truncate(0x04040000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 10,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "lichen",
+        symbol: 'F',
+        level: 0,
+        move_speed: 1,
+        ac: 9,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0024),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Stick, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 20,
+        nutrition: 200,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001FC00),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 1,
+        color: Color::BrightGreen,
+    },
+    MonsterType {
+        name: "brown mold",
+        symbol: 'F',
+        level: 1,
+        move_speed: 0,
+        ac: 9,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::None, DamageType::Cold, 0, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 50,
+        nutrition: 30,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x22),
+        conveys: Resistance::from_bits_truncate(0x22),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001FC00),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 2,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "yellow mold",
+        symbol: 'F',
+        level: 1,
+        move_speed: 0,
+        ac: 9,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::None, DamageType::Stun, 0, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 50,
+        nutrition: 30,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x1001FC00),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 2,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "green mold",
+        symbol: 'F',
+        level: 1,
+        move_speed: 0,
+        ac: 9,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::None, DamageType::Acid, 0, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 50,
+        nutrition: 30,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0xC0),
+        conveys: Resistance::from_bits_truncate(0x80),
+        flags1: MonsterFlags1::from_bits_truncate(0x0801FC00),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 2,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "red mold",
+        symbol: 'F',
+        level: 1,
+        move_speed: 0,
+        ac: 9,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::None, DamageType::Fire, 0, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 50,
+        nutrition: 30,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::from_bits_truncate(0x21),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001FC00),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 2,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "shrieker",
+        symbol: 'F',
+        level: 3,
+        move_speed: 1,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 100,
+        nutrition: 100,
+        sound: MonsterSound::Shriek,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001FC00),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 2,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "violet fungus",
+        symbol: 'F',
+        level: 3,
+        move_speed: 1,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Touch, DamageType::Stick, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 100,
+        nutrition: 100,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x0001FC00),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 5,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "gnome",
+        symbol: 'G',
+        level: 1,
+        move_speed: 6,
+        ac: 10,
+        magic_resistance: 4,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 650,
+        nutrition: 100,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40000041),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 3,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "gnome lord",
+        symbol: 'G',
+        level: 3,
+        move_speed: 8,
+        ac: 10,
+        magic_resistance: 4,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 700,
+        nutrition: 120,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40010440),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 4,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "gnomish wizard",
+        symbol: 'G',
+        level: 3,
+        move_speed: 10,
+        ac: 4,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 700,
+        nutrition: 120,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x80000040),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 5,
+        color: Color::BrightBlue,
+    },
+    MonsterType {
+        name: "gnome king",
+        symbol: 'G',
+        level: 5,
+        move_speed: 10,
+        ac: 10,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 750,
+        nutrition: 150,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40010840),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 6,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "giant",
+        symbol: 'H',
+        level: 6,
+        move_speed: 6,
+        ac: 0,
+        magic_resistance: 0,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0221),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2250,
+        nutrition: 750,
+        sound: MonsterSound::Boast,
+        size: MonsterSize::Huge,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x6E002000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 8,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "stone giant",
+        symbol: 'H',
+        level: 6,
+        move_speed: 6,
+        ac: 0,
+        magic_resistance: 0,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2250,
+        nutrition: 750,
+        sound: MonsterSound::Boast,
+        size: MonsterSize::Huge,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x6E002000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 8,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "hill giant",
+        symbol: 'H',
+        level: 8,
+        move_speed: 10,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2200,
+        nutrition: 700,
+        sound: MonsterSound::Boast,
+        size: MonsterSize::Huge,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x6E002000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 10,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "fire giant",
+        symbol: 'H',
+        level: 9,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 5,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2250,
+        nutrition: 750,
+        sound: MonsterSound::Boast,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x01),
+        conveys: Resistance::from_bits_truncate(0x01),
+        flags1: MonsterFlags1::from_bits_truncate(0x20020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x6E002000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 11,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "frost giant",
+        symbol: 'H',
+        level: 10,
+        move_speed: 12,
+        ac: 3,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x08A1),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 12),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2250,
+        nutrition: 750,
+        sound: MonsterSound::Boast,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x02),
+        conveys: Resistance::from_bits_truncate(0x02),
+        flags1: MonsterFlags1::from_bits_truncate(0x20020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x6E002000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 13,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "ettin",
+        symbol: 'H',
+        level: 10,
+        move_speed: 12,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 3, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1700,
+        nutrition: 500,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Huge,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20060000),
+        flags2: MonsterFlags2::from_bits_truncate(0x46100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 13,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "storm giant",
+        symbol: 'H',
+        level: 16,
+        move_speed: 12,
+        ac: 3,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 12),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2250,
+        nutrition: 750,
+        sound: MonsterSound::Boast,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x10),
+        conveys: Resistance::from_bits_truncate(0x10),
+        flags1: MonsterFlags1::from_bits_truncate(0x20020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x6E002000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 19,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "titan",
+        symbol: 'H',
+        level: 16,
+        move_speed: 18,
+        ac: -3,
+        magic_resistance: 70,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0001),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2300,
+        nutrition: 900,
+        sound: MonsterSound::Spell,
+        size: MonsterSize::Huge,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020001),
+        flags2: MonsterFlags2::from_bits_truncate(0xCE000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 20,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "minotaur",
+        symbol: 'H',
+        level: 15,
+        move_speed: 15,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0220),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 10),
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 10),
+            Attack::new(AttackType::Butt, DamageType::Physical, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 700,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20060000),
+        flags2: MonsterFlags2::from_bits_truncate(0x06100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 17,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "jabberwock",
+        symbol: 'J',
+        level: 15,
+        move_speed: 12,
+        ac: -2,
+        magic_resistance: 50,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 10),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 10),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 10),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 10),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1300,
+        nutrition: 600,
+        sound: MonsterSound::Burble,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20040001),
+        flags2: MonsterFlags2::from_bits_truncate(0x46100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 18,
+        color: Color::Orange,
+    },
+    MonsterType {
+        name: "Keystone Kop",
+        symbol: 'K',
+        level: 1,
+        move_speed: 6,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0260),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 200,
+        sound: MonsterSound::Arrest,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40910008),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 3,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "Kop Sergeant",
+        symbol: 'K',
+        level: 2,
+        move_speed: 8,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x02A0),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 200,
+        sound: MonsterSound::Arrest,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44910008),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 4,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "Kop Lieutenant",
+        symbol: 'K',
+        level: 3,
+        move_speed: 10,
+        ac: 10,
+        magic_resistance: 20,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0220),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 200,
+        sound: MonsterSound::Arrest,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44910008),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 5,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "Kop Kaptain",
+        symbol: 'K',
+        level: 4,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 20,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0220),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 200,
+        sound: MonsterSound::Arrest,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44910008),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 6,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "lich",
+        symbol: 'L',
+        level: 11,
+        move_speed: 6,
+        ac: 0,
+        magic_resistance: 30,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Cold, 1, 10),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 100,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::from_bits_truncate(0x02),
+        flags1: MonsterFlags1::from_bits_truncate(0x10820400),
+        flags2: MonsterFlags2::from_bits_truncate(0x80100002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 14,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "demilich",
+        symbol: 'L',
+        level: 14,
+        move_speed: 9,
+        ac: -2,
+        magic_resistance: 60,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Cold, 3, 4),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 100,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::from_bits_truncate(0x02),
+        flags1: MonsterFlags1::from_bits_truncate(0x10820400),
+        flags2: MonsterFlags2::from_bits_truncate(0x80100002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 18,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "master lich",
+        symbol: 'L',
+        level: 17,
+        move_speed: 9,
+        ac: -4,
+        magic_resistance: 90,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0431),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Cold, 3, 6),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 100,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x27),
+        conveys: Resistance::from_bits_truncate(0x03),
+        flags1: MonsterFlags1::from_bits_truncate(0x10820400),
+        flags2: MonsterFlags2::from_bits_truncate(0x80100002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0104),
+        difficulty: 21,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "arch-lich",
+        symbol: 'L',
+        level: 25,
+        move_speed: 9,
+        ac: -6,
+        magic_resistance: 90,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0431),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Cold, 5, 6),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 100,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x37),
+        conveys: Resistance::from_bits_truncate(0x03),
+        flags1: MonsterFlags1::from_bits_truncate(0x10820400),
+        flags2: MonsterFlags2::from_bits_truncate(0x80100002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0104),
+        difficulty: 29,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "kobold mummy",
+        symbol: 'M',
+        level: 3,
+        move_speed: 8,
+        ac: 6,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 50,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 4,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "gnome mummy",
+        symbol: 'M',
+        level: 4,
+        move_speed: 10,
+        ac: 6,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 650,
+        nutrition: 50,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100042),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 5,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "orc mummy",
+        symbol: 'M',
+        level: 5,
+        move_speed: 10,
+        ac: 5,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 850,
+        nutrition: 75,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x30100082),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 6,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "dwarf mummy",
+        symbol: 'M',
+        level: 5,
+        move_speed: 10,
+        ac: 5,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 900,
+        nutrition: 150,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x30100022),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 6,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "elf mummy",
+        symbol: 'M',
+        level: 6,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 30,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 175,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100012),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 7,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "human mummy",
+        symbol: 'M',
+        level: 6,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 30,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 200,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 7,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "ettin mummy",
+        symbol: 'M',
+        level: 7,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 30,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1700,
+        nutrition: 250,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 8,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "giant mummy",
+        symbol: 'M',
+        level: 8,
+        move_speed: 14,
+        ac: 3,
+        magic_resistance: 30,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2050,
+        nutrition: 375,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x24102002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 10,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "red naga hatchling",
+        symbol: 'N',
+        level: 3,
+        move_speed: 10,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 500,
+        nutrition: 100,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::from_bits_truncate(0x21),
+        flags1: MonsterFlags1::from_bits_truncate(0x60286800),
+        flags2: MonsterFlags2::from_bits_truncate(0x04000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 4,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "black naga hatchling",
+        symbol: 'N',
+        level: 3,
+        move_speed: 10,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 500,
+        nutrition: 100,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0xE0),
+        conveys: Resistance::from_bits_truncate(0xA0),
+        flags1: MonsterFlags1::from_bits_truncate(0x28286800),
+        flags2: MonsterFlags2::from_bits_truncate(0x04000000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 4,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "golden naga hatchling",
+        symbol: 'N',
+        level: 3,
+        move_speed: 10,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 500,
+        nutrition: 100,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x60286800),
+        flags2: MonsterFlags2::from_bits_truncate(0x04000000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 4,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "guardian naga hatchling",
+        symbol: 'N',
+        level: 3,
+        move_speed: 10,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 500,
+        nutrition: 100,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x60286800),
+        flags2: MonsterFlags2::from_bits_truncate(0x04000000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 4,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "red naga",
+        symbol: 'N',
+        level: 6,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Breath, DamageType::Fire, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2600,
+        nutrition: 400,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::from_bits_truncate(0x21),
+        flags1: MonsterFlags1::from_bits_truncate(0x60686800),
+        flags2: MonsterFlags2::from_bits_truncate(0x04000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "black naga",
+        symbol: 'N',
+        level: 8,
+        move_speed: 14,
+        ac: 2,
+        magic_resistance: 10,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Spit, DamageType::Acid, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2600,
+        nutrition: 400,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0xE0),
+        conveys: Resistance::from_bits_truncate(0xA0),
+        flags1: MonsterFlags1::from_bits_truncate(0x28686800),
+        flags2: MonsterFlags2::from_bits_truncate(0x04000000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 10,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "golden naga",
+        symbol: 'N',
+        level: 10,
+        move_speed: 14,
+        ac: 2,
+        magic_resistance: 70,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Magic, DamageType::Spell, 4, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2600,
+        nutrition: 400,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x60686800),
+        flags2: MonsterFlags2::from_bits_truncate(0x04000000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 13,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "guardian naga",
+        symbol: 'N',
+        level: 12,
+        move_speed: 16,
+        ac: 0,
+        magic_resistance: 50,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Paralyze, 1, 6),
+            Attack::new(AttackType::Spit, DamageType::DrainStr, 1, 6),
+            Attack::new(AttackType::Hugs, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2600,
+        nutrition: 400,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x70686800),
+        flags2: MonsterFlags2::from_bits_truncate(0x04000000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 16,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "ogre",
+        symbol: 'O',
+        level: 5,
+        move_speed: 10,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 5),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1600,
+        nutrition: 500,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x74000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 7,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "ogre lord",
+        symbol: 'O',
+        level: 7,
+        move_speed: 12,
+        ac: 3,
+        magic_resistance: 30,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1700,
+        nutrition: 700,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x74010400),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 9,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "ogre king",
+        symbol: 'O',
+        level: 9,
+        move_speed: 14,
+        ac: 4,
+        magic_resistance: 60,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 3, 5),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1700,
+        nutrition: 750,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x74010800),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 11,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "gray ooze",
+        symbol: 'P',
+        level: 3,
+        move_speed: 1,
+        ac: 8,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0032),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Rust, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 500,
+        nutrition: 250,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xE3),
+        conveys: Resistance::from_bits_truncate(0x23),
+        flags1: MonsterFlags1::from_bits_truncate(0x6801F404),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 4,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "brown pudding",
+        symbol: 'P',
+        level: 5,
+        move_speed: 3,
+        ac: 8,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Decay, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 500,
+        nutrition: 250,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xF2),
+        conveys: Resistance::from_bits_truncate(0x32),
+        flags1: MonsterFlags1::from_bits_truncate(0x6801F404),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "green slime",
+        symbol: 'P',
+        level: 6,
+        move_speed: 6,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0431),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Slime, 1, 4),
+            Attack::new(AttackType::None, DamageType::Slime, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 150,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0xF2),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x7801F404),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 8,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "black pudding",
+        symbol: 'P',
+        level: 10,
+        move_speed: 6,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Corrode, 3, 8),
+            Attack::new(AttackType::None, DamageType::Corrode, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 900,
+        nutrition: 250,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0xF2),
+        conveys: Resistance::from_bits_truncate(0x32),
+        flags1: MonsterFlags1::from_bits_truncate(0x6801F404),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 12,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "quantum mechanic",
+        symbol: 'Q',
+        level: 7,
+        move_speed: 12,
+        ac: 3,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0023),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Teleport, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 20,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x72020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 9,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "rust monster",
+        symbol: 'R',
+        level: 5,
+        move_speed: 18,
+        ac: 2,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Rust, 0, 0),
+            Attack::new(AttackType::Touch, DamageType::Rust, 0, 0),
+            Attack::new(AttackType::None, DamageType::Rust, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1000,
+        nutrition: 250,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x80042002),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "disenchanter",
+        symbol: 'R',
+        level: 12,
+        move_speed: 12,
+        ac: -10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0422),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Disenchant, 4, 4),
+            Attack::new(AttackType::None, DamageType::Disenchant, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 750,
+        nutrition: 200,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20040000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 14,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "garter snake",
+        symbol: 'S',
+        level: 1,
+        move_speed: 8,
+        ac: 8,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0061),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 2),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 50,
+        nutrition: 60,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x204C6882),
+        flags2: MonsterFlags2::empty(),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 3,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "snake",
+        symbol: 'S',
+        level: 4,
+        move_speed: 15,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::DrainStr, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 100,
+        nutrition: 80,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x304C6882),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "water moccasin",
+        symbol: 'S',
+        level: 4,
+        move_speed: 15,
+        ac: 3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0260),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::DrainStr, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 150,
+        nutrition: 80,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x304C6882),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 7,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "python",
+        symbol: 'S',
+        level: 6,
+        move_speed: 3,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Touch, DamageType::Physical, 0, 0),
+            Attack::new(AttackType::Hugs, DamageType::Wrap, 1, 4),
+            Attack::new(AttackType::Hugs, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 250,
+        nutrition: 100,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x204C6802),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 8,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "pit viper",
+        symbol: 'S',
+        level: 6,
+        move_speed: 15,
+        ac: 2,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::DrainStr, 1, 4),
+            Attack::new(AttackType::Bite, DamageType::DrainStr, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 100,
+        nutrition: 60,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x304C6882),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 9,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "cobra",
+        symbol: 'S',
+        level: 6,
+        move_speed: 18,
+        ac: 2,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::DrainStr, 2, 4),
+            Attack::new(AttackType::Spit, DamageType::Blind, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 250,
+        nutrition: 100,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x304C6882),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 10,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "troll",
+        symbol: 'T',
+        level: 7,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 2),
+            Attack::new(AttackType::Claw, DamageType::Physical, 4, 2),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 350,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20820000),
+        flags2: MonsterFlags2::from_bits_truncate(0x05100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 9,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "ice troll",
+        symbol: 'T',
+        level: 9,
+        move_speed: 10,
+        ac: 2,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0821),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Claw, DamageType::Cold, 2, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1000,
+        nutrition: 300,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x02),
+        conveys: Resistance::from_bits_truncate(0x02),
+        flags1: MonsterFlags1::from_bits_truncate(0x20820000),
+        flags2: MonsterFlags2::from_bits_truncate(0x05100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "rock troll",
+        symbol: 'T',
+        level: 9,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 3, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 300,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20820000),
+        flags2: MonsterFlags2::from_bits_truncate(0x45100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 12,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "water troll",
+        symbol: 'T',
+        level: 11,
+        move_speed: 14,
+        ac: 4,
+        magic_resistance: 40,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0220),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 350,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20820002),
+        flags2: MonsterFlags2::from_bits_truncate(0x05100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 13,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "Olog-hai",
+        symbol: 'T',
+        level: 13,
+        move_speed: 12,
+        ac: -4,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 3, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 400,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20820000),
+        flags2: MonsterFlags2::from_bits_truncate(0x45100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 16,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "umber hulk",
+        symbol: 'U',
+        level: 9,
+        move_speed: 6,
+        ac: 2,
+        magic_resistance: 25,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 4),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 5),
+            Attack::new(AttackType::Gaze, DamageType::Confuse, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 500,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20000020),
+        flags2: MonsterFlags2::from_bits_truncate(0x04000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "vampire",
+        symbol: 'V',
+        level: 10,
+        move_speed: 12,
+        ac: 1,
+        magic_resistance: 25,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Bite, DamageType::DrainLife, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Vampire,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x24),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10820401),
+        flags2: MonsterFlags2::from_bits_truncate(0x07104002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "vampire lord",
+        symbol: 'V',
+        level: 12,
+        move_speed: 14,
+        ac: 0,
+        magic_resistance: 50,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Bite, DamageType::DrainLife, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Vampire,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x24),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10820401),
+        flags2: MonsterFlags2::from_bits_truncate(0x07114402),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 14,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "Vlad the Impaler",
+        symbol: 'V',
+        level: 28,
+        move_speed: 26,
+        ac: -6,
+        magic_resistance: 80,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1210),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 10),
+            Attack::new(AttackType::Bite, DamageType::DrainLife, 1, 12),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Vampire,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x24),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10820401),
+        flags2: MonsterFlags2::from_bits_truncate(0x07194803),
+        flags3: MonsterFlags3::from_bits_truncate(0x0248),
+        difficulty: 32,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "barrow wight",
+        symbol: 'W',
+        level: 3,
+        move_speed: 12,
+        ac: 5,
+        magic_resistance: 5,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::DrainLife, 0, 0),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 0,
+        sound: MonsterSound::Spell,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00020400),
+        flags2: MonsterFlags2::from_bits_truncate(0x41100002),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 7,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "wraith",
+        symbol: 'W',
+        level: 6,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 15,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::DrainLife, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 0,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xA6),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00120401),
+        flags2: MonsterFlags2::from_bits_truncate(0x01100002),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 8,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "Nazgul",
+        symbol: 'W',
+        level: 13,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 25,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::DrainLife, 1, 4),
+            Attack::new(AttackType::Breath, DamageType::Sleep, 2, 25),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 0,
+        sound: MonsterSound::Spell,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00020400),
+        flags2: MonsterFlags2::from_bits_truncate(0x45110003),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 17,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "xorn",
+        symbol: 'X',
+        level: 8,
+        move_speed: 9,
+        ac: -2,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Bite, DamageType::Physical, 4, 6),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1200,
+        nutrition: 700,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x83),
+        conveys: Resistance::from_bits_truncate(0x80),
+        flags1: MonsterFlags1::from_bits_truncate(0x80200408),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 11,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "monkey",
+        symbol: 'Y',
+        level: 2,
+        move_speed: 12,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::StealItem, 0, 0),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 100,
+        nutrition: 50,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60060000),
+        flags2: MonsterFlags2::empty(),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 4,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "ape",
+        symbol: 'Y',
+        level: 4,
+        move_speed: 12,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x00A2),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1100,
+        nutrition: 500,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60060000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 6,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "owlbear",
+        symbol: 'Y',
+        level: 5,
+        move_speed: 12,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0023),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Hugs, DamageType::Physical, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1700,
+        nutrition: 700,
+        sound: MonsterSound::Roar,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20060000),
+        flags2: MonsterFlags2::from_bits_truncate(0x06100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "yeti",
+        symbol: 'Y',
+        level: 5,
+        move_speed: 15,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1600,
+        nutrition: 700,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x02),
+        conveys: Resistance::from_bits_truncate(0x02),
+        flags1: MonsterFlags1::from_bits_truncate(0x20060000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "carnivorous ape",
+        symbol: 'Y',
+        level: 6,
+        move_speed: 12,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Hugs, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1250,
+        nutrition: 550,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20060000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "sasquatch",
+        symbol: 'Y',
+        level: 7,
+        move_speed: 15,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1550,
+        nutrition: 750,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x61060000),
+        flags2: MonsterFlags2::from_bits_truncate(0x04000000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 9,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "kobold zombie",
+        symbol: 'Z',
+        level: 0,
+        move_speed: 6,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 50,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x01100002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 1,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "gnome zombie",
+        symbol: 'Z',
+        level: 1,
+        move_speed: 6,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 5),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 650,
+        nutrition: 50,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x01100042),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 2,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "orc zombie",
+        symbol: 'Z',
+        level: 2,
+        move_speed: 6,
+        ac: 9,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00B1),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 850,
+        nutrition: 75,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x01100082),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 3,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "dwarf zombie",
+        symbol: 'Z',
+        level: 2,
+        move_speed: 6,
+        ac: 9,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00B1),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 900,
+        nutrition: 150,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x01100022),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 3,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "elf zombie",
+        symbol: 'Z',
+        level: 3,
+        move_speed: 6,
+        ac: 9,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00B1),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 7),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 175,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x01100012),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 4,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "human zombie",
+        symbol: 'Z',
+        level: 4,
+        move_speed: 6,
+        ac: 8,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00B1),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 200,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x01100002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 5,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "ettin zombie",
+        symbol: 'Z',
+        level: 6,
+        move_speed: 8,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 10),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1700,
+        nutrition: 250,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x05100002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 7,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "ghoul",
+        symbol: 'Z',
+        level: 3,
+        move_speed: 6,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Paralyze, 1, 2),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 50,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x70030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x00900002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 5,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "giant zombie",
+        symbol: 'Z',
+        level: 8,
+        move_speed: 8,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0031),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2050,
+        nutrition: 375,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x05102002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 9,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "skeleton",
+        symbol: 'Z',
+        level: 12,
+        move_speed: 8,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0210),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Touch, DamageType::Slow, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 300,
+        nutrition: 5,
+        sound: MonsterSound::Bones,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xA6),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00230400),
+        flags2: MonsterFlags2::from_bits_truncate(0x46900002),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 14,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "straw golem",
+        symbol: '\'',
+        level: 3,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 2),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 2),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 4,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "paper golem",
+        symbol: '\'',
+        level: 3,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 400,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 4,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "rope golem",
+        symbol: '\'',
+        level: 4,
+        move_speed: 9,
+        ac: 8,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Hugs, DamageType::Physical, 6, 1),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 450,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x24),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "gold golem",
+        symbol: '\'',
+        level: 5,
+        move_speed: 9,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 3),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 450,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x64),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00230400),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "leather golem",
+        symbol: '\'',
+        level: 6,
+        move_speed: 6,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x24),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 7,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "wood golem",
+        symbol: '\'',
+        level: 7,
+        move_speed: 3,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 900,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x26),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00230400),
+        flags2: MonsterFlags2::from_bits_truncate(0x00140000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 8,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "flesh golem",
+        symbol: '\'',
+        level: 9,
+        move_speed: 8,
+        ac: 9,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0001),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1400,
+        nutrition: 600,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x37),
+        conveys: Resistance::from_bits_truncate(0x37),
+        flags1: MonsterFlags1::from_bits_truncate(0x00030400),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 10,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "clay golem",
+        symbol: '\'',
+        level: 11,
+        move_speed: 7,
+        ac: 7,
+        magic_resistance: 40,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1550,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x24),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00230400),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 12,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "stone golem",
+        symbol: '\'',
+        level: 14,
+        move_speed: 6,
+        ac: 5,
+        magic_resistance: 50,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1900,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0xA4),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00230400),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 15,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "glass golem",
+        symbol: '\'',
+        level: 16,
+        move_speed: 6,
+        ac: 1,
+        magic_resistance: 50,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1800,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x64),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00230400),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 18,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "iron golem",
+        symbol: '\'',
+        level: 18,
+        move_speed: 6,
+        ac: 3,
+        magic_resistance: 60,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 10),
+            Attack::new(AttackType::Breath, DamageType::DrainStr, 4, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2000,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x37),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10230400),
+        flags2: MonsterFlags2::from_bits_truncate(0x44100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 22,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "human",
+        symbol: '@',
+        level: 0,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44000009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 2,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "wererat",
+        symbol: '@',
+        level: 2,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0001),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Were,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x70820000),
+        flags2: MonsterFlags2::from_bits_truncate(0x4010000D),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 3,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "werejackal",
+        symbol: '@',
+        level: 2,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0001),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Were,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x70820000),
+        flags2: MonsterFlags2::from_bits_truncate(0x4010000D),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 3,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "werewolf",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0001),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Were,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x70820000),
+        flags2: MonsterFlags2::from_bits_truncate(0x4010000D),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 6,
+        color: Color::Orange,
+    },
+    MonsterType {
+        name: "elf",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 2,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 350,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x04),
+        conveys: Resistance::from_bits_truncate(0x04),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44000011),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "Woodland-elf",
+        symbol: '@',
+        level: 4,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00A2),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 350,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x04),
+        conveys: Resistance::from_bits_truncate(0x04),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40000010),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 6,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "Green-elf",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00A2),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 350,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x04),
+        conveys: Resistance::from_bits_truncate(0x04),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40000010),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 7,
+        color: Color::BrightGreen,
+    },
+    MonsterType {
+        name: "Grey-elf",
+        symbol: '@',
+        level: 6,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00A2),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 350,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x04),
+        conveys: Resistance::from_bits_truncate(0x04),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40000010),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 8,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "elf-lord",
+        symbol: '@',
+        level: 8,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00A2),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 350,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x04),
+        conveys: Resistance::from_bits_truncate(0x04),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44010410),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 11,
+        color: Color::BrightBlue,
+    },
+    MonsterType {
+        name: "Elvenking",
+        symbol: '@',
+        level: 9,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 25,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 800,
+        nutrition: 350,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x04),
+        conveys: Resistance::from_bits_truncate(0x04),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44010810),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 11,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "doppelganger",
+        symbol: '@',
+        level: 9,
+        move_speed: 12,
+        ac: 5,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 12),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Imitate,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x04),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44104009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 11,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "shopkeeper",
+        symbol: '@',
+        level: 12,
+        move_speed: 18,
+        ac: 0,
+        magic_resistance: 50,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Sell,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 15,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "guard",
+        symbol: '@',
+        level: 12,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 40,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guard,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44200209),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 14,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "prisoner",
+        symbol: '@',
+        level: 12,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Djinni,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 14,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "Oracle",
+        symbol: '@',
+        level: 12,
+        move_speed: 0,
+        ac: 0,
+        magic_resistance: 50,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::None, DamageType::MagicMissile, 0, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Oracle,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00220009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 13,
+        color: Color::BrightBlue,
+    },
+    MonsterType {
+        name: "aligned priest",
+        symbol: '@',
+        level: 12,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 50,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 10),
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Magic, DamageType::Clerical, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Priest,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x10),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x40200409),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 15,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "high priest",
+        symbol: '@',
+        level: 25,
+        move_speed: 15,
+        ac: 7,
+        magic_resistance: 70,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 10),
+            Attack::new(AttackType::Kick, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Magic, DamageType::Clerical, 2, 8),
+            Attack::new(AttackType::Magic, DamageType::Clerical, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Priest,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x35),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC2001809),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 30,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "soldier",
+        symbol: '@',
+        level: 6,
+        move_speed: 10,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Soldier,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x45100209),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "sergeant",
+        symbol: '@',
+        level: 8,
+        move_speed: 10,
+        ac: 10,
+        magic_resistance: 5,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x00A1),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Soldier,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x45100209),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 10,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "nurse",
+        symbol: '@',
+        level: 11,
+        move_speed: 6,
+        ac: 0,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0023),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Heal, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Nurse,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 13,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "lieutenant",
+        symbol: '@',
+        level: 10,
+        move_speed: 10,
+        ac: 10,
+        magic_resistance: 15,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 3, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 3, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Soldier,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x45100209),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "captain",
+        symbol: '@',
+        level: 12,
+        move_speed: 10,
+        ac: 10,
+        magic_resistance: 15,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Soldier,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x45100209),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 14,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "watchman",
+        symbol: '@',
+        level: 6,
+        move_speed: 10,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x02A1),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Soldier,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x45200209),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "watch captain",
+        symbol: '@',
+        level: 10,
+        move_speed: 10,
+        ac: 10,
+        magic_resistance: 15,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0221),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 3, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 3, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Soldier,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x45200209),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "Medusa",
+        symbol: '@',
+        level: 20,
+        move_speed: 12,
+        ac: 2,
+        magic_resistance: 50,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Gaze, DamageType::Stone, 0, 0),
+            Attack::new(AttackType::Bite, DamageType::DrainStr, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Hiss,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0xA0),
+        conveys: Resistance::from_bits_truncate(0xA0),
+        flags1: MonsterFlags1::from_bits_truncate(0x70020203),
+        flags2: MonsterFlags2::from_bits_truncate(0x041A0001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0240),
+        difficulty: 25,
+        color: Color::BrightGreen,
+    },
+    MonsterType {
+        name: "Wizard of Yendor",
+        symbol: '@',
+        level: 30,
+        move_speed: 12,
+        ac: -8,
+        magic_resistance: 100,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 2, 12),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Cuss,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::from_bits_truncate(0x21),
+        flags1: MonsterFlags1::from_bits_truncate(0x67820401),
+        flags2: MonsterFlags2::from_bits_truncate(0x86110809),
+        flags3: MonsterFlags3::from_bits_truncate(0x025F),
+        difficulty: 34,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Croesus",
+        symbol: '@',
+        level: 20,
+        move_speed: 15,
+        ac: 0,
+        magic_resistance: 40,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 10),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guard,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xF7190809),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 22,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "ghost",
+        symbol: ' ',
+        level: 10,
+        move_speed: 3,
+        ac: -5,
+        magic_resistance: 50,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0210),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Physical, 1, 1),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xAE),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00120409),
+        flags2: MonsterFlags2::from_bits_truncate(0x01100003),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 12,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "shade",
+        symbol: ' ',
+        level: 12,
+        move_speed: 10,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0210),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Paralyze, 2, 6),
+            Attack::new(AttackType::Touch, DamageType::Slow, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 0,
+        sound: MonsterSound::Wail,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xAE),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x01120409),
+        flags2: MonsterFlags2::from_bits_truncate(0x03900003),
+        flags3: MonsterFlags3::from_bits_truncate(0x0100),
+        difficulty: 14,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "water demon",
+        symbol: '&',
+        level: 8,
+        move_speed: 12,
+        ac: -4,
+        magic_resistance: 30,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0210),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Djinni,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10020002),
+        flags2: MonsterFlags2::from_bits_truncate(0x43100101),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 11,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "succubus",
+        symbol: '&',
+        level: 6,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 70,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::SuccubusSeduction, 0, 0),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::SeduceSound,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10020001),
+        flags2: MonsterFlags2::from_bits_truncate(0x03120100),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 8,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "horned devil",
+        symbol: '&',
+        level: 6,
+        move_speed: 9,
+        ac: -5,
+        magic_resistance: 50,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0412),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 3),
+            Attack::new(AttackType::Sting, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10200000),
+        flags2: MonsterFlags2::from_bits_truncate(0x03100100),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 9,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "incubus",
+        symbol: '&',
+        level: 6,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 70,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0011),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::SuccubusSeduction, 0, 0),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::SeduceSound,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10020001),
+        flags2: MonsterFlags2::from_bits_truncate(0x03110100),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 8,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "erinys",
+        symbol: '&',
+        level: 7,
+        move_speed: 12,
+        ac: 2,
+        magic_resistance: 30,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0492),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::DrainStr, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x47120101),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 10,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "barbed devil",
+        symbol: '&',
+        level: 8,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 35,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0492),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Sting, DamageType::Physical, 3, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10200000),
+        flags2: MonsterFlags2::from_bits_truncate(0x03100100),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 10,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "marilith",
+        symbol: '&',
+        level: 7,
+        move_speed: 12,
+        ac: -6,
+        magic_resistance: 80,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0411),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Cuss,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x110A0000),
+        flags2: MonsterFlags2::from_bits_truncate(0x43120100),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 11,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "vrock",
+        symbol: '&',
+        level: 8,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 50,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0492),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10000000),
+        flags2: MonsterFlags2::from_bits_truncate(0x03100100),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 11,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "hezrou",
+        symbol: '&',
+        level: 9,
+        move_speed: 6,
+        ac: -2,
+        magic_resistance: 55,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0492),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 3),
+            Attack::new(AttackType::Bite, DamageType::Physical, 4, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x03100100),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 12,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "bone devil",
+        symbol: '&',
+        level: 9,
+        move_speed: 15,
+        ac: -1,
+        magic_resistance: 40,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0492),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 3, 4),
+            Attack::new(AttackType::Sting, DamageType::DrainStr, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10000000),
+        flags2: MonsterFlags2::from_bits_truncate(0x43100100),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 13,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "ice devil",
+        symbol: '&',
+        level: 11,
+        move_speed: 6,
+        ac: -4,
+        magic_resistance: 55,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0412),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Sting, DamageType::Cold, 3, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x23),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11000000),
+        flags2: MonsterFlags2::from_bits_truncate(0x03100100),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 14,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "nalfeshnee",
+        symbol: '&',
+        level: 11,
+        move_speed: 9,
+        ac: -1,
+        magic_resistance: 65,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0411),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 4),
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Spell,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x03100100),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 15,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "pit fiend",
+        symbol: '&',
+        level: 13,
+        move_speed: 6,
+        ac: -3,
+        magic_resistance: 65,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0412),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 2),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 2),
+            Attack::new(AttackType::Hugs, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11000000),
+        flags2: MonsterFlags2::from_bits_truncate(0x43100100),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 16,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "sandestin",
+        symbol: '&',
+        level: 13,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 60,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0411),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 400,
+        sound: MonsterSound::Cuss,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x80),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x00020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x45004001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 15,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "balrog",
+        symbol: '&',
+        level: 16,
+        move_speed: 5,
+        ac: -2,
+        magic_resistance: 75,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0411),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 8, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11000001),
+        flags2: MonsterFlags2::from_bits_truncate(0x47100100),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 20,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "Juiblex",
+        symbol: '&',
+        level: 50,
+        move_speed: 3,
+        ac: -7,
+        magic_resistance: 65,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1610),
+        attacks: [
+            Attack::new(AttackType::Engulf, DamageType::Disease, 4, 10),
+            Attack::new(AttackType::Spit, DamageType::Acid, 3, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 0,
+        sound: MonsterSound::Gurgle,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0xE1),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x19008205),
+        flags2: MonsterFlags2::from_bits_truncate(0x03190501),
+        flags3: MonsterFlags3::from_bits_truncate(0x0141),
+        difficulty: 26,
+        color: Color::BrightGreen,
+    },
+    MonsterType {
+        name: "Yeenoghu",
+        symbol: '&',
+        level: 56,
+        move_speed: 18,
+        ac: -5,
+        magic_resistance: 80,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1610),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 3, 6),
+            Attack::new(AttackType::Weapon, DamageType::Confuse, 2, 8),
+            Attack::new(AttackType::Claw, DamageType::Paralyze, 1, 6),
+            Attack::new(AttackType::Magic, DamageType::MagicMissile, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 900,
+        nutrition: 500,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11000001),
+        flags2: MonsterFlags2::from_bits_truncate(0x43190501),
+        flags3: MonsterFlags3::from_bits_truncate(0x0301),
+        difficulty: 31,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Orcus",
+        symbol: '&',
+        level: 66,
+        move_speed: 9,
+        ac: -6,
+        magic_resistance: 85,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1610),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 3, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 4),
+            Attack::new(AttackType::Magic, DamageType::Spell, 8, 6),
+            Attack::new(AttackType::Sting, DamageType::DrainStr, 2, 4),
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Grunt,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11000001),
+        flags2: MonsterFlags2::from_bits_truncate(0x43190901),
+        flags3: MonsterFlags3::from_bits_truncate(0x0345),
+        difficulty: 36,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Geryon",
+        symbol: '&',
+        level: 72,
+        move_speed: 3,
+        ac: -3,
+        magic_resistance: 75,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x1610),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 6),
+            Attack::new(AttackType::Claw, DamageType::Physical, 3, 6),
+            Attack::new(AttackType::Sting, DamageType::DrainStr, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Bribe,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11080001),
+        flags2: MonsterFlags2::from_bits_truncate(0x03190901),
+        flags3: MonsterFlags3::from_bits_truncate(0x0301),
+        difficulty: 36,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Dispater",
+        symbol: '&',
+        level: 78,
+        move_speed: 15,
+        ac: -2,
+        magic_resistance: 80,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x1610),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 6),
+            Attack::new(AttackType::Magic, DamageType::Spell, 6, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Bribe,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11020001),
+        flags2: MonsterFlags2::from_bits_truncate(0x43190901),
+        flags3: MonsterFlags3::from_bits_truncate(0x0301),
+        difficulty: 40,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Baalzebub",
+        symbol: '&',
+        level: 89,
+        move_speed: 9,
+        ac: -5,
+        magic_resistance: 85,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x1610),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::DrainStr, 2, 6),
+            Attack::new(AttackType::Gaze, DamageType::Stun, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Bribe,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11000001),
+        flags2: MonsterFlags2::from_bits_truncate(0x03190901),
+        flags3: MonsterFlags3::from_bits_truncate(0x0341),
+        difficulty: 45,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Asmodeus",
+        symbol: '&',
+        level: 105,
+        move_speed: 12,
+        ac: -7,
+        magic_resistance: 90,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x1610),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 4, 4),
+            Attack::new(AttackType::Magic, DamageType::Cold, 6, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Bribe,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x23),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11020001),
+        flags2: MonsterFlags2::from_bits_truncate(0x07190901),
+        flags3: MonsterFlags3::from_bits_truncate(0x0341),
+        difficulty: 53,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Demogorgon",
+        symbol: '&',
+        level: 106,
+        move_speed: 15,
+        ac: -8,
+        magic_resistance: 95,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1610),
+        attacks: [
+            Attack::new(AttackType::Magic, DamageType::Spell, 8, 6),
+            Attack::new(AttackType::Sting, DamageType::DrainLife, 1, 4),
+            Attack::new(AttackType::Claw, DamageType::Disease, 1, 6),
+            Attack::new(AttackType::Claw, DamageType::Disease, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 500,
+        sound: MonsterSound::Growl,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x21),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11002001),
+        flags2: MonsterFlags2::from_bits_truncate(0x03190901),
+        flags3: MonsterFlags3::from_bits_truncate(0x0301),
+        difficulty: 57,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Death",
+        symbol: '&',
+        level: 30,
+        move_speed: 12,
+        ac: -5,
+        magic_resistance: 100,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Death, 8, 8),
+            Attack::new(AttackType::Touch, DamageType::Death, 8, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 1,
+        sound: MonsterSound::Rider,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xB7),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x05820001),
+        flags2: MonsterFlags2::from_bits_truncate(0x07180001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0700),
+        difficulty: 34,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Pestilence",
+        symbol: '&',
+        level: 30,
+        move_speed: 12,
+        ac: -5,
+        magic_resistance: 100,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Pestilence, 8, 8),
+            Attack::new(AttackType::Touch, DamageType::Pestilence, 8, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 1,
+        sound: MonsterSound::Rider,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xB7),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x05820001),
+        flags2: MonsterFlags2::from_bits_truncate(0x07180001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0700),
+        difficulty: 34,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Famine",
+        symbol: '&',
+        level: 30,
+        move_speed: 12,
+        ac: -5,
+        magic_resistance: 100,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Touch, DamageType::Famine, 8, 8),
+            Attack::new(AttackType::Touch, DamageType::Famine, 8, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 1,
+        sound: MonsterSound::Rider,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xB7),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x05820001),
+        flags2: MonsterFlags2::from_bits_truncate(0x07180001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0700),
+        difficulty: 34,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "mail daemon",
+        symbol: '&',
+        level: 56,
+        move_speed: 24,
+        ac: 10,
+        magic_resistance: 127,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0210),
+        attacks: [
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 600,
+        nutrition: 300,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xB7),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11020403),
+        flags2: MonsterFlags2::from_bits_truncate(0x01200001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 26,
+        color: Color::BrightBlue,
+    },
+    MonsterType {
+        name: "djinni",
+        symbol: '&',
+        level: 7,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0210),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 400,
+        sound: MonsterSound::Djinni,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xA0),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x10020001),
+        flags2: MonsterFlags2::from_bits_truncate(0x41000001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "jellyfish",
+        symbol: ';',
+        level: 3,
+        move_speed: 3,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0220),
+        attacks: [
+            Attack::new(AttackType::Sting, DamageType::DrainStr, 3, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 80,
+        nutrition: 20,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x1008EA02),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 5,
+        color: Color::Blue,
+    },
+    MonsterType {
+        name: "piranha",
+        symbol: ';',
+        level: 5,
+        move_speed: 12,
+        ac: 4,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x02A0),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 60,
+        nutrition: 30,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Small,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x204C6A02),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "shark",
+        symbol: ';',
+        level: 7,
+        move_speed: 12,
+        ac: 2,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0220),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 5, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 500,
+        nutrition: 350,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x206C6A02),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 9,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "giant eel",
+        symbol: ';',
+        level: 5,
+        move_speed: 9,
+        ac: -1,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0220),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 3, 6),
+            Attack::new(AttackType::Touch, DamageType::Wrap, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 200,
+        nutrition: 250,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x204C6A02),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::Cyan,
+    },
+    MonsterType {
+        name: "electric eel",
+        symbol: ';',
+        level: 7,
+        move_speed: 10,
+        ac: -3,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0220),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Electric, 4, 6),
+            Attack::new(AttackType::Touch, DamageType::Wrap, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 200,
+        nutrition: 250,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x10),
+        conveys: Resistance::from_bits_truncate(0x10),
+        flags1: MonsterFlags1::from_bits_truncate(0x204C6A02),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 10,
+        color: Color::BrightBlue,
+    },
+    MonsterType {
+        name: "kraken",
+        symbol: ';',
+        level: 20,
+        move_speed: 3,
+        ac: 6,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0220),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Hugs, DamageType::Wrap, 2, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 5, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1800,
+        nutrition: 1000,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Huge,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042202),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 22,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "newt",
+        symbol: ':',
+        level: 0,
+        move_speed: 6,
+        ac: 8,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0025),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 2),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 10,
+        nutrition: 20,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042202),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 1,
+        color: Color::Yellow,
+    },
+    MonsterType {
+        name: "gecko",
+        symbol: ':',
+        level: 1,
+        move_speed: 6,
+        ac: 8,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0025),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 3),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 10,
+        nutrition: 20,
+        sound: MonsterSound::Squeak,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 2,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "iguana",
+        symbol: ':',
+        level: 2,
+        move_speed: 6,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0025),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 30,
+        nutrition: 30,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 3,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "baby crocodile",
+        symbol: ':',
+        level: 3,
+        move_speed: 6,
+        ac: 7,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0020),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 200,
+        nutrition: 200,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042202),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 4,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "lizard",
+        symbol: ':',
+        level: 5,
+        move_speed: 6,
+        ac: 6,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0025),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 10,
+        nutrition: 40,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::from_bits_truncate(0x80),
+        conveys: Resistance::from_bits_truncate(0x80),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 6,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "chameleon",
+        symbol: ':',
+        level: 6,
+        move_speed: 5,
+        ac: 6,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0022),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 4, 2),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 100,
+        nutrition: 100,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Tiny,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20042000),
+        flags2: MonsterFlags2::from_bits_truncate(0x00104001),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 7,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "crocodile",
+        symbol: ':',
+        level: 6,
+        move_speed: 9,
+        ac: 5,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0021),
+        attacks: [
+            Attack::new(AttackType::Bite, DamageType::Physical, 4, 2),
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 12),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Large,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x20642202),
+        flags2: MonsterFlags2::from_bits_truncate(0x04100000),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 7,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "salamander",
+        symbol: ':',
+        level: 8,
+        move_speed: 12,
+        ac: -1,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0401),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Touch, DamageType::Fire, 1, 6),
+            Attack::new(AttackType::Hugs, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Hugs, DamageType::Fire, 3, 6),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1500,
+        nutrition: 400,
+        sound: MonsterSound::Mumble,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x05),
+        conveys: Resistance::from_bits_truncate(0x01),
+        flags1: MonsterFlags1::from_bits_truncate(0x102A0000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC1100000),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::Orange,
+    },
+    MonsterType {
+        name: "long worm tail",
+        symbol: '~',
+        level: 0,
+        move_speed: 0,
+        ac: 0,
+        magic_resistance: 0,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1210),
+        attacks: [
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 0,
+        nutrition: 0,
+        sound: MonsterSound::Silent,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::empty(),
+        flags2: MonsterFlags2::from_bits_truncate(0x00000001),
+        flags3: MonsterFlags3::empty(),
+        difficulty: 1,
+        color: Color::Brown,
+    },
+    MonsterType {
+        name: "archeologist",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 1,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020060),
+        flags2: MonsterFlags2::from_bits_truncate(0x44000009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "barbarian",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 1,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44000009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "caveman",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44010009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "cavewoman",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44020009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "healer",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 1,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44000009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "knight",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 1,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44000009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "monk",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 2,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Kick, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x40020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44010009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 11,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "priest",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 2,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44010009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "priestess",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 2,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44020009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "ranger",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 2,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44000009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "rogue",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 1,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x74000009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "samurai",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 1,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44000009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "tourist",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 1,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44000009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "valkyrie",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 1,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x02),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44020009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "wizard",
+        symbol: '@',
+        level: 10,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 3,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4000009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 12,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "Lord Carnarvon",
+        symbol: '@',
+        level: 20,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 30,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020060),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4290009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 22,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Pelias",
+        symbol: '@',
+        level: 20,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4290009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 22,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Shaman Karnov",
+        symbol: '@',
+        level: 20,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 30,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4290009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 22,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Hippocrates",
+        symbol: '@',
+        level: 20,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 40,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4290009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 22,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "King Arthur",
+        symbol: '@',
+        level: 20,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 40,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4290009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 23,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Grand Master",
+        symbol: '@',
+        level: 25,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 70,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 4, 10),
+            Attack::new(AttackType::Kick, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Magic, DamageType::Clerical, 2, 8),
+            Attack::new(AttackType::Magic, DamageType::Clerical, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x35),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x41020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x86210009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 30,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "Arch Priest",
+        symbol: '@',
+        level: 25,
+        move_speed: 12,
+        ac: 7,
+        magic_resistance: 70,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 10),
+            Attack::new(AttackType::Kick, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Magic, DamageType::Clerical, 2, 8),
+            Attack::new(AttackType::Magic, DamageType::Clerical, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x35),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4210009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 30,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "Orion",
+        symbol: '@',
+        level: 20,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2200,
+        nutrition: 700,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Huge,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020202),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4290009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0380),
+        difficulty: 22,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Master of Thieves",
+        symbol: '@',
+        level: 20,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 30,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x80),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xF4210009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 24,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Lord Sato",
+        symbol: '@',
+        level: 20,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 30,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4290009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 23,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Twoflower",
+        symbol: '@',
+        level: 20,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4290009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 22,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "Norn",
+        symbol: '@',
+        level: 20,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 80,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1800,
+        nutrition: 550,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x02),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4220009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 23,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Neferet the Green",
+        symbol: '@',
+        level: 20,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 60,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Magic, DamageType::Spell, 2, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Leader,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC42A0009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0280),
+        difficulty: 23,
+        color: Color::Green,
+    },
+    MonsterType {
+        name: "Minion of Huhetotl",
+        symbol: '&',
+        level: 16,
+        move_speed: 12,
+        ac: -2,
+        magic_resistance: 75,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1210),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 8, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 6),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Nemesis,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0xA1),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11000001),
+        flags2: MonsterFlags2::from_bits_truncate(0x47100101),
+        flags3: MonsterFlags3::from_bits_truncate(0x0350),
+        difficulty: 23,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "Thoth Amon",
+        symbol: '@',
+        level: 16,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1210),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Nemesis,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xA0),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC7190009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0250),
+        difficulty: 22,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Chromatic Dragon",
+        symbol: 'D',
+        level: 16,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 30,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Breath, DamageType::RandomBreath, 6, 6),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 2, 8),
+            Attack::new(AttackType::Bite, DamageType::Physical, 4, 8),
+            Attack::new(AttackType::Bite, DamageType::Physical, 4, 8),
+            Attack::new(AttackType::Sting, DamageType::Physical, 1, 6),
+        ],
+        corpse_weight: 4500,
+        nutrition: 1700,
+        sound: MonsterSound::Nemesis,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::from_bits_truncate(0xFF),
+        conveys: Resistance::from_bits_truncate(0xBF),
+        flags1: MonsterFlags1::from_bits_truncate(0x31202000),
+        flags2: MonsterFlags2::from_bits_truncate(0xB7120001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0250),
+        difficulty: 23,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Cyclops",
+        symbol: 'H',
+        level: 18,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 8),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 8),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1900,
+        nutrition: 700,
+        sound: MonsterSound::Nemesis,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x80),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x6F112001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0350),
+        difficulty: 23,
+        color: Color::Gray,
+    },
+    MonsterType {
+        name: "Ixoth",
+        symbol: 'D',
+        level: 15,
+        move_speed: 12,
+        ac: -1,
+        magic_resistance: 20,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Breath, DamageType::Fire, 8, 6),
+            Attack::new(AttackType::Bite, DamageType::Physical, 4, 8),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 4),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 2, 4),
+            Attack::NONE,
+        ],
+        corpse_weight: 4500,
+        nutrition: 1600,
+        sound: MonsterSound::Nemesis,
+        size: MonsterSize::Gigantic,
+        resistances: Resistance::from_bits_truncate(0x81),
+        conveys: Resistance::from_bits_truncate(0x01),
+        flags1: MonsterFlags1::from_bits_truncate(0x21202001),
+        flags2: MonsterFlags2::from_bits_truncate(0xB7190001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0250),
+        difficulty: 22,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "Master Kaen",
+        symbol: '@',
+        level: 25,
+        move_speed: 12,
+        ac: -10,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 16, 2),
+            Attack::new(AttackType::Claw, DamageType::Physical, 16, 2),
+            Attack::new(AttackType::Magic, DamageType::Clerical, 0, 0),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Nemesis,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xA0),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x41020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC7190009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0250),
+        difficulty: 31,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Nalzok",
+        symbol: '&',
+        level: 16,
+        move_speed: 12,
+        ac: -2,
+        magic_resistance: 85,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1210),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 8, 4),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 4, 6),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Nemesis,
+        size: MonsterSize::Large,
+        resistances: Resistance::from_bits_truncate(0xA1),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x11000001),
+        flags2: MonsterFlags2::from_bits_truncate(0x47190101),
+        flags3: MonsterFlags3::from_bits_truncate(0x0350),
+        difficulty: 23,
+        color: Color::Red,
+    },
+    MonsterType {
+        name: "Scorpius",
+        symbol: 's',
+        level: 15,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 0,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 2, 6),
+            Attack::new(AttackType::Sting, DamageType::Disease, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 750,
+        nutrition: 350,
+        sound: MonsterSound::Nemesis,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0xA0),
+        conveys: Resistance::from_bits_truncate(0x20),
+        flags1: MonsterFlags1::from_bits_truncate(0x30442000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC7190001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0050),
+        difficulty: 17,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Master Assassin",
+        symbol: '@',
+        level: 15,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 30,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::DrainStr, 2, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 8),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Nemesis,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x80),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC7110009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0250),
+        difficulty: 20,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Ashikaga Takauji",
+        symbol: '@',
+        level: 15,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 40,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1210),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 6),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Nemesis,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x80),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC7190009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0250),
+        difficulty: 19,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Lord Surtur",
+        symbol: 'H',
+        level: 15,
+        move_speed: 12,
+        ac: 2,
+        magic_resistance: 50,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x1200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 10),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 10),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 2, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 2250,
+        nutrition: 850,
+        sound: MonsterSound::Nemesis,
+        size: MonsterSize::Huge,
+        resistances: Resistance::from_bits_truncate(0x81),
+        conveys: Resistance::from_bits_truncate(0x01),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x6F192001),
+        flags3: MonsterFlags3::from_bits_truncate(0x0350),
+        difficulty: 19,
+        color: Color::Magenta,
+    },
+    MonsterType {
+        name: "Dark One",
+        symbol: '@',
+        level: 15,
+        move_speed: 12,
+        ac: 0,
+        magic_resistance: 80,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x1210),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Claw, DamageType::StealAmulet, 1, 4),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Nemesis,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x80),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC7100009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0250),
+        difficulty: 20,
+        color: Color::Black,
+    },
+    MonsterType {
+        name: "student",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020060),
+        flags2: MonsterFlags2::from_bits_truncate(0x44200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "chieftain",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "neanderthal",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 2, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "attendant",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::from_bits_truncate(0x20),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "page",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "abbot",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Claw, DamageType::Physical, 8, 2),
+            Attack::new(AttackType::Kick, DamageType::Stun, 3, 2),
+            Attack::new(AttackType::Magic, DamageType::Clerical, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x40020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "acolyte",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Magic, DamageType::Clerical, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "hunter",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 4),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x61020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0300),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "thug",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x54200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "ninja",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Humanoid,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44100009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "roshi",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Lawful,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "guide",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 20,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "warrior",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 10,
+        alignment: Alignment::Chaotic,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 8),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0x44220009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 7,
+        color: Color::White,
+    },
+    MonsterType {
+        name: "apprentice",
+        symbol: '@',
+        level: 5,
+        move_speed: 12,
+        ac: 10,
+        magic_resistance: 30,
+        alignment: Alignment::Neutral,
+        geno: GenoFlags::from_bits_truncate(0x0200),
+        attacks: [
+            Attack::new(AttackType::Weapon, DamageType::Physical, 1, 6),
+            Attack::new(AttackType::Magic, DamageType::Spell, 0, 0),
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+            Attack::NONE,
+        ],
+        corpse_weight: 1450,
+        nutrition: 400,
+        sound: MonsterSound::Guardian,
+        size: MonsterSize::Medium,
+        resistances: Resistance::empty(),
+        conveys: Resistance::empty(),
+        flags1: MonsterFlags1::from_bits_truncate(0x60020000),
+        flags2: MonsterFlags2::from_bits_truncate(0xC4200009),
+        flags3: MonsterFlags3::from_bits_truncate(0x0200),
+        difficulty: 8,
+        color: Color::White,
+    },
+];
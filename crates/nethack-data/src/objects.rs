@@ -1,14082 +1,519 @@
-// @generated by tools/extract_data.py — do not edit manually
+//! Object type table and lookups derived from it.
 
-#![allow(clippy::unreadable_literal)]
+mod table;
 
-use nethack_types::*;
+pub use table::OBJECTS;
 
-pub static OBJECTS: [ObjectType; 454] = [
-    ObjectType {
-        name: "strange object",
-        description: None,
-        class: ObjectClass::IllObj,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 0,
-        cost: 0,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Liquid,
-        color: Color::Black,
-        nutrition: 0,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "arrow",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: -21,
-        prob: 55,
-        delay: 0,
-        weight: 1,
-        cost: 2,
-        damage_small: 6,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 1,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "elven arrow",
-        description: Some("runed arrow"),
-        class: ObjectClass::Weapon,
-        sub_type: -21,
-        prob: 20,
-        delay: 0,
-        weight: 1,
-        cost: 2,
-        damage_small: 7,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 1,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "orcish arrow",
-        description: Some("crude arrow"),
-        class: ObjectClass::Weapon,
-        sub_type: -21,
-        prob: 20,
-        delay: 0,
-        weight: 1,
-        cost: 2,
-        damage_small: 5,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Black,
-        nutrition: 1,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "silver arrow",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: -21,
-        prob: 12,
-        delay: 0,
-        weight: 1,
-        cost: 5,
-        damage_small: 6,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Silver,
-        color: Color::Gray,
-        nutrition: 1,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "ya",
-        description: Some("bamboo arrow"),
-        class: ObjectClass::Weapon,
-        sub_type: -21,
-        prob: 15,
-        delay: 0,
-        weight: 1,
-        cost: 4,
-        damage_small: 7,
-        damage_large: 7,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Metal,
-        color: Color::Cyan,
-        nutrition: 1,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "crossbow bolt",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: -23,
-        prob: 55,
-        delay: 0,
-        weight: 1,
-        cost: 2,
-        damage_small: 4,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 1,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "dart",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: -24,
-        prob: 60,
-        delay: 0,
-        weight: 1,
-        cost: 2,
-        damage_small: 3,
-        damage_large: 2,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 1,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "shuriken",
-        description: Some("throwing star"),
-        class: ObjectClass::Weapon,
-        sub_type: -25,
-        prob: 35,
-        delay: 0,
-        weight: 1,
-        cost: 5,
-        damage_small: 8,
-        damage_large: 6,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 1,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "boomerang",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: -26,
-        prob: 15,
-        delay: 0,
-        weight: 5,
-        cost: 20,
-        damage_small: 9,
-        damage_large: 9,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 5,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "spear",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 18,
-        prob: 50,
-        delay: 0,
-        weight: 30,
-        cost: 3,
-        damage_small: 6,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "elven spear",
-        description: Some("runed spear"),
-        class: ObjectClass::Weapon,
-        sub_type: 18,
-        prob: 10,
-        delay: 0,
-        weight: 30,
-        cost: 3,
-        damage_small: 7,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "orcish spear",
-        description: Some("crude spear"),
-        class: ObjectClass::Weapon,
-        sub_type: 18,
-        prob: 13,
-        delay: 0,
-        weight: 30,
-        cost: 3,
-        damage_small: 5,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Black,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "dwarvish spear",
-        description: Some("stout spear"),
-        class: ObjectClass::Weapon,
-        sub_type: 18,
-        prob: 12,
-        delay: 0,
-        weight: 35,
-        cost: 3,
-        damage_small: 8,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 35,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "silver spear",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 18,
-        prob: 2,
-        delay: 0,
-        weight: 36,
-        cost: 40,
-        damage_small: 6,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Silver,
-        color: Color::Gray,
-        nutrition: 36,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "javelin",
-        description: Some("throwing spear"),
-        class: ObjectClass::Weapon,
-        sub_type: 18,
-        prob: 10,
-        delay: 0,
-        weight: 20,
-        cost: 3,
-        damage_small: 6,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "trident",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 19,
-        prob: 8,
-        delay: 0,
-        weight: 25,
-        cost: 5,
-        damage_small: 6,
-        damage_large: 4,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 25,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "dagger",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 1,
-        prob: 30,
-        delay: 0,
-        weight: 10,
-        cost: 4,
-        damage_small: 4,
-        damage_large: 3,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "elven dagger",
-        description: Some("runed dagger"),
-        class: ObjectClass::Weapon,
-        sub_type: 1,
-        prob: 10,
-        delay: 0,
-        weight: 10,
-        cost: 4,
-        damage_small: 5,
-        damage_large: 3,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "orcish dagger",
-        description: Some("crude dagger"),
-        class: ObjectClass::Weapon,
-        sub_type: 1,
-        prob: 12,
-        delay: 0,
-        weight: 10,
-        cost: 4,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Black,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "silver dagger",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 1,
-        prob: 3,
-        delay: 0,
-        weight: 12,
-        cost: 40,
-        damage_small: 4,
-        damage_large: 3,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Silver,
-        color: Color::Gray,
-        nutrition: 12,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "athame",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 1,
-        prob: 0,
-        delay: 0,
-        weight: 10,
-        cost: 4,
-        damage_small: 4,
-        damage_large: 3,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "scalpel",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 2,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 6,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Metal,
-        color: Color::Cyan,
-        nutrition: 5,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "knife",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 2,
-        prob: 20,
-        delay: 0,
-        weight: 5,
-        cost: 4,
-        damage_small: 3,
-        damage_large: 2,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 5,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "stiletto",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 2,
-        prob: 5,
-        delay: 0,
-        weight: 5,
-        cost: 4,
-        damage_small: 3,
-        damage_large: 2,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 5,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "worm tooth",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 2,
-        prob: 0,
-        delay: 0,
-        weight: 20,
-        cost: 2,
-        damage_small: 2,
-        damage_large: 2,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Liquid,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "crysknife",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 2,
-        prob: 0,
-        delay: 0,
-        weight: 20,
-        cost: 100,
-        damage_small: 10,
-        damage_large: 10,
-        oc1: 3,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "axe",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 3,
-        prob: 40,
-        delay: 0,
-        weight: 60,
-        cost: 8,
-        damage_small: 6,
-        damage_large: 4,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 60,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "battle-axe",
-        description: Some("double-headed axe"),
-        class: ObjectClass::Weapon,
-        sub_type: 3,
-        prob: 10,
-        delay: 0,
-        weight: 120,
-        cost: 40,
-        damage_small: 8,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 120,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "short sword",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 5,
-        prob: 8,
-        delay: 0,
-        weight: 30,
-        cost: 10,
-        damage_small: 6,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "elven short sword",
-        description: Some("runed short sword"),
-        class: ObjectClass::Weapon,
-        sub_type: 5,
-        prob: 2,
-        delay: 0,
-        weight: 30,
-        cost: 10,
-        damage_small: 8,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "orcish short sword",
-        description: Some("crude short sword"),
-        class: ObjectClass::Weapon,
-        sub_type: 5,
-        prob: 3,
-        delay: 0,
-        weight: 30,
-        cost: 10,
-        damage_small: 5,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Black,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "dwarvish short sword",
-        description: Some("broad short sword"),
-        class: ObjectClass::Weapon,
-        sub_type: 5,
-        prob: 2,
-        delay: 0,
-        weight: 30,
-        cost: 10,
-        damage_small: 7,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "scimitar",
-        description: Some("curved sword"),
-        class: ObjectClass::Weapon,
-        sub_type: 9,
-        prob: 15,
-        delay: 0,
-        weight: 40,
-        cost: 15,
-        damage_small: 8,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 40,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "silver saber",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 10,
-        prob: 6,
-        delay: 0,
-        weight: 40,
-        cost: 75,
-        damage_small: 8,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Silver,
-        color: Color::Gray,
-        nutrition: 40,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "broadsword",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 6,
-        prob: 8,
-        delay: 0,
-        weight: 70,
-        cost: 10,
-        damage_small: 4,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 70,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "elven broadsword",
-        description: Some("runed broadsword"),
-        class: ObjectClass::Weapon,
-        sub_type: 6,
-        prob: 4,
-        delay: 0,
-        weight: 70,
-        cost: 10,
-        damage_small: 6,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 70,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "long sword",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 7,
-        prob: 50,
-        delay: 0,
-        weight: 40,
-        cost: 15,
-        damage_small: 8,
-        damage_large: 12,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 40,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "two-handed sword",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 8,
-        prob: 22,
-        delay: 0,
-        weight: 150,
-        cost: 50,
-        damage_small: 12,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 150,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "katana",
-        description: Some("samurai sword"),
-        class: ObjectClass::Weapon,
-        sub_type: 7,
-        prob: 4,
-        delay: 0,
-        weight: 40,
-        cost: 80,
-        damage_small: 10,
-        damage_large: 12,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 40,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "tsurugi",
-        description: Some("long samurai sword"),
-        class: ObjectClass::Weapon,
-        sub_type: 8,
-        prob: 0,
-        delay: 0,
-        weight: 60,
-        cost: 500,
-        damage_small: 16,
-        damage_large: 8,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Metal,
-        color: Color::Cyan,
-        nutrition: 60,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "runesword",
-        description: Some("runed broadsword"),
-        class: ObjectClass::Weapon,
-        sub_type: 6,
-        prob: 0,
-        delay: 0,
-        weight: 40,
-        cost: 300,
-        damage_small: 4,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Black,
-        nutrition: 40,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "partisan",
-        description: Some("vulgar polearm"),
-        class: ObjectClass::Weapon,
-        sub_type: 17,
-        prob: 5,
-        delay: 0,
-        weight: 80,
-        cost: 10,
-        damage_small: 6,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 80,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "ranseur",
-        description: Some("hilted polearm"),
-        class: ObjectClass::Weapon,
-        sub_type: 17,
-        prob: 5,
-        delay: 0,
-        weight: 50,
-        cost: 6,
-        damage_small: 4,
-        damage_large: 4,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "spetum",
-        description: Some("forked polearm"),
-        class: ObjectClass::Weapon,
-        sub_type: 17,
-        prob: 5,
-        delay: 0,
-        weight: 50,
-        cost: 5,
-        damage_small: 6,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "glaive",
-        description: Some("single-edged polearm"),
-        class: ObjectClass::Weapon,
-        sub_type: 17,
-        prob: 8,
-        delay: 0,
-        weight: 75,
-        cost: 6,
-        damage_small: 6,
-        damage_large: 10,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 75,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "lance",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 20,
-        prob: 4,
-        delay: 0,
-        weight: 180,
-        cost: 10,
-        damage_small: 6,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 180,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "halberd",
-        description: Some("angled poleaxe"),
-        class: ObjectClass::Weapon,
-        sub_type: 17,
-        prob: 8,
-        delay: 0,
-        weight: 150,
-        cost: 10,
-        damage_small: 10,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 150,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "bardiche",
-        description: Some("long poleaxe"),
-        class: ObjectClass::Weapon,
-        sub_type: 17,
-        prob: 4,
-        delay: 0,
-        weight: 120,
-        cost: 7,
-        damage_small: 4,
-        damage_large: 4,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 120,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "voulge",
-        description: Some("pole cleaver"),
-        class: ObjectClass::Weapon,
-        sub_type: 17,
-        prob: 4,
-        delay: 0,
-        weight: 125,
-        cost: 5,
-        damage_small: 4,
-        damage_large: 4,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 125,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "dwarvish mattock",
-        description: Some("broad pick"),
-        class: ObjectClass::Weapon,
-        sub_type: 4,
-        prob: 13,
-        delay: 0,
-        weight: 120,
-        cost: 50,
-        damage_small: 12,
-        damage_large: 8,
-        oc1: -1,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 120,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "fauchard",
-        description: Some("pole sickle"),
-        class: ObjectClass::Weapon,
-        sub_type: 17,
-        prob: 6,
-        delay: 0,
-        weight: 60,
-        cost: 5,
-        damage_small: 6,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 60,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "guisarme",
-        description: Some("pruning hook"),
-        class: ObjectClass::Weapon,
-        sub_type: 17,
-        prob: 6,
-        delay: 0,
-        weight: 80,
-        cost: 5,
-        damage_small: 4,
-        damage_large: 8,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 80,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "bill-guisarme",
-        description: Some("hooked polearm"),
-        class: ObjectClass::Weapon,
-        sub_type: 17,
-        prob: 4,
-        delay: 0,
-        weight: 120,
-        cost: 7,
-        damage_small: 4,
-        damage_large: 10,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 120,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "lucern hammer",
-        description: Some("pronged polearm"),
-        class: ObjectClass::Weapon,
-        sub_type: 17,
-        prob: 5,
-        delay: 0,
-        weight: 150,
-        cost: 7,
-        damage_small: 4,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 150,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "bec de corbin",
-        description: Some("beaked polearm"),
-        class: ObjectClass::Weapon,
-        sub_type: 17,
-        prob: 4,
-        delay: 0,
-        weight: 100,
-        cost: 8,
-        damage_small: 8,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 100,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "mace",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 12,
-        prob: 40,
-        delay: 0,
-        weight: 30,
-        cost: 5,
-        damage_small: 6,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "morning star",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 13,
-        prob: 12,
-        delay: 0,
-        weight: 120,
-        cost: 10,
-        damage_small: 4,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 120,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "war hammer",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 15,
-        prob: 15,
-        delay: 0,
-        weight: 50,
-        cost: 5,
-        damage_small: 4,
-        damage_large: 4,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "club",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 11,
-        prob: 12,
-        delay: 0,
-        weight: 30,
-        cost: 3,
-        damage_small: 6,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "rubber hose",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 27,
-        prob: 0,
-        delay: 0,
-        weight: 20,
-        cost: 3,
-        damage_small: 4,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Plastic,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "quarterstaff",
-        description: Some("staff"),
-        class: ObjectClass::Weapon,
-        sub_type: 16,
-        prob: 11,
-        delay: 0,
-        weight: 40,
-        cost: 5,
-        damage_small: 6,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 40,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "aklys",
-        description: Some("thonged club"),
-        class: ObjectClass::Weapon,
-        sub_type: 11,
-        prob: 8,
-        delay: 0,
-        weight: 15,
-        cost: 4,
-        damage_small: 6,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "flail",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 14,
-        prob: 40,
-        delay: 0,
-        weight: 15,
-        cost: 4,
-        damage_small: 6,
-        damage_large: 4,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "bullwhip",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 27,
-        prob: 2,
-        delay: 0,
-        weight: 20,
-        cost: 4,
-        damage_small: 2,
-        damage_large: 1,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "bow",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 21,
-        prob: 24,
-        delay: 0,
-        weight: 30,
-        cost: 60,
-        damage_small: 2,
-        damage_large: 2,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "elven bow",
-        description: Some("runed bow"),
-        class: ObjectClass::Weapon,
-        sub_type: 21,
-        prob: 12,
-        delay: 0,
-        weight: 30,
-        cost: 60,
-        damage_small: 2,
-        damage_large: 2,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "orcish bow",
-        description: Some("crude bow"),
-        class: ObjectClass::Weapon,
-        sub_type: 21,
-        prob: 12,
-        delay: 0,
-        weight: 30,
-        cost: 60,
-        damage_small: 2,
-        damage_large: 2,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Black,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "yumi",
-        description: Some("long bow"),
-        class: ObjectClass::Weapon,
-        sub_type: 21,
-        prob: 0,
-        delay: 0,
-        weight: 30,
-        cost: 60,
-        damage_small: 2,
-        damage_large: 2,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "sling",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 22,
-        prob: 40,
-        delay: 0,
-        weight: 3,
-        cost: 20,
-        damage_small: 2,
-        damage_large: 2,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 3,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "crossbow",
-        description: None,
-        class: ObjectClass::Weapon,
-        sub_type: 23,
-        prob: 45,
-        delay: 0,
-        weight: 50,
-        cost: 40,
-        damage_small: 2,
-        damage_large: 2,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "elven leather helm",
-        description: Some("leather hat"),
-        class: ObjectClass::Armor,
-        sub_type: 2,
-        prob: 6,
-        delay: 1,
-        weight: 3,
-        cost: 8,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 3,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "orcish helm",
-        description: Some("iron skull cap"),
-        class: ObjectClass::Armor,
-        sub_type: 2,
-        prob: 6,
-        delay: 1,
-        weight: 30,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Black,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "dwarvish iron helm",
-        description: Some("hard hat"),
-        class: ObjectClass::Armor,
-        sub_type: 2,
-        prob: 6,
-        delay: 1,
-        weight: 40,
-        cost: 20,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 40,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "fedora",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 2,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 1,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Cloth,
-        color: Color::Brown,
-        nutrition: 3,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "cornuthaum",
-        description: Some("conical hat"),
-        class: ObjectClass::Armor,
-        sub_type: 2,
-        prob: 3,
-        delay: 1,
-        weight: 4,
-        cost: 80,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Cloth,
-        color: Color::Blue,
-        nutrition: 4,
-        prop: 35,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "dunce cap",
-        description: Some("conical hat"),
-        class: ObjectClass::Armor,
-        sub_type: 2,
-        prob: 3,
-        delay: 1,
-        weight: 4,
-        cost: 1,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Cloth,
-        color: Color::Blue,
-        nutrition: 4,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "dented pot",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 2,
-        prob: 2,
-        delay: 0,
-        weight: 10,
-        cost: 8,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Black,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "helmet",
-        description: Some("plumed helmet"),
-        class: ObjectClass::Armor,
-        sub_type: 2,
-        prob: 10,
-        delay: 1,
-        weight: 30,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "helm of brilliance",
-        description: Some("etched helmet"),
-        class: ObjectClass::Armor,
-        sub_type: 2,
-        prob: 6,
-        delay: 1,
-        weight: 50,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Green,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "helm of opposite alignment",
-        description: Some("crested helmet"),
-        class: ObjectClass::Armor,
-        sub_type: 2,
-        prob: 6,
-        delay: 1,
-        weight: 50,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "helm of telepathy",
-        description: Some("visored helmet"),
-        class: ObjectClass::Armor,
-        sub_type: 2,
-        prob: 2,
-        delay: 1,
-        weight: 50,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 50,
-        prop: 30,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "gray dragon scale mail",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 1200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 9,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Gray,
-        nutrition: 40,
-        prop: 12,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "silver dragon scale mail",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 1200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 9,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::BrightCyan,
-        nutrition: 40,
-        prop: 64,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "red dragon scale mail",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 900,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 9,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Red,
-        nutrition: 40,
-        prop: 1,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "white dragon scale mail",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 900,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 9,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::White,
-        nutrition: 40,
-        prop: 2,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "orange dragon scale mail",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 900,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 9,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Orange,
-        nutrition: 40,
-        prop: 3,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "black dragon scale mail",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 1200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 9,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Black,
-        nutrition: 40,
-        prop: 4,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "blue dragon scale mail",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 900,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 9,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Blue,
-        nutrition: 40,
-        prop: 5,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "green dragon scale mail",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 900,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 9,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Green,
-        nutrition: 40,
-        prop: 6,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "yellow dragon scale mail",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 900,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 9,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Yellow,
-        nutrition: 40,
-        prop: 7,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "gray dragon scales",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 700,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 3,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Gray,
-        nutrition: 40,
-        prop: 12,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "silver dragon scales",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 700,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 3,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::BrightCyan,
-        nutrition: 40,
-        prop: 64,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "red dragon scales",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 500,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 3,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Red,
-        nutrition: 40,
-        prop: 1,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "white dragon scales",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 500,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 3,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::White,
-        nutrition: 40,
-        prop: 2,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "orange dragon scales",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 500,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 3,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Orange,
-        nutrition: 40,
-        prop: 3,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "black dragon scales",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 700,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 3,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Black,
-        nutrition: 40,
-        prop: 4,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "blue dragon scales",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 500,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 3,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Blue,
-        nutrition: 40,
-        prop: 5,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "green dragon scales",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 500,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 3,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Green,
-        nutrition: 40,
-        prop: 6,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "yellow dragon scales",
-        description: Some("None"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 0,
-        delay: 5,
-        weight: 40,
-        cost: 500,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 3,
-        oc2: 0,
-        material: Material::DragonHide,
-        color: Color::Yellow,
-        nutrition: 40,
-        prop: 7,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "plate mail",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 44,
-        delay: 5,
-        weight: 450,
-        cost: 600,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 7,
-        oc2: 2,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 450,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "crystal plate mail",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 10,
-        delay: 5,
-        weight: 450,
-        cost: 820,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 7,
-        oc2: 2,
-        material: Material::Glass,
-        color: Color::White,
-        nutrition: 450,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "bronze plate mail",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 25,
-        delay: 5,
-        weight: 450,
-        cost: 400,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 6,
-        oc2: 1,
-        material: Material::Copper,
-        color: Color::Yellow,
-        nutrition: 450,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "splint mail",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 62,
-        delay: 5,
-        weight: 400,
-        cost: 80,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 6,
-        oc2: 1,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 400,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "banded mail",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 72,
-        delay: 5,
-        weight: 350,
-        cost: 90,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 6,
-        oc2: 1,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 350,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "dwarvish mithril-coat",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 10,
-        delay: 1,
-        weight: 150,
-        cost: 240,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 6,
-        oc2: 2,
-        material: Material::Mithril,
-        color: Color::Gray,
-        nutrition: 150,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "elven mithril-coat",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 15,
-        delay: 1,
-        weight: 150,
-        cost: 240,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 5,
-        oc2: 2,
-        material: Material::Mithril,
-        color: Color::Gray,
-        nutrition: 150,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "chain mail",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 72,
-        delay: 5,
-        weight: 300,
-        cost: 75,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 5,
-        oc2: 1,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 300,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "orcish chain mail",
-        description: Some("crude chain mail"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 20,
-        delay: 5,
-        weight: 300,
-        cost: 75,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 4,
-        oc2: 1,
-        material: Material::Iron,
-        color: Color::Black,
-        nutrition: 300,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "scale mail",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 72,
-        delay: 5,
-        weight: 250,
-        cost: 45,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 4,
-        oc2: 1,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 250,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "studded leather armor",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 72,
-        delay: 3,
-        weight: 200,
-        cost: 15,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 3,
-        oc2: 1,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 200,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "ring mail",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 72,
-        delay: 5,
-        weight: 250,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 3,
-        oc2: 1,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 250,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "orcish ring mail",
-        description: Some("crude ring mail"),
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 20,
-        delay: 5,
-        weight: 250,
-        cost: 80,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 2,
-        oc2: 1,
-        material: Material::Iron,
-        color: Color::Black,
-        nutrition: 250,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "leather armor",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 82,
-        delay: 3,
-        weight: 150,
-        cost: 5,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 2,
-        oc2: 1,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 150,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "leather jacket",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 0,
-        prob: 12,
-        delay: 0,
-        weight: 30,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Black,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "Hawaiian shirt",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 6,
-        prob: 8,
-        delay: 0,
-        weight: 5,
-        cost: 3,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Cloth,
-        color: Color::Magenta,
-        nutrition: 5,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "T-shirt",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 6,
-        prob: 2,
-        delay: 0,
-        weight: 5,
-        cost: 2,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Cloth,
-        color: Color::White,
-        nutrition: 5,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "mummy wrapping",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 5,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 2,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Cloth,
-        color: Color::Gray,
-        nutrition: 3,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "elven cloak",
-        description: Some("faded pall"),
-        class: ObjectClass::Armor,
-        sub_type: 5,
-        prob: 8,
-        delay: 0,
-        weight: 10,
-        cost: 60,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 1,
-        material: Material::Cloth,
-        color: Color::Black,
-        nutrition: 10,
-        prop: 41,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "orcish cloak",
-        description: Some("coarse mantelet"),
-        class: ObjectClass::Armor,
-        sub_type: 5,
-        prob: 8,
-        delay: 0,
-        weight: 10,
-        cost: 40,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Cloth,
-        color: Color::Black,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "dwarvish cloak",
-        description: Some("hooded cloak"),
-        class: ObjectClass::Armor,
-        sub_type: 5,
-        prob: 8,
-        delay: 0,
-        weight: 10,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Cloth,
-        color: Color::Brown,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "oilskin cloak",
-        description: Some("slippery cloak"),
-        class: ObjectClass::Armor,
-        sub_type: 5,
-        prob: 8,
-        delay: 0,
-        weight: 10,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 2,
-        material: Material::Cloth,
-        color: Color::Brown,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "robe",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 5,
-        prob: 3,
-        delay: 0,
-        weight: 15,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 2,
-        oc2: 2,
-        material: Material::Cloth,
-        color: Color::Red,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "alchemy smock",
-        description: Some("apron"),
-        class: ObjectClass::Armor,
-        sub_type: 5,
-        prob: 9,
-        delay: 0,
-        weight: 10,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 1,
-        material: Material::Cloth,
-        color: Color::White,
-        nutrition: 10,
-        prop: 6,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "leather cloak",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 5,
-        prob: 8,
-        delay: 0,
-        weight: 15,
-        cost: 40,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 1,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "cloak of protection",
-        description: Some("tattered cape"),
-        class: ObjectClass::Armor,
-        sub_type: 5,
-        prob: 9,
-        delay: 0,
-        weight: 10,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 3,
-        oc2: 3,
-        material: Material::Cloth,
-        color: Color::Brown,
-        nutrition: 10,
-        prop: 58,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "cloak of invisibility",
-        description: Some("opera cloak"),
-        class: ObjectClass::Armor,
-        sub_type: 5,
-        prob: 10,
-        delay: 0,
-        weight: 10,
-        cost: 60,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 1,
-        material: Material::Cloth,
-        color: Color::BrightMagenta,
-        nutrition: 10,
-        prop: 39,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "cloak of magic resistance",
-        description: Some("ornamental cope"),
-        class: ObjectClass::Armor,
-        sub_type: 5,
-        prob: 2,
-        delay: 0,
-        weight: 10,
-        cost: 60,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 1,
-        material: Material::Cloth,
-        color: Color::White,
-        nutrition: 10,
-        prop: 12,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "cloak of displacement",
-        description: Some("piece of cloth"),
-        class: ObjectClass::Armor,
-        sub_type: 5,
-        prob: 10,
-        delay: 0,
-        weight: 10,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 1,
-        material: Material::Cloth,
-        color: Color::Brown,
-        nutrition: 10,
-        prop: 40,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "small shield",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 1,
-        prob: 6,
-        delay: 0,
-        weight: 30,
-        cost: 3,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "elven shield",
-        description: Some("blue and green shield"),
-        class: ObjectClass::Armor,
-        sub_type: 1,
-        prob: 2,
-        delay: 0,
-        weight: 40,
-        cost: 7,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Green,
-        nutrition: 40,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "Uruk-hai shield",
-        description: Some("white-handed shield"),
-        class: ObjectClass::Armor,
-        sub_type: 1,
-        prob: 2,
-        delay: 0,
-        weight: 50,
-        cost: 7,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "orcish shield",
-        description: Some("red-eyed shield"),
-        class: ObjectClass::Armor,
-        sub_type: 1,
-        prob: 2,
-        delay: 0,
-        weight: 50,
-        cost: 7,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Red,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "large shield",
-        description: None,
-        class: ObjectClass::Armor,
-        sub_type: 1,
-        prob: 7,
-        delay: 0,
-        weight: 100,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 100,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "dwarvish roundshield",
-        description: Some("large round shield"),
-        class: ObjectClass::Armor,
-        sub_type: 1,
-        prob: 4,
-        delay: 0,
-        weight: 100,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 100,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "shield of reflection",
-        description: Some("polished silver shield"),
-        class: ObjectClass::Armor,
-        sub_type: 1,
-        prob: 3,
-        delay: 0,
-        weight: 50,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Silver,
-        color: Color::Gray,
-        nutrition: 50,
-        prop: 64,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "leather gloves",
-        description: Some("old gloves"),
-        class: ObjectClass::Armor,
-        sub_type: 3,
-        prob: 16,
-        delay: 1,
-        weight: 10,
-        cost: 8,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "gauntlets of fumbling",
-        description: Some("padded gloves"),
-        class: ObjectClass::Armor,
-        sub_type: 3,
-        prob: 8,
-        delay: 1,
-        weight: 10,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 10,
-        prop: 25,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "gauntlets of power",
-        description: Some("riding gloves"),
-        class: ObjectClass::Armor,
-        sub_type: 3,
-        prob: 8,
-        delay: 1,
-        weight: 30,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "gauntlets of dexterity",
-        description: Some("fencing gloves"),
-        class: ObjectClass::Armor,
-        sub_type: 3,
-        prob: 8,
-        delay: 1,
-        weight: 10,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "low boots",
-        description: Some("walking shoes"),
-        class: ObjectClass::Armor,
-        sub_type: 4,
-        prob: 25,
-        delay: 2,
-        weight: 10,
-        cost: 8,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "iron shoes",
-        description: Some("hard shoes"),
-        class: ObjectClass::Armor,
-        sub_type: 4,
-        prob: 7,
-        delay: 2,
-        weight: 50,
-        cost: 16,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "high boots",
-        description: Some("jackboots"),
-        class: ObjectClass::Armor,
-        sub_type: 4,
-        prob: 15,
-        delay: 2,
-        weight: 20,
-        cost: 12,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 2,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "speed boots",
-        description: Some("combat boots"),
-        class: ObjectClass::Armor,
-        sub_type: 4,
-        prob: 12,
-        delay: 2,
-        weight: 20,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 63,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "water walking boots",
-        description: Some("jungle boots"),
-        class: ObjectClass::Armor,
-        sub_type: 4,
-        prob: 12,
-        delay: 2,
-        weight: 15,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 15,
-        prop: 49,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "jumping boots",
-        description: Some("hiking boots"),
-        class: ObjectClass::Armor,
-        sub_type: 4,
-        prob: 12,
-        delay: 2,
-        weight: 20,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 44,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "elven boots",
-        description: Some("mud boots"),
-        class: ObjectClass::Armor,
-        sub_type: 4,
-        prob: 12,
-        delay: 2,
-        weight: 15,
-        cost: 8,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 15,
-        prop: 41,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "kicking boots",
-        description: Some("buckled boots"),
-        class: ObjectClass::Armor,
-        sub_type: 4,
-        prob: 12,
-        delay: 2,
-        weight: 50,
-        cost: 8,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Brown,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "fumble boots",
-        description: Some("riding boots"),
-        class: ObjectClass::Armor,
-        sub_type: 4,
-        prob: 12,
-        delay: 2,
-        weight: 20,
-        cost: 30,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 25,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "levitation boots",
-        description: Some("snow boots"),
-        class: ObjectClass::Armor,
-        sub_type: 4,
-        prob: 12,
-        delay: 2,
-        weight: 15,
-        cost: 30,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 15,
-        prop: 47,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "adornment",
-        description: Some("wooden"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 15,
-        prop: 38,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "gain strength",
-        description: Some("granite"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Gray,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "gain constitution",
-        description: Some("opal"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Gray,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "increase accuracy",
-        description: Some("clay"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Red,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "increase damage",
-        description: Some("coral"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Orange,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "protection",
-        description: Some("black onyx"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Black,
-        nutrition: 15,
-        prop: 58,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "regeneration",
-        description: Some("moonstone"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Gray,
-        nutrition: 15,
-        prop: 56,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "searching",
-        description: Some("tiger eye"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Brown,
-        nutrition: 15,
-        prop: 34,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "stealth",
-        description: Some("jade"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Green,
-        nutrition: 15,
-        prop: 41,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "sustain ability",
-        description: Some("bronze"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Copper,
-        color: Color::Yellow,
-        nutrition: 15,
-        prop: 66,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "levitation",
-        description: Some("agate"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Red,
-        nutrition: 15,
-        prop: 47,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "hunger",
-        description: Some("topaz"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Cyan,
-        nutrition: 15,
-        prop: 28,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "aggravate monster",
-        description: Some("sapphire"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Blue,
-        nutrition: 15,
-        prop: 42,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "conflict",
-        description: Some("ruby"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Red,
-        nutrition: 15,
-        prop: 43,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "warning",
-        description: Some("diamond"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::White,
-        nutrition: 15,
-        prop: 31,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "poison resistance",
-        description: Some("pearl"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Bone,
-        color: Color::White,
-        nutrition: 15,
-        prop: 6,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "fire resistance",
-        description: Some("iron"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 15,
-        prop: 1,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "cold resistance",
-        description: Some("brass"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Copper,
-        color: Color::Yellow,
-        nutrition: 15,
-        prop: 2,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "shock resistance",
-        description: Some("copper"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Copper,
-        color: Color::Yellow,
-        nutrition: 15,
-        prop: 5,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "free action",
-        description: Some("twisted"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 15,
-        prop: 65,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "slow digestion",
-        description: Some("steel"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 15,
-        prop: 53,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "teleportation",
-        description: Some("silver"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Silver,
-        color: Color::Gray,
-        nutrition: 15,
-        prop: 45,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "teleport control",
-        description: Some("gold"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gold,
-        color: Color::Yellow,
-        nutrition: 15,
-        prop: 46,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "polymorph",
-        description: Some("ivory"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Bone,
-        color: Color::White,
-        nutrition: 15,
-        prop: 60,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "polymorph control",
-        description: Some("emerald"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::BrightGreen,
-        nutrition: 15,
-        prop: 61,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "invisibility",
-        description: Some("wire"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 15,
-        prop: 39,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "see invisible",
-        description: Some("engagement"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 15,
-        prop: 29,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "protection from shape changers",
-        description: Some("shiny"),
-        class: ObjectClass::Ring,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 3,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::BrightCyan,
-        nutrition: 15,
-        prop: 59,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "amulet of ESP",
-        description: Some("circular"),
-        class: ObjectClass::Amulet,
-        sub_type: 0,
-        prob: 175,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 20,
-        prop: 30,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "amulet of life saving",
-        description: Some("spherical"),
-        class: ObjectClass::Amulet,
-        sub_type: 0,
-        prob: 75,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 20,
-        prop: 67,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "amulet of strangulation",
-        description: Some("oval"),
-        class: ObjectClass::Amulet,
-        sub_type: 0,
-        prob: 135,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 20,
-        prop: 19,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "amulet of restful sleep",
-        description: Some("triangular"),
-        class: ObjectClass::Amulet,
-        sub_type: 0,
-        prob: 135,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 20,
-        prop: 27,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "amulet versus poison",
-        description: Some("pyramidal"),
-        class: ObjectClass::Amulet,
-        sub_type: 0,
-        prob: 165,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 20,
-        prop: 6,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "amulet of change",
-        description: Some("square"),
-        class: ObjectClass::Amulet,
-        sub_type: 0,
-        prob: 130,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "amulet of unchanging",
-        description: Some("concave"),
-        class: ObjectClass::Amulet,
-        sub_type: 0,
-        prob: 45,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 20,
-        prop: 62,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "amulet of reflection",
-        description: Some("hexagonal"),
-        class: ObjectClass::Amulet,
-        sub_type: 0,
-        prob: 75,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 20,
-        prop: 64,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "amulet of magical breathing",
-        description: Some("octagonal"),
-        class: ObjectClass::Amulet,
-        sub_type: 0,
-        prob: 65,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 20,
-        prop: 51,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "cheap plastic imitation of the Amulet of Yendor",
-        description: Some("Amulet of Yendor"),
-        class: ObjectClass::Amulet,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 20,
-        cost: 0,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Plastic,
-        color: Color::Cyan,
-        nutrition: 1,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "Amulet of Yendor",
-        description: Some("Amulet of Yendor"),
-        class: ObjectClass::Amulet,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 20,
-        cost: 30000,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mithril,
-        color: Color::Cyan,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: true,
-            no_wish: true,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "large box",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 40,
-        delay: 0,
-        weight: 350,
-        cost: 8,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 350,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "chest",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 35,
-        delay: 0,
-        weight: 600,
-        cost: 16,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 600,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "ice box",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 5,
-        delay: 0,
-        weight: 900,
-        cost: 42,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Plastic,
-        color: Color::White,
-        nutrition: 900,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "sack",
-        description: Some("bag"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 35,
-        delay: 0,
-        weight: 15,
-        cost: 2,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Cloth,
-        color: Color::Brown,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "oilskin sack",
-        description: Some("bag"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 5,
-        delay: 0,
-        weight: 15,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Cloth,
-        color: Color::Brown,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "bag of holding",
-        description: Some("bag"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 20,
-        delay: 0,
-        weight: 15,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Cloth,
-        color: Color::Brown,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "bag of tricks",
-        description: Some("bag"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 20,
-        delay: 0,
-        weight: 15,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Cloth,
-        color: Color::Brown,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "skeleton key",
-        description: Some("key"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 80,
-        delay: 0,
-        weight: 3,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 3,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "lock pick",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 60,
-        delay: 0,
-        weight: 4,
-        cost: 20,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 4,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "credit card",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 1,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Plastic,
-        color: Color::White,
-        nutrition: 1,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "tallow candle",
-        description: Some("candle"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 20,
-        delay: 0,
-        weight: 2,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wax,
-        color: Color::White,
-        nutrition: 2,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "wax candle",
-        description: Some("candle"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 5,
-        delay: 0,
-        weight: 2,
-        cost: 20,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wax,
-        color: Color::White,
-        nutrition: 2,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "brass lantern",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 30,
-        delay: 0,
-        weight: 30,
-        cost: 12,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Copper,
-        color: Color::Yellow,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "oil lamp",
-        description: Some("lamp"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 45,
-        delay: 0,
-        weight: 20,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Copper,
-        color: Color::Yellow,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "magic lamp",
-        description: Some("lamp"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 20,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Copper,
-        color: Color::Yellow,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "expensive camera",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 12,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Plastic,
-        color: Color::Black,
-        nutrition: 12,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "mirror",
-        description: Some("looking glass"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 45,
-        delay: 0,
-        weight: 13,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Gray,
-        nutrition: 13,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "crystal ball",
-        description: Some("glass orb"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 150,
-        cost: 60,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::BrightCyan,
-        nutrition: 150,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "lenses",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 5,
-        delay: 0,
-        weight: 3,
-        cost: 80,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::BrightCyan,
-        nutrition: 3,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "blindfold",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 50,
-        delay: 0,
-        weight: 2,
-        cost: 20,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Cloth,
-        color: Color::Black,
-        nutrition: 2,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "towel",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 50,
-        delay: 0,
-        weight: 2,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Cloth,
-        color: Color::Magenta,
-        nutrition: 2,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "saddle",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 5,
-        delay: 0,
-        weight: 200,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 200,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "leash",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 65,
-        delay: 0,
-        weight: 12,
-        cost: 20,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 12,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "stethoscope",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 25,
-        delay: 0,
-        weight: 4,
-        cost: 75,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 4,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "tinning kit",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 100,
-        cost: 30,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 100,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "tin opener",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 35,
-        delay: 0,
-        weight: 4,
-        cost: 30,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 4,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "can of grease",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 15,
-        cost: 20,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "figurine",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 25,
-        delay: 0,
-        weight: 50,
-        cost: 80,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Gray,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "magic marker",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 2,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Plastic,
-        color: Color::Red,
-        nutrition: 2,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "land mine",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 300,
-        cost: 180,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Red,
-        nutrition: 300,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "beartrap",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 200,
-        cost: 60,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 200,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "tin whistle",
-        description: Some("whistle"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 100,
-        delay: 0,
-        weight: 3,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Metal,
-        color: Color::Cyan,
-        nutrition: 3,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "magic whistle",
-        description: Some("whistle"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 30,
-        delay: 0,
-        weight: 3,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Metal,
-        color: Color::Cyan,
-        nutrition: 3,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "wooden flute",
-        description: Some("flute"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 4,
-        delay: 0,
-        weight: 5,
-        cost: 12,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 5,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "magic flute",
-        description: Some("flute"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 2,
-        delay: 0,
-        weight: 5,
-        cost: 36,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 5,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "tooled horn",
-        description: Some("horn"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 5,
-        delay: 0,
-        weight: 18,
-        cost: 15,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Bone,
-        color: Color::White,
-        nutrition: 18,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "frost horn",
-        description: Some("horn"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 2,
-        delay: 0,
-        weight: 18,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Bone,
-        color: Color::White,
-        nutrition: 18,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "fire horn",
-        description: Some("horn"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 2,
-        delay: 0,
-        weight: 18,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Bone,
-        color: Color::White,
-        nutrition: 18,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "horn of plenty",
-        description: Some("horn"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 2,
-        delay: 0,
-        weight: 18,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Bone,
-        color: Color::White,
-        nutrition: 18,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "wooden harp",
-        description: Some("harp"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 4,
-        delay: 0,
-        weight: 30,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "magic harp",
-        description: Some("harp"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 2,
-        delay: 0,
-        weight: 30,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "bell",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 2,
-        delay: 0,
-        weight: 30,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Copper,
-        color: Color::Yellow,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "bugle",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 4,
-        delay: 0,
-        weight: 10,
-        cost: 15,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Copper,
-        color: Color::Yellow,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "leather drum",
-        description: Some("drum"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 4,
-        delay: 0,
-        weight: 25,
-        cost: 25,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 25,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "drum of earthquake",
-        description: Some("drum"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 2,
-        delay: 0,
-        weight: 25,
-        cost: 25,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 25,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "pick-axe",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 4,
-        prob: 20,
-        delay: 0,
-        weight: 100,
-        cost: 50,
-        damage_small: 6,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 100,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "grappling hook",
-        description: Some("iron hook"),
-        class: ObjectClass::Tool,
-        sub_type: 14,
-        prob: 5,
-        delay: 0,
-        weight: 30,
-        cost: 50,
-        damage_small: 2,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "unicorn horn",
-        description: None,
-        class: ObjectClass::Tool,
-        sub_type: 28,
-        prob: 0,
-        delay: 0,
-        weight: 20,
-        cost: 100,
-        damage_small: 12,
-        damage_large: 12,
-        oc1: 1,
-        oc2: 0,
-        material: Material::Bone,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "Candelabrum of Invocation",
-        description: Some("candelabrum"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 10,
-        cost: 5000,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gold,
-        color: Color::Yellow,
-        nutrition: 200,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: true,
-            no_wish: true,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "Bell of Opening",
-        description: Some("silver bell"),
-        class: ObjectClass::Tool,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 10,
-        cost: 5000,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Silver,
-        color: Color::Gray,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: true,
-            no_wish: true,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "tripe ration",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 140,
-        delay: 2,
-        weight: 10,
-        cost: 15,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Flesh,
-        color: Color::Brown,
-        nutrition: 200,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "corpse",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 1,
-        weight: 0,
-        cost: 5,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Flesh,
-        color: Color::Brown,
-        nutrition: 0,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "egg",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 85,
-        delay: 1,
-        weight: 1,
-        cost: 9,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Flesh,
-        color: Color::White,
-        nutrition: 80,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "meatball",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 1,
-        weight: 1,
-        cost: 5,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Flesh,
-        color: Color::Brown,
-        nutrition: 5,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "meat stick",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 1,
-        weight: 1,
-        cost: 5,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Flesh,
-        color: Color::Brown,
-        nutrition: 5,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "huge chunk of meat",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 20,
-        weight: 400,
-        cost: 105,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Flesh,
-        color: Color::Brown,
-        nutrition: 2000,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "meat ring",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 1,
-        weight: 5,
-        cost: 1,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Flesh,
-        color: Color::Brown,
-        nutrition: 5,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "glob of gray ooze",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 2,
-        weight: 20,
-        cost: 6,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Flesh,
-        color: Color::Gray,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "glob of brown pudding",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 2,
-        weight: 20,
-        cost: 6,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Flesh,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "glob of green slime",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 2,
-        weight: 20,
-        cost: 6,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Flesh,
-        color: Color::Green,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "glob of black pudding",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 2,
-        weight: 20,
-        cost: 6,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Flesh,
-        color: Color::Black,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "kelp frond",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 1,
-        weight: 1,
-        cost: 6,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Green,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "eucalyptus leaf",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 3,
-        delay: 1,
-        weight: 1,
-        cost: 6,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Green,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "apple",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 15,
-        delay: 1,
-        weight: 2,
-        cost: 7,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Red,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "orange",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 10,
-        delay: 1,
-        weight: 2,
-        cost: 9,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Orange,
-        nutrition: 80,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "pear",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 10,
-        delay: 1,
-        weight: 2,
-        cost: 7,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::BrightGreen,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "melon",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 10,
-        delay: 1,
-        weight: 5,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::BrightGreen,
-        nutrition: 100,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "banana",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 10,
-        delay: 1,
-        weight: 2,
-        cost: 9,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Yellow,
-        nutrition: 80,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "carrot",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 15,
-        delay: 1,
-        weight: 2,
-        cost: 7,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Orange,
-        nutrition: 50,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "sprig of wolfsbane",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 7,
-        delay: 1,
-        weight: 1,
-        cost: 7,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Green,
-        nutrition: 40,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "clove of garlic",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 7,
-        delay: 1,
-        weight: 1,
-        cost: 7,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::White,
-        nutrition: 40,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "slime mold",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 75,
-        delay: 1,
-        weight: 5,
-        cost: 17,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Brown,
-        nutrition: 250,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "lump of royal jelly",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 1,
-        weight: 2,
-        cost: 15,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Yellow,
-        nutrition: 200,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "cream pie",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 25,
-        delay: 1,
-        weight: 10,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::White,
-        nutrition: 100,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "candy bar",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 13,
-        delay: 1,
-        weight: 2,
-        cost: 10,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Brown,
-        nutrition: 100,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "fortune cookie",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 55,
-        delay: 1,
-        weight: 1,
-        cost: 7,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Yellow,
-        nutrition: 40,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "pancake",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 25,
-        delay: 2,
-        weight: 2,
-        cost: 15,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Yellow,
-        nutrition: 200,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "lembas wafer",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 20,
-        delay: 2,
-        weight: 5,
-        cost: 45,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::White,
-        nutrition: 800,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "cram ration",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 20,
-        delay: 3,
-        weight: 15,
-        cost: 35,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Brown,
-        nutrition: 600,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "food ration",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 380,
-        delay: 5,
-        weight: 20,
-        cost: 45,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Brown,
-        nutrition: 800,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "K-ration",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 1,
-        weight: 10,
-        cost: 25,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Brown,
-        nutrition: 400,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "C-ration",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 0,
-        delay: 1,
-        weight: 10,
-        cost: 20,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Veggy,
-        color: Color::Brown,
-        nutrition: 300,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "tin",
-        description: None,
-        class: ObjectClass::Food,
-        sub_type: 0,
-        prob: 75,
-        delay: 0,
-        weight: 10,
-        cost: 5,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Metal,
-        color: Color::Cyan,
-        nutrition: 0,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "gain ability",
-        description: Some("ruby"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 42,
-        delay: 0,
-        weight: 20,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Red,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "restore ability",
-        description: Some("pink"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 40,
-        delay: 0,
-        weight: 20,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::BrightMagenta,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "confusion",
-        description: Some("orange"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 42,
-        delay: 0,
-        weight: 20,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Orange,
-        nutrition: 10,
-        prop: 14,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "blindness",
-        description: Some("yellow"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 40,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Yellow,
-        nutrition: 10,
-        prop: 15,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "paralysis",
-        description: Some("emerald"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 42,
-        delay: 0,
-        weight: 20,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::BrightGreen,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "speed",
-        description: Some("dark green"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 42,
-        delay: 0,
-        weight: 20,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Green,
-        nutrition: 10,
-        prop: 63,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "levitation",
-        description: Some("cyan"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 42,
-        delay: 0,
-        weight: 20,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Cyan,
-        nutrition: 10,
-        prop: 47,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "hallucination",
-        description: Some("sky blue"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 40,
-        delay: 0,
-        weight: 20,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Cyan,
-        nutrition: 10,
-        prop: 23,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "invisibility",
-        description: Some("brilliant blue"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 40,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::BrightBlue,
-        nutrition: 10,
-        prop: 39,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "see invisible",
-        description: Some("magenta"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 42,
-        delay: 0,
-        weight: 20,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Magenta,
-        nutrition: 10,
-        prop: 29,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "healing",
-        description: Some("purple-red"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 57,
-        delay: 0,
-        weight: 20,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Magenta,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "extra healing",
-        description: Some("puce"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 47,
-        delay: 0,
-        weight: 20,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Red,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "gain level",
-        description: Some("milky"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 20,
-        delay: 0,
-        weight: 20,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::White,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "enlightenment",
-        description: Some("swirly"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 20,
-        delay: 0,
-        weight: 20,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Brown,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "monster detection",
-        description: Some("bubbly"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 40,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::White,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "object detection",
-        description: Some("smoky"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 42,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Gray,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "gain energy",
-        description: Some("cloudy"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 42,
-        delay: 0,
-        weight: 20,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::White,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "sleeping",
-        description: Some("effervescent"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 42,
-        delay: 0,
-        weight: 20,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Gray,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "full healing",
-        description: Some("black"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 10,
-        delay: 0,
-        weight: 20,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Black,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "polymorph",
-        description: Some("golden"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 10,
-        delay: 0,
-        weight: 20,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Yellow,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "booze",
-        description: Some("brown"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 42,
-        delay: 0,
-        weight: 20,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Brown,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "sickness",
-        description: Some("fizzy"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 42,
-        delay: 0,
-        weight: 20,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Cyan,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "fruit juice",
-        description: Some("dark"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 42,
-        delay: 0,
-        weight: 20,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Black,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "acid",
-        description: Some("white"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 10,
-        delay: 0,
-        weight: 20,
-        cost: 250,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::White,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "oil",
-        description: Some("murky"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 30,
-        delay: 0,
-        weight: 20,
-        cost: 250,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Brown,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "water",
-        description: Some("clear"),
-        class: ObjectClass::Potion,
-        sub_type: 0,
-        prob: 92,
-        delay: 0,
-        weight: 20,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Cyan,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "enchant armor",
-        description: Some("ZELGO MER"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 63,
-        delay: 0,
-        weight: 5,
-        cost: 80,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "destroy armor",
-        description: Some("JUYED AWK YACC"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 45,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "confuse monster",
-        description: Some("NR 9"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 53,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "scare monster",
-        description: Some("XIXAXA XOXAXA XUXAXA"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 35,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "remove curse",
-        description: Some("PRATYAVAYAH"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 65,
-        delay: 0,
-        weight: 5,
-        cost: 80,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "enchant weapon",
-        description: Some("DAIYEN FOOELS"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 80,
-        delay: 0,
-        weight: 5,
-        cost: 60,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "create monster",
-        description: Some("LEP GEX VEN ZEA"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 45,
-        delay: 0,
-        weight: 5,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "taming",
-        description: Some("PRIRUTSENIE"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 5,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "genocide",
-        description: Some("ELBIB YLOH"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 5,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "light",
-        description: Some("VERR YED HORRE"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 90,
-        delay: 0,
-        weight: 5,
-        cost: 50,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "teleportation",
-        description: Some("VENZAR BORGAVVE"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 55,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "gold detection",
-        description: Some("THARR"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 33,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "food detection",
-        description: Some("YUM YUM"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 25,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "identify",
-        description: Some("KERNOD WEL"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 180,
-        delay: 0,
-        weight: 5,
-        cost: 20,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "magic mapping",
-        description: Some("ELAM EBOW"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 45,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "amnesia",
-        description: Some("DUAM XNAHT"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 35,
-        delay: 0,
-        weight: 5,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "fire",
-        description: Some("ANDOVA BEGARIN"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 30,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "earth",
-        description: Some("KIRJE"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 18,
-        delay: 0,
-        weight: 5,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "punishment",
-        description: Some("VE FORBRYDERNE"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 5,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "charging",
-        description: Some("HACKEM MUCHE"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 5,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "stinking cloud",
-        description: Some("VELOX NEB"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 5,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("FOOBIE BLETCH"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("TEMOV"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("GARVEN DEH"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("READ ME"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("ETAOIN SHRDLU"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("LOREM IPSUM"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("FNORD"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("KO BATE"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("ABRA KA DABRA"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("ASHPD SODALG"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("ZLORFIK"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("GNIK SISI VLE"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("HAPAX LEGOMENON"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("EIRIS SAZUN IDISI"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("PHOL ENDE WODAN"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("GHOTI"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("MAPIRO MAHAMA DIROMAT"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("VAS CORP BET MANI"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("XOR OTA"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("STRC PRST SKRZ KRK"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "mail",
-        description: Some("stamped"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 5,
-        cost: 0,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "blank paper",
-        description: Some("unlabeled"),
-        class: ObjectClass::Scroll,
-        sub_type: 0,
-        prob: 28,
-        delay: 0,
-        weight: 5,
-        cost: 60,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "dig",
-        description: Some("parchment"),
-        class: ObjectClass::SpellBook,
-        sub_type: 35,
-        prob: 20,
-        delay: 6,
-        weight: 50,
-        cost: 500,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 5,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "magic missile",
-        description: Some("vellum"),
-        class: ObjectClass::SpellBook,
-        sub_type: 29,
-        prob: 45,
-        delay: 2,
-        weight: 50,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 2,
-        material: Material::Leather,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "fireball",
-        description: Some("ragged"),
-        class: ObjectClass::SpellBook,
-        sub_type: 29,
-        prob: 20,
-        delay: 4,
-        weight: 50,
-        cost: 400,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 4,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "cone of cold",
-        description: Some("dog eared"),
-        class: ObjectClass::SpellBook,
-        sub_type: 29,
-        prob: 10,
-        delay: 7,
-        weight: 50,
-        cost: 400,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 4,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "sleep",
-        description: Some("mottled"),
-        class: ObjectClass::SpellBook,
-        sub_type: 32,
-        prob: 50,
-        delay: 1,
-        weight: 50,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "finger of death",
-        description: Some("stained"),
-        class: ObjectClass::SpellBook,
-        sub_type: 29,
-        prob: 5,
-        delay: 10,
-        weight: 50,
-        cost: 700,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 7,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "light",
-        description: Some("cloth"),
-        class: ObjectClass::SpellBook,
-        sub_type: 31,
-        prob: 45,
-        delay: 1,
-        weight: 50,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Paper,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "detect monsters",
-        description: Some("leathery"),
-        class: ObjectClass::SpellBook,
-        sub_type: 31,
-        prob: 43,
-        delay: 1,
-        weight: 50,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Paper,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "healing",
-        description: Some("white"),
-        class: ObjectClass::SpellBook,
-        sub_type: 30,
-        prob: 40,
-        delay: 2,
-        weight: 50,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "knock",
-        description: Some("pink"),
-        class: ObjectClass::SpellBook,
-        sub_type: 35,
-        prob: 35,
-        delay: 1,
-        weight: 50,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Paper,
-        color: Color::BrightMagenta,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "force bolt",
-        description: Some("red"),
-        class: ObjectClass::SpellBook,
-        sub_type: 29,
-        prob: 35,
-        delay: 2,
-        weight: 50,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Paper,
-        color: Color::Red,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "confuse monster",
-        description: Some("orange"),
-        class: ObjectClass::SpellBook,
-        sub_type: 32,
-        prob: 30,
-        delay: 2,
-        weight: 50,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 2,
-        material: Material::Paper,
-        color: Color::Orange,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "cure blindness",
-        description: Some("yellow"),
-        class: ObjectClass::SpellBook,
-        sub_type: 30,
-        prob: 25,
-        delay: 2,
-        weight: 50,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 2,
-        material: Material::Paper,
-        color: Color::Yellow,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "drain life",
-        description: Some("velvet"),
-        class: ObjectClass::SpellBook,
-        sub_type: 29,
-        prob: 10,
-        delay: 2,
-        weight: 50,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 2,
-        material: Material::Paper,
-        color: Color::Magenta,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "slow monster",
-        description: Some("light green"),
-        class: ObjectClass::SpellBook,
-        sub_type: 32,
-        prob: 30,
-        delay: 2,
-        weight: 50,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 2,
-        material: Material::Paper,
-        color: Color::BrightGreen,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "wizard lock",
-        description: Some("dark green"),
-        class: ObjectClass::SpellBook,
-        sub_type: 35,
-        prob: 30,
-        delay: 3,
-        weight: 50,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 2,
-        material: Material::Paper,
-        color: Color::Green,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "create monster",
-        description: Some("turquoise"),
-        class: ObjectClass::SpellBook,
-        sub_type: 33,
-        prob: 35,
-        delay: 3,
-        weight: 50,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 2,
-        material: Material::Paper,
-        color: Color::BrightCyan,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "detect food",
-        description: Some("cyan"),
-        class: ObjectClass::SpellBook,
-        sub_type: 31,
-        prob: 30,
-        delay: 3,
-        weight: 50,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 2,
-        material: Material::Paper,
-        color: Color::Cyan,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "cause fear",
-        description: Some("light blue"),
-        class: ObjectClass::SpellBook,
-        sub_type: 32,
-        prob: 25,
-        delay: 3,
-        weight: 50,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 3,
-        material: Material::Paper,
-        color: Color::BrightBlue,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "clairvoyance",
-        description: Some("dark blue"),
-        class: ObjectClass::SpellBook,
-        sub_type: 31,
-        prob: 15,
-        delay: 3,
-        weight: 50,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 3,
-        material: Material::Paper,
-        color: Color::Blue,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "cure sickness",
-        description: Some("indigo"),
-        class: ObjectClass::SpellBook,
-        sub_type: 30,
-        prob: 32,
-        delay: 3,
-        weight: 50,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 3,
-        material: Material::Paper,
-        color: Color::Blue,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "charm monster",
-        description: Some("magenta"),
-        class: ObjectClass::SpellBook,
-        sub_type: 32,
-        prob: 20,
-        delay: 3,
-        weight: 50,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 3,
-        material: Material::Paper,
-        color: Color::Magenta,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "haste self",
-        description: Some("purple"),
-        class: ObjectClass::SpellBook,
-        sub_type: 34,
-        prob: 33,
-        delay: 4,
-        weight: 50,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 3,
-        material: Material::Paper,
-        color: Color::Magenta,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "detect unseen",
-        description: Some("violet"),
-        class: ObjectClass::SpellBook,
-        sub_type: 31,
-        prob: 20,
-        delay: 4,
-        weight: 50,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 3,
-        material: Material::Paper,
-        color: Color::Magenta,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "levitation",
-        description: Some("tan"),
-        class: ObjectClass::SpellBook,
-        sub_type: 34,
-        prob: 20,
-        delay: 4,
-        weight: 50,
-        cost: 400,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 4,
-        material: Material::Paper,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "extra healing",
-        description: Some("plaid"),
-        class: ObjectClass::SpellBook,
-        sub_type: 30,
-        prob: 27,
-        delay: 5,
-        weight: 50,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 3,
-        material: Material::Paper,
-        color: Color::Green,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "restore ability",
-        description: Some("light brown"),
-        class: ObjectClass::SpellBook,
-        sub_type: 30,
-        prob: 25,
-        delay: 5,
-        weight: 50,
-        cost: 400,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 4,
-        material: Material::Paper,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "invisibility",
-        description: Some("dark brown"),
-        class: ObjectClass::SpellBook,
-        sub_type: 34,
-        prob: 25,
-        delay: 5,
-        weight: 50,
-        cost: 400,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 4,
-        material: Material::Paper,
-        color: Color::Brown,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "detect treasure",
-        description: Some("gray"),
-        class: ObjectClass::SpellBook,
-        sub_type: 31,
-        prob: 20,
-        delay: 5,
-        weight: 50,
-        cost: 400,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 4,
-        material: Material::Paper,
-        color: Color::Gray,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "remove curse",
-        description: Some("wrinkled"),
-        class: ObjectClass::SpellBook,
-        sub_type: 33,
-        prob: 25,
-        delay: 5,
-        weight: 50,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 3,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "magic mapping",
-        description: Some("dusty"),
-        class: ObjectClass::SpellBook,
-        sub_type: 31,
-        prob: 18,
-        delay: 7,
-        weight: 50,
-        cost: 500,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 5,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "identify",
-        description: Some("bronze"),
-        class: ObjectClass::SpellBook,
-        sub_type: 31,
-        prob: 20,
-        delay: 6,
-        weight: 50,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 3,
-        material: Material::Paper,
-        color: Color::Yellow,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "turn undead",
-        description: Some("copper"),
-        class: ObjectClass::SpellBook,
-        sub_type: 33,
-        prob: 16,
-        delay: 8,
-        weight: 50,
-        cost: 600,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 6,
-        material: Material::Paper,
-        color: Color::Yellow,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "polymorph",
-        description: Some("silver"),
-        class: ObjectClass::SpellBook,
-        sub_type: 35,
-        prob: 10,
-        delay: 8,
-        weight: 50,
-        cost: 600,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 6,
-        material: Material::Paper,
-        color: Color::Gray,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "teleport away",
-        description: Some("gold"),
-        class: ObjectClass::SpellBook,
-        sub_type: 34,
-        prob: 15,
-        delay: 6,
-        weight: 50,
-        cost: 600,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 6,
-        material: Material::Paper,
-        color: Color::Yellow,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "create familiar",
-        description: Some("glittering"),
-        class: ObjectClass::SpellBook,
-        sub_type: 33,
-        prob: 10,
-        delay: 7,
-        weight: 50,
-        cost: 600,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 6,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "cancellation",
-        description: Some("shining"),
-        class: ObjectClass::SpellBook,
-        sub_type: 35,
-        prob: 15,
-        delay: 8,
-        weight: 50,
-        cost: 700,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 7,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "protection",
-        description: Some("dull"),
-        class: ObjectClass::SpellBook,
-        sub_type: 33,
-        prob: 18,
-        delay: 3,
-        weight: 50,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "jumping",
-        description: Some("thin"),
-        class: ObjectClass::SpellBook,
-        sub_type: 34,
-        prob: 20,
-        delay: 3,
-        weight: 50,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "stone to flesh",
-        description: Some("thick"),
-        class: ObjectClass::SpellBook,
-        sub_type: 30,
-        prob: 15,
-        delay: 1,
-        weight: 50,
-        cost: 300,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 3,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "blank paper",
-        description: Some("plain"),
-        class: ObjectClass::SpellBook,
-        sub_type: 0,
-        prob: 18,
-        delay: 0,
-        weight: 50,
-        cost: 0,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "novel",
-        description: Some("paperback"),
-        class: ObjectClass::SpellBook,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 0,
-        cost: 20,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 1,
-        material: Material::Paper,
-        color: Color::BrightBlue,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "Book of the Dead",
-        description: Some("papyrus"),
-        class: ObjectClass::SpellBook,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 20,
-        cost: 10000,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 7,
-        material: Material::Paper,
-        color: Color::White,
-        nutrition: 20,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: true,
-            no_wish: true,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "light",
-        description: Some("glass"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 95,
-        delay: 0,
-        weight: 7,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::BrightCyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "secret door detection",
-        description: Some("balsa"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 50,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "enlightenment",
-        description: Some("crystal"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 15,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::BrightCyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "create monster",
-        description: Some("maple"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 45,
-        delay: 0,
-        weight: 7,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "wishing",
-        description: Some("pine"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 5,
-        delay: 0,
-        weight: 7,
-        cost: 500,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 1,
-        },
-    },
-    ObjectType {
-        name: "nothing",
-        description: Some("oak"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 25,
-        delay: 0,
-        weight: 7,
-        cost: 100,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: false,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "striking",
-        description: Some("ebony"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 75,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "make invisible",
-        description: Some("marble"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 45,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Gray,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "slow monster",
-        description: Some("tin"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 50,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Metal,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "speed monster",
-        description: Some("brass"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 50,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Copper,
-        color: Color::Yellow,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "undead turning",
-        description: Some("copper"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 50,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Copper,
-        color: Color::Yellow,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "polymorph",
-        description: Some("silver"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 45,
-        delay: 0,
-        weight: 7,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Silver,
-        color: Color::Gray,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "cancellation",
-        description: Some("platinum"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 45,
-        delay: 0,
-        weight: 7,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Platinum,
-        color: Color::White,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "teleportation",
-        description: Some("iridium"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 45,
-        delay: 0,
-        weight: 7,
-        cost: 200,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Metal,
-        color: Color::BrightCyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "opening",
-        description: Some("zinc"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 25,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Metal,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "locking",
-        description: Some("aluminum"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 25,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Metal,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "probing",
-        description: Some("uranium"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 30,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Metal,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 2,
-        },
-    },
-    ObjectType {
-        name: "digging",
-        description: Some("iron"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 55,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "magic missile",
-        description: Some("steel"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 50,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "fire",
-        description: Some("hexagonal"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 40,
-        delay: 0,
-        weight: 7,
-        cost: 175,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "cold",
-        description: Some("short"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 40,
-        delay: 0,
-        weight: 7,
-        cost: 175,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "sleep",
-        description: Some("runed"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 50,
-        delay: 0,
-        weight: 7,
-        cost: 175,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "death",
-        description: Some("long"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 5,
-        delay: 0,
-        weight: 7,
-        cost: 500,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "lightning",
-        description: Some("curved"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 40,
-        delay: 0,
-        weight: 7,
-        cost: 175,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 3,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("forked"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Wood,
-        color: Color::Brown,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("spiked"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "",
-        description: Some("jeweled"),
-        class: ObjectClass::Wand,
-        sub_type: 0,
-        prob: 0,
-        delay: 0,
-        weight: 7,
-        cost: 150,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Gray,
-        nutrition: 30,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: false,
-            uses_known: true,
-            pre_discovered: false,
-            magic: true,
-            charged: true,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "gold piece",
-        description: None,
-        class: ObjectClass::Coin,
-        sub_type: 0,
-        prob: 1000,
-        delay: 0,
-        weight: 1,
-        cost: 1,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gold,
-        color: Color::Yellow,
-        nutrition: 0,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "dilithium crystal",
-        description: Some("white"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 2,
-        delay: 0,
-        weight: 1,
-        cost: 4500,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::White,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "diamond",
-        description: Some("white"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 3,
-        delay: 0,
-        weight: 1,
-        cost: 4000,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::White,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "ruby",
-        description: Some("red"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 4,
-        delay: 0,
-        weight: 1,
-        cost: 3500,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Red,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "jacinth",
-        description: Some("orange"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 3,
-        delay: 0,
-        weight: 1,
-        cost: 3250,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Orange,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "sapphire",
-        description: Some("blue"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 4,
-        delay: 0,
-        weight: 1,
-        cost: 3000,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Blue,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "black opal",
-        description: Some("black"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 3,
-        delay: 0,
-        weight: 1,
-        cost: 2500,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Black,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "emerald",
-        description: Some("green"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 5,
-        delay: 0,
-        weight: 1,
-        cost: 2500,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Green,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "turquoise",
-        description: Some("green"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 6,
-        delay: 0,
-        weight: 1,
-        cost: 2000,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Green,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "citrine",
-        description: Some("yellow"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 4,
-        delay: 0,
-        weight: 1,
-        cost: 1500,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Yellow,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "aquamarine",
-        description: Some("green"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 6,
-        delay: 0,
-        weight: 1,
-        cost: 1500,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Green,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "amber",
-        description: Some("yellowish brown"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 8,
-        delay: 0,
-        weight: 1,
-        cost: 1000,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Brown,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "topaz",
-        description: Some("yellowish brown"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 10,
-        delay: 0,
-        weight: 1,
-        cost: 900,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Brown,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: true,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "jet",
-        description: Some("black"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 6,
-        delay: 0,
-        weight: 1,
-        cost: 850,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Black,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "opal",
-        description: Some("white"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 12,
-        delay: 0,
-        weight: 1,
-        cost: 800,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::White,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "chrysoberyl",
-        description: Some("yellow"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 8,
-        delay: 0,
-        weight: 1,
-        cost: 700,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Yellow,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "garnet",
-        description: Some("red"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 12,
-        delay: 0,
-        weight: 1,
-        cost: 700,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Red,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "amethyst",
-        description: Some("violet"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 14,
-        delay: 0,
-        weight: 1,
-        cost: 600,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Magenta,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "jasper",
-        description: Some("red"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 15,
-        delay: 0,
-        weight: 1,
-        cost: 500,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Red,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "fluorite",
-        description: Some("violet"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 15,
-        delay: 0,
-        weight: 1,
-        cost: 400,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Magenta,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "obsidian",
-        description: Some("black"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 9,
-        delay: 0,
-        weight: 1,
-        cost: 200,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Black,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "agate",
-        description: Some("orange"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 12,
-        delay: 0,
-        weight: 1,
-        cost: 200,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Orange,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "jade",
-        description: Some("green"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 10,
-        delay: 0,
-        weight: 1,
-        cost: 300,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Gemstone,
-        color: Color::Green,
-        nutrition: 15,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "worthless piece of white glass",
-        description: Some("white"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 77,
-        delay: 0,
-        weight: 1,
-        cost: 0,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::White,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "worthless piece of blue glass",
-        description: Some("blue"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 77,
-        delay: 0,
-        weight: 1,
-        cost: 0,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Blue,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "worthless piece of red glass",
-        description: Some("red"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 77,
-        delay: 0,
-        weight: 1,
-        cost: 0,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Red,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "worthless piece of yellowish brown glass",
-        description: Some("yellowish brown"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 77,
-        delay: 0,
-        weight: 1,
-        cost: 0,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Brown,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "worthless piece of orange glass",
-        description: Some("orange"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 76,
-        delay: 0,
-        weight: 1,
-        cost: 0,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Orange,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "worthless piece of yellow glass",
-        description: Some("yellow"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 77,
-        delay: 0,
-        weight: 1,
-        cost: 0,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Yellow,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "worthless piece of black glass",
-        description: Some("black"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 76,
-        delay: 0,
-        weight: 1,
-        cost: 0,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Black,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "worthless piece of green glass",
-        description: Some("green"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 77,
-        delay: 0,
-        weight: 1,
-        cost: 0,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Green,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "worthless piece of violet glass",
-        description: Some("violet"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 77,
-        delay: 0,
-        weight: 1,
-        cost: 0,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Glass,
-        color: Color::Magenta,
-        nutrition: 6,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "luckstone",
-        description: Some("gray"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 10,
-        delay: 0,
-        weight: 10,
-        cost: 60,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Gray,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "loadstone",
-        description: Some("gray"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 10,
-        delay: 0,
-        weight: 500,
-        cost: 1,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Gray,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "touchstone",
-        description: Some("gray"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 8,
-        delay: 0,
-        weight: 10,
-        cost: 45,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Gray,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: true,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "flint",
-        description: Some("gray"),
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 10,
-        delay: 0,
-        weight: 10,
-        cost: 1,
-        damage_small: 6,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Gray,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "rock",
-        description: None,
-        class: ObjectClass::Gem,
-        sub_type: -22,
-        prob: 100,
-        delay: 0,
-        weight: 10,
-        cost: 0,
-        damage_small: 3,
-        damage_large: 3,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Gray,
-        nutrition: 10,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "boulder",
-        description: None,
-        class: ObjectClass::Rock,
-        sub_type: 0,
-        prob: 100,
-        delay: 0,
-        weight: 6000,
-        cost: 0,
-        damage_small: 20,
-        damage_large: 20,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::Gray,
-        nutrition: 2000,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: true,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "statue",
-        description: None,
-        class: ObjectClass::Rock,
-        sub_type: 0,
-        prob: 900,
-        delay: 0,
-        weight: 2500,
-        cost: 0,
-        damage_small: 20,
-        damage_large: 20,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Mineral,
-        color: Color::White,
-        nutrition: 2500,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "heavy iron ball",
-        description: None,
-        class: ObjectClass::Ball,
-        sub_type: 0,
-        prob: 1000,
-        delay: 0,
-        weight: 480,
-        cost: 10,
-        damage_small: 25,
-        damage_large: 25,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 200,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "iron chain",
-        description: None,
-        class: ObjectClass::Chain,
-        sub_type: 0,
-        prob: 1000,
-        delay: 0,
-        weight: 120,
-        cost: 0,
-        damage_small: 4,
-        damage_large: 4,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Iron,
-        color: Color::Cyan,
-        nutrition: 200,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: true,
-            merge: false,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: false,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "blinding venom",
-        description: Some("splash of venom"),
-        class: ObjectClass::Venom,
-        sub_type: 0,
-        prob: 500,
-        delay: 0,
-        weight: 1,
-        cost: 0,
-        damage_small: 0,
-        damage_large: 0,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Liquid,
-        color: Color::Brown,
-        nutrition: 0,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: true,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
-    ObjectType {
-        name: "acid venom",
-        description: Some("splash of venom"),
-        class: ObjectClass::Venom,
-        sub_type: 0,
-        prob: 500,
-        delay: 0,
-        weight: 1,
-        cost: 0,
-        damage_small: 6,
-        damage_large: 6,
-        oc1: 0,
-        oc2: 0,
-        material: Material::Liquid,
-        color: Color::Brown,
-        nutrition: 0,
-        prop: 0,
-        flags: ObjectTypeFlags {
-            name_known: false,
-            merge: true,
-            uses_known: false,
-            pre_discovered: false,
-            magic: false,
-            charged: false,
-            unique: false,
-            no_wish: true,
-            big: false,
-            tough: false,
-            dir: 0,
-        },
-    },
+use nethack_rng::NhRng;
+use nethack_types::{Buc, GenoFlags, MonsterId, ObjectClass, ObjectId, ObjectInstance};
+
+use crate::csv::CsvWriter;
+use crate::monsters::MONSTERS;
+
+/// Check the per-class generation probabilities, as C's `init_objects()`
+/// does at startup.
+///
+/// Each class's `prob` values must sum to 1000, or to 0 (C then spreads the
+/// probability evenly). Returns the offending classes with their sums.
+pub fn validate_probabilities() -> Result<(), Vec<(ObjectClass, u32)>> {
+    let bad: Vec<_> = (0..ObjectClass::MAX)
+        .filter_map(|i| ObjectClass::from_repr(i as u8))
+        .map(|class| {
+            let sum = OBJECTS
+                .iter()
+                .filter(|o| o.class == class)
+                .map(|o| o.prob as u32)
+                .sum::<u32>();
+            (class, sum)
+        })
+        .filter(|&(_, sum)| sum != 0 && sum != 1000)
+        .collect();
+    if bad.is_empty() { Ok(()) } else { Err(bad) }
+}
+
+/// Stack size `mksobj()` gives a newly made object of type `id`.
+///
+/// Ammunition and missiles come in 6-11, as do rocks. Other gems (except
+/// luckstones), most food and candles sometimes come in small stacks;
+/// everything else is single. Only the quantity rolls are made, as with
+/// [`default_buc`]. Gold amounts come from `mkgold()`, not `mksobj()`, so
+/// gold pieces count as 1 here.
+pub fn stack_quantity(rng: &mut NhRng, id: ObjectId) -> u32 {
+    use ObjectId as O;
+    let obj = &OBJECTS[id as usize];
+    match obj.class {
+        // is_multigen(): -P_SHURIKEN <= oc_skill <= -P_BOW
+        ObjectClass::Weapon if obj.flags.merge && (-25..=-21).contains(&obj.sub_type) => {
+            rng.rn1(6, 6) as u32
+        }
+        ObjectClass::Food => match id {
+            O::KelpFrond => rng.rnd(2) as u32,
+            O::Corpse
+            | O::MeatRing
+            | O::GlobOfGrayOoze
+            | O::GlobOfBrownPudding
+            | O::GlobOfGreenSlime
+            | O::GlobOfBlackPudding => 1,
+            _ => sometimes_two(rng),
+        },
+        ObjectClass::Tool if matches!(id, O::TallowCandle | O::WaxCandle) => {
+            1 + if rng.rn2(2) != 0 {
+                rng.rn2(7) as u32
+            } else {
+                0
+            }
+        }
+        ObjectClass::Gem => match id {
+            O::Rock => rng.rn1(6, 6) as u32,
+            O::Loadstone | O::Luckstone => 1,
+            _ => sometimes_two(rng),
+        },
+        _ => 1,
+    }
+}
+
+/// A pair one time in six.
+fn sometimes_two(rng: &mut NhRng) -> u32 {
+    if rng.rn2(6) == 0 { 2 } else { 1 }
+}
+
+/// Whether two stacks combine into one, following the checks in C's
+/// `mergable()` that apply to [`ObjectInstance`].
+///
+/// The type must allow merging, and enchantment, erosion, BUC status and
+/// whether it is known must all agree. A name only blocks merging when both
+/// stacks are named differently, or for corpses, when just one is named.
+pub fn can_merge(a: &ObjectInstance, b: &ObjectInstance) -> bool {
+    if a.id != b.id || !OBJECTS[a.id as usize].flags.merge {
+        return false;
+    }
+    if a.enchant != b.enchant
+        || a.erosion != b.erosion
+        || a.buc != b.buc
+        || a.buc_known != b.buc_known
+        || a.corpsenm != b.corpsenm
+    {
+        return false;
+    }
+    match (&a.name, &b.name) {
+        (Some(x), Some(y)) => x == y,
+        (None, None) => true,
+        _ => a.id != ObjectId::Corpse,
+    }
+}
+
+/// The object a `monster` leaves behind when it dies: a glob for the four
+/// puddings and oozes, the generic corpse otherwise. A corpse also records
+/// the monster in [`ObjectInstance::corpsenm`]. Returns `None` for other
+/// monsters marked `G_NOCORPSE`, such as vortices, elementals and ghosts;
+/// as in C's `make_corpse()`, the globs are made before that check, so
+/// green slime still leaves one.
+pub fn corpse_object_for(monster: MonsterId) -> Option<ObjectId> {
+    match monster {
+        MonsterId::GrayOoze => Some(ObjectId::GlobOfGrayOoze),
+        MonsterId::BrownPudding => Some(ObjectId::GlobOfBrownPudding),
+        MonsterId::GreenSlime => Some(ObjectId::GlobOfGreenSlime),
+        MonsterId::BlackPudding => Some(ObjectId::GlobOfBlackPudding),
+        _ if MONSTERS[monster as usize]
+            .geno
+            .contains(GenoFlags::NOCORPSE) =>
+        {
+            None
+        }
+        _ => Some(ObjectId::Corpse),
+    }
+}
+
+/// The monster an object refers to: the species of a corpse, tin, egg,
+/// statue or figurine, or the fixed monster of a glob.
+pub fn corpse_monster(obj: &ObjectInstance) -> Option<MonsterId> {
+    use ObjectId as O;
+    match obj.id {
+        O::GlobOfGrayOoze => Some(MonsterId::GrayOoze),
+        O::GlobOfBrownPudding => Some(MonsterId::BrownPudding),
+        O::GlobOfGreenSlime => Some(MonsterId::GreenSlime),
+        O::GlobOfBlackPudding => Some(MonsterId::BlackPudding),
+        O::Corpse | O::Tin | O::Egg | O::Statue | O::Figurine => obj.corpsenm,
+        _ => None,
+    }
+}
+
+/// The BUC status `mksobj()` gives a newly made object of type `id`.
+///
+/// Only the rolls that decide blessing and curses are made, plus a charged
+/// ring's enchantment, which can curse it. The other draws `mksobj()` mixes
+/// in (quantity, enchantment, box contents) are skipped, so the outcomes
+/// follow C's odds but the RNG stream does not stay in step with it. `rne()`
+/// is rolled for an experience level 1 hero.
+pub fn default_buc(rng: &mut NhRng, id: ObjectId) -> Buc {
+    use ObjectId as O;
+    let obj = &OBJECTS[id as usize];
+    match obj.class {
+        ObjectClass::Weapon => {
+            if rng.rn2(11) == 0 {
+                blessed_if(rng.rn2(2) != 0)
+            } else if rng.rn2(10) == 0 {
+                Buc::Cursed
+            } else {
+                bless_or_curse(rng, 10)
+            }
+        }
+        ObjectClass::Armor => {
+            let bad = matches!(
+                id,
+                O::FumbleBoots
+                    | O::LevitationBoots
+                    | O::HelmOfOppositeAlignment
+                    | O::GauntletsOfFumbling
+            );
+            if rng.rn2(10) != 0 && (bad || rng.rn2(11) == 0) {
+                Buc::Cursed
+            } else if rng.rn2(10) == 0 {
+                blessed_if(rng.rn2(2) != 0)
+            } else {
+                bless_or_curse(rng, 10)
+            }
+        }
+        ObjectClass::Ring if obj.flags.charged => {
+            let buc = bless_or_curse(rng, 3);
+            let sign = match buc {
+                Buc::Blessed => 1,
+                Buc::Uncursed => 0,
+                Buc::Cursed => -1,
+            };
+            let mut spe = 0;
+            if rng.rn2(10) != 0 {
+                spe = if rng.rn2(10) != 0 && sign != 0 {
+                    sign * rng.rne(3, 1)
+                } else if rng.rn2(2) != 0 {
+                    rng.rne(3, 1)
+                } else {
+                    -rng.rne(3, 1)
+                };
+            }
+            // Useless +0 rings are made much less common.
+            if spe == 0 {
+                spe = rng.rn2(4) - rng.rn2(3);
+            }
+            // Negative rings are usually cursed.
+            if spe < 0 && rng.rn2(5) != 0 {
+                Buc::Cursed
+            } else {
+                buc
+            }
+        }
+        ObjectClass::Ring => {
+            let bad = matches!(
+                id,
+                O::RingOfTeleportation | O::RingOfPolymorph | O::AggravateMonster | O::Hunger
+            );
+            if rng.rn2(10) != 0 && (bad || rng.rn2(9) == 0) {
+                Buc::Cursed
+            } else {
+                Buc::Uncursed
+            }
+        }
+        ObjectClass::Amulet => {
+            let bad = matches!(
+                id,
+                O::AmuletOfStrangulation | O::AmuletOfChange | O::AmuletOfRestfulSleep
+            );
+            if rng.rn2(10) != 0 && bad {
+                Buc::Cursed
+            } else {
+                bless_or_curse(rng, 10)
+            }
+        }
+        ObjectClass::Potion => bless_or_curse(rng, 4),
+        ObjectClass::Scroll if id != O::Mail => bless_or_curse(rng, 4),
+        ObjectClass::SpellBook | ObjectClass::Wand => bless_or_curse(rng, 17),
+        ObjectClass::Tool => match id {
+            O::TallowCandle | O::WaxCandle | O::BrassLantern | O::OilLamp => bless_or_curse(rng, 5),
+            O::MagicLamp | O::CrystalBall => bless_or_curse(rng, 2),
+            O::CanOfGrease => bless_or_curse(rng, 10),
+            O::Figurine => bless_or_curse(rng, 4),
+            _ => Buc::Uncursed,
+        },
+        ObjectClass::Gem if id == O::Loadstone => Buc::Cursed,
+        _ => Buc::Uncursed,
+    }
+}
+
+/// C's `blessorcurse()` on an uncursed object: one time in `chance`, a coin
+/// flip between cursed and blessed.
+fn bless_or_curse(rng: &mut NhRng, chance: i32) -> Buc {
+    if rng.rn2(chance) != 0 {
+        Buc::Uncursed
+    } else if rng.rn2(2) == 0 {
+        Buc::Cursed
+    } else {
+        Buc::Blessed
+    }
+}
+
+fn blessed_if(blessed: bool) -> Buc {
+    if blessed { Buc::Blessed } else { Buc::Uncursed }
+}
+
+/// Column names of [`to_csv`], in order.
+const CSV_HEADER: [&str; 28] = [
+    "id",
+    "name",
+    "description",
+    "class",
+    "sub_type",
+    "prob",
+    "delay",
+    "weight",
+    "cost",
+    "damage_small",
+    "damage_large",
+    "oc1",
+    "oc2",
+    "material",
+    "color",
+    "nutrition",
+    "prop",
+    "name_known",
+    "merge",
+    "uses_known",
+    "pre_discovered",
+    "magic",
+    "charged",
+    "unique",
+    "no_wish",
+    "big",
+    "tough",
+    "dir",
 ];
+
+/// The object table as CSV: a header row, then one row per entry in
+/// [`ObjectId`] order. Enums are written by variant name and the
+/// [`ObjectTypeFlags`](nethack_types::ObjectTypeFlags) members get a column
+/// each.
+pub fn to_csv() -> String {
+    let mut csv = CsvWriter::default();
+    for name in CSV_HEADER {
+        csv.field(name);
+    }
+    csv.end_row();
+    for (id, obj) in OBJECTS.iter().enumerate() {
+        let f = &obj.flags;
+        csv.field(id)
+            .field(obj.name)
+            .field(obj.description.unwrap_or(""))
+            .field(format_args!("{:?}", obj.class))
+            .field(obj.sub_type)
+            .field(obj.prob)
+            .field(obj.delay)
+            .field(obj.weight)
+            .field(obj.cost)
+            .field(obj.damage_small)
+            .field(obj.damage_large)
+            .field(obj.oc1)
+            .field(obj.oc2)
+            .field(format_args!("{:?}", obj.material))
+            .field(format_args!("{:?}", obj.color))
+            .field(obj.nutrition)
+            .field(obj.prop)
+            .field(f.name_known)
+            .field(f.merge)
+            .field(f.uses_known)
+            .field(f.pre_discovered)
+            .field(f.magic)
+            .field(f.charged)
+            .field(f.unique)
+            .field(f.no_wish)
+            .field(f.big)
+            .field(f.tough)
+            .field(f.dir);
+        csv.end_row();
+    }
+    csv.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_quantities() {
+        let mut rng = NhRng::new(7);
+        let arrows: Vec<_> = (0..200)
+            .map(|_| stack_quantity(&mut rng, ObjectId::Arrow))
+            .collect();
+        assert!(arrows.iter().all(|q| (6..=11).contains(q)));
+        assert!(arrows.contains(&6) && arrows.contains(&11));
+        for _ in 0..200 {
+            assert_eq!(stack_quantity(&mut rng, ObjectId::LongSword), 1);
+            assert_eq!(stack_quantity(&mut rng, ObjectId::Luckstone), 1);
+            assert!((1..=7).contains(&stack_quantity(&mut rng, ObjectId::WaxCandle)));
+        }
+    }
+
+    #[test]
+    fn csv_has_a_row_per_object() {
+        let csv = to_csv();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), ObjectId::NUM_OBJECTS + 1);
+        assert_eq!(lines[0], CSV_HEADER.join(","));
+        assert!(lines[0].starts_with("id,name,description,class,sub_type,prob,"));
+        let sword = lines[1 + ObjectId::LongSword as usize];
+        assert!(
+            sword.contains(",long sword,,Weapon,7,50,0,40,15,8,12,"),
+            "{sword}"
+        );
+    }
+
+    fn effect(id: ObjectId) -> Option<u16> {
+        OBJECTS[id as usize].effect_id()
+    }
+
+    #[test]
+    fn scroll_of_identify_effect() {
+        assert_eq!(
+            effect(ObjectId::ScrollOfIdentify),
+            Some(ObjectId::ScrollOfIdentify as u16)
+        );
+        assert_eq!(
+            OBJECTS[effect(ObjectId::ScrollOfIdentify).unwrap() as usize].name,
+            "identify"
+        );
+    }
+
+    #[test]
+    fn magic_consumables_have_effects() {
+        assert!(effect(ObjectId::Water).is_some());
+        assert!(effect(ObjectId::ForceBolt).is_some());
+        assert!(effect(ObjectId::Digging).is_some());
+    }
+
+    #[test]
+    fn non_magic_objects_have_no_effect() {
+        assert_eq!(effect(ObjectId::LongSword), None);
+        assert_eq!(effect(ObjectId::Corpse), None);
+        assert_eq!(effect(ObjectId::ScrollFOOBIEBLETCH), None);
+    }
+
+    #[test]
+    fn matching_stacks_merge() {
+        let arrows = ObjectInstance {
+            enchant: 2,
+            buc: Buc::Blessed,
+            buc_known: true,
+            ..ObjectInstance::new(ObjectId::Arrow)
+        };
+        assert!(can_merge(&arrows, &arrows.clone()));
+        let named = ObjectInstance {
+            name: Some("Robin".into()),
+            ..arrows.clone()
+        };
+        assert!(can_merge(&arrows, &named));
+    }
+
+    #[test]
+    fn differing_stacks_do_not_merge() {
+        let arrows = ObjectInstance::new(ObjectId::Arrow);
+        let differ = |change: fn(&mut ObjectInstance)| {
+            let mut other = arrows.clone();
+            change(&mut other);
+            can_merge(&arrows, &other)
+        };
+        assert!(!differ(|o| o.id = ObjectId::ElvenArrow));
+        assert!(!differ(|o| o.enchant = 1));
+        assert!(!differ(|o| o.erosion = (1, 0)));
+        assert!(!differ(|o| o.buc = Buc::Cursed));
+        assert!(!differ(|o| o.buc_known = true));
+
+        // Long swords never stack, and corpses need matching names.
+        let sword = ObjectInstance::new(ObjectId::LongSword);
+        assert!(!can_merge(&sword, &sword));
+        let corpse = ObjectInstance::new(ObjectId::Corpse);
+        let named = ObjectInstance {
+            name: Some("Fido".into()),
+            ..corpse.clone()
+        };
+        assert!(can_merge(&corpse, &corpse));
+        assert!(!can_merge(&corpse, &named));
+        let newt = ObjectInstance {
+            corpsenm: Some(MonsterId::Newt),
+            ..corpse.clone()
+        };
+        assert!(!can_merge(&corpse, &newt));
+    }
+
+    #[test]
+    fn corpses_map_back_to_monsters() {
+        let id = corpse_object_for(MonsterId::Cockatrice).unwrap();
+        assert_eq!(id, ObjectId::Corpse);
+        let corpse = ObjectInstance {
+            corpsenm: Some(MonsterId::Cockatrice),
+            ..ObjectInstance::new(id)
+        };
+        assert_eq!(corpse_monster(&corpse), Some(MonsterId::Cockatrice));
+
+        let glob = ObjectInstance::new(corpse_object_for(MonsterId::GreenSlime).unwrap());
+        assert_eq!(glob.id, ObjectId::GlobOfGreenSlime);
+        assert_eq!(corpse_monster(&glob), Some(MonsterId::GreenSlime));
+        assert_eq!(corpse_monster(&ObjectInstance::new(ObjectId::Arrow)), None);
+    }
+
+    #[test]
+    fn nocorpse_monsters_leave_nothing() {
+        for id in [
+            MonsterId::FogCloud,
+            MonsterId::EnergyVortex,
+            MonsterId::AirElemental,
+        ] {
+            assert_eq!(corpse_object_for(id), None, "{id:?}");
+        }
+    }
+
+    #[test]
+    fn table_ids_match_their_index() {
+        for (i, obj) in OBJECTS.iter().enumerate() {
+            assert_eq!(obj.id as usize, i, "{}", obj.summary());
+        }
+    }
+
+    #[test]
+    fn bundled_probabilities_validate() {
+        assert_eq!(validate_probabilities(), Ok(()));
+    }
+
+    /// Roll `n` objects of type `id` and count (blessed, uncursed, cursed).
+    fn buc_counts(id: ObjectId, n: usize) -> (usize, usize, usize) {
+        let mut rng = NhRng::new(42);
+        let mut counts = (0, 0, 0);
+        for _ in 0..n {
+            match default_buc(&mut rng, id) {
+                Buc::Blessed => counts.0 += 1,
+                Buc::Uncursed => counts.1 += 1,
+                Buc::Cursed => counts.2 += 1,
+            }
+        }
+        counts
+    }
+
+    #[test]
+    fn default_buc_is_mostly_uncursed() {
+        // blessorcurse(otmp, 4): 1/8 blessed, 3/4 uncursed, 1/8 cursed.
+        let (blessed, uncursed, cursed) = buc_counts(ObjectId::PotionOfPolymorph, 8000);
+        assert!((900..=1100).contains(&blessed), "{blessed}");
+        assert!((5800..=6200).contains(&uncursed), "{uncursed}");
+        assert!((900..=1100).contains(&cursed), "{cursed}");
+
+        let (_, uncursed, _) = buc_counts(ObjectId::LongSword, 8000);
+        assert!(uncursed > 6000, "{uncursed}");
+        assert_eq!(buc_counts(ObjectId::Mail, 100), (0, 100, 0));
+    }
+
+    #[test]
+    fn default_buc_curses_bad_items() {
+        assert_eq!(buc_counts(ObjectId::Loadstone, 100), (0, 0, 100));
+        let (_, _, cursed) = buc_counts(ObjectId::AmuletOfStrangulation, 1000);
+        assert!(cursed >= 850, "{cursed}");
+        let (_, _, cursed) = buc_counts(ObjectId::RingOfTeleportation, 1000);
+        assert!(cursed >= 850, "{cursed}");
+    }
+}
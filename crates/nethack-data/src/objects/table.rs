@@ -0,0 +1,14536 @@
+// @generated by tools/extract_data.py — do not edit manually
+
+#![allow(clippy::unreadable_literal)]
+
+use nethack_types::*;
+
+pub static OBJECTS: [ObjectType; 454] = [
+    ObjectType {
+        id: ObjectId::StrangeObject,
+        name: "strange object",
+        description: None,
+        class: ObjectClass::IllObj,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 0,
+        cost: 0,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Liquid,
+        color: Color::Black,
+        nutrition: 0,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Arrow,
+        name: "arrow",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: -21,
+        prob: 55,
+        delay: 0,
+        weight: 1,
+        cost: 2,
+        damage_small: 6,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 1,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ElvenArrow,
+        name: "elven arrow",
+        description: Some("runed arrow"),
+        class: ObjectClass::Weapon,
+        sub_type: -21,
+        prob: 20,
+        delay: 0,
+        weight: 1,
+        cost: 2,
+        damage_small: 7,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 1,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OrcishArrow,
+        name: "orcish arrow",
+        description: Some("crude arrow"),
+        class: ObjectClass::Weapon,
+        sub_type: -21,
+        prob: 20,
+        delay: 0,
+        weight: 1,
+        cost: 2,
+        damage_small: 5,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Black,
+        nutrition: 1,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SilverArrow,
+        name: "silver arrow",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: -21,
+        prob: 12,
+        delay: 0,
+        weight: 1,
+        cost: 5,
+        damage_small: 6,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Silver,
+        color: Color::Gray,
+        nutrition: 1,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Ya,
+        name: "ya",
+        description: Some("bamboo arrow"),
+        class: ObjectClass::Weapon,
+        sub_type: -21,
+        prob: 15,
+        delay: 0,
+        weight: 1,
+        cost: 4,
+        damage_small: 7,
+        damage_large: 7,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Metal,
+        color: Color::Cyan,
+        nutrition: 1,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CrossbowBolt,
+        name: "crossbow bolt",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: -23,
+        prob: 55,
+        delay: 0,
+        weight: 1,
+        cost: 2,
+        damage_small: 4,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 1,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Dart,
+        name: "dart",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: -24,
+        prob: 60,
+        delay: 0,
+        weight: 1,
+        cost: 2,
+        damage_small: 3,
+        damage_large: 2,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 1,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Shuriken,
+        name: "shuriken",
+        description: Some("throwing star"),
+        class: ObjectClass::Weapon,
+        sub_type: -25,
+        prob: 35,
+        delay: 0,
+        weight: 1,
+        cost: 5,
+        damage_small: 8,
+        damage_large: 6,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 1,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Boomerang,
+        name: "boomerang",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: -26,
+        prob: 15,
+        delay: 0,
+        weight: 5,
+        cost: 20,
+        damage_small: 9,
+        damage_large: 9,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 5,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Spear,
+        name: "spear",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 18,
+        prob: 50,
+        delay: 0,
+        weight: 30,
+        cost: 3,
+        damage_small: 6,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ElvenSpear,
+        name: "elven spear",
+        description: Some("runed spear"),
+        class: ObjectClass::Weapon,
+        sub_type: 18,
+        prob: 10,
+        delay: 0,
+        weight: 30,
+        cost: 3,
+        damage_small: 7,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OrcishSpear,
+        name: "orcish spear",
+        description: Some("crude spear"),
+        class: ObjectClass::Weapon,
+        sub_type: 18,
+        prob: 13,
+        delay: 0,
+        weight: 30,
+        cost: 3,
+        damage_small: 5,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Black,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DwarvishSpear,
+        name: "dwarvish spear",
+        description: Some("stout spear"),
+        class: ObjectClass::Weapon,
+        sub_type: 18,
+        prob: 12,
+        delay: 0,
+        weight: 35,
+        cost: 3,
+        damage_small: 8,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 35,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SilverSpear,
+        name: "silver spear",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 18,
+        prob: 2,
+        delay: 0,
+        weight: 36,
+        cost: 40,
+        damage_small: 6,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Silver,
+        color: Color::Gray,
+        nutrition: 36,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Javelin,
+        name: "javelin",
+        description: Some("throwing spear"),
+        class: ObjectClass::Weapon,
+        sub_type: 18,
+        prob: 10,
+        delay: 0,
+        weight: 20,
+        cost: 3,
+        damage_small: 6,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Trident,
+        name: "trident",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 19,
+        prob: 8,
+        delay: 0,
+        weight: 25,
+        cost: 5,
+        damage_small: 6,
+        damage_large: 4,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 25,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Dagger,
+        name: "dagger",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 1,
+        prob: 30,
+        delay: 0,
+        weight: 10,
+        cost: 4,
+        damage_small: 4,
+        damage_large: 3,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ElvenDagger,
+        name: "elven dagger",
+        description: Some("runed dagger"),
+        class: ObjectClass::Weapon,
+        sub_type: 1,
+        prob: 10,
+        delay: 0,
+        weight: 10,
+        cost: 4,
+        damage_small: 5,
+        damage_large: 3,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OrcishDagger,
+        name: "orcish dagger",
+        description: Some("crude dagger"),
+        class: ObjectClass::Weapon,
+        sub_type: 1,
+        prob: 12,
+        delay: 0,
+        weight: 10,
+        cost: 4,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Black,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SilverDagger,
+        name: "silver dagger",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 1,
+        prob: 3,
+        delay: 0,
+        weight: 12,
+        cost: 40,
+        damage_small: 4,
+        damage_large: 3,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Silver,
+        color: Color::Gray,
+        nutrition: 12,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Athame,
+        name: "athame",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 1,
+        prob: 0,
+        delay: 0,
+        weight: 10,
+        cost: 4,
+        damage_small: 4,
+        damage_large: 3,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Scalpel,
+        name: "scalpel",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 2,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 6,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Metal,
+        color: Color::Cyan,
+        nutrition: 5,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Knife,
+        name: "knife",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 2,
+        prob: 20,
+        delay: 0,
+        weight: 5,
+        cost: 4,
+        damage_small: 3,
+        damage_large: 2,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 5,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Stiletto,
+        name: "stiletto",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 2,
+        prob: 5,
+        delay: 0,
+        weight: 5,
+        cost: 4,
+        damage_small: 3,
+        damage_large: 2,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 5,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WormTooth,
+        name: "worm tooth",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 2,
+        prob: 0,
+        delay: 0,
+        weight: 20,
+        cost: 2,
+        damage_small: 2,
+        damage_large: 2,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Liquid,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Crysknife,
+        name: "crysknife",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 2,
+        prob: 0,
+        delay: 0,
+        weight: 20,
+        cost: 100,
+        damage_small: 10,
+        damage_large: 10,
+        oc1: 3,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Axe,
+        name: "axe",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 3,
+        prob: 40,
+        delay: 0,
+        weight: 60,
+        cost: 8,
+        damage_small: 6,
+        damage_large: 4,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 60,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BattleAxe,
+        name: "battle-axe",
+        description: Some("double-headed axe"),
+        class: ObjectClass::Weapon,
+        sub_type: 3,
+        prob: 10,
+        delay: 0,
+        weight: 120,
+        cost: 40,
+        damage_small: 8,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 120,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ShortSword,
+        name: "short sword",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 5,
+        prob: 8,
+        delay: 0,
+        weight: 30,
+        cost: 10,
+        damage_small: 6,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ElvenShortSword,
+        name: "elven short sword",
+        description: Some("runed short sword"),
+        class: ObjectClass::Weapon,
+        sub_type: 5,
+        prob: 2,
+        delay: 0,
+        weight: 30,
+        cost: 10,
+        damage_small: 8,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OrcishShortSword,
+        name: "orcish short sword",
+        description: Some("crude short sword"),
+        class: ObjectClass::Weapon,
+        sub_type: 5,
+        prob: 3,
+        delay: 0,
+        weight: 30,
+        cost: 10,
+        damage_small: 5,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Black,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DwarvishShortSword,
+        name: "dwarvish short sword",
+        description: Some("broad short sword"),
+        class: ObjectClass::Weapon,
+        sub_type: 5,
+        prob: 2,
+        delay: 0,
+        weight: 30,
+        cost: 10,
+        damage_small: 7,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Scimitar,
+        name: "scimitar",
+        description: Some("curved sword"),
+        class: ObjectClass::Weapon,
+        sub_type: 9,
+        prob: 15,
+        delay: 0,
+        weight: 40,
+        cost: 15,
+        damage_small: 8,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 40,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SilverSaber,
+        name: "silver saber",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 10,
+        prob: 6,
+        delay: 0,
+        weight: 40,
+        cost: 75,
+        damage_small: 8,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Silver,
+        color: Color::Gray,
+        nutrition: 40,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Broadsword,
+        name: "broadsword",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 6,
+        prob: 8,
+        delay: 0,
+        weight: 70,
+        cost: 10,
+        damage_small: 4,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 70,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ElvenBroadsword,
+        name: "elven broadsword",
+        description: Some("runed broadsword"),
+        class: ObjectClass::Weapon,
+        sub_type: 6,
+        prob: 4,
+        delay: 0,
+        weight: 70,
+        cost: 10,
+        damage_small: 6,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 70,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LongSword,
+        name: "long sword",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 7,
+        prob: 50,
+        delay: 0,
+        weight: 40,
+        cost: 15,
+        damage_small: 8,
+        damage_large: 12,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 40,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::TwoHandedSword,
+        name: "two-handed sword",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 8,
+        prob: 22,
+        delay: 0,
+        weight: 150,
+        cost: 50,
+        damage_small: 12,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 150,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Katana,
+        name: "katana",
+        description: Some("samurai sword"),
+        class: ObjectClass::Weapon,
+        sub_type: 7,
+        prob: 4,
+        delay: 0,
+        weight: 40,
+        cost: 80,
+        damage_small: 10,
+        damage_large: 12,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 40,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Tsurugi,
+        name: "tsurugi",
+        description: Some("long samurai sword"),
+        class: ObjectClass::Weapon,
+        sub_type: 8,
+        prob: 0,
+        delay: 0,
+        weight: 60,
+        cost: 500,
+        damage_small: 16,
+        damage_large: 8,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Metal,
+        color: Color::Cyan,
+        nutrition: 60,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Runesword,
+        name: "runesword",
+        description: Some("runed broadsword"),
+        class: ObjectClass::Weapon,
+        sub_type: 6,
+        prob: 0,
+        delay: 0,
+        weight: 40,
+        cost: 300,
+        damage_small: 4,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Black,
+        nutrition: 40,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Partisan,
+        name: "partisan",
+        description: Some("vulgar polearm"),
+        class: ObjectClass::Weapon,
+        sub_type: 17,
+        prob: 5,
+        delay: 0,
+        weight: 80,
+        cost: 10,
+        damage_small: 6,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 80,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Ranseur,
+        name: "ranseur",
+        description: Some("hilted polearm"),
+        class: ObjectClass::Weapon,
+        sub_type: 17,
+        prob: 5,
+        delay: 0,
+        weight: 50,
+        cost: 6,
+        damage_small: 4,
+        damage_large: 4,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Spetum,
+        name: "spetum",
+        description: Some("forked polearm"),
+        class: ObjectClass::Weapon,
+        sub_type: 17,
+        prob: 5,
+        delay: 0,
+        weight: 50,
+        cost: 5,
+        damage_small: 6,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Glaive,
+        name: "glaive",
+        description: Some("single-edged polearm"),
+        class: ObjectClass::Weapon,
+        sub_type: 17,
+        prob: 8,
+        delay: 0,
+        weight: 75,
+        cost: 6,
+        damage_small: 6,
+        damage_large: 10,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 75,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Lance,
+        name: "lance",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 20,
+        prob: 4,
+        delay: 0,
+        weight: 180,
+        cost: 10,
+        damage_small: 6,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 180,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Halberd,
+        name: "halberd",
+        description: Some("angled poleaxe"),
+        class: ObjectClass::Weapon,
+        sub_type: 17,
+        prob: 8,
+        delay: 0,
+        weight: 150,
+        cost: 10,
+        damage_small: 10,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 150,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Bardiche,
+        name: "bardiche",
+        description: Some("long poleaxe"),
+        class: ObjectClass::Weapon,
+        sub_type: 17,
+        prob: 4,
+        delay: 0,
+        weight: 120,
+        cost: 7,
+        damage_small: 4,
+        damage_large: 4,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 120,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Voulge,
+        name: "voulge",
+        description: Some("pole cleaver"),
+        class: ObjectClass::Weapon,
+        sub_type: 17,
+        prob: 4,
+        delay: 0,
+        weight: 125,
+        cost: 5,
+        damage_small: 4,
+        damage_large: 4,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 125,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DwarvishMattock,
+        name: "dwarvish mattock",
+        description: Some("broad pick"),
+        class: ObjectClass::Weapon,
+        sub_type: 4,
+        prob: 13,
+        delay: 0,
+        weight: 120,
+        cost: 50,
+        damage_small: 12,
+        damage_large: 8,
+        oc1: -1,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 120,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Fauchard,
+        name: "fauchard",
+        description: Some("pole sickle"),
+        class: ObjectClass::Weapon,
+        sub_type: 17,
+        prob: 6,
+        delay: 0,
+        weight: 60,
+        cost: 5,
+        damage_small: 6,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 60,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Guisarme,
+        name: "guisarme",
+        description: Some("pruning hook"),
+        class: ObjectClass::Weapon,
+        sub_type: 17,
+        prob: 6,
+        delay: 0,
+        weight: 80,
+        cost: 5,
+        damage_small: 4,
+        damage_large: 8,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 80,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BillGuisarme,
+        name: "bill-guisarme",
+        description: Some("hooked polearm"),
+        class: ObjectClass::Weapon,
+        sub_type: 17,
+        prob: 4,
+        delay: 0,
+        weight: 120,
+        cost: 7,
+        damage_small: 4,
+        damage_large: 10,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 120,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LucernHammer,
+        name: "lucern hammer",
+        description: Some("pronged polearm"),
+        class: ObjectClass::Weapon,
+        sub_type: 17,
+        prob: 5,
+        delay: 0,
+        weight: 150,
+        cost: 7,
+        damage_small: 4,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 150,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BecDeCorbin,
+        name: "bec de corbin",
+        description: Some("beaked polearm"),
+        class: ObjectClass::Weapon,
+        sub_type: 17,
+        prob: 4,
+        delay: 0,
+        weight: 100,
+        cost: 8,
+        damage_small: 8,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 100,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Mace,
+        name: "mace",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 12,
+        prob: 40,
+        delay: 0,
+        weight: 30,
+        cost: 5,
+        damage_small: 6,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::MorningStar,
+        name: "morning star",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 13,
+        prob: 12,
+        delay: 0,
+        weight: 120,
+        cost: 10,
+        damage_small: 4,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 120,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WarHammer,
+        name: "war hammer",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 15,
+        prob: 15,
+        delay: 0,
+        weight: 50,
+        cost: 5,
+        damage_small: 4,
+        damage_large: 4,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Club,
+        name: "club",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 11,
+        prob: 12,
+        delay: 0,
+        weight: 30,
+        cost: 3,
+        damage_small: 6,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::RubberHose,
+        name: "rubber hose",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 27,
+        prob: 0,
+        delay: 0,
+        weight: 20,
+        cost: 3,
+        damage_small: 4,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Plastic,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Quarterstaff,
+        name: "quarterstaff",
+        description: Some("staff"),
+        class: ObjectClass::Weapon,
+        sub_type: 16,
+        prob: 11,
+        delay: 0,
+        weight: 40,
+        cost: 5,
+        damage_small: 6,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 40,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Aklys,
+        name: "aklys",
+        description: Some("thonged club"),
+        class: ObjectClass::Weapon,
+        sub_type: 11,
+        prob: 8,
+        delay: 0,
+        weight: 15,
+        cost: 4,
+        damage_small: 6,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Flail,
+        name: "flail",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 14,
+        prob: 40,
+        delay: 0,
+        weight: 15,
+        cost: 4,
+        damage_small: 6,
+        damage_large: 4,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Bullwhip,
+        name: "bullwhip",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 27,
+        prob: 2,
+        delay: 0,
+        weight: 20,
+        cost: 4,
+        damage_small: 2,
+        damage_large: 1,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Bow,
+        name: "bow",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 21,
+        prob: 24,
+        delay: 0,
+        weight: 30,
+        cost: 60,
+        damage_small: 2,
+        damage_large: 2,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ElvenBow,
+        name: "elven bow",
+        description: Some("runed bow"),
+        class: ObjectClass::Weapon,
+        sub_type: 21,
+        prob: 12,
+        delay: 0,
+        weight: 30,
+        cost: 60,
+        damage_small: 2,
+        damage_large: 2,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OrcishBow,
+        name: "orcish bow",
+        description: Some("crude bow"),
+        class: ObjectClass::Weapon,
+        sub_type: 21,
+        prob: 12,
+        delay: 0,
+        weight: 30,
+        cost: 60,
+        damage_small: 2,
+        damage_large: 2,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Black,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Yumi,
+        name: "yumi",
+        description: Some("long bow"),
+        class: ObjectClass::Weapon,
+        sub_type: 21,
+        prob: 0,
+        delay: 0,
+        weight: 30,
+        cost: 60,
+        damage_small: 2,
+        damage_large: 2,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Sling,
+        name: "sling",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 22,
+        prob: 40,
+        delay: 0,
+        weight: 3,
+        cost: 20,
+        damage_small: 2,
+        damage_large: 2,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 3,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Crossbow,
+        name: "crossbow",
+        description: None,
+        class: ObjectClass::Weapon,
+        sub_type: 23,
+        prob: 45,
+        delay: 0,
+        weight: 50,
+        cost: 40,
+        damage_small: 2,
+        damage_large: 2,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ElvenLeatherHelm,
+        name: "elven leather helm",
+        description: Some("leather hat"),
+        class: ObjectClass::Armor,
+        sub_type: 2,
+        prob: 6,
+        delay: 1,
+        weight: 3,
+        cost: 8,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 3,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OrcishHelm,
+        name: "orcish helm",
+        description: Some("iron skull cap"),
+        class: ObjectClass::Armor,
+        sub_type: 2,
+        prob: 6,
+        delay: 1,
+        weight: 30,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Black,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DwarvishIronHelm,
+        name: "dwarvish iron helm",
+        description: Some("hard hat"),
+        class: ObjectClass::Armor,
+        sub_type: 2,
+        prob: 6,
+        delay: 1,
+        weight: 40,
+        cost: 20,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 40,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Fedora,
+        name: "fedora",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 2,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 1,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Cloth,
+        color: Color::Brown,
+        nutrition: 3,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Cornuthaum,
+        name: "cornuthaum",
+        description: Some("conical hat"),
+        class: ObjectClass::Armor,
+        sub_type: 2,
+        prob: 3,
+        delay: 1,
+        weight: 4,
+        cost: 80,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Cloth,
+        color: Color::Blue,
+        nutrition: 4,
+        prop: 35,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DunceCap,
+        name: "dunce cap",
+        description: Some("conical hat"),
+        class: ObjectClass::Armor,
+        sub_type: 2,
+        prob: 3,
+        delay: 1,
+        weight: 4,
+        cost: 1,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Cloth,
+        color: Color::Blue,
+        nutrition: 4,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DentedPot,
+        name: "dented pot",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 2,
+        prob: 2,
+        delay: 0,
+        weight: 10,
+        cost: 8,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Black,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Helmet,
+        name: "helmet",
+        description: Some("plumed helmet"),
+        class: ObjectClass::Armor,
+        sub_type: 2,
+        prob: 10,
+        delay: 1,
+        weight: 30,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::HelmOfBrilliance,
+        name: "helm of brilliance",
+        description: Some("etched helmet"),
+        class: ObjectClass::Armor,
+        sub_type: 2,
+        prob: 6,
+        delay: 1,
+        weight: 50,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Green,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::HelmOfOppositeAlignment,
+        name: "helm of opposite alignment",
+        description: Some("crested helmet"),
+        class: ObjectClass::Armor,
+        sub_type: 2,
+        prob: 6,
+        delay: 1,
+        weight: 50,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::HelmOfTelepathy,
+        name: "helm of telepathy",
+        description: Some("visored helmet"),
+        class: ObjectClass::Armor,
+        sub_type: 2,
+        prob: 2,
+        delay: 1,
+        weight: 50,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 50,
+        prop: 30,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GrayDragonScaleMail,
+        name: "gray dragon scale mail",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 1200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 9,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Gray,
+        nutrition: 40,
+        prop: 12,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SilverDragonScaleMail,
+        name: "silver dragon scale mail",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 1200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 9,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::BrightCyan,
+        nutrition: 40,
+        prop: 64,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::RedDragonScaleMail,
+        name: "red dragon scale mail",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 900,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 9,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Red,
+        nutrition: 40,
+        prop: 1,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WhiteDragonScaleMail,
+        name: "white dragon scale mail",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 900,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 9,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::White,
+        nutrition: 40,
+        prop: 2,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OrangeDragonScaleMail,
+        name: "orange dragon scale mail",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 900,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 9,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Orange,
+        nutrition: 40,
+        prop: 3,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BlackDragonScaleMail,
+        name: "black dragon scale mail",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 1200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 9,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Black,
+        nutrition: 40,
+        prop: 4,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BlueDragonScaleMail,
+        name: "blue dragon scale mail",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 900,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 9,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Blue,
+        nutrition: 40,
+        prop: 5,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GreenDragonScaleMail,
+        name: "green dragon scale mail",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 900,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 9,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Green,
+        nutrition: 40,
+        prop: 6,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::YellowDragonScaleMail,
+        name: "yellow dragon scale mail",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 900,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 9,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Yellow,
+        nutrition: 40,
+        prop: 7,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GrayDragonScales,
+        name: "gray dragon scales",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 700,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 3,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Gray,
+        nutrition: 40,
+        prop: 12,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SilverDragonScales,
+        name: "silver dragon scales",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 700,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 3,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::BrightCyan,
+        nutrition: 40,
+        prop: 64,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::RedDragonScales,
+        name: "red dragon scales",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 500,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 3,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Red,
+        nutrition: 40,
+        prop: 1,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WhiteDragonScales,
+        name: "white dragon scales",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 500,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 3,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::White,
+        nutrition: 40,
+        prop: 2,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OrangeDragonScales,
+        name: "orange dragon scales",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 500,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 3,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Orange,
+        nutrition: 40,
+        prop: 3,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BlackDragonScales,
+        name: "black dragon scales",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 700,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 3,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Black,
+        nutrition: 40,
+        prop: 4,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BlueDragonScales,
+        name: "blue dragon scales",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 500,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 3,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Blue,
+        nutrition: 40,
+        prop: 5,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GreenDragonScales,
+        name: "green dragon scales",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 500,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 3,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Green,
+        nutrition: 40,
+        prop: 6,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::YellowDragonScales,
+        name: "yellow dragon scales",
+        description: Some("None"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 0,
+        delay: 5,
+        weight: 40,
+        cost: 500,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 3,
+        oc2: 0,
+        material: Material::DragonHide,
+        color: Color::Yellow,
+        nutrition: 40,
+        prop: 7,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::PlateMail,
+        name: "plate mail",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 44,
+        delay: 5,
+        weight: 450,
+        cost: 600,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 7,
+        oc2: 2,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 450,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CrystalPlateMail,
+        name: "crystal plate mail",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 10,
+        delay: 5,
+        weight: 450,
+        cost: 820,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 7,
+        oc2: 2,
+        material: Material::Glass,
+        color: Color::White,
+        nutrition: 450,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BronzePlateMail,
+        name: "bronze plate mail",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 25,
+        delay: 5,
+        weight: 450,
+        cost: 400,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 6,
+        oc2: 1,
+        material: Material::Copper,
+        color: Color::Yellow,
+        nutrition: 450,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SplintMail,
+        name: "splint mail",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 62,
+        delay: 5,
+        weight: 400,
+        cost: 80,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 6,
+        oc2: 1,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 400,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BandedMail,
+        name: "banded mail",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 72,
+        delay: 5,
+        weight: 350,
+        cost: 90,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 6,
+        oc2: 1,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 350,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DwarvishMithrilCoat,
+        name: "dwarvish mithril-coat",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 10,
+        delay: 1,
+        weight: 150,
+        cost: 240,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 6,
+        oc2: 2,
+        material: Material::Mithril,
+        color: Color::Gray,
+        nutrition: 150,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ElvenMithrilCoat,
+        name: "elven mithril-coat",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 15,
+        delay: 1,
+        weight: 150,
+        cost: 240,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 5,
+        oc2: 2,
+        material: Material::Mithril,
+        color: Color::Gray,
+        nutrition: 150,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ChainMail,
+        name: "chain mail",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 72,
+        delay: 5,
+        weight: 300,
+        cost: 75,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 5,
+        oc2: 1,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 300,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OrcishChainMail,
+        name: "orcish chain mail",
+        description: Some("crude chain mail"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 20,
+        delay: 5,
+        weight: 300,
+        cost: 75,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 4,
+        oc2: 1,
+        material: Material::Iron,
+        color: Color::Black,
+        nutrition: 300,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScaleMail,
+        name: "scale mail",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 72,
+        delay: 5,
+        weight: 250,
+        cost: 45,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 4,
+        oc2: 1,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 250,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::StuddedLeatherArmor,
+        name: "studded leather armor",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 72,
+        delay: 3,
+        weight: 200,
+        cost: 15,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 3,
+        oc2: 1,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 200,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::RingMail,
+        name: "ring mail",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 72,
+        delay: 5,
+        weight: 250,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 3,
+        oc2: 1,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 250,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OrcishRingMail,
+        name: "orcish ring mail",
+        description: Some("crude ring mail"),
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 20,
+        delay: 5,
+        weight: 250,
+        cost: 80,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 2,
+        oc2: 1,
+        material: Material::Iron,
+        color: Color::Black,
+        nutrition: 250,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LeatherArmor,
+        name: "leather armor",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 82,
+        delay: 3,
+        weight: 150,
+        cost: 5,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 2,
+        oc2: 1,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 150,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LeatherJacket,
+        name: "leather jacket",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 0,
+        prob: 12,
+        delay: 0,
+        weight: 30,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Black,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::HawaiianShirt,
+        name: "Hawaiian shirt",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 6,
+        prob: 8,
+        delay: 0,
+        weight: 5,
+        cost: 3,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Cloth,
+        color: Color::Magenta,
+        nutrition: 5,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::TShirt,
+        name: "T-shirt",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 6,
+        prob: 2,
+        delay: 0,
+        weight: 5,
+        cost: 2,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Cloth,
+        color: Color::White,
+        nutrition: 5,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::MummyWrapping,
+        name: "mummy wrapping",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 5,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 2,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Cloth,
+        color: Color::Gray,
+        nutrition: 3,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ElvenCloak,
+        name: "elven cloak",
+        description: Some("faded pall"),
+        class: ObjectClass::Armor,
+        sub_type: 5,
+        prob: 8,
+        delay: 0,
+        weight: 10,
+        cost: 60,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 1,
+        material: Material::Cloth,
+        color: Color::Black,
+        nutrition: 10,
+        prop: 41,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OrcishCloak,
+        name: "orcish cloak",
+        description: Some("coarse mantelet"),
+        class: ObjectClass::Armor,
+        sub_type: 5,
+        prob: 8,
+        delay: 0,
+        weight: 10,
+        cost: 40,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Cloth,
+        color: Color::Black,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DwarvishCloak,
+        name: "dwarvish cloak",
+        description: Some("hooded cloak"),
+        class: ObjectClass::Armor,
+        sub_type: 5,
+        prob: 8,
+        delay: 0,
+        weight: 10,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Cloth,
+        color: Color::Brown,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OilskinCloak,
+        name: "oilskin cloak",
+        description: Some("slippery cloak"),
+        class: ObjectClass::Armor,
+        sub_type: 5,
+        prob: 8,
+        delay: 0,
+        weight: 10,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 2,
+        material: Material::Cloth,
+        color: Color::Brown,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Robe,
+        name: "robe",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 5,
+        prob: 3,
+        delay: 0,
+        weight: 15,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 2,
+        oc2: 2,
+        material: Material::Cloth,
+        color: Color::Red,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AlchemySmock,
+        name: "alchemy smock",
+        description: Some("apron"),
+        class: ObjectClass::Armor,
+        sub_type: 5,
+        prob: 9,
+        delay: 0,
+        weight: 10,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 1,
+        material: Material::Cloth,
+        color: Color::White,
+        nutrition: 10,
+        prop: 6,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LeatherCloak,
+        name: "leather cloak",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 5,
+        prob: 8,
+        delay: 0,
+        weight: 15,
+        cost: 40,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 1,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CloakOfProtection,
+        name: "cloak of protection",
+        description: Some("tattered cape"),
+        class: ObjectClass::Armor,
+        sub_type: 5,
+        prob: 9,
+        delay: 0,
+        weight: 10,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 3,
+        oc2: 3,
+        material: Material::Cloth,
+        color: Color::Brown,
+        nutrition: 10,
+        prop: 58,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CloakOfInvisibility,
+        name: "cloak of invisibility",
+        description: Some("opera cloak"),
+        class: ObjectClass::Armor,
+        sub_type: 5,
+        prob: 10,
+        delay: 0,
+        weight: 10,
+        cost: 60,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 1,
+        material: Material::Cloth,
+        color: Color::BrightMagenta,
+        nutrition: 10,
+        prop: 39,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CloakOfMagicResistance,
+        name: "cloak of magic resistance",
+        description: Some("ornamental cope"),
+        class: ObjectClass::Armor,
+        sub_type: 5,
+        prob: 2,
+        delay: 0,
+        weight: 10,
+        cost: 60,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 1,
+        material: Material::Cloth,
+        color: Color::White,
+        nutrition: 10,
+        prop: 12,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CloakOfDisplacement,
+        name: "cloak of displacement",
+        description: Some("piece of cloth"),
+        class: ObjectClass::Armor,
+        sub_type: 5,
+        prob: 10,
+        delay: 0,
+        weight: 10,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 1,
+        material: Material::Cloth,
+        color: Color::Brown,
+        nutrition: 10,
+        prop: 40,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SmallShield,
+        name: "small shield",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 1,
+        prob: 6,
+        delay: 0,
+        weight: 30,
+        cost: 3,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ElvenShield,
+        name: "elven shield",
+        description: Some("blue and green shield"),
+        class: ObjectClass::Armor,
+        sub_type: 1,
+        prob: 2,
+        delay: 0,
+        weight: 40,
+        cost: 7,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Green,
+        nutrition: 40,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::UrukHaiShield,
+        name: "Uruk-hai shield",
+        description: Some("white-handed shield"),
+        class: ObjectClass::Armor,
+        sub_type: 1,
+        prob: 2,
+        delay: 0,
+        weight: 50,
+        cost: 7,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OrcishShield,
+        name: "orcish shield",
+        description: Some("red-eyed shield"),
+        class: ObjectClass::Armor,
+        sub_type: 1,
+        prob: 2,
+        delay: 0,
+        weight: 50,
+        cost: 7,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Red,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LargeShield,
+        name: "large shield",
+        description: None,
+        class: ObjectClass::Armor,
+        sub_type: 1,
+        prob: 7,
+        delay: 0,
+        weight: 100,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 100,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DwarvishRoundshield,
+        name: "dwarvish roundshield",
+        description: Some("large round shield"),
+        class: ObjectClass::Armor,
+        sub_type: 1,
+        prob: 4,
+        delay: 0,
+        weight: 100,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 100,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ShieldOfReflection,
+        name: "shield of reflection",
+        description: Some("polished silver shield"),
+        class: ObjectClass::Armor,
+        sub_type: 1,
+        prob: 3,
+        delay: 0,
+        weight: 50,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Silver,
+        color: Color::Gray,
+        nutrition: 50,
+        prop: 64,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LeatherGloves,
+        name: "leather gloves",
+        description: Some("old gloves"),
+        class: ObjectClass::Armor,
+        sub_type: 3,
+        prob: 16,
+        delay: 1,
+        weight: 10,
+        cost: 8,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GauntletsOfFumbling,
+        name: "gauntlets of fumbling",
+        description: Some("padded gloves"),
+        class: ObjectClass::Armor,
+        sub_type: 3,
+        prob: 8,
+        delay: 1,
+        weight: 10,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 10,
+        prop: 25,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GauntletsOfPower,
+        name: "gauntlets of power",
+        description: Some("riding gloves"),
+        class: ObjectClass::Armor,
+        sub_type: 3,
+        prob: 8,
+        delay: 1,
+        weight: 30,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GauntletsOfDexterity,
+        name: "gauntlets of dexterity",
+        description: Some("fencing gloves"),
+        class: ObjectClass::Armor,
+        sub_type: 3,
+        prob: 8,
+        delay: 1,
+        weight: 10,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LowBoots,
+        name: "low boots",
+        description: Some("walking shoes"),
+        class: ObjectClass::Armor,
+        sub_type: 4,
+        prob: 25,
+        delay: 2,
+        weight: 10,
+        cost: 8,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::IronShoes,
+        name: "iron shoes",
+        description: Some("hard shoes"),
+        class: ObjectClass::Armor,
+        sub_type: 4,
+        prob: 7,
+        delay: 2,
+        weight: 50,
+        cost: 16,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::HighBoots,
+        name: "high boots",
+        description: Some("jackboots"),
+        class: ObjectClass::Armor,
+        sub_type: 4,
+        prob: 15,
+        delay: 2,
+        weight: 20,
+        cost: 12,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 2,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpeedBoots,
+        name: "speed boots",
+        description: Some("combat boots"),
+        class: ObjectClass::Armor,
+        sub_type: 4,
+        prob: 12,
+        delay: 2,
+        weight: 20,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 63,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WaterWalkingBoots,
+        name: "water walking boots",
+        description: Some("jungle boots"),
+        class: ObjectClass::Armor,
+        sub_type: 4,
+        prob: 12,
+        delay: 2,
+        weight: 15,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 15,
+        prop: 49,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::JumpingBoots,
+        name: "jumping boots",
+        description: Some("hiking boots"),
+        class: ObjectClass::Armor,
+        sub_type: 4,
+        prob: 12,
+        delay: 2,
+        weight: 20,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 44,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ElvenBoots,
+        name: "elven boots",
+        description: Some("mud boots"),
+        class: ObjectClass::Armor,
+        sub_type: 4,
+        prob: 12,
+        delay: 2,
+        weight: 15,
+        cost: 8,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 15,
+        prop: 41,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::KickingBoots,
+        name: "kicking boots",
+        description: Some("buckled boots"),
+        class: ObjectClass::Armor,
+        sub_type: 4,
+        prob: 12,
+        delay: 2,
+        weight: 50,
+        cost: 8,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Brown,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::FumbleBoots,
+        name: "fumble boots",
+        description: Some("riding boots"),
+        class: ObjectClass::Armor,
+        sub_type: 4,
+        prob: 12,
+        delay: 2,
+        weight: 20,
+        cost: 30,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 25,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LevitationBoots,
+        name: "levitation boots",
+        description: Some("snow boots"),
+        class: ObjectClass::Armor,
+        sub_type: 4,
+        prob: 12,
+        delay: 2,
+        weight: 15,
+        cost: 30,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 15,
+        prop: 47,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Adornment,
+        name: "adornment",
+        description: Some("wooden"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 15,
+        prop: 38,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GainStrength,
+        name: "gain strength",
+        description: Some("granite"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Gray,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GainConstitution,
+        name: "gain constitution",
+        description: Some("opal"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Gray,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::IncreaseAccuracy,
+        name: "increase accuracy",
+        description: Some("clay"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Red,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::IncreaseDamage,
+        name: "increase damage",
+        description: Some("coral"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Orange,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::RingOfProtection,
+        name: "protection",
+        description: Some("black onyx"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Black,
+        nutrition: 15,
+        prop: 58,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Regeneration,
+        name: "regeneration",
+        description: Some("moonstone"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Gray,
+        nutrition: 15,
+        prop: 56,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Searching,
+        name: "searching",
+        description: Some("tiger eye"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Brown,
+        nutrition: 15,
+        prop: 34,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Stealth,
+        name: "stealth",
+        description: Some("jade"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Green,
+        nutrition: 15,
+        prop: 41,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SustainAbility,
+        name: "sustain ability",
+        description: Some("bronze"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Copper,
+        color: Color::Yellow,
+        nutrition: 15,
+        prop: 66,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::RingOfLevitation,
+        name: "levitation",
+        description: Some("agate"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Red,
+        nutrition: 15,
+        prop: 47,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Hunger,
+        name: "hunger",
+        description: Some("topaz"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Cyan,
+        nutrition: 15,
+        prop: 28,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AggravateMonster,
+        name: "aggravate monster",
+        description: Some("sapphire"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Blue,
+        nutrition: 15,
+        prop: 42,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Conflict,
+        name: "conflict",
+        description: Some("ruby"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Red,
+        nutrition: 15,
+        prop: 43,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Warning,
+        name: "warning",
+        description: Some("diamond"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::White,
+        nutrition: 15,
+        prop: 31,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::PoisonResistance,
+        name: "poison resistance",
+        description: Some("pearl"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Bone,
+        color: Color::White,
+        nutrition: 15,
+        prop: 6,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::FireResistance,
+        name: "fire resistance",
+        description: Some("iron"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 15,
+        prop: 1,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ColdResistance,
+        name: "cold resistance",
+        description: Some("brass"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Copper,
+        color: Color::Yellow,
+        nutrition: 15,
+        prop: 2,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ShockResistance,
+        name: "shock resistance",
+        description: Some("copper"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Copper,
+        color: Color::Yellow,
+        nutrition: 15,
+        prop: 5,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::FreeAction,
+        name: "free action",
+        description: Some("twisted"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 15,
+        prop: 65,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SlowDigestion,
+        name: "slow digestion",
+        description: Some("steel"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 15,
+        prop: 53,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::RingOfTeleportation,
+        name: "teleportation",
+        description: Some("silver"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Silver,
+        color: Color::Gray,
+        nutrition: 15,
+        prop: 45,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::TeleportControl,
+        name: "teleport control",
+        description: Some("gold"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gold,
+        color: Color::Yellow,
+        nutrition: 15,
+        prop: 46,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::RingOfPolymorph,
+        name: "polymorph",
+        description: Some("ivory"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Bone,
+        color: Color::White,
+        nutrition: 15,
+        prop: 60,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::PolymorphControl,
+        name: "polymorph control",
+        description: Some("emerald"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::BrightGreen,
+        nutrition: 15,
+        prop: 61,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::RingOfInvisibility,
+        name: "invisibility",
+        description: Some("wire"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 15,
+        prop: 39,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::RingOfSeeInvisible,
+        name: "see invisible",
+        description: Some("engagement"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 15,
+        prop: 29,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ProtectionFromShapeChangers,
+        name: "protection from shape changers",
+        description: Some("shiny"),
+        class: ObjectClass::Ring,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 3,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::BrightCyan,
+        nutrition: 15,
+        prop: 59,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AmuletOfESP,
+        name: "amulet of ESP",
+        description: Some("circular"),
+        class: ObjectClass::Amulet,
+        sub_type: 0,
+        prob: 175,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 20,
+        prop: 30,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AmuletOfLifeSaving,
+        name: "amulet of life saving",
+        description: Some("spherical"),
+        class: ObjectClass::Amulet,
+        sub_type: 0,
+        prob: 75,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 20,
+        prop: 67,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AmuletOfStrangulation,
+        name: "amulet of strangulation",
+        description: Some("oval"),
+        class: ObjectClass::Amulet,
+        sub_type: 0,
+        prob: 135,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 20,
+        prop: 19,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AmuletOfRestfulSleep,
+        name: "amulet of restful sleep",
+        description: Some("triangular"),
+        class: ObjectClass::Amulet,
+        sub_type: 0,
+        prob: 135,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 20,
+        prop: 27,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AmuletVersusPoison,
+        name: "amulet versus poison",
+        description: Some("pyramidal"),
+        class: ObjectClass::Amulet,
+        sub_type: 0,
+        prob: 165,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 20,
+        prop: 6,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AmuletOfChange,
+        name: "amulet of change",
+        description: Some("square"),
+        class: ObjectClass::Amulet,
+        sub_type: 0,
+        prob: 130,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AmuletOfUnchanging,
+        name: "amulet of unchanging",
+        description: Some("concave"),
+        class: ObjectClass::Amulet,
+        sub_type: 0,
+        prob: 45,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 20,
+        prop: 62,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AmuletOfReflection,
+        name: "amulet of reflection",
+        description: Some("hexagonal"),
+        class: ObjectClass::Amulet,
+        sub_type: 0,
+        prob: 75,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 20,
+        prop: 64,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AmuletOfMagicalBreathing,
+        name: "amulet of magical breathing",
+        description: Some("octagonal"),
+        class: ObjectClass::Amulet,
+        sub_type: 0,
+        prob: 65,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 20,
+        prop: 51,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CheapPlasticImitationOfTheAmuletOfYendor,
+        name: "cheap plastic imitation of the Amulet of Yendor",
+        description: Some("Amulet of Yendor"),
+        class: ObjectClass::Amulet,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 20,
+        cost: 0,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Plastic,
+        color: Color::Cyan,
+        nutrition: 1,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AmuletOfYendor,
+        name: "Amulet of Yendor",
+        description: Some("Amulet of Yendor"),
+        class: ObjectClass::Amulet,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 20,
+        cost: 30000,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mithril,
+        color: Color::Cyan,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: true,
+            no_wish: true,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LargeBox,
+        name: "large box",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 40,
+        delay: 0,
+        weight: 350,
+        cost: 8,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 350,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Chest,
+        name: "chest",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 35,
+        delay: 0,
+        weight: 600,
+        cost: 16,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 600,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::IceBox,
+        name: "ice box",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 5,
+        delay: 0,
+        weight: 900,
+        cost: 42,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Plastic,
+        color: Color::White,
+        nutrition: 900,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Sack,
+        name: "sack",
+        description: Some("bag"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 35,
+        delay: 0,
+        weight: 15,
+        cost: 2,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Cloth,
+        color: Color::Brown,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OilskinSack,
+        name: "oilskin sack",
+        description: Some("bag"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 5,
+        delay: 0,
+        weight: 15,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Cloth,
+        color: Color::Brown,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BagOfHolding,
+        name: "bag of holding",
+        description: Some("bag"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 20,
+        delay: 0,
+        weight: 15,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Cloth,
+        color: Color::Brown,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BagOfTricks,
+        name: "bag of tricks",
+        description: Some("bag"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 20,
+        delay: 0,
+        weight: 15,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Cloth,
+        color: Color::Brown,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SkeletonKey,
+        name: "skeleton key",
+        description: Some("key"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 80,
+        delay: 0,
+        weight: 3,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 3,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LockPick,
+        name: "lock pick",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 60,
+        delay: 0,
+        weight: 4,
+        cost: 20,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 4,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CreditCard,
+        name: "credit card",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 1,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Plastic,
+        color: Color::White,
+        nutrition: 1,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::TallowCandle,
+        name: "tallow candle",
+        description: Some("candle"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 20,
+        delay: 0,
+        weight: 2,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wax,
+        color: Color::White,
+        nutrition: 2,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WaxCandle,
+        name: "wax candle",
+        description: Some("candle"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 5,
+        delay: 0,
+        weight: 2,
+        cost: 20,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wax,
+        color: Color::White,
+        nutrition: 2,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BrassLantern,
+        name: "brass lantern",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 30,
+        delay: 0,
+        weight: 30,
+        cost: 12,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Copper,
+        color: Color::Yellow,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::OilLamp,
+        name: "oil lamp",
+        description: Some("lamp"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 45,
+        delay: 0,
+        weight: 20,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Copper,
+        color: Color::Yellow,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::MagicLamp,
+        name: "magic lamp",
+        description: Some("lamp"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 20,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Copper,
+        color: Color::Yellow,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ExpensiveCamera,
+        name: "expensive camera",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 12,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Plastic,
+        color: Color::Black,
+        nutrition: 12,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Mirror,
+        name: "mirror",
+        description: Some("looking glass"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 45,
+        delay: 0,
+        weight: 13,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Gray,
+        nutrition: 13,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CrystalBall,
+        name: "crystal ball",
+        description: Some("glass orb"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 150,
+        cost: 60,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::BrightCyan,
+        nutrition: 150,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Lenses,
+        name: "lenses",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 5,
+        delay: 0,
+        weight: 3,
+        cost: 80,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::BrightCyan,
+        nutrition: 3,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Blindfold,
+        name: "blindfold",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 50,
+        delay: 0,
+        weight: 2,
+        cost: 20,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Cloth,
+        color: Color::Black,
+        nutrition: 2,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Towel,
+        name: "towel",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 50,
+        delay: 0,
+        weight: 2,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Cloth,
+        color: Color::Magenta,
+        nutrition: 2,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Saddle,
+        name: "saddle",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 5,
+        delay: 0,
+        weight: 200,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 200,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Leash,
+        name: "leash",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 65,
+        delay: 0,
+        weight: 12,
+        cost: 20,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 12,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Stethoscope,
+        name: "stethoscope",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 25,
+        delay: 0,
+        weight: 4,
+        cost: 75,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 4,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::TinningKit,
+        name: "tinning kit",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 100,
+        cost: 30,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 100,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::TinOpener,
+        name: "tin opener",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 35,
+        delay: 0,
+        weight: 4,
+        cost: 30,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 4,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CanOfGrease,
+        name: "can of grease",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 15,
+        cost: 20,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Figurine,
+        name: "figurine",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 25,
+        delay: 0,
+        weight: 50,
+        cost: 80,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Gray,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::MagicMarker,
+        name: "magic marker",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 2,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Plastic,
+        color: Color::Red,
+        nutrition: 2,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LandMine,
+        name: "land mine",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 300,
+        cost: 180,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Red,
+        nutrition: 300,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Beartrap,
+        name: "beartrap",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 200,
+        cost: 60,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 200,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::TinWhistle,
+        name: "tin whistle",
+        description: Some("whistle"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 100,
+        delay: 0,
+        weight: 3,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Metal,
+        color: Color::Cyan,
+        nutrition: 3,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::MagicWhistle,
+        name: "magic whistle",
+        description: Some("whistle"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 30,
+        delay: 0,
+        weight: 3,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Metal,
+        color: Color::Cyan,
+        nutrition: 3,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WoodenFlute,
+        name: "wooden flute",
+        description: Some("flute"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 4,
+        delay: 0,
+        weight: 5,
+        cost: 12,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 5,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::MagicFlute,
+        name: "magic flute",
+        description: Some("flute"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 2,
+        delay: 0,
+        weight: 5,
+        cost: 36,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 5,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::TooledHorn,
+        name: "tooled horn",
+        description: Some("horn"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 5,
+        delay: 0,
+        weight: 18,
+        cost: 15,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Bone,
+        color: Color::White,
+        nutrition: 18,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::FrostHorn,
+        name: "frost horn",
+        description: Some("horn"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 2,
+        delay: 0,
+        weight: 18,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Bone,
+        color: Color::White,
+        nutrition: 18,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::FireHorn,
+        name: "fire horn",
+        description: Some("horn"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 2,
+        delay: 0,
+        weight: 18,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Bone,
+        color: Color::White,
+        nutrition: 18,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::HornOfPlenty,
+        name: "horn of plenty",
+        description: Some("horn"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 2,
+        delay: 0,
+        weight: 18,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Bone,
+        color: Color::White,
+        nutrition: 18,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WoodenHarp,
+        name: "wooden harp",
+        description: Some("harp"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 4,
+        delay: 0,
+        weight: 30,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::MagicHarp,
+        name: "magic harp",
+        description: Some("harp"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 2,
+        delay: 0,
+        weight: 30,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Bell,
+        name: "bell",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 2,
+        delay: 0,
+        weight: 30,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Copper,
+        color: Color::Yellow,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Bugle,
+        name: "bugle",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 4,
+        delay: 0,
+        weight: 10,
+        cost: 15,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Copper,
+        color: Color::Yellow,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LeatherDrum,
+        name: "leather drum",
+        description: Some("drum"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 4,
+        delay: 0,
+        weight: 25,
+        cost: 25,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 25,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DrumOfEarthquake,
+        name: "drum of earthquake",
+        description: Some("drum"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 2,
+        delay: 0,
+        weight: 25,
+        cost: 25,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 25,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::PickAxe,
+        name: "pick-axe",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 4,
+        prob: 20,
+        delay: 0,
+        weight: 100,
+        cost: 50,
+        damage_small: 6,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 100,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GrapplingHook,
+        name: "grappling hook",
+        description: Some("iron hook"),
+        class: ObjectClass::Tool,
+        sub_type: 14,
+        prob: 5,
+        delay: 0,
+        weight: 30,
+        cost: 50,
+        damage_small: 2,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::UnicornHorn,
+        name: "unicorn horn",
+        description: None,
+        class: ObjectClass::Tool,
+        sub_type: 28,
+        prob: 0,
+        delay: 0,
+        weight: 20,
+        cost: 100,
+        damage_small: 12,
+        damage_large: 12,
+        oc1: 1,
+        oc2: 0,
+        material: Material::Bone,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CandelabrumOfInvocation,
+        name: "Candelabrum of Invocation",
+        description: Some("candelabrum"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 10,
+        cost: 5000,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gold,
+        color: Color::Yellow,
+        nutrition: 200,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: true,
+            no_wish: true,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BellOfOpening,
+        name: "Bell of Opening",
+        description: Some("silver bell"),
+        class: ObjectClass::Tool,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 10,
+        cost: 5000,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Silver,
+        color: Color::Gray,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: true,
+            no_wish: true,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::TripeRation,
+        name: "tripe ration",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 140,
+        delay: 2,
+        weight: 10,
+        cost: 15,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Flesh,
+        color: Color::Brown,
+        nutrition: 200,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Corpse,
+        name: "corpse",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 1,
+        weight: 0,
+        cost: 5,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Flesh,
+        color: Color::Brown,
+        nutrition: 0,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Egg,
+        name: "egg",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 85,
+        delay: 1,
+        weight: 1,
+        cost: 9,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Flesh,
+        color: Color::White,
+        nutrition: 80,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Meatball,
+        name: "meatball",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 1,
+        weight: 1,
+        cost: 5,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Flesh,
+        color: Color::Brown,
+        nutrition: 5,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::MeatStick,
+        name: "meat stick",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 1,
+        weight: 1,
+        cost: 5,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Flesh,
+        color: Color::Brown,
+        nutrition: 5,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::HugeChunkOfMeat,
+        name: "huge chunk of meat",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 20,
+        weight: 400,
+        cost: 105,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Flesh,
+        color: Color::Brown,
+        nutrition: 2000,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::MeatRing,
+        name: "meat ring",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 1,
+        weight: 5,
+        cost: 1,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Flesh,
+        color: Color::Brown,
+        nutrition: 5,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GlobOfGrayOoze,
+        name: "glob of gray ooze",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 2,
+        weight: 20,
+        cost: 6,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Flesh,
+        color: Color::Gray,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GlobOfBrownPudding,
+        name: "glob of brown pudding",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 2,
+        weight: 20,
+        cost: 6,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Flesh,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GlobOfGreenSlime,
+        name: "glob of green slime",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 2,
+        weight: 20,
+        cost: 6,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Flesh,
+        color: Color::Green,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GlobOfBlackPudding,
+        name: "glob of black pudding",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 2,
+        weight: 20,
+        cost: 6,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Flesh,
+        color: Color::Black,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::KelpFrond,
+        name: "kelp frond",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 1,
+        weight: 1,
+        cost: 6,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Green,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::EucalyptusLeaf,
+        name: "eucalyptus leaf",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 3,
+        delay: 1,
+        weight: 1,
+        cost: 6,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Green,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Apple,
+        name: "apple",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 15,
+        delay: 1,
+        weight: 2,
+        cost: 7,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Red,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Orange,
+        name: "orange",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 10,
+        delay: 1,
+        weight: 2,
+        cost: 9,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Orange,
+        nutrition: 80,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Pear,
+        name: "pear",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 10,
+        delay: 1,
+        weight: 2,
+        cost: 7,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::BrightGreen,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Melon,
+        name: "melon",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 10,
+        delay: 1,
+        weight: 5,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::BrightGreen,
+        nutrition: 100,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Banana,
+        name: "banana",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 10,
+        delay: 1,
+        weight: 2,
+        cost: 9,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Yellow,
+        nutrition: 80,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Carrot,
+        name: "carrot",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 15,
+        delay: 1,
+        weight: 2,
+        cost: 7,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Orange,
+        nutrition: 50,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SprigOfWolfsbane,
+        name: "sprig of wolfsbane",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 7,
+        delay: 1,
+        weight: 1,
+        cost: 7,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Green,
+        nutrition: 40,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CloveOfGarlic,
+        name: "clove of garlic",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 7,
+        delay: 1,
+        weight: 1,
+        cost: 7,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::White,
+        nutrition: 40,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SlimeMold,
+        name: "slime mold",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 75,
+        delay: 1,
+        weight: 5,
+        cost: 17,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Brown,
+        nutrition: 250,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LumpOfRoyalJelly,
+        name: "lump of royal jelly",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 1,
+        weight: 2,
+        cost: 15,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Yellow,
+        nutrition: 200,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CreamPie,
+        name: "cream pie",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 25,
+        delay: 1,
+        weight: 10,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::White,
+        nutrition: 100,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CandyBar,
+        name: "candy bar",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 13,
+        delay: 1,
+        weight: 2,
+        cost: 10,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Brown,
+        nutrition: 100,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::FortuneCookie,
+        name: "fortune cookie",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 55,
+        delay: 1,
+        weight: 1,
+        cost: 7,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Yellow,
+        nutrition: 40,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Pancake,
+        name: "pancake",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 25,
+        delay: 2,
+        weight: 2,
+        cost: 15,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Yellow,
+        nutrition: 200,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::LembasWafer,
+        name: "lembas wafer",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 20,
+        delay: 2,
+        weight: 5,
+        cost: 45,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::White,
+        nutrition: 800,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CramRation,
+        name: "cram ration",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 20,
+        delay: 3,
+        weight: 15,
+        cost: 35,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Brown,
+        nutrition: 600,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::FoodRation,
+        name: "food ration",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 380,
+        delay: 5,
+        weight: 20,
+        cost: 45,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Brown,
+        nutrition: 800,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::KRation,
+        name: "K-ration",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 1,
+        weight: 10,
+        cost: 25,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Brown,
+        nutrition: 400,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CRation,
+        name: "C-ration",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 0,
+        delay: 1,
+        weight: 10,
+        cost: 20,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Veggy,
+        color: Color::Brown,
+        nutrition: 300,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Tin,
+        name: "tin",
+        description: None,
+        class: ObjectClass::Food,
+        sub_type: 0,
+        prob: 75,
+        delay: 0,
+        weight: 10,
+        cost: 5,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Metal,
+        color: Color::Cyan,
+        nutrition: 0,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GainAbility,
+        name: "gain ability",
+        description: Some("ruby"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 42,
+        delay: 0,
+        weight: 20,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Red,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::PotionOfRestoreAbility,
+        name: "restore ability",
+        description: Some("pink"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 40,
+        delay: 0,
+        weight: 20,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::BrightMagenta,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Confusion,
+        name: "confusion",
+        description: Some("orange"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 42,
+        delay: 0,
+        weight: 20,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Orange,
+        nutrition: 10,
+        prop: 14,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Blindness,
+        name: "blindness",
+        description: Some("yellow"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 40,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Yellow,
+        nutrition: 10,
+        prop: 15,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Paralysis,
+        name: "paralysis",
+        description: Some("emerald"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 42,
+        delay: 0,
+        weight: 20,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::BrightGreen,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Speed,
+        name: "speed",
+        description: Some("dark green"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 42,
+        delay: 0,
+        weight: 20,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Green,
+        nutrition: 10,
+        prop: 63,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::PotionOfLevitation,
+        name: "levitation",
+        description: Some("cyan"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 42,
+        delay: 0,
+        weight: 20,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Cyan,
+        nutrition: 10,
+        prop: 47,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Hallucination,
+        name: "hallucination",
+        description: Some("sky blue"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 40,
+        delay: 0,
+        weight: 20,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Cyan,
+        nutrition: 10,
+        prop: 23,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::PotionOfInvisibility,
+        name: "invisibility",
+        description: Some("brilliant blue"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 40,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::BrightBlue,
+        nutrition: 10,
+        prop: 39,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::PotionOfSeeInvisible,
+        name: "see invisible",
+        description: Some("magenta"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 42,
+        delay: 0,
+        weight: 20,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Magenta,
+        nutrition: 10,
+        prop: 29,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::PotionOfHealing,
+        name: "healing",
+        description: Some("purple-red"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 57,
+        delay: 0,
+        weight: 20,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Magenta,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::PotionOfExtraHealing,
+        name: "extra healing",
+        description: Some("puce"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 47,
+        delay: 0,
+        weight: 20,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Red,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GainLevel,
+        name: "gain level",
+        description: Some("milky"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 20,
+        delay: 0,
+        weight: 20,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::White,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::PotionOfEnlightenment,
+        name: "enlightenment",
+        description: Some("swirly"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 20,
+        delay: 0,
+        weight: 20,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Brown,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::MonsterDetection,
+        name: "monster detection",
+        description: Some("bubbly"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 40,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::White,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ObjectDetection,
+        name: "object detection",
+        description: Some("smoky"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 42,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Gray,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GainEnergy,
+        name: "gain energy",
+        description: Some("cloudy"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 42,
+        delay: 0,
+        weight: 20,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::White,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Sleeping,
+        name: "sleeping",
+        description: Some("effervescent"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 42,
+        delay: 0,
+        weight: 20,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Gray,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::FullHealing,
+        name: "full healing",
+        description: Some("black"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 10,
+        delay: 0,
+        weight: 20,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Black,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::PotionOfPolymorph,
+        name: "polymorph",
+        description: Some("golden"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 10,
+        delay: 0,
+        weight: 20,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Yellow,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Booze,
+        name: "booze",
+        description: Some("brown"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 42,
+        delay: 0,
+        weight: 20,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Brown,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Sickness,
+        name: "sickness",
+        description: Some("fizzy"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 42,
+        delay: 0,
+        weight: 20,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Cyan,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::FruitJuice,
+        name: "fruit juice",
+        description: Some("dark"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 42,
+        delay: 0,
+        weight: 20,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Black,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Acid,
+        name: "acid",
+        description: Some("white"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 10,
+        delay: 0,
+        weight: 20,
+        cost: 250,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::White,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Oil,
+        name: "oil",
+        description: Some("murky"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 30,
+        delay: 0,
+        weight: 20,
+        cost: 250,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Brown,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Water,
+        name: "water",
+        description: Some("clear"),
+        class: ObjectClass::Potion,
+        sub_type: 0,
+        prob: 92,
+        delay: 0,
+        weight: 20,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Cyan,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::EnchantArmor,
+        name: "enchant armor",
+        description: Some("ZELGO MER"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 63,
+        delay: 0,
+        weight: 5,
+        cost: 80,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DestroyArmor,
+        name: "destroy armor",
+        description: Some("JUYED AWK YACC"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 45,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollOfConfuseMonster,
+        name: "confuse monster",
+        description: Some("NR 9"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 53,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScareMonster,
+        name: "scare monster",
+        description: Some("XIXAXA XOXAXA XUXAXA"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 35,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollOfRemoveCurse,
+        name: "remove curse",
+        description: Some("PRATYAVAYAH"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 65,
+        delay: 0,
+        weight: 5,
+        cost: 80,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::EnchantWeapon,
+        name: "enchant weapon",
+        description: Some("DAIYEN FOOELS"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 80,
+        delay: 0,
+        weight: 5,
+        cost: 60,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollOfCreateMonster,
+        name: "create monster",
+        description: Some("LEP GEX VEN ZEA"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 45,
+        delay: 0,
+        weight: 5,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Taming,
+        name: "taming",
+        description: Some("PRIRUTSENIE"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 5,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Genocide,
+        name: "genocide",
+        description: Some("ELBIB YLOH"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 5,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollOfLight,
+        name: "light",
+        description: Some("VERR YED HORRE"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 90,
+        delay: 0,
+        weight: 5,
+        cost: 50,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollOfTeleportation,
+        name: "teleportation",
+        description: Some("VENZAR BORGAVVE"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 55,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GoldDetection,
+        name: "gold detection",
+        description: Some("THARR"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 33,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::FoodDetection,
+        name: "food detection",
+        description: Some("YUM YUM"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 25,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollOfIdentify,
+        name: "identify",
+        description: Some("KERNOD WEL"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 180,
+        delay: 0,
+        weight: 5,
+        cost: 20,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollOfMagicMapping,
+        name: "magic mapping",
+        description: Some("ELAM EBOW"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 45,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Amnesia,
+        name: "amnesia",
+        description: Some("DUAM XNAHT"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 35,
+        delay: 0,
+        weight: 5,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollOfFire,
+        name: "fire",
+        description: Some("ANDOVA BEGARIN"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 30,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Earth,
+        name: "earth",
+        description: Some("KIRJE"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 18,
+        delay: 0,
+        weight: 5,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Punishment,
+        name: "punishment",
+        description: Some("VE FORBRYDERNE"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 5,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Charging,
+        name: "charging",
+        description: Some("HACKEM MUCHE"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 5,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::StinkingCloud,
+        name: "stinking cloud",
+        description: Some("VELOX NEB"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 5,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollFOOBIEBLETCH,
+        name: "",
+        description: Some("FOOBIE BLETCH"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollTEMOV,
+        name: "",
+        description: Some("TEMOV"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollGARVENDEH,
+        name: "",
+        description: Some("GARVEN DEH"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollREADME,
+        name: "",
+        description: Some("READ ME"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollETAOINSHRDLU,
+        name: "",
+        description: Some("ETAOIN SHRDLU"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollLOREMIPSUM,
+        name: "",
+        description: Some("LOREM IPSUM"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollFNORD,
+        name: "",
+        description: Some("FNORD"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollKOBATE,
+        name: "",
+        description: Some("KO BATE"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollABRAKADABRA,
+        name: "",
+        description: Some("ABRA KA DABRA"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollASHPDSODALG,
+        name: "",
+        description: Some("ASHPD SODALG"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollZLORFIK,
+        name: "",
+        description: Some("ZLORFIK"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollGNIKSISIVLE,
+        name: "",
+        description: Some("GNIK SISI VLE"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollHAPAXLEGOMENON,
+        name: "",
+        description: Some("HAPAX LEGOMENON"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollEIRISSAZUNIDISI,
+        name: "",
+        description: Some("EIRIS SAZUN IDISI"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollPHOLENDEWODAN,
+        name: "",
+        description: Some("PHOL ENDE WODAN"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollGHOTI,
+        name: "",
+        description: Some("GHOTI"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollMAPIROMAHAMADIROMAT,
+        name: "",
+        description: Some("MAPIRO MAHAMA DIROMAT"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollVASCORPBETMANI,
+        name: "",
+        description: Some("VAS CORP BET MANI"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollXOROTA,
+        name: "",
+        description: Some("XOR OTA"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollSTRCPRSTSKRZKRK,
+        name: "",
+        description: Some("STRC PRST SKRZ KRK"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Mail,
+        name: "mail",
+        description: Some("stamped"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 5,
+        cost: 0,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ScrollOfBlankPaper,
+        name: "blank paper",
+        description: Some("unlabeled"),
+        class: ObjectClass::Scroll,
+        sub_type: 0,
+        prob: 28,
+        delay: 0,
+        weight: 5,
+        cost: 60,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Dig,
+        name: "dig",
+        description: Some("parchment"),
+        class: ObjectClass::SpellBook,
+        sub_type: 35,
+        prob: 20,
+        delay: 6,
+        weight: 50,
+        cost: 500,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 5,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfMagicMissile,
+        name: "magic missile",
+        description: Some("vellum"),
+        class: ObjectClass::SpellBook,
+        sub_type: 29,
+        prob: 45,
+        delay: 2,
+        weight: 50,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 2,
+        material: Material::Leather,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Fireball,
+        name: "fireball",
+        description: Some("ragged"),
+        class: ObjectClass::SpellBook,
+        sub_type: 29,
+        prob: 20,
+        delay: 4,
+        weight: 50,
+        cost: 400,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 4,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ConeOfCold,
+        name: "cone of cold",
+        description: Some("dog eared"),
+        class: ObjectClass::SpellBook,
+        sub_type: 29,
+        prob: 10,
+        delay: 7,
+        weight: 50,
+        cost: 400,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 4,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfSleep,
+        name: "sleep",
+        description: Some("mottled"),
+        class: ObjectClass::SpellBook,
+        sub_type: 32,
+        prob: 50,
+        delay: 1,
+        weight: 50,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::FingerOfDeath,
+        name: "finger of death",
+        description: Some("stained"),
+        class: ObjectClass::SpellBook,
+        sub_type: 29,
+        prob: 5,
+        delay: 10,
+        weight: 50,
+        cost: 700,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 7,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfLight,
+        name: "light",
+        description: Some("cloth"),
+        class: ObjectClass::SpellBook,
+        sub_type: 31,
+        prob: 45,
+        delay: 1,
+        weight: 50,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Paper,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DetectMonsters,
+        name: "detect monsters",
+        description: Some("leathery"),
+        class: ObjectClass::SpellBook,
+        sub_type: 31,
+        prob: 43,
+        delay: 1,
+        weight: 50,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Paper,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfHealing,
+        name: "healing",
+        description: Some("white"),
+        class: ObjectClass::SpellBook,
+        sub_type: 30,
+        prob: 40,
+        delay: 2,
+        weight: 50,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Knock,
+        name: "knock",
+        description: Some("pink"),
+        class: ObjectClass::SpellBook,
+        sub_type: 35,
+        prob: 35,
+        delay: 1,
+        weight: 50,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Paper,
+        color: Color::BrightMagenta,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::ForceBolt,
+        name: "force bolt",
+        description: Some("red"),
+        class: ObjectClass::SpellBook,
+        sub_type: 29,
+        prob: 35,
+        delay: 2,
+        weight: 50,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Paper,
+        color: Color::Red,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfConfuseMonster,
+        name: "confuse monster",
+        description: Some("orange"),
+        class: ObjectClass::SpellBook,
+        sub_type: 32,
+        prob: 30,
+        delay: 2,
+        weight: 50,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 2,
+        material: Material::Paper,
+        color: Color::Orange,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CureBlindness,
+        name: "cure blindness",
+        description: Some("yellow"),
+        class: ObjectClass::SpellBook,
+        sub_type: 30,
+        prob: 25,
+        delay: 2,
+        weight: 50,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 2,
+        material: Material::Paper,
+        color: Color::Yellow,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DrainLife,
+        name: "drain life",
+        description: Some("velvet"),
+        class: ObjectClass::SpellBook,
+        sub_type: 29,
+        prob: 10,
+        delay: 2,
+        weight: 50,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 2,
+        material: Material::Paper,
+        color: Color::Magenta,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfSlowMonster,
+        name: "slow monster",
+        description: Some("light green"),
+        class: ObjectClass::SpellBook,
+        sub_type: 32,
+        prob: 30,
+        delay: 2,
+        weight: 50,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 2,
+        material: Material::Paper,
+        color: Color::BrightGreen,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WizardLock,
+        name: "wizard lock",
+        description: Some("dark green"),
+        class: ObjectClass::SpellBook,
+        sub_type: 35,
+        prob: 30,
+        delay: 3,
+        weight: 50,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 2,
+        material: Material::Paper,
+        color: Color::Green,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfCreateMonster,
+        name: "create monster",
+        description: Some("turquoise"),
+        class: ObjectClass::SpellBook,
+        sub_type: 33,
+        prob: 35,
+        delay: 3,
+        weight: 50,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 2,
+        material: Material::Paper,
+        color: Color::BrightCyan,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DetectFood,
+        name: "detect food",
+        description: Some("cyan"),
+        class: ObjectClass::SpellBook,
+        sub_type: 31,
+        prob: 30,
+        delay: 3,
+        weight: 50,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 2,
+        material: Material::Paper,
+        color: Color::Cyan,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CauseFear,
+        name: "cause fear",
+        description: Some("light blue"),
+        class: ObjectClass::SpellBook,
+        sub_type: 32,
+        prob: 25,
+        delay: 3,
+        weight: 50,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 3,
+        material: Material::Paper,
+        color: Color::BrightBlue,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Clairvoyance,
+        name: "clairvoyance",
+        description: Some("dark blue"),
+        class: ObjectClass::SpellBook,
+        sub_type: 31,
+        prob: 15,
+        delay: 3,
+        weight: 50,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 3,
+        material: Material::Paper,
+        color: Color::Blue,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CureSickness,
+        name: "cure sickness",
+        description: Some("indigo"),
+        class: ObjectClass::SpellBook,
+        sub_type: 30,
+        prob: 32,
+        delay: 3,
+        weight: 50,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 3,
+        material: Material::Paper,
+        color: Color::Blue,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CharmMonster,
+        name: "charm monster",
+        description: Some("magenta"),
+        class: ObjectClass::SpellBook,
+        sub_type: 32,
+        prob: 20,
+        delay: 3,
+        weight: 50,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 3,
+        material: Material::Paper,
+        color: Color::Magenta,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::HasteSelf,
+        name: "haste self",
+        description: Some("purple"),
+        class: ObjectClass::SpellBook,
+        sub_type: 34,
+        prob: 33,
+        delay: 4,
+        weight: 50,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 3,
+        material: Material::Paper,
+        color: Color::Magenta,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DetectUnseen,
+        name: "detect unseen",
+        description: Some("violet"),
+        class: ObjectClass::SpellBook,
+        sub_type: 31,
+        prob: 20,
+        delay: 4,
+        weight: 50,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 3,
+        material: Material::Paper,
+        color: Color::Magenta,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfLevitation,
+        name: "levitation",
+        description: Some("tan"),
+        class: ObjectClass::SpellBook,
+        sub_type: 34,
+        prob: 20,
+        delay: 4,
+        weight: 50,
+        cost: 400,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 4,
+        material: Material::Paper,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfExtraHealing,
+        name: "extra healing",
+        description: Some("plaid"),
+        class: ObjectClass::SpellBook,
+        sub_type: 30,
+        prob: 27,
+        delay: 5,
+        weight: 50,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 3,
+        material: Material::Paper,
+        color: Color::Green,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfRestoreAbility,
+        name: "restore ability",
+        description: Some("light brown"),
+        class: ObjectClass::SpellBook,
+        sub_type: 30,
+        prob: 25,
+        delay: 5,
+        weight: 50,
+        cost: 400,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 4,
+        material: Material::Paper,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfInvisibility,
+        name: "invisibility",
+        description: Some("dark brown"),
+        class: ObjectClass::SpellBook,
+        sub_type: 34,
+        prob: 25,
+        delay: 5,
+        weight: 50,
+        cost: 400,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 4,
+        material: Material::Paper,
+        color: Color::Brown,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DetectTreasure,
+        name: "detect treasure",
+        description: Some("gray"),
+        class: ObjectClass::SpellBook,
+        sub_type: 31,
+        prob: 20,
+        delay: 5,
+        weight: 50,
+        cost: 400,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 4,
+        material: Material::Paper,
+        color: Color::Gray,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfRemoveCurse,
+        name: "remove curse",
+        description: Some("wrinkled"),
+        class: ObjectClass::SpellBook,
+        sub_type: 33,
+        prob: 25,
+        delay: 5,
+        weight: 50,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 3,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfMagicMapping,
+        name: "magic mapping",
+        description: Some("dusty"),
+        class: ObjectClass::SpellBook,
+        sub_type: 31,
+        prob: 18,
+        delay: 7,
+        weight: 50,
+        cost: 500,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 5,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfIdentify,
+        name: "identify",
+        description: Some("bronze"),
+        class: ObjectClass::SpellBook,
+        sub_type: 31,
+        prob: 20,
+        delay: 6,
+        weight: 50,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 3,
+        material: Material::Paper,
+        color: Color::Yellow,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::TurnUndead,
+        name: "turn undead",
+        description: Some("copper"),
+        class: ObjectClass::SpellBook,
+        sub_type: 33,
+        prob: 16,
+        delay: 8,
+        weight: 50,
+        cost: 600,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 6,
+        material: Material::Paper,
+        color: Color::Yellow,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfPolymorph,
+        name: "polymorph",
+        description: Some("silver"),
+        class: ObjectClass::SpellBook,
+        sub_type: 35,
+        prob: 10,
+        delay: 8,
+        weight: 50,
+        cost: 600,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 6,
+        material: Material::Paper,
+        color: Color::Gray,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::TeleportAway,
+        name: "teleport away",
+        description: Some("gold"),
+        class: ObjectClass::SpellBook,
+        sub_type: 34,
+        prob: 15,
+        delay: 6,
+        weight: 50,
+        cost: 600,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 6,
+        material: Material::Paper,
+        color: Color::Yellow,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::CreateFamiliar,
+        name: "create familiar",
+        description: Some("glittering"),
+        class: ObjectClass::SpellBook,
+        sub_type: 33,
+        prob: 10,
+        delay: 7,
+        weight: 50,
+        cost: 600,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 6,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfCancellation,
+        name: "cancellation",
+        description: Some("shining"),
+        class: ObjectClass::SpellBook,
+        sub_type: 35,
+        prob: 15,
+        delay: 8,
+        weight: 50,
+        cost: 700,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 7,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfProtection,
+        name: "protection",
+        description: Some("dull"),
+        class: ObjectClass::SpellBook,
+        sub_type: 33,
+        prob: 18,
+        delay: 3,
+        weight: 50,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Jumping,
+        name: "jumping",
+        description: Some("thin"),
+        class: ObjectClass::SpellBook,
+        sub_type: 34,
+        prob: 20,
+        delay: 3,
+        weight: 50,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::StoneToFlesh,
+        name: "stone to flesh",
+        description: Some("thick"),
+        class: ObjectClass::SpellBook,
+        sub_type: 30,
+        prob: 15,
+        delay: 1,
+        weight: 50,
+        cost: 300,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 3,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpellOfBlankPaper,
+        name: "blank paper",
+        description: Some("plain"),
+        class: ObjectClass::SpellBook,
+        sub_type: 0,
+        prob: 18,
+        delay: 0,
+        weight: 50,
+        cost: 0,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Novel,
+        name: "novel",
+        description: Some("paperback"),
+        class: ObjectClass::SpellBook,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 0,
+        cost: 20,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 1,
+        material: Material::Paper,
+        color: Color::BrightBlue,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BookOfTheDead,
+        name: "Book of the Dead",
+        description: Some("papyrus"),
+        class: ObjectClass::SpellBook,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 20,
+        cost: 10000,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 7,
+        material: Material::Paper,
+        color: Color::White,
+        nutrition: 20,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: true,
+            no_wish: true,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandOfLight,
+        name: "light",
+        description: Some("glass"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 95,
+        delay: 0,
+        weight: 7,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::BrightCyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SecretDoorDetection,
+        name: "secret door detection",
+        description: Some("balsa"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 50,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandOfEnlightenment,
+        name: "enlightenment",
+        description: Some("crystal"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 15,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::BrightCyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandOfCreateMonster,
+        name: "create monster",
+        description: Some("maple"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 45,
+        delay: 0,
+        weight: 7,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Wishing,
+        name: "wishing",
+        description: Some("pine"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 5,
+        delay: 0,
+        weight: 7,
+        cost: 500,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 1,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Nothing,
+        name: "nothing",
+        description: Some("oak"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 25,
+        delay: 0,
+        weight: 7,
+        cost: 100,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: false,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Striking,
+        name: "striking",
+        description: Some("ebony"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 75,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::MakeInvisible,
+        name: "make invisible",
+        description: Some("marble"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 45,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Gray,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandOfSlowMonster,
+        name: "slow monster",
+        description: Some("tin"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 50,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Metal,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::SpeedMonster,
+        name: "speed monster",
+        description: Some("brass"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 50,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Copper,
+        color: Color::Yellow,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::UndeadTurning,
+        name: "undead turning",
+        description: Some("copper"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 50,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Copper,
+        color: Color::Yellow,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandOfPolymorph,
+        name: "polymorph",
+        description: Some("silver"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 45,
+        delay: 0,
+        weight: 7,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Silver,
+        color: Color::Gray,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandOfCancellation,
+        name: "cancellation",
+        description: Some("platinum"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 45,
+        delay: 0,
+        weight: 7,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Platinum,
+        color: Color::White,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandOfTeleportation,
+        name: "teleportation",
+        description: Some("iridium"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 45,
+        delay: 0,
+        weight: 7,
+        cost: 200,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Metal,
+        color: Color::BrightCyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Opening,
+        name: "opening",
+        description: Some("zinc"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 25,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Metal,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Locking,
+        name: "locking",
+        description: Some("aluminum"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 25,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Metal,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Probing,
+        name: "probing",
+        description: Some("uranium"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 30,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Metal,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 2,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Digging,
+        name: "digging",
+        description: Some("iron"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 55,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandOfMagicMissile,
+        name: "magic missile",
+        description: Some("steel"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 50,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandOfFire,
+        name: "fire",
+        description: Some("hexagonal"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 40,
+        delay: 0,
+        weight: 7,
+        cost: 175,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Cold,
+        name: "cold",
+        description: Some("short"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 40,
+        delay: 0,
+        weight: 7,
+        cost: 175,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandOfSleep,
+        name: "sleep",
+        description: Some("runed"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 50,
+        delay: 0,
+        weight: 7,
+        cost: 175,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Death,
+        name: "death",
+        description: Some("long"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 5,
+        delay: 0,
+        weight: 7,
+        cost: 500,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Lightning,
+        name: "lightning",
+        description: Some("curved"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 40,
+        delay: 0,
+        weight: 7,
+        cost: 175,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 3,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandForked,
+        name: "",
+        description: Some("forked"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Wood,
+        color: Color::Brown,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandSpiked,
+        name: "",
+        description: Some("spiked"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WandJeweled,
+        name: "",
+        description: Some("jeweled"),
+        class: ObjectClass::Wand,
+        sub_type: 0,
+        prob: 0,
+        delay: 0,
+        weight: 7,
+        cost: 150,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Gray,
+        nutrition: 30,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: false,
+            uses_known: true,
+            pre_discovered: false,
+            magic: true,
+            charged: true,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::GoldPiece,
+        name: "gold piece",
+        description: None,
+        class: ObjectClass::Coin,
+        sub_type: 0,
+        prob: 1000,
+        delay: 0,
+        weight: 1,
+        cost: 1,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gold,
+        color: Color::Yellow,
+        nutrition: 0,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::DilithiumCrystal,
+        name: "dilithium crystal",
+        description: Some("white"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 2,
+        delay: 0,
+        weight: 1,
+        cost: 4500,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::White,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Diamond,
+        name: "diamond",
+        description: Some("white"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 3,
+        delay: 0,
+        weight: 1,
+        cost: 4000,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::White,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Ruby,
+        name: "ruby",
+        description: Some("red"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 4,
+        delay: 0,
+        weight: 1,
+        cost: 3500,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Red,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Jacinth,
+        name: "jacinth",
+        description: Some("orange"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 3,
+        delay: 0,
+        weight: 1,
+        cost: 3250,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Orange,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Sapphire,
+        name: "sapphire",
+        description: Some("blue"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 4,
+        delay: 0,
+        weight: 1,
+        cost: 3000,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Blue,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BlackOpal,
+        name: "black opal",
+        description: Some("black"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 3,
+        delay: 0,
+        weight: 1,
+        cost: 2500,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Black,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Emerald,
+        name: "emerald",
+        description: Some("green"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 5,
+        delay: 0,
+        weight: 1,
+        cost: 2500,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Green,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Turquoise,
+        name: "turquoise",
+        description: Some("green"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 6,
+        delay: 0,
+        weight: 1,
+        cost: 2000,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Green,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Citrine,
+        name: "citrine",
+        description: Some("yellow"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 4,
+        delay: 0,
+        weight: 1,
+        cost: 1500,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Yellow,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Aquamarine,
+        name: "aquamarine",
+        description: Some("green"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 6,
+        delay: 0,
+        weight: 1,
+        cost: 1500,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Green,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Amber,
+        name: "amber",
+        description: Some("yellowish brown"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 8,
+        delay: 0,
+        weight: 1,
+        cost: 1000,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Brown,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Topaz,
+        name: "topaz",
+        description: Some("yellowish brown"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 10,
+        delay: 0,
+        weight: 1,
+        cost: 900,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Brown,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: true,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Jet,
+        name: "jet",
+        description: Some("black"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 6,
+        delay: 0,
+        weight: 1,
+        cost: 850,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Black,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Opal,
+        name: "opal",
+        description: Some("white"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 12,
+        delay: 0,
+        weight: 1,
+        cost: 800,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::White,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Chrysoberyl,
+        name: "chrysoberyl",
+        description: Some("yellow"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 8,
+        delay: 0,
+        weight: 1,
+        cost: 700,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Yellow,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Garnet,
+        name: "garnet",
+        description: Some("red"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 12,
+        delay: 0,
+        weight: 1,
+        cost: 700,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Red,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Amethyst,
+        name: "amethyst",
+        description: Some("violet"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 14,
+        delay: 0,
+        weight: 1,
+        cost: 600,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Magenta,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Jasper,
+        name: "jasper",
+        description: Some("red"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 15,
+        delay: 0,
+        weight: 1,
+        cost: 500,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Red,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Fluorite,
+        name: "fluorite",
+        description: Some("violet"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 15,
+        delay: 0,
+        weight: 1,
+        cost: 400,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Magenta,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Obsidian,
+        name: "obsidian",
+        description: Some("black"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 9,
+        delay: 0,
+        weight: 1,
+        cost: 200,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Black,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Agate,
+        name: "agate",
+        description: Some("orange"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 12,
+        delay: 0,
+        weight: 1,
+        cost: 200,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Orange,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Jade,
+        name: "jade",
+        description: Some("green"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 10,
+        delay: 0,
+        weight: 1,
+        cost: 300,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Gemstone,
+        color: Color::Green,
+        nutrition: 15,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WorthlessPieceOfWhiteGlass,
+        name: "worthless piece of white glass",
+        description: Some("white"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 77,
+        delay: 0,
+        weight: 1,
+        cost: 0,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::White,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WorthlessPieceOfBlueGlass,
+        name: "worthless piece of blue glass",
+        description: Some("blue"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 77,
+        delay: 0,
+        weight: 1,
+        cost: 0,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Blue,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WorthlessPieceOfRedGlass,
+        name: "worthless piece of red glass",
+        description: Some("red"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 77,
+        delay: 0,
+        weight: 1,
+        cost: 0,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Red,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WorthlessPieceOfYellowishBrownGlass,
+        name: "worthless piece of yellowish brown glass",
+        description: Some("yellowish brown"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 77,
+        delay: 0,
+        weight: 1,
+        cost: 0,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Brown,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WorthlessPieceOfOrangeGlass,
+        name: "worthless piece of orange glass",
+        description: Some("orange"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 76,
+        delay: 0,
+        weight: 1,
+        cost: 0,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Orange,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WorthlessPieceOfYellowGlass,
+        name: "worthless piece of yellow glass",
+        description: Some("yellow"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 77,
+        delay: 0,
+        weight: 1,
+        cost: 0,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Yellow,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WorthlessPieceOfBlackGlass,
+        name: "worthless piece of black glass",
+        description: Some("black"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 76,
+        delay: 0,
+        weight: 1,
+        cost: 0,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Black,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WorthlessPieceOfGreenGlass,
+        name: "worthless piece of green glass",
+        description: Some("green"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 77,
+        delay: 0,
+        weight: 1,
+        cost: 0,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Green,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::WorthlessPieceOfVioletGlass,
+        name: "worthless piece of violet glass",
+        description: Some("violet"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 77,
+        delay: 0,
+        weight: 1,
+        cost: 0,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Glass,
+        color: Color::Magenta,
+        nutrition: 6,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Luckstone,
+        name: "luckstone",
+        description: Some("gray"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 10,
+        delay: 0,
+        weight: 10,
+        cost: 60,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Gray,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Loadstone,
+        name: "loadstone",
+        description: Some("gray"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 10,
+        delay: 0,
+        weight: 500,
+        cost: 1,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Gray,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Touchstone,
+        name: "touchstone",
+        description: Some("gray"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 8,
+        delay: 0,
+        weight: 10,
+        cost: 45,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Gray,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: true,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Flint,
+        name: "flint",
+        description: Some("gray"),
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 10,
+        delay: 0,
+        weight: 10,
+        cost: 1,
+        damage_small: 6,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Gray,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Rock,
+        name: "rock",
+        description: None,
+        class: ObjectClass::Gem,
+        sub_type: -22,
+        prob: 100,
+        delay: 0,
+        weight: 10,
+        cost: 0,
+        damage_small: 3,
+        damage_large: 3,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Gray,
+        nutrition: 10,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Boulder,
+        name: "boulder",
+        description: None,
+        class: ObjectClass::Rock,
+        sub_type: 0,
+        prob: 100,
+        delay: 0,
+        weight: 6000,
+        cost: 0,
+        damage_small: 20,
+        damage_large: 20,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::Gray,
+        nutrition: 2000,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: true,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::Statue,
+        name: "statue",
+        description: None,
+        class: ObjectClass::Rock,
+        sub_type: 0,
+        prob: 900,
+        delay: 0,
+        weight: 2500,
+        cost: 0,
+        damage_small: 20,
+        damage_large: 20,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Mineral,
+        color: Color::White,
+        nutrition: 2500,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::HeavyIronBall,
+        name: "heavy iron ball",
+        description: None,
+        class: ObjectClass::Ball,
+        sub_type: 0,
+        prob: 1000,
+        delay: 0,
+        weight: 480,
+        cost: 10,
+        damage_small: 25,
+        damage_large: 25,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 200,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::IronChain,
+        name: "iron chain",
+        description: None,
+        class: ObjectClass::Chain,
+        sub_type: 0,
+        prob: 1000,
+        delay: 0,
+        weight: 120,
+        cost: 0,
+        damage_small: 4,
+        damage_large: 4,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Iron,
+        color: Color::Cyan,
+        nutrition: 200,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: true,
+            merge: false,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: false,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::BlindingVenom,
+        name: "blinding venom",
+        description: Some("splash of venom"),
+        class: ObjectClass::Venom,
+        sub_type: 0,
+        prob: 500,
+        delay: 0,
+        weight: 1,
+        cost: 0,
+        damage_small: 0,
+        damage_large: 0,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Liquid,
+        color: Color::Brown,
+        nutrition: 0,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: true,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+    ObjectType {
+        id: ObjectId::AcidVenom,
+        name: "acid venom",
+        description: Some("splash of venom"),
+        class: ObjectClass::Venom,
+        sub_type: 0,
+        prob: 500,
+        delay: 0,
+        weight: 1,
+        cost: 0,
+        damage_small: 6,
+        damage_large: 6,
+        oc1: 0,
+        oc2: 0,
+        material: Material::Liquid,
+        color: Color::Brown,
+        nutrition: 0,
+        prop: 0,
+        flags: ObjectTypeFlags {
+            name_known: false,
+            merge: true,
+            uses_known: false,
+            pre_discovered: false,
+            magic: false,
+            charged: false,
+            unique: false,
+            no_wish: true,
+            big: false,
+            tough: false,
+            dir: 0,
+        },
+    },
+];
@@ -0,0 +1,268 @@
+//! Race table and starting attributes, from `role.c` and `attrib.c`.
+
+use nethack_rng::NhRng;
+use nethack_types::{
+    AlignmentMask, MonsterFlags2, MonsterId, RaceDefinition, RaceKind, RoleAdvance, RoleKind,
+    RoleName,
+};
+use strum::EnumCount;
+
+/// Attribute indices in C's `A_STR`..`A_CHA` order.
+pub const A_STR: usize = 0;
+pub const A_INT: usize = 1;
+pub const A_WIS: usize = 2;
+pub const A_DEX: usize = 3;
+pub const A_CON: usize = 4;
+pub const A_CHA: usize = 5;
+pub const A_MAX: usize = 6;
+
+/// `STR18(x)`: exceptional strength 18/x.
+const fn str18(x: i8) -> i8 {
+    18 + x
+}
+
+/// Gender bits of a `Race.allow` mask.
+const ROLE_MALE: u16 = 0x1000;
+const ROLE_FEMALE: u16 = 0x2000;
+
+const LAWFUL: u16 = AlignmentMask::LAWFUL.bits() as u16;
+const NEUTRAL: u16 = AlignmentMask::NEUTRAL.bits() as u16;
+const CHAOTIC: u16 = AlignmentMask::CHAOTIC.bits() as u16;
+
+const MH_HUMAN: u16 = MonsterFlags2::HUMAN.bits() as u16;
+const MH_ELF: u16 = MonsterFlags2::ELF.bits() as u16;
+const MH_DWARF: u16 = MonsterFlags2::DWARF.bits() as u16;
+const MH_GNOME: u16 = MonsterFlags2::GNOME.bits() as u16;
+const MH_ORC: u16 = MonsterFlags2::ORC.bits() as u16;
+
+const fn advance(f: [i8; 6]) -> RoleAdvance {
+    RoleAdvance {
+        init_fixed: f[0],
+        init_random: f[1],
+        low_fixed: f[2],
+        low_random: f[3],
+        high_fixed: f[4],
+        high_random: f[5],
+    }
+}
+
+const fn pm(id: MonsterId) -> Option<u16> {
+    Some(id as u16)
+}
+
+/// `races[]`, indexed by [`RaceKind`].
+pub const RACES: [RaceDefinition; RaceKind::COUNT] = [
+    RaceDefinition {
+        noun: "human",
+        adj: "human",
+        coll: "humanity",
+        filecode: "Hum",
+        individual: RoleName {
+            male: "man",
+            female: Some("woman"),
+        },
+        male_num: pm(MonsterId::Human),
+        female_num: None,
+        mummy_num: pm(MonsterId::HumanMummy),
+        zombie_num: pm(MonsterId::HumanZombie),
+        allow_mask: MH_HUMAN | ROLE_MALE | ROLE_FEMALE | LAWFUL | NEUTRAL | CHAOTIC,
+        self_mask: MH_HUMAN,
+        love_mask: 0,
+        hate_mask: MH_GNOME | MH_ORC,
+        attr_min: [3, 3, 3, 3, 3, 3],
+        attr_max: [str18(100), 18, 18, 18, 18, 18],
+        hp_advance: advance([2, 0, 0, 2, 1, 0]),
+        en_advance: advance([1, 0, 2, 0, 2, 0]),
+    },
+    RaceDefinition {
+        noun: "elf",
+        adj: "elven",
+        coll: "elvenkind",
+        filecode: "Elf",
+        individual: RoleName {
+            male: "elf",
+            female: None,
+        },
+        male_num: pm(MonsterId::Elf),
+        female_num: None,
+        mummy_num: pm(MonsterId::ElfMummy),
+        zombie_num: pm(MonsterId::ElfZombie),
+        allow_mask: MH_ELF | ROLE_MALE | ROLE_FEMALE | CHAOTIC,
+        self_mask: MH_ELF,
+        love_mask: MH_ELF,
+        hate_mask: MH_ORC,
+        attr_min: [3, 3, 3, 3, 3, 3],
+        attr_max: [18, 20, 20, 18, 16, 18],
+        hp_advance: advance([1, 0, 0, 1, 1, 0]),
+        en_advance: advance([2, 0, 3, 0, 3, 0]),
+    },
+    RaceDefinition {
+        noun: "dwarf",
+        adj: "dwarven",
+        coll: "dwarvenkind",
+        filecode: "Dwa",
+        individual: RoleName {
+            male: "dwarf",
+            female: None,
+        },
+        male_num: pm(MonsterId::Dwarf),
+        female_num: None,
+        mummy_num: pm(MonsterId::DwarfMummy),
+        zombie_num: pm(MonsterId::DwarfZombie),
+        allow_mask: MH_DWARF | ROLE_MALE | ROLE_FEMALE | LAWFUL,
+        self_mask: MH_DWARF,
+        love_mask: MH_DWARF | MH_GNOME,
+        hate_mask: MH_ORC,
+        attr_min: [3, 3, 3, 3, 3, 3],
+        attr_max: [str18(100), 16, 16, 20, 20, 16],
+        hp_advance: advance([4, 0, 0, 3, 2, 0]),
+        en_advance: advance([0, 0, 0, 0, 0, 0]),
+    },
+    RaceDefinition {
+        noun: "gnome",
+        adj: "gnomish",
+        coll: "gnomehood",
+        filecode: "Gno",
+        individual: RoleName {
+            male: "gnome",
+            female: None,
+        },
+        male_num: pm(MonsterId::Gnome),
+        female_num: None,
+        mummy_num: pm(MonsterId::GnomeMummy),
+        zombie_num: pm(MonsterId::GnomeZombie),
+        allow_mask: MH_GNOME | ROLE_MALE | ROLE_FEMALE | NEUTRAL,
+        self_mask: MH_GNOME,
+        love_mask: MH_DWARF | MH_GNOME,
+        hate_mask: MH_HUMAN,
+        attr_min: [3, 3, 3, 3, 3, 3],
+        attr_max: [str18(50), 19, 18, 18, 18, 18],
+        hp_advance: advance([1, 0, 0, 1, 0, 0]),
+        en_advance: advance([2, 0, 2, 0, 2, 0]),
+    },
+    RaceDefinition {
+        noun: "orc",
+        adj: "orcish",
+        coll: "orcdom",
+        filecode: "Orc",
+        individual: RoleName {
+            male: "orc",
+            female: None,
+        },
+        male_num: pm(MonsterId::Orc),
+        female_num: None,
+        mummy_num: pm(MonsterId::OrcMummy),
+        zombie_num: pm(MonsterId::OrcZombie),
+        allow_mask: MH_ORC | ROLE_MALE | ROLE_FEMALE | CHAOTIC,
+        self_mask: MH_ORC,
+        love_mask: 0,
+        hate_mask: MH_HUMAN | MH_ELF | MH_DWARF,
+        attr_min: [3, 3, 3, 3, 3, 3],
+        attr_max: [str18(50), 16, 16, 18, 18, 16],
+        hp_advance: advance([1, 0, 0, 1, 0, 0]),
+        en_advance: advance([1, 0, 1, 0, 1, 0]),
+    },
+];
+
+/// `attrbase` and `attrdist` from `roles[]`, indexed by [`RoleKind`]. The
+/// rest of the role table is not ported yet.
+const ROLE_ATTRIBUTES: [([i8; A_MAX], [i8; A_MAX]); RoleKind::COUNT] = [
+    ([7, 10, 10, 7, 7, 7], [20, 20, 20, 10, 20, 10]),
+    ([16, 7, 7, 15, 16, 6], [30, 6, 7, 20, 30, 7]),
+    ([10, 7, 7, 7, 8, 6], [30, 6, 7, 20, 30, 7]),
+    ([7, 7, 13, 7, 11, 16], [15, 20, 20, 15, 25, 5]),
+    ([13, 7, 14, 8, 10, 17], [30, 15, 15, 10, 20, 10]),
+    ([10, 7, 8, 8, 7, 7], [25, 10, 20, 20, 15, 10]),
+    ([7, 7, 10, 7, 7, 7], [15, 10, 30, 15, 20, 10]),
+    ([13, 13, 13, 9, 13, 7], [30, 10, 10, 20, 20, 10]),
+    ([7, 7, 7, 10, 7, 6], [20, 10, 10, 30, 20, 10]),
+    ([10, 8, 7, 10, 17, 6], [30, 10, 8, 30, 14, 8]),
+    ([7, 10, 6, 7, 7, 10], [15, 10, 10, 15, 30, 20]),
+    ([10, 7, 7, 7, 10, 7], [30, 6, 7, 20, 30, 7]),
+    ([7, 10, 7, 7, 7, 7], [10, 30, 10, 20, 20, 10]),
+];
+
+/// Attribute points `u_init()` hands to `init_attr()`.
+const ATTRIBUTE_POINTS: i32 = 75;
+
+/// Starting attributes, as `init_attr(75)` in `attrib.c` rolls them.
+///
+/// Each attribute starts at the role's base, then the remaining points are
+/// handed out one at a time, `rn2(100)` picking the attribute by the role's
+/// distribution. An attribute at the race's maximum is skipped, so the
+/// result always lies within the race's bounds. C gives up after 100 skips
+/// in a row. The 1-in-20 variation `u_init()` applies after the starting
+/// inventory is left to the caller, as it is drawn later.
+pub fn roll_attributes_for(rng: &mut NhRng, role: RoleKind, race: RaceKind) -> [i8; A_MAX] {
+    let (base, dist) = &ROLE_ATTRIBUTES[role as usize];
+    let race = &RACES[race as usize];
+    let mut attrs = *base;
+    let mut points = ATTRIBUTE_POINTS - base.iter().map(|&a| i32::from(a)).sum::<i32>();
+
+    // `points` is never negative for the C tables, but init_attr() also
+    // takes points back the same way when it is.
+    let mut tries = 0;
+    while points != 0 && tries < 100 {
+        let Some(i) = pick_attribute(rng, dist) else {
+            continue;
+        };
+        let step = points.signum();
+        let at_bound = if step > 0 {
+            attrs[i] >= race.attr_max[i]
+        } else {
+            attrs[i] <= race.attr_min[i]
+        };
+        if at_bound {
+            tries += 1;
+            continue;
+        }
+        tries = 0;
+        attrs[i] += step as i8;
+        points -= step;
+    }
+    attrs
+}
+
+/// Index chosen by `rn2(100)` against a distribution summing to 100.
+fn pick_attribute(rng: &mut NhRng, dist: &[i8; A_MAX]) -> Option<usize> {
+    let mut x = rng.rn2(100);
+    dist.iter().position(|&d| {
+        x -= i32::from(d);
+        x <= 0
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn role_distributions_sum_to_100() {
+        for (role, (_, dist)) in RoleKind::iter().zip(ROLE_ATTRIBUTES) {
+            let sum: i32 = dist.iter().map(|&d| i32::from(d)).sum();
+            assert_eq!(sum, 100, "{role:?}");
+        }
+    }
+
+    #[test]
+    fn gnome_attributes_stay_within_gnome_limits() {
+        let gnome = &RACES[RaceKind::Gnome as usize];
+        assert_eq!(gnome.noun, "gnome");
+        for seed in 0..200 {
+            let mut rng = NhRng::new(seed);
+            for role in [RoleKind::Archeologist, RoleKind::Caveman, RoleKind::Wizard] {
+                let attrs = roll_attributes_for(&mut rng, role, RaceKind::Gnome);
+                for i in 0..A_MAX {
+                    assert!(
+                        (gnome.attr_min[i]..=gnome.attr_max[i]).contains(&attrs[i]),
+                        "{role:?} seed {seed}: {attrs:?}"
+                    );
+                }
+                let total: i32 = attrs.iter().map(|&a| i32::from(a)).sum();
+                assert!(total <= ATTRIBUTE_POINTS);
+                assert!(attrs[A_INT] <= 19 && attrs[A_STR] <= str18(50));
+            }
+        }
+    }
+}
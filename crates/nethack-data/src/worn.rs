@@ -0,0 +1,53 @@
+//! Armor class from worn armor, after `find_ac()` in `do_wear.c`.
+
+use nethack_types::{ObjectClass, ObjectId, ObjectInstance};
+
+use crate::objects::OBJECTS;
+
+/// `ARM_BONUS()`: the AC a piece of armor of type `id` gives with
+/// enchantment `enchant` and greatest erosion level `erosion`.
+///
+/// Erosion takes a point off the base AC per level, but never more than the
+/// base AC itself; enchantment is not affected. Non-armor gives nothing.
+pub fn effective_armor_ac(id: ObjectId, enchant: i8, erosion: u8) -> i8 {
+    let obj = &OBJECTS[id as usize];
+    if obj.class != ObjectClass::Armor {
+        return 0;
+    }
+    let base = i32::from(obj.oc1);
+    let bonus = base + i32::from(enchant) - i32::from(erosion).min(base);
+    bonus.clamp(i8::MIN.into(), i8::MAX.into()) as i8
+}
+
+/// The hero's AC wearing `worn`, starting from the current form's natural
+/// AC `base_ac`. Each item counts its worse erosion (`greatest_erosion()`).
+/// Rings of protection and the Protection intrinsic are left to the caller.
+pub fn total_ac(base_ac: i8, worn: &[ObjectInstance]) -> i8 {
+    let ac = worn.iter().fold(i32::from(base_ac), |ac, o| {
+        let erosion = o.erosion.0.max(o.erosion.1);
+        ac - i32::from(effective_armor_ac(o.id, o.enchant, erosion))
+    });
+    ac.clamp(i8::MIN.into(), i8::MAX.into()) as i8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn erosion_reduces_plate_mail_ac() {
+        assert_eq!(effective_armor_ac(ObjectId::PlateMail, 0, 0), 7);
+        assert_eq!(effective_armor_ac(ObjectId::PlateMail, 0, 3), 4);
+        assert_eq!(effective_armor_ac(ObjectId::PlateMail, 2, 3), 6);
+        // Erosion can't take away more than the base AC.
+        let base = OBJECTS[ObjectId::LeatherGloves as usize].oc1;
+        assert_eq!(effective_armor_ac(ObjectId::LeatherGloves, 1, 3), 1);
+        assert_eq!(base, 1);
+        assert_eq!(effective_armor_ac(ObjectId::LongSword, 3, 0), 0);
+
+        let mut plate = ObjectInstance::new(ObjectId::PlateMail);
+        assert_eq!(total_ac(10, std::slice::from_ref(&plate)), 3);
+        plate.erosion = (1, 3);
+        assert_eq!(total_ac(10, &[plate]), 6);
+    }
+}
@@ -3,8 +3,9 @@
 //! For each `.lev` fixture file, finds the corresponding `.des` source and level
 //! name, parses with Rust, reads the C binary, and compares opcode-by-opcode.
 
+use nethack_data::disasm::{self, DisasmOptions};
 use nethack_data::{des_parser, lev_reader};
-use nethack_types::sp_lev::SpLevOpcode;
+use nethack_types::sp_lev::{LevelFlags, SpLevOpcode, SpecialLevel};
 use std::collections::HashMap;
 use std::path::Path;
 
@@ -38,9 +39,47 @@ fn build_rust_levels() -> HashMap<String, (String, Vec<SpLevOpcode>)> {
 
 /// Format an opcode for diff output.
 fn format_opcode(op: &SpLevOpcode) -> String {
-    match &op.operand {
-        None => format!("{:?}", op.opcode),
-        Some(operand) => format!("{:?} {:?}", op.opcode, operand),
+    disasm::format_opcode(op, &DisasmOptions::default())
+}
+
+/// `lev_comp` output is the reference for stack balance, so every fixture
+/// must pass `validate_stack`.
+#[test]
+fn lev_fixtures_have_balanced_stacks() {
+    let mut lev_files: Vec<_> = std::fs::read_dir(FIXTURES_DIR)
+        .expect("read fixtures dir")
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "lev"))
+        .collect();
+    lev_files.sort();
+    assert!(!lev_files.is_empty());
+
+    for lev_path in &lev_files {
+        let data =
+            std::fs::read(lev_path).unwrap_or_else(|_| panic!("read {}", lev_path.display()));
+        let level = SpecialLevel {
+            name: lev_path.file_stem().unwrap().to_string_lossy().to_string(),
+            opcodes: lev_reader::read_lev(&data).expect("read .lev"),
+        };
+        if let Err(e) = level.validate_stack() {
+            panic!("{}: {e}", level.name);
+        }
+    }
+}
+
+#[test]
+fn sokoban_levels_are_premapped() {
+    for name in ["soko1-1", "soko2-2", "soko4-1"] {
+        let path = Path::new(FIXTURES_DIR).join(format!("{name}.lev"));
+        let data = std::fs::read(&path).unwrap_or_else(|_| panic!("read {}", path.display()));
+        let level = SpecialLevel {
+            name: name.into(),
+            opcodes: lev_reader::read_lev(&data).expect("read .lev"),
+        };
+        let flags = level.level_flags();
+        assert!(flags.is_premapped(), "{name}: {flags:?}");
+        assert!(flags.contains(LevelFlags::NOTELEPORT | LevelFlags::SOLIDIFY));
     }
 }
 
@@ -0,0 +1,636 @@
+//! The map grid a level is built on, before it becomes a playable level.
+
+use bitflags::bitflags;
+use nethack_rng::NhRng;
+use nethack_types::{
+    COLNO, DoorState, LevelFlags, LocationType, LvlInitStyle, ROWNO, RoomType, Selection,
+};
+
+use crate::mkmap;
+
+/// Rightmost maze column (`x_maze_max` in C): the last even column.
+pub const X_MAZE_MAX: i32 = (COLNO as i32 - 1) & !1;
+/// Bottom maze row (`y_maze_max` in C): the last even row.
+pub const Y_MAZE_MAX: i32 = (ROWNO as i32 - 1) & !1;
+
+bitflags! {
+    /// Wall properties from `rm.h` (`W_*` bits in `wall_info`).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct WallInfo: u8 {
+        const NONDIGGABLE = 0x08;
+        const NONPASSWALL = 0x10;
+    }
+}
+
+/// One map location, the subset of C's `struct rm` that level creation sets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub typ: LocationType,
+    pub lit: bool,
+    pub wall_info: WallInfo,
+    /// `doormask`, meaningful on doors only.
+    pub doormask: DoorState,
+    /// `drawbridgemask`, meaningful on a drawbridge span only: the `DB_*`
+    /// direction of its portcullis, plus `DB_LAVA` over lava.
+    pub drawbridgemask: u8,
+    /// The wall or drawbridge runs east-west.
+    pub horizontal: bool,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            typ: LocationType::Stone,
+            lit: false,
+            wall_info: WallInfo::empty(),
+            doormask: DoorState::NODOOR,
+            drawbridgemask: 0,
+            horizontal: false,
+        }
+    }
+}
+
+/// Arguments of `SPO_INITLEVEL`, matching C's `lev_init`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InitParams {
+    /// Solid fill terrain; `None` for a mines level that skips the fill.
+    pub filling: Option<LocationType>,
+    pub walled: bool,
+    /// `None` picks at random.
+    pub lit: Option<bool>,
+    pub joined: bool,
+    pub smoothed: bool,
+    /// Mines background terrain.
+    pub bg: LocationType,
+    /// Mines foreground (open) terrain.
+    pub fg: LocationType,
+}
+
+/// A room built by `SPO_ROOM` or marked out by `SPO_REGION`, kept for the
+/// room filler (`fill_special_room()`) that stocks shops and populates zoos.
+/// The bounds are the floor, inside the walls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoomRegion {
+    pub x1: i32,
+    pub y1: i32,
+    pub x2: i32,
+    pub y2: i32,
+    pub rtype: RoomType,
+    pub irregular: bool,
+    pub joined: bool,
+    /// `false` for an `unfilled` region, which the filler leaves empty.
+    pub needs_fill: bool,
+}
+
+/// Engraved text at a map location. Only the headstones of graves are made
+/// so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Engraving {
+    pub x: i32,
+    pub y: i32,
+    pub text: String,
+}
+
+/// A `COLNO` x `ROWNO` grid of [`Cell`]s plus the level-wide state that
+/// special-level code reads and writes while building it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelCanvas {
+    /// Row-major cells, indexed by `y * COLNO + x`.
+    cells: Vec<Cell>,
+    pub flags: LevelFlags,
+    /// Rooms and special regions in the order they were made.
+    pub rooms: Vec<RoomRegion>,
+    /// At most one per location.
+    pub engravings: Vec<Engraving>,
+}
+
+impl Default for LevelCanvas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LevelCanvas {
+    /// An all-stone, unlit canvas.
+    pub fn new() -> Self {
+        Self {
+            cells: vec![Cell::default(); COLNO * ROWNO],
+            flags: LevelFlags::empty(),
+            rooms: Vec::new(),
+            engravings: Vec::new(),
+        }
+    }
+
+    fn index(x: i32, y: i32) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= COLNO || y as usize >= ROWNO {
+            None
+        } else {
+            Some(y as usize * COLNO + x as usize)
+        }
+    }
+
+    /// The cell at `(x, y)`, or `None` off the map.
+    pub fn get(&self, x: i32, y: i32) -> Option<&Cell> {
+        Self::index(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut Cell> {
+        Self::index(x, y).map(|i| &mut self.cells[i])
+    }
+
+    /// Terrain at `(x, y)`; off-map locations read as stone.
+    pub fn typ(&self, x: i32, y: i32) -> LocationType {
+        self.get(x, y).map_or(LocationType::Stone, |c| c.typ)
+    }
+
+    /// Set the terrain at `(x, y)`, ignoring off-map locations.
+    pub fn set_typ(&mut self, x: i32, y: i32, typ: LocationType) {
+        if let Some(cell) = self.get_mut(x, y) {
+            cell.typ = typ;
+        }
+    }
+
+    /// C's `SET_TYPLIT`: set terrain and lighting together. Lava is always
+    /// lit, and `lit: None` rolls `rn2(2)` per cell.
+    pub fn set_typlit(
+        &mut self,
+        x: i32,
+        y: i32,
+        typ: LocationType,
+        lit: Option<bool>,
+        rng: &mut NhRng,
+    ) {
+        let Some(cell) = self.get_mut(x, y) else {
+            return;
+        };
+        cell.typ = typ;
+        cell.lit = if typ == LocationType::LavaPool {
+            true
+        } else {
+            lit.unwrap_or_else(|| rng.rn2(2) != 0)
+        };
+    }
+
+    /// `make_engr_at()`: engrave `text` at `(x, y)`, replacing any engraving
+    /// already there.
+    pub fn engrave(&mut self, x: i32, y: i32, text: &str) {
+        self.del_engr_at(x, y);
+        self.engravings.push(Engraving {
+            x,
+            y,
+            text: text.into(),
+        });
+    }
+
+    /// `del_engr_at()`: remove the engraving at `(x, y)`, if any.
+    pub fn del_engr_at(&mut self, x: i32, y: i32) {
+        self.engravings.retain(|e| (e.x, e.y) != (x, y));
+    }
+
+    /// The engraving at `(x, y)`.
+    pub fn engraving_at(&self, x: i32, y: i32) -> Option<&Engraving> {
+        self.engravings.iter().find(|e| (e.x, e.y) == (x, y))
+    }
+
+    /// Light every cell in the clipped rectangle, as `light_region()`.
+    pub fn light_rect(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) {
+        for y in y1.max(0)..=y2.min(ROWNO as i32 - 1) {
+            for x in x1.max(0)..=x2.min(COLNO as i32 - 1) {
+                if let Some(cell) = self.get_mut(x, y) {
+                    cell.lit = true;
+                }
+            }
+        }
+    }
+
+    /// `add_room()` for an ordinary (non-special) room: floor over the
+    /// rectangle, walls and corners around it, all lit when `lit` is set.
+    /// Returns the room's index in [`rooms`](Self::rooms).
+    pub fn add_room(
+        &mut self,
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        lit: bool,
+        rtype: RoomType,
+    ) -> usize {
+        if lit {
+            self.light_rect(x1 - 1, y1 - 1, x2 + 1, y2 + 1);
+        }
+        for x in x1 - 1..=x2 + 1 {
+            self.set_typ(x, y1 - 1, LocationType::HWall);
+            self.set_typ(x, y2 + 1, LocationType::HWall);
+        }
+        for y in y1..=y2 {
+            self.set_typ(x1 - 1, y, LocationType::VWall);
+            self.set_typ(x2 + 1, y, LocationType::VWall);
+            for x in x1..=x2 {
+                self.set_typ(x, y, LocationType::Room);
+            }
+        }
+        self.set_typ(x1 - 1, y1 - 1, LocationType::TlCorner);
+        self.set_typ(x2 + 1, y1 - 1, LocationType::TrCorner);
+        self.set_typ(x1 - 1, y2 + 1, LocationType::BlCorner);
+        self.set_typ(x2 + 1, y2 + 1, LocationType::BrCorner);
+        self.rooms.push(RoomRegion {
+            x1,
+            y1,
+            x2,
+            y2,
+            rtype,
+            irregular: false,
+            joined: true,
+            needs_fill: true,
+        });
+        self.rooms.len() - 1
+    }
+
+    /// Initialize the grid for `SPO_INITLEVEL`, following `splev_initlev()`.
+    /// Returns `false`, leaving the canvas alone, for
+    /// [`LvlInitStyle::Rogue`]: `makeroguerooms()` is not ported.
+    #[must_use]
+    pub fn init(&mut self, style: LvlInitStyle, params: &InitParams, rng: &mut NhRng) -> bool {
+        match style {
+            LvlInitStyle::None => {}
+            LvlInitStyle::SolidFill => {
+                let lit = params.lit.unwrap_or_else(|| rng.rn2(2) != 0);
+                if let Some(filling) = params.filling {
+                    self.fill_solid(filling, lit, rng);
+                }
+            }
+            LvlInitStyle::MazeGrid => {
+                let filling = params.filling.unwrap_or(LocationType::Stone);
+                self.fill_maze_grid(2, 0, X_MAZE_MAX, Y_MAZE_MAX, filling);
+            }
+            LvlInitStyle::Mines => {
+                let lit = params.lit.unwrap_or_else(|| rng.rn2(2) != 0);
+                if let Some(filling) = params.filling {
+                    self.fill_solid(filling, false, rng);
+                }
+                mkmap::mkmap(self, params, lit, rng);
+            }
+            LvlInitStyle::Rogue => return false,
+        }
+        true
+    }
+
+    /// `lvlfill_solid()`: fill the maze area with one terrain.
+    fn fill_solid(&mut self, filling: LocationType, lit: bool, rng: &mut NhRng) {
+        for x in 2..=X_MAZE_MAX {
+            for y in 0..=Y_MAZE_MAX {
+                self.set_typlit(x, y, filling, Some(lit), rng);
+            }
+        }
+    }
+
+    /// `lvlfill_maze_grid()`: stone on odd/odd cells and the top two rows,
+    /// `filling` elsewhere, or all stone for a corridor maze.
+    fn fill_maze_grid(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, filling: LocationType) {
+        let corrmaze = self.flags.contains(LevelFlags::CORRMAZE);
+        for x in x1..=x2 {
+            for y in y1..=y2 {
+                let typ = if corrmaze || y < 2 || (x % 2 == 1 && y % 2 == 1) {
+                    LocationType::Stone
+                } else {
+                    filling
+                };
+                self.set_typ(x, y, typ);
+            }
+        }
+    }
+
+    /// `dig_corridor()` without `nxcor`: dig from `org` towards `dest`,
+    /// turning `btyp` into `ftyp` (a corridor is secret one time in a
+    /// hundred). Returns `false` if the path runs off the map, hits other
+    /// terrain, or takes more than 500 steps.
+    pub fn dig_corridor(
+        &mut self,
+        org: (i32, i32),
+        dest: (i32, i32),
+        ftyp: LocationType,
+        btyp: LocationType,
+        rng: &mut NhRng,
+    ) -> bool {
+        let (mut xx, mut yy) = org;
+        let (tx, ty) = dest;
+        let (colno, rowno) = (COLNO as i32, ROWNO as i32);
+        if xx <= 0 || yy <= 0 || tx <= 0 || ty <= 0 {
+            return false;
+        }
+        if xx > colno - 1 || tx > colno - 1 || yy > rowno - 1 || ty > rowno - 1 {
+            return false;
+        }
+        let (mut dx, mut dy) = if tx > xx {
+            (1, 0)
+        } else if ty > yy {
+            (0, 1)
+        } else if tx < xx {
+            (-1, 0)
+        } else {
+            (0, -1)
+        };
+        let open = |canvas: &Self, x, y| {
+            let t = canvas.typ(x, y);
+            t == btyp || t == ftyp || t == LocationType::SCorr
+        };
+
+        xx -= dx;
+        yy -= dy;
+        let mut cct = 0;
+        while xx != tx || yy != ty {
+            if cct > 500 {
+                return false;
+            }
+            cct += 1;
+            xx += dx;
+            yy += dy;
+            if xx >= colno - 1 || xx <= 0 || yy <= 0 || yy >= rowno - 1 {
+                return false;
+            }
+
+            let t = self.typ(xx, yy);
+            if t == btyp {
+                let typ = if ftyp != LocationType::Corr || rng.rn2(100) != 0 {
+                    ftyp
+                } else {
+                    LocationType::SCorr
+                };
+                self.set_typ(xx, yy, typ);
+            } else if t != ftyp && t != LocationType::SCorr {
+                return false;
+            }
+
+            // Find the next position, sometimes skewing towards the
+            // shorter axis so corridors are less straight.
+            let mut dix = (xx - tx).abs();
+            let mut diy = (yy - ty).abs();
+            if dix > diy && diy != 0 && rng.rn2(dix - diy + 1) == 0 {
+                dix = 0;
+            } else if diy > dix && dix != 0 && rng.rn2(diy - dix + 1) == 0 {
+                diy = 0;
+            }
+
+            // Change direction?
+            if dy != 0 && dix > diy {
+                let ddx = if xx > tx { -1 } else { 1 };
+                if open(self, xx + ddx, yy) {
+                    (dx, dy) = (ddx, 0);
+                    continue;
+                }
+            } else if dx != 0 && diy > dix {
+                let ddy = if yy > ty { -1 } else { 1 };
+                if open(self, xx, yy + ddy) {
+                    (dx, dy) = (0, ddy);
+                    continue;
+                }
+            }
+
+            // Straight on, else turn, else turn the other way.
+            if open(self, xx + dx, yy + dy) {
+                continue;
+            }
+            if dx != 0 {
+                (dx, dy) = (0, if ty < yy { -1 } else { 1 });
+            } else {
+                (dx, dy) = (if tx < xx { -1 } else { 1 }, 0);
+            }
+            if open(self, xx + dx, yy + dy) {
+                continue;
+            }
+            (dx, dy) = (-dx, -dy);
+        }
+        true
+    }
+
+    /// `set_wall_property()`: add `prop` to every wall, stone or tree cell in
+    /// the clipped region.
+    pub fn set_wall_property(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, prop: WallInfo) {
+        for y in y1.max(0)..=y2.min(ROWNO as i32 - 1) {
+            for x in x1.max(1)..=x2.min(COLNO as i32 - 1) {
+                if let Some(cell) = self.get_mut(x, y)
+                    && (cell.typ.is_stwall() || cell.typ == LocationType::Tree)
+                {
+                    cell.wall_info |= prop;
+                }
+            }
+        }
+    }
+
+    /// The map as text, one line per row from column 1 (column 0 is never
+    /// shown). Terrain uses the `.des` MAP characters where there is one and
+    /// NetHack's default symbols otherwise.
+    ///
+    /// A `shroud` level that is not `premapped` shows only the `revealed`
+    /// cells; anything else is drawn in full.
+    pub fn render_ascii(&self, revealed: Option<&Selection>) -> String {
+        let hidden = self.flags.is_shrouded() && !self.flags.is_premapped();
+        let mut out = String::with_capacity(COLNO * ROWNO);
+        for y in 0..ROWNO as i32 {
+            for x in 1..COLNO as i32 {
+                let shown = !hidden || revealed.is_some_and(|sel| sel.get(x, y));
+                out.push(match self.get(x, y) {
+                    Some(cell) if shown => symbol(cell),
+                    _ => ' ',
+                });
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// `wallify_map()`: turn stone next to open floor into walls, horizontal
+    /// when the floor is above or below and vertical otherwise.
+    pub fn wallify(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) {
+        for y in y1..=y2 {
+            let (lo_y, hi_y) = ((y - 1).max(0), if y < y2 { y + 1 } else { y2 });
+            for x in x1..=x2 {
+                if self.typ(x, y) != LocationType::Stone {
+                    continue;
+                }
+                let (lo_x, hi_x) = ((x - 1).max(0), if x < x2 { x + 1 } else { x2 });
+                let wall = (lo_y..=hi_y).find_map(|yy| {
+                    (lo_x..=hi_x)
+                        .any(|xx| {
+                            let t = self.typ(xx, yy);
+                            t.is_room() || t == LocationType::CrossWall
+                        })
+                        .then_some(if yy != y {
+                            LocationType::HWall
+                        } else {
+                            LocationType::VWall
+                        })
+                });
+                if let Some(wall) = wall {
+                    self.set_typ(x, y, wall);
+                }
+            }
+        }
+    }
+}
+
+fn symbol(cell: &Cell) -> char {
+    use LocationType as L;
+    match cell.typ {
+        L::Stone => ' ',
+        L::VWall | L::TlWall | L::TrWall | L::Grave => '|',
+        L::HWall
+        | L::TlCorner
+        | L::TrCorner
+        | L::BlCorner
+        | L::BrCorner
+        | L::CrossWall
+        | L::TuWall
+        | L::TdWall => '-',
+        L::DbWall | L::DrawbridgeUp | L::DrawbridgeDown | L::Corr => '#',
+        L::Tree => 'T',
+        L::SDoor => 'S',
+        L::SCorr => 'H',
+        L::Pool => 'P',
+        L::Moat => '}',
+        L::Water => 'W',
+        L::LavaPool => 'L',
+        L::IronBars => 'F',
+        L::Door
+            if cell
+                .doormask
+                .intersects(DoorState::CLOSED | DoorState::LOCKED) =>
+        {
+            '+'
+        }
+        L::Door if cell.doormask.contains(DoorState::ISOPEN) => '|',
+        L::Door | L::Room => '.',
+        L::Stairs | L::Ladder => '>',
+        L::Fountain => '{',
+        L::Throne => '\\',
+        L::Sink => 'K',
+        L::Altar => '_',
+        L::Ice => 'I',
+        L::Air => 'A',
+        L::Cloud => 'C',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(filling: LocationType) -> InitParams {
+        InitParams {
+            filling: Some(filling),
+            walled: false,
+            lit: Some(false),
+            joined: false,
+            smoothed: false,
+            bg: LocationType::Stone,
+            fg: LocationType::Room,
+        }
+    }
+
+    #[test]
+    fn render_respects_shroud_and_premapped() {
+        let mut canvas = LevelCanvas::new();
+        canvas.add_room(10, 5, 14, 8, true, RoomType::Ordinary);
+        canvas.set_typ(12, 6, LocationType::Fountain);
+        let full = canvas.render_ascii(None);
+        let lines: Vec<_> = full.lines().collect();
+        assert_eq!(lines.len(), ROWNO);
+        assert!(lines.iter().all(|l| l.chars().count() == COLNO - 1));
+        // Column x is at index x - 1.
+        // Walls surround the floor at 10..=14, 5..=8.
+        assert_eq!(&lines[4][8..15], "-------");
+        assert_eq!(&lines[6][8..15], "|..{..|");
+
+        canvas.flags |= LevelFlags::PREMAPPED;
+        assert_eq!(canvas.render_ascii(None), full);
+
+        canvas.flags = LevelFlags::SHROUD;
+        let blank = canvas.render_ascii(None);
+        assert!(blank.chars().all(|c| c == ' ' || c == '\n'));
+        assert_eq!(blank.len(), full.len());
+
+        let mut seen = Selection::new();
+        seen.set(12, 6, true);
+        let glimpse = canvas.render_ascii(Some(&seen));
+        assert_eq!(glimpse.replace([' ', '\n'], ""), "{");
+        assert_eq!(&glimpse.lines().nth(6).unwrap()[11..12], "{");
+
+        canvas.flags |= LevelFlags::PREMAPPED;
+        assert_eq!(canvas.render_ascii(None), full);
+    }
+
+    #[test]
+    fn solidfill_room() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(42);
+        let p = InitParams {
+            lit: Some(true),
+            ..params(LocationType::Room)
+        };
+        assert!(canvas.init(LvlInitStyle::SolidFill, &p, &mut rng));
+        for x in 2..=X_MAZE_MAX {
+            for y in 0..=Y_MAZE_MAX {
+                let cell = canvas.get(x, y).unwrap();
+                assert_eq!(cell.typ, LocationType::Room, "({x},{y})");
+                assert!(cell.lit);
+            }
+        }
+        // Columns outside the maze area are left alone.
+        assert_eq!(canvas.typ(0, 5), LocationType::Stone);
+        assert_eq!(canvas.typ(1, 5), LocationType::Stone);
+        assert_eq!(canvas.typ(COLNO as i32 - 1, 5), LocationType::Stone);
+    }
+
+    #[test]
+    fn mazegrid_alternates() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(42);
+        assert!(canvas.init(
+            LvlInitStyle::MazeGrid,
+            &params(LocationType::HWall),
+            &mut rng,
+        ));
+        for x in 2..=X_MAZE_MAX {
+            for y in 0..=Y_MAZE_MAX {
+                let expected = if y < 2 || (x % 2 == 1 && y % 2 == 1) {
+                    LocationType::Stone
+                } else {
+                    LocationType::HWall
+                };
+                assert_eq!(canvas.typ(x, y), expected, "({x},{y})");
+            }
+        }
+        assert_eq!(canvas.typ(3, 3), LocationType::Stone);
+        assert_eq!(canvas.typ(4, 3), LocationType::HWall);
+        assert_eq!(canvas.typ(3, 4), LocationType::HWall);
+    }
+
+    #[test]
+    fn corrmaze_grid_is_stone() {
+        let mut canvas = LevelCanvas::new();
+        canvas.flags |= LevelFlags::CORRMAZE;
+        let mut rng = NhRng::new(42);
+        assert!(canvas.init(
+            LvlInitStyle::MazeGrid,
+            &params(LocationType::HWall),
+            &mut rng,
+        ));
+        assert!(
+            (0..COLNO as i32)
+                .all(|x| (0..ROWNO as i32).all(|y| canvas.typ(x, y) == LocationType::Stone))
+        );
+    }
+
+    #[test]
+    fn wallify_surrounds_floor() {
+        let mut canvas = LevelCanvas::new();
+        canvas.set_typ(5, 5, LocationType::Room);
+        canvas.wallify(0, 0, COLNO as i32 - 1, ROWNO as i32 - 1);
+        assert_eq!(canvas.typ(5, 4), LocationType::HWall);
+        assert_eq!(canvas.typ(4, 4), LocationType::HWall);
+        assert_eq!(canvas.typ(5, 6), LocationType::HWall);
+        assert_eq!(canvas.typ(4, 5), LocationType::VWall);
+        assert_eq!(canvas.typ(6, 5), LocationType::VWall);
+        assert_eq!(canvas.typ(7, 5), LocationType::Stone);
+    }
+}
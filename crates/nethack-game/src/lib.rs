@@ -0,0 +1,9 @@
+//! Game logic: level construction now, monsters, objects and the game loop
+//! in later phases.
+
+pub mod canvas;
+mod mkmap;
+pub mod sp_lev;
+
+pub use canvas::{Cell, InitParams, LevelCanvas, RoomRegion, WallInfo};
+pub use sp_lev::{SpLevError, execute};
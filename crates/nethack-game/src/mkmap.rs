@@ -0,0 +1,397 @@
+//! Cellular-automaton cave generation for `INIT_MAP: mines`, ported from
+//! `mkmap.c`.
+
+use nethack_rng::NhRng;
+use nethack_types::{COLNO, LocationType, ROWNO, RoomType};
+
+use crate::canvas::{InitParams, LevelCanvas, RoomRegion};
+
+const HEIGHT: i32 = ROWNO as i32 - 1;
+const WIDTH: i32 = COLNO as i32 - 2;
+
+const N_P1_ITER: usize = 1;
+const N_P2_ITER: usize = 1;
+const N_P3_ITER: usize = 2;
+
+/// `MAXNROFROOMS * 2`: `join_map()` stops making cave rooms at this many.
+const MAX_CAVE_ROOMS: usize = 80;
+
+/// Neighbour offsets in C's `dirs[]` order.
+const DIRS: [(i32, i32); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// `mkmap()`. `splev_initlev()` has already resolved a random `lit`, so
+/// C's depth-based lighting roll never applies here.
+///
+/// Not yet ported: iced-pool marking.
+pub(crate) fn mkmap(canvas: &mut LevelCanvas, params: &InitParams, lit: bool, rng: &mut NhRng) {
+    let (bg, fg) = (params.bg, params.fg);
+
+    init_map(canvas, bg);
+    init_fill(canvas, bg, fg, rng);
+    for _ in 0..N_P1_ITER {
+        pass_one(canvas, bg, fg);
+    }
+    for _ in 0..N_P2_ITER {
+        pass_two(canvas, bg, fg);
+    }
+    if params.smoothed {
+        for _ in 0..N_P3_ITER {
+            pass_three(canvas, bg, fg);
+        }
+    }
+    if params.joined {
+        join_map(canvas, bg, fg, rng);
+    }
+    finish_map(canvas, fg, bg, lit, params.walled);
+}
+
+fn init_map(canvas: &mut LevelCanvas, bg: LocationType) {
+    for x in 1..COLNO as i32 {
+        for y in 0..ROWNO as i32 {
+            canvas.set_typ(x, y, bg);
+        }
+    }
+}
+
+/// Scatter `fg` over 40% of the interior.
+fn init_fill(canvas: &mut LevelCanvas, bg: LocationType, fg: LocationType, rng: &mut NhRng) {
+    let limit = (WIDTH * HEIGHT * 2) / 5;
+    let mut count = 0;
+    while count < limit {
+        let x = rng.rn1(WIDTH - 1, 2);
+        let y = rng.rnd(HEIGHT - 1);
+        if canvas.typ(x, y) == bg {
+            canvas.set_typ(x, y, fg);
+            count += 1;
+        }
+    }
+}
+
+/// `get_map()`: terrain, with everything outside the cave area as `bg`.
+fn get_map(canvas: &LevelCanvas, x: i32, y: i32, bg: LocationType) -> LocationType {
+    if x <= 0 || y < 0 || x > WIDTH || y >= HEIGHT {
+        bg
+    } else {
+        canvas.typ(x, y)
+    }
+}
+
+fn fg_neighbours(
+    canvas: &LevelCanvas,
+    x: i32,
+    y: i32,
+    bg: LocationType,
+    fg: LocationType,
+) -> usize {
+    DIRS.iter()
+        .filter(|(dx, dy)| get_map(canvas, x + dx, y + dy, bg) == fg)
+        .count()
+}
+
+/// Update in place: sparse cells die, crowded cells fill.
+fn pass_one(canvas: &mut LevelCanvas, bg: LocationType, fg: LocationType) {
+    for x in 2..=WIDTH {
+        for y in 1..HEIGHT {
+            match fg_neighbours(canvas, x, y, bg, fg) {
+                0..=2 => canvas.set_typ(x, y, bg),
+                5..=8 => canvas.set_typ(x, y, fg),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Compute every interior cell from the current grid with `rule`, then
+/// apply the results together (C's `new_locations` buffer).
+fn buffered_pass(
+    canvas: &mut LevelCanvas,
+    bg: LocationType,
+    fg: LocationType,
+    rule: impl Fn(usize) -> bool,
+) {
+    let mut next = Vec::with_capacity((WIDTH as usize - 1) * (HEIGHT as usize - 1));
+    for x in 2..=WIDTH {
+        for y in 1..HEIGHT {
+            let typ = if rule(fg_neighbours(canvas, x, y, bg, fg)) {
+                bg
+            } else {
+                get_map(canvas, x, y, bg)
+            };
+            next.push((x, y, typ));
+        }
+    }
+    for (x, y, typ) in next {
+        canvas.set_typ(x, y, typ);
+    }
+}
+
+fn pass_two(canvas: &mut LevelCanvas, bg: LocationType, fg: LocationType) {
+    buffered_pass(canvas, bg, fg, |count| count == 5);
+}
+
+fn pass_three(canvas: &mut LevelCanvas, bg: LocationType, fg: LocationType) {
+    buffered_pass(canvas, bg, fg, |count| count < 3);
+}
+
+/// `join_map()`: make each cave of more than three `fg` cells an irregular
+/// room, fill in the smaller ones, then dig `fg` corridors through stone
+/// from room to room.
+fn join_map(canvas: &mut LevelCanvas, bg: LocationType, fg: LocationType, rng: &mut NhRng) {
+    // C's per-cell `roomno`, as an index into `canvas.rooms`.
+    let mut roomno = vec![None; COLNO * ROWNO];
+    let at = |x: i32, y: i32| y as usize * COLNO + x as usize;
+
+    'caves: for x in 2..=WIDTH {
+        for y in 1..HEIGHT {
+            if canvas.typ(x, y) != fg || roomno[at(x, y)].is_some() {
+                continue;
+            }
+            let room = canvas.rooms.len();
+            let cave = flood_fill(canvas, &mut roomno, x, y, room);
+            if cave.len() > 3 {
+                let (mut x1, mut y1, mut x2, mut y2) = (x, y, x, y);
+                for &(cx, cy) in &cave {
+                    (x1, y1) = (x1.min(cx), y1.min(cy));
+                    (x2, y2) = (x2.max(cx), y2.max(cy));
+                }
+                canvas.rooms.push(RoomRegion {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    rtype: RoomType::Ordinary,
+                    irregular: true,
+                    joined: false,
+                    needs_fill: false,
+                });
+                if canvas.rooms.len() >= MAX_CAVE_ROOMS {
+                    break 'caves;
+                }
+            } else {
+                // A tiny hole; erase it so the hero cannot end up stuck there.
+                for (cx, cy) in cave {
+                    canvas.set_typ(cx, cy, bg);
+                    roomno[at(cx, cy)] = None;
+                }
+            }
+        }
+    }
+
+    // The rooms are already in order, so join each to the next.
+    let mut croom = 0;
+    for croom2 in 1..canvas.rooms.len() {
+        let (a, b) = (canvas.rooms[croom], canvas.rooms[croom2]);
+        let ends = somexy(&a, croom, &roomno, rng).zip(somexy(&b, croom2, &roomno, rng));
+        let (sm, em) = ends.unwrap_or_else(|| {
+            log::warn!("no start/end room location in join_map");
+            let centre = |r: &RoomRegion| (r.x1 + (r.x2 - r.x1) / 2, r.y1 + (r.y2 - r.y1) / 2);
+            (centre(&a), centre(&b))
+        });
+        canvas.dig_corridor(sm, em, fg, LocationType::Stone, rng);
+
+        // Only move on from `croom` once the two rooms do not overlap.
+        if b.x1 > a.x2 || ((b.y1 > a.y2 || b.y2 < a.y1) && rng.rn2(3) != 0) {
+            croom = croom2;
+        }
+    }
+}
+
+/// `flood_fill_rm()` for a mines cave: mark the `fg` cells 8-connected to
+/// `(x, y)` as room `room` and unlit, returning them.
+fn flood_fill(
+    canvas: &mut LevelCanvas,
+    roomno: &mut [Option<usize>],
+    x: i32,
+    y: i32,
+    room: usize,
+) -> Vec<(i32, i32)> {
+    let fg = canvas.typ(x, y);
+    let at = |x: i32, y: i32| y as usize * COLNO + x as usize;
+    let mut cave = Vec::new();
+    let mut pending = vec![(x, y)];
+    roomno[at(x, y)] = Some(room);
+    while let Some((cx, cy)) = pending.pop() {
+        if let Some(cell) = canvas.get_mut(cx, cy) {
+            cell.lit = false;
+        }
+        cave.push((cx, cy));
+        for (dx, dy) in DIRS {
+            let (nx, ny) = (cx + dx, cy + dy);
+            if (1..=WIDTH).contains(&nx)
+                && (0..ROWNO as i32).contains(&ny)
+                && canvas.typ(nx, ny) == fg
+                && roomno[at(nx, ny)].is_none()
+            {
+                roomno[at(nx, ny)] = Some(room);
+                pending.push((nx, ny));
+            }
+        }
+    }
+    cave
+}
+
+/// `somexy()` for an irregular room: up to 100 random tries inside the
+/// bounding box, then a scan, for a cell that belongs to the room.
+fn somexy(
+    room: &RoomRegion,
+    index: usize,
+    roomno: &[Option<usize>],
+    rng: &mut NhRng,
+) -> Option<(i32, i32)> {
+    let inside = |x: i32, y: i32| roomno[y as usize * COLNO + x as usize] == Some(index);
+    for _ in 0..100 {
+        let x = rng.rn1(room.x2 - room.x1 + 1, room.x1);
+        let y = rng.rn1(room.y2 - room.y1 + 1, room.y1);
+        if inside(x, y) {
+            return Some((x, y));
+        }
+    }
+    (room.x1..=room.x2)
+        .flat_map(|x| (room.y1..=room.y2).map(move |y| (x, y)))
+        .find(|&(x, y)| inside(x, y))
+}
+
+/// `finish_map()`: add walls and light the open terrain.
+fn finish_map(
+    canvas: &mut LevelCanvas,
+    fg: LocationType,
+    bg: LocationType,
+    lit: bool,
+    walled: bool,
+) {
+    if walled {
+        canvas.wallify(1, 0, COLNO as i32 - 1, ROWNO as i32 - 1);
+    }
+    for x in 1..COLNO as i32 {
+        for y in 0..ROWNO as i32 {
+            let Some(cell) = canvas.get_mut(x, y) else {
+                continue;
+            };
+            let t = cell.typ;
+            if lit
+                && ((!fg.is_rock() && t == fg)
+                    || (!bg.is_rock() && t == bg)
+                    || (bg == LocationType::Tree && t == bg)
+                    || (walled && t.is_wall()))
+            {
+                cell.lit = true;
+            }
+            // Lava is lit even on an otherwise dark level.
+            if t == LocationType::LavaPool {
+                cell.lit = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nethack_types::LvlInitStyle;
+
+    fn mines_params() -> InitParams {
+        InitParams {
+            filling: None,
+            walled: true,
+            lit: Some(true),
+            joined: false,
+            smoothed: true,
+            bg: LocationType::Stone,
+            fg: LocationType::Room,
+        }
+    }
+
+    fn build_with(seed: u64, params: &InitParams) -> LevelCanvas {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(seed);
+        assert!(canvas.init(LvlInitStyle::Mines, params, &mut rng));
+        canvas
+    }
+
+    fn build(seed: u64) -> LevelCanvas {
+        build_with(seed, &mines_params())
+    }
+
+    /// Sizes of the 8-connected regions of `typ`.
+    fn regions(canvas: &LevelCanvas, typ: LocationType) -> Vec<usize> {
+        let mut seen = vec![false; COLNO * ROWNO];
+        let mut sizes = Vec::new();
+        for x in 0..COLNO as i32 {
+            for y in 0..ROWNO as i32 {
+                if canvas.typ(x, y) != typ || seen[y as usize * COLNO + x as usize] {
+                    continue;
+                }
+                seen[y as usize * COLNO + x as usize] = true;
+                let (mut pending, mut size) = (vec![(x, y)], 0);
+                while let Some((cx, cy)) = pending.pop() {
+                    size += 1;
+                    for (dx, dy) in DIRS {
+                        let (nx, ny) = (cx + dx, cy + dy);
+                        if canvas.get(nx, ny).is_some_and(|c| c.typ == typ)
+                            && !seen[ny as usize * COLNO + nx as usize]
+                        {
+                            seen[ny as usize * COLNO + nx as usize] = true;
+                            pending.push((nx, ny));
+                        }
+                    }
+                }
+                sizes.push(size);
+            }
+        }
+        sizes
+    }
+
+    #[test]
+    fn mines_is_deterministic() {
+        assert_eq!(build(42), build(42));
+        assert_ne!(build(42), build(43));
+    }
+
+    #[test]
+    fn mines_floor_is_lit_and_walled() {
+        let canvas = build(7);
+        let mut floor = 0;
+        for x in 0..COLNO as i32 {
+            for y in 0..ROWNO as i32 {
+                let cell = canvas.get(x, y).unwrap();
+                if cell.typ == LocationType::Room {
+                    floor += 1;
+                    assert!(cell.lit);
+                    // Floor never touches bare stone once walled.
+                    for (dx, dy) in DIRS {
+                        assert_ne!(canvas.typ(x + dx, y + dy), LocationType::Stone);
+                    }
+                }
+            }
+        }
+        assert!(floor > 100, "only {floor} floor cells");
+        // Column 0 is outside the cave area.
+        assert!((0..ROWNO as i32).all(|y| canvas.typ(0, y) == LocationType::Stone));
+    }
+
+    #[test]
+    fn joined_caves_become_connected_rooms() {
+        let params = InitParams {
+            joined: true,
+            ..mines_params()
+        };
+        for seed in 0..20 {
+            let canvas = build_with(seed, &params);
+            assert_ne!(canvas, build(seed));
+            assert!(!canvas.rooms.is_empty());
+            assert!(canvas.rooms.iter().all(|r| r.irregular));
+            assert_eq!(regions(&canvas, LocationType::Room).len(), 1, "seed {seed}");
+        }
+        // Unjoined caves are left apart, tiny holes and all.
+        assert!((0..20).any(|seed| regions(&build(seed), LocationType::Room).len() > 1));
+    }
+}
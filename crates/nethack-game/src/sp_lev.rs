@@ -0,0 +1,1207 @@
+//! Interpreter for compiled special levels, ported from the opcode loop in
+//! `sp_lev.c` (`sp_level_coder()`).
+//!
+//! Opcodes are ported as level building needs them; anything else stops
+//! execution with [`SpLevError::Unsupported`].
+
+use nethack_data::grave;
+use nethack_rng::NhRng;
+use nethack_types::{
+    COLNO, DoorState, LevelFlags, LocationType, LvlInitStyle, ROWNO, RoomType, Selection,
+    SpLevOpcode, SpOpcode, SpOperand,
+};
+
+use crate::canvas::{InitParams, LevelCanvas, RoomRegion, WallInfo};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SpLevError {
+    #[error("stack underflow at opcode {index} ({opcode:?})")]
+    StackUnderflow { index: usize, opcode: SpOpcode },
+    #[error("expected {expected} operand at opcode {index} ({opcode:?}), got {found:?}")]
+    TypeMismatch {
+        index: usize,
+        opcode: SpOpcode,
+        expected: &'static str,
+        found: SpOperand,
+    },
+    #[error("jump from opcode {index} to negative target {target}")]
+    BadJump { index: usize, target: i64 },
+    #[error("unsupported opcode {opcode:?} at opcode {index}")]
+    Unsupported { index: usize, opcode: SpOpcode },
+}
+
+/// `SP_CPUFLAG_*`: the bits of the result `SPO_CMP` pushes.
+const CPUFLAG_LT: i64 = 1;
+const CPUFLAG_GT: i64 = 2;
+const CPUFLAG_EQ: i64 = 4;
+
+/// `SET_LIT_NOCHANGE`: a map char that leaves lighting as it is.
+const LIT_NOCHANGE: i16 = -2;
+
+/// Room alignments from `sp_lev.h` (`LEFT`/`TOP` is 1).
+const ALIGN_CENTER: i64 = 3;
+const ALIGN_RIGHT: i64 = 5;
+const ALIGN_BOTTOM: i64 = 5;
+
+/// Drawbridge directions from `rm.h` (`DB_NORTH` etc.).
+const DB_NORTH: i64 = 0;
+const DB_SOUTH: i64 = 1;
+const DB_EAST: i64 = 2;
+const DB_WEST: i64 = 3;
+/// `DB_LAVA`: the span is over lava rather than a moat.
+const DB_LAVA: u8 = 0x08;
+
+/// `W_NORTH` etc. from `sp_lev.h`.
+const WALL_NORTH: i64 = 1;
+const WALL_SOUTH: i64 = 2;
+const WALL_EAST: i64 = 4;
+const WALL_WEST: i64 = 8;
+
+/// Interpreter state, C's `struct sp_coder` plus the `xstart`/`ystart` map
+/// origin globals.
+struct Coder<'a> {
+    ops: &'a [SpLevOpcode],
+    /// Index of the opcode being executed.
+    pc: usize,
+    stack: Vec<SpOperand>,
+    /// Origin that non-random coordinates are relative to.
+    xstart: i32,
+    ystart: i32,
+    /// Rooms being built, innermost last, as indexes into the canvas rooms
+    /// (C's `tmproomlist`). `None` marks a room that could not be made.
+    rooms: Vec<Option<usize>>,
+    canvas: &'a mut LevelCanvas,
+    /// Dungeon depth of the level, C's `depth(&u.uz)`.
+    depth: i32,
+    rng: &'a mut NhRng,
+}
+
+/// Run a compiled special level against `canvas`, for a level at dungeon
+/// depth `depth`.
+pub fn execute(
+    ops: &[SpLevOpcode],
+    canvas: &mut LevelCanvas,
+    depth: i32,
+    rng: &mut NhRng,
+) -> Result<(), SpLevError> {
+    Coder {
+        ops,
+        pc: 0,
+        stack: Vec::new(),
+        // sp_level_coder_init(): until a MAP moves it, the origin is column 1.
+        xstart: 1,
+        ystart: 0,
+        rooms: Vec::new(),
+        canvas,
+        depth,
+        rng,
+    }
+    .run()
+}
+
+impl Coder<'_> {
+    fn run(&mut self) -> Result<(), SpLevError> {
+        while self.pc < self.ops.len() {
+            let op = &self.ops[self.pc];
+            match op.opcode {
+                SpOpcode::Null => {}
+                SpOpcode::Exit => break,
+                SpOpcode::Push => {
+                    if let Some(operand) = &op.operand {
+                        self.stack.push(operand.clone());
+                    }
+                }
+                SpOpcode::Pop => {
+                    self.pop()?;
+                }
+                SpOpcode::Copy => {
+                    let top = self.pop()?;
+                    self.stack.push(top.clone());
+                    self.stack.push(top);
+                }
+                SpOpcode::InitLevel => self.init_level()?,
+                SpOpcode::LevelFlags => {
+                    let flags = self.pop_int()?;
+                    self.canvas.flags |= LevelFlags::from_bits_truncate(flags as u32);
+                }
+                SpOpcode::Rn2 => {
+                    let n = self.pop_int()?;
+                    let roll = if n > 1 { self.rng.rn2(n as i32) } else { 0 };
+                    self.stack.push(SpOperand::Int(roll.into()));
+                }
+                SpOpcode::Cmp => {
+                    let b = self.pop()?;
+                    let a = self.pop()?;
+                    let flags = match (a, b) {
+                        (SpOperand::Int(a), SpOperand::Int(b)) if a < b => CPUFLAG_LT,
+                        (SpOperand::Int(a), SpOperand::Int(b)) if a > b => CPUFLAG_GT,
+                        (a, b) if a == b => CPUFLAG_EQ,
+                        _ => 0,
+                    };
+                    self.stack.push(SpOperand::Int(flags));
+                }
+                opcode if opcode.is_jump() => {
+                    let offset = self.pop_int()?;
+                    let flags = if opcode == SpOpcode::Jmp {
+                        CPUFLAG_LT | CPUFLAG_GT | CPUFLAG_EQ
+                    } else {
+                        self.pop_int()?
+                    };
+                    if jump_taken(opcode, flags) {
+                        // sp_lev.c adds the offset to the jump's own index.
+                        let target = self.pc as i64 + offset;
+                        if target < 0 {
+                            return Err(SpLevError::BadJump {
+                                index: self.pc,
+                                target,
+                            });
+                        }
+                        self.pc = target as usize;
+                        continue;
+                    }
+                }
+                SpOpcode::SelPoint => {
+                    let (x, y) = self.pop_coord()?;
+                    let mut sel = Selection::new();
+                    sel.set(x, y, true);
+                    self.stack.push(SpOperand::Sel { sel, raw: None });
+                }
+                SpOpcode::Terrain => self.terrain()?,
+                SpOpcode::Fountain => self.feature(LocationType::Fountain)?,
+                SpOpcode::Sink => self.feature(LocationType::Sink)?,
+                SpOpcode::Pool => self.feature(LocationType::Pool)?,
+                SpOpcode::Region => self.region()?,
+                SpOpcode::Room => self.room()?,
+                SpOpcode::EndRoom => {
+                    self.rooms.pop();
+                }
+                SpOpcode::RoomDoor => self.room_door()?,
+                SpOpcode::Drawbridge => self.drawbridge()?,
+                SpOpcode::Grave => self.grave()?,
+                SpOpcode::NonDiggable => self.wall_property(WallInfo::NONDIGGABLE)?,
+                SpOpcode::NonPasswall => self.wall_property(WallInfo::NONPASSWALL)?,
+                opcode => {
+                    return Err(SpLevError::Unsupported {
+                        index: self.pc,
+                        opcode,
+                    });
+                }
+            }
+            self.pc += 1;
+        }
+        Ok(())
+    }
+
+    fn opcode(&self) -> SpOpcode {
+        self.ops[self.pc].opcode
+    }
+
+    fn pop(&mut self) -> Result<SpOperand, SpLevError> {
+        self.stack.pop().ok_or(SpLevError::StackUnderflow {
+            index: self.pc,
+            opcode: self.opcode(),
+        })
+    }
+
+    fn pop_int(&mut self) -> Result<i64, SpLevError> {
+        match self.pop()? {
+            SpOperand::Int(n) => Ok(n),
+            other => Err(self.mismatch("int", other)),
+        }
+    }
+
+    fn pop_str(&mut self) -> Result<String, SpLevError> {
+        match self.pop()? {
+            SpOperand::String(s) => Ok(s),
+            other => Err(self.mismatch("string", other)),
+        }
+    }
+
+    /// Pop a fixed coordinate, translated to map coordinates.
+    fn pop_coord(&mut self) -> Result<(i32, i32), SpLevError> {
+        match self.pop()? {
+            SpOperand::Coord {
+                x,
+                y,
+                is_random: false,
+                ..
+            } => Ok(self.map_location(x.into(), y.into())),
+            // Random placement needs get_location()'s humidity checks.
+            SpOperand::Coord { .. } => Err(SpLevError::Unsupported {
+                index: self.pc,
+                opcode: self.opcode(),
+            }),
+            other => Err(self.mismatch("coord", other)),
+        }
+    }
+
+    fn pop_sel(&mut self) -> Result<Selection, SpLevError> {
+        match self.pop()? {
+            SpOperand::Sel { sel, .. } => Ok(sel),
+            other => Err(self.mismatch("selection", other)),
+        }
+    }
+
+    fn pop_mapchar(&mut self) -> Result<(Option<LocationType>, i16), SpLevError> {
+        match self.pop()? {
+            SpOperand::MapChar { typ, lit } => Ok((location_type(typ.into()), lit)),
+            other => Err(self.mismatch("mapchar", other)),
+        }
+    }
+
+    fn mismatch(&self, expected: &'static str, found: SpOperand) -> SpLevError {
+        SpLevError::TypeMismatch {
+            index: self.pc,
+            opcode: self.opcode(),
+            expected,
+            found,
+        }
+    }
+
+    /// Pop a region, translated to map coordinates as `get_location()` does.
+    fn pop_region(&mut self) -> Result<(i32, i32, i32, i32), SpLevError> {
+        match self.pop()? {
+            SpOperand::Region { x1, y1, x2, y2 } => {
+                let (x1, y1) = self.map_location(x1.into(), y1.into());
+                let (x2, y2) = self.map_location(x2.into(), y2.into());
+                Ok((x1, y1, x2, y2))
+            }
+            other => Err(self.mismatch("region", other)),
+        }
+    }
+
+    /// The room that in-room opcodes apply to (C's `coder->croom`).
+    fn croom(&self) -> Option<RoomRegion> {
+        self.rooms
+            .last()
+            .copied()
+            .flatten()
+            .map(|i| self.canvas.rooms[i])
+    }
+
+    /// Offset a non-negative (fixed) location by the current room's corner,
+    /// or by the map origin outside a room.
+    fn map_location(&self, x: i32, y: i32) -> (i32, i32) {
+        if x < 0 {
+            return (x, y);
+        }
+        match self.croom() {
+            Some(room) => (x + room.x1, y + room.y1),
+            None => (x + self.xstart, y + self.ystart),
+        }
+    }
+
+    /// `SPO_INITLEVEL`: eight ints pushed as style, filling, walled, lit,
+    /// joined, smoothed, bg, fg.
+    fn init_level(&mut self) -> Result<(), SpLevError> {
+        let fg = self.pop_int()?;
+        let bg = self.pop_int()?;
+        let smoothed = self.pop_int()?;
+        let joined = self.pop_int()?;
+        let lit = self.pop_int()?;
+        let walled = self.pop_int()?;
+        let filling = self.pop_int()?;
+        let style = self.pop_int()?;
+        let Some(style) = u8::try_from(style).ok().and_then(LvlInitStyle::from_repr) else {
+            return Err(self.mismatch("init style", SpOperand::Int(style)));
+        };
+        let params = InitParams {
+            filling: location_type(filling),
+            walled: walled != 0,
+            lit: (lit >= 0).then_some(lit != 0),
+            joined: joined != 0,
+            smoothed: smoothed != 0,
+            bg: location_type(bg).unwrap_or(LocationType::Stone),
+            fg: location_type(fg).unwrap_or(LocationType::Room),
+        };
+        if !self.canvas.init(style, &params, self.rng) {
+            return Err(SpLevError::Unsupported {
+                index: self.pc,
+                opcode: self.opcode(),
+            });
+        }
+        Ok(())
+    }
+
+    /// `SPO_TERRAIN`: set every selected cell, as `sel_set_ter()`.
+    fn terrain(&mut self) -> Result<(), SpLevError> {
+        let (typ, lit) = self.pop_mapchar()?;
+        let sel = self.pop_sel()?;
+        // Map chars past MAX_TYPE (such as 'x') leave the terrain alone.
+        let Some(typ) = typ else {
+            return Ok(());
+        };
+        for (x, y) in sel.iter() {
+            if lit == LIT_NOCHANGE && typ != LocationType::LavaPool {
+                self.canvas.set_typ(x, y, typ);
+            } else {
+                let lit = (lit >= 0).then_some(lit != 0);
+                self.canvas.set_typlit(x, y, typ, lit, self.rng);
+            }
+        }
+        Ok(())
+    }
+
+    /// `SPO_FOUNTAIN` / `SPO_SINK` / `SPO_POOL`: set every spot of the
+    /// popped selection (a `SEL_POINT` for a single coordinate), keeping
+    /// existing furniture and replacing anything else.
+    fn feature(&mut self, typ: LocationType) -> Result<(), SpLevError> {
+        let sel = self.pop_sel()?;
+        for (x, y) in sel.iter() {
+            let here = self.canvas.typ(x, y);
+            if here.is_furniture() {
+                continue;
+            }
+            if !here.can_place_furniture() {
+                log::warn!("placing {typ:?} on {here:?} at ({x},{y})");
+            }
+            self.canvas.set_typ(x, y, typ);
+        }
+        Ok(())
+    }
+
+    /// `litstate_rnd()`: a negative `lit` is random, and usually lit on
+    /// shallow levels. Makes the same one or two core draws as C.
+    fn litstate_rnd(&mut self, lit: i64) -> bool {
+        if lit < 0 {
+            self.rng.rnd(1 + self.depth.abs()) < 11 && self.rng.rn2(77) != 0
+        } else {
+            lit != 0
+        }
+    }
+
+    /// `SPO_REGION`: light the area and, unless it is a plain ordinary
+    /// region, record it as a room for the filler and open it for the
+    /// contents up to the matching `SPO_ENDROOM`. Irregular rooms are kept
+    /// as their bounding rectangle.
+    fn region(&mut self) -> Result<(), SpLevError> {
+        let flags = self.pop_int()?;
+        let mut rtype = self.pop_int()?;
+        let lit = self.pop_int()?;
+        let (x1, y1, x2, y2) = self.pop_region()?;
+        let lit = self.litstate_rnd(lit);
+        let unfilled = rtype > i64::from(RoomType::MAX);
+        if unfilled {
+            rtype -= i64::from(RoomType::MAX) + 1;
+        }
+        let Some(rtype) = u8::try_from(rtype).ok().and_then(RoomType::from_repr) else {
+            return Err(self.mismatch("room type", SpOperand::Int(rtype)));
+        };
+        let irregular = flags & 2 != 0;
+        if rtype == RoomType::Ordinary && !irregular && !unfilled {
+            if lit {
+                self.canvas.light_rect(x1, y1, x2, y2);
+            }
+            // No room is made, so the contents stay relative to the map.
+            self.rooms.push(None);
+            return Ok(());
+        }
+        // add_room() lights the walls around the room as well.
+        if lit {
+            self.canvas.light_rect(x1 - 1, y1 - 1, x2 + 1, y2 + 1);
+        }
+        self.canvas.rooms.push(RoomRegion {
+            x1,
+            y1,
+            x2,
+            y2,
+            rtype,
+            irregular,
+            joined: flags & 4 == 0,
+            needs_fill: !unfilled,
+        });
+        self.rooms.push(Some(self.canvas.rooms.len() - 1));
+        Ok(())
+    }
+
+    /// `SPO_ROOM`: ten ints pushed as type, chance, lit, flags, xalign,
+    /// yalign, x, y, w, h. Only fully placed rooms are supported; random
+    /// ones need `create_room()`'s rectangle search.
+    fn room(&mut self) -> Result<(), SpLevError> {
+        let h = self.pop_int()? as i32;
+        let w = self.pop_int()? as i32;
+        let y = self.pop_int()? as i32;
+        let x = self.pop_int()? as i32;
+        let yalign = self.pop_int()?;
+        let xalign = self.pop_int()?;
+        let flags = self.pop_int()?;
+        let lit = self.pop_int()?;
+        let chance = self.pop_int()?;
+        let rtype = self.pop_int()?;
+        if [x, y, w, h].iter().any(|&n| n < 0) || xalign < 0 || yalign < 0 {
+            return Err(SpLevError::Unsupported {
+                index: self.pc,
+                opcode: self.opcode(),
+            });
+        }
+        let Some(mut rtype) = u8::try_from(rtype).ok().and_then(RoomType::from_repr) else {
+            return Err(self.mismatch("room type", SpOperand::Int(rtype)));
+        };
+        // build_room(): a failed chance roll leaves an ordinary room.
+        if chance != 0 && i64::from(self.rng.rn2(100)) >= chance {
+            rtype = RoomType::Ordinary;
+        }
+        let lit = self.litstate_rnd(lit);
+
+        // create_room(): x and y pick a cell of a 5x5 grid over the map,
+        // and the alignment places the room within it.
+        let (cols, rows) = (COLNO as i32, ROWNO as i32);
+        let mut lx = (x - 1) * cols / 5 + 1;
+        let mut ly = (y - 1) * rows / 5 + 1;
+        match xalign {
+            ALIGN_CENTER => lx += (cols / 5 - w) / 2,
+            ALIGN_RIGHT => lx += cols / 5 - w,
+            _ => {}
+        }
+        match yalign {
+            ALIGN_CENTER => ly += (rows / 5 - h) / 2,
+            ALIGN_BOTTOM => ly += rows / 5 - h,
+            _ => {}
+        }
+        if lx + w - 1 > cols - 2 {
+            lx = cols - w - 3;
+        }
+        lx = lx.max(2);
+        if ly + h - 1 > rows - 2 {
+            ly = rows - h - 3;
+        }
+        ly = ly.max(2);
+
+        let index = self
+            .canvas
+            .add_room(lx, ly, lx + w - 1, ly + h - 1, lit, rtype);
+        let room = &mut self.canvas.rooms[index];
+        room.needs_fill = flags & 1 != 0;
+        room.joined = flags & 4 == 0;
+        self.rooms.push(Some(index));
+        Ok(())
+    }
+
+    /// `SPO_ROOMDOOR`: four ints pushed as pos, mask, secret, wall, placed
+    /// on a wall of the current room as `create_door()` does. Outside a room
+    /// the door is dropped, as in C. Only a single fixed wall is supported;
+    /// random or combined walls need `okdoor()`'s retry loop.
+    ///
+    /// C trusts a fixed position and would put the door past the end of
+    /// the wall, so an out-of-range position is warned about and skipped.
+    fn room_door(&mut self) -> Result<(), SpLevError> {
+        let wall = self.pop_int()?;
+        let secret = self.pop_int()?;
+        let mask = self.pop_int()?;
+        let pos = self.pop_int()?;
+        let Some(room) = self.croom() else {
+            return Ok(());
+        };
+        let wall_len = match wall {
+            WALL_NORTH | WALL_SOUTH => room.x2 - room.x1 + 1,
+            WALL_EAST | WALL_WEST => room.y2 - room.y1 + 1,
+            _ => {
+                return Err(SpLevError::Unsupported {
+                    index: self.pc,
+                    opcode: self.opcode(),
+                });
+            }
+        };
+
+        let secret = if secret < 0 {
+            self.rng.rn2(2) != 0
+        } else {
+            secret != 0
+        };
+        let mask = if mask < 0 {
+            self.random_door_mask(secret)
+        } else {
+            DoorState::from_bits_truncate(mask as u8)
+        };
+        let pos = if pos < 0 {
+            self.rng.rn2(wall_len)
+        } else {
+            pos as i32
+        };
+        if pos >= wall_len {
+            log::warn!(
+                "room door position {pos} is past the end of a wall of length {wall_len}; skipping it"
+            );
+            return Ok(());
+        }
+
+        let (x, y) = match wall {
+            WALL_NORTH => (room.x1 + pos, room.y1 - 1),
+            WALL_SOUTH => (room.x1 + pos, room.y2 + 1),
+            WALL_WEST => (room.x1 - 1, room.y1 + pos),
+            _ => (room.x2 + 1, room.y1 + pos),
+        };
+        if let Some(cell) = self.canvas.get_mut(x, y) {
+            cell.typ = if secret {
+                LocationType::SDoor
+            } else {
+                LocationType::Door
+            };
+            cell.doormask = mask;
+        }
+        Ok(())
+    }
+
+    /// `create_door()`'s roll for a `random` door state.
+    fn random_door_mask(&mut self, secret: bool) -> DoorState {
+        if secret {
+            let mut mask = if self.rng.rn2(5) == 0 {
+                DoorState::LOCKED
+            } else {
+                DoorState::CLOSED
+            };
+            if self.rng.rn2(20) == 0 {
+                mask |= DoorState::TRAPPED;
+            }
+            return mask;
+        }
+        if self.rng.rn2(3) != 0 {
+            return DoorState::NODOOR;
+        }
+        if self.rng.rn2(5) == 0 {
+            return DoorState::ISOPEN;
+        }
+        let mut mask = if self.rng.rn2(6) == 0 {
+            DoorState::LOCKED
+        } else {
+            DoorState::CLOSED
+        };
+        if self.rng.rn2(25) == 0 {
+            mask |= DoorState::TRAPPED;
+        }
+        mask
+    }
+
+    /// `SPO_DRAWBRIDGE`: the span's coord, then the open state (-1 for
+    /// random) and a `DB_*` direction, built as `create_drawbridge()` does.
+    /// The span sits on water or lava with the portcullis in the wall next
+    /// to it; without a wall there, C complains and builds nothing. The
+    /// span's `drawbridgemask` keeps the direction and whether it is over
+    /// lava, and the two cells are marked as running across each other.
+    fn drawbridge(&mut self) -> Result<(), SpLevError> {
+        let dir = self.pop_int()?;
+        let open = self.pop_int()?;
+        let (x, y) = self.pop_coord()?;
+        let open = if open == -1 {
+            self.rng.rn2(2) == 0
+        } else {
+            open != 0
+        };
+        let (wx, wy, horizontal, dir) = match dir {
+            DB_NORTH => (x, y - 1, true, dir),
+            DB_SOUTH => (x, y + 1, true, dir),
+            DB_EAST => (x + 1, y, false, dir),
+            _ => (x - 1, y, false, DB_WEST),
+        };
+        if !self.canvas.typ(wx, wy).is_wall() {
+            log::warn!("cannot create drawbridge at ({x},{y}): no wall at ({wx},{wy})");
+            return Ok(());
+        }
+
+        // Built raised, then lowered if it starts open.
+        let mut typ = LocationType::DrawbridgeUp;
+        if open {
+            typ = typ.drawbridge_toggle().unwrap_or(typ);
+        }
+        if let Some(span) = self.canvas.get_mut(x, y) {
+            let lava = span.typ == LocationType::LavaPool;
+            span.typ = typ;
+            span.horizontal = !horizontal;
+            span.drawbridgemask = dir as u8 | if lava { DB_LAVA } else { 0 };
+        }
+        if let Some(wall) = self.canvas.get_mut(wx, wy) {
+            wall.horizontal = horizontal;
+            if open {
+                wall.typ = LocationType::Door;
+                wall.doormask = DoorState::NODOOR;
+            } else {
+                wall.typ = LocationType::DbWall;
+                wall.wall_info = WallInfo::NONDIGGABLE;
+            }
+        }
+        Ok(())
+    }
+
+    /// `SPO_GRAVE`: a coordinate, the epitaph and how to use it: 2 engraves
+    /// the given text, 1 a random epitaph (as `make_grave()` with no text),
+    /// anything else leaves the headstone blank.
+    fn grave(&mut self) -> Result<(), SpLevError> {
+        let kind = self.pop_int()?;
+        let text = self.pop_str()?;
+        let (x, y) = self.pop_coord()?;
+        if self.canvas.get(x, y).is_none() {
+            return Ok(());
+        }
+        self.canvas.set_typ(x, y, LocationType::Grave);
+        match kind {
+            2 => self.canvas.engrave(x, y, &text),
+            1 => {
+                let epitaph = grave::random_epitaph(self.rng);
+                self.canvas.engrave(x, y, epitaph);
+            }
+            _ => self.canvas.del_engr_at(x, y),
+        }
+        Ok(())
+    }
+
+    /// `SPO_NON_DIGGABLE` / `SPO_NON_PASSWALL`.
+    fn wall_property(&mut self, prop: WallInfo) -> Result<(), SpLevError> {
+        let (x1, y1, x2, y2) = self.pop_region()?;
+        self.canvas.set_wall_property(x1, y1, x2, y2, prop);
+        Ok(())
+    }
+}
+
+/// `spo_conditional_jump()`'s test of the `SPO_CMP` flags. Unequal strings
+/// set no flag at all, so they take neither `JE` nor `JNE`.
+fn jump_taken(opcode: SpOpcode, flags: i64) -> bool {
+    let test = match opcode {
+        SpOpcode::Jmp => return true,
+        SpOpcode::Jl => CPUFLAG_LT,
+        SpOpcode::Jle => CPUFLAG_LT | CPUFLAG_EQ,
+        SpOpcode::Jg => CPUFLAG_GT,
+        SpOpcode::Jge => CPUFLAG_GT | CPUFLAG_EQ,
+        SpOpcode::Je => CPUFLAG_EQ,
+        SpOpcode::Jne => !CPUFLAG_EQ,
+        _ => 0,
+    };
+    flags & test != 0
+}
+
+/// A terrain type from an int operand; negative and out-of-range values
+/// (random fills, `INVALID_TYPE`) have none.
+fn location_type(typ: i64) -> Option<LocationType> {
+    u8::try_from(typ).ok().and_then(LocationType::from_repr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nethack_types::{COLNO, HumidityFlags, LocationType, ObjectClass, ROWNO};
+
+    fn op(opcode: SpOpcode) -> SpLevOpcode {
+        SpLevOpcode {
+            opcode,
+            operand: None,
+        }
+    }
+
+    fn push(operand: SpOperand) -> SpLevOpcode {
+        SpLevOpcode {
+            opcode: SpOpcode::Push,
+            operand: Some(operand),
+        }
+    }
+
+    fn region(x1: i16, y1: i16, x2: i16, y2: i16) -> SpLevOpcode {
+        push(SpOperand::Region { x1, y1, x2, y2 })
+    }
+
+    #[test]
+    fn non_diggable_region() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        canvas.set_typ(5, 5, LocationType::Room);
+        let ops = [region(2, 3, 10, 8), op(SpOpcode::NonDiggable)];
+        execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+
+        // Region coordinates are relative to the default origin (1, 0).
+        for x in 0..COLNO as i32 {
+            for y in 0..ROWNO as i32 {
+                let cell = canvas.get(x, y).unwrap();
+                let inside = (3..=11).contains(&x) && (3..=8).contains(&y);
+                let expected = inside && cell.typ != LocationType::Room;
+                assert_eq!(
+                    cell.wall_info.contains(WallInfo::NONDIGGABLE),
+                    expected,
+                    "({x},{y})"
+                );
+                assert!(!cell.wall_info.contains(WallInfo::NONPASSWALL));
+            }
+        }
+    }
+
+    #[test]
+    fn non_passwall_region() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        let ops = [region(0, 0, 3, 3), op(SpOpcode::NonPasswall)];
+        execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+        let flags = canvas.get(2, 2).unwrap().wall_info;
+        assert_eq!(flags, WallInfo::NONPASSWALL);
+        assert!(canvas.get(6, 2).unwrap().wall_info.is_empty());
+    }
+
+    #[test]
+    fn missing_region_is_an_error() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        let err = execute(&[op(SpOpcode::NonDiggable)], &mut canvas, 1, &mut rng).unwrap_err();
+        assert!(matches!(err, SpLevError::StackUnderflow { index: 0, .. }));
+        let err = execute(
+            &[push(SpOperand::Int(3)), op(SpOpcode::NonDiggable)],
+            &mut canvas,
+            1,
+            &mut rng,
+        )
+        .unwrap_err();
+        assert!(matches!(err, SpLevError::TypeMismatch { index: 1, .. }));
+    }
+
+    #[test]
+    fn rogue_init_is_unsupported() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        // style, filling, walled, lit, joined, smoothed, bg, fg
+        let init = |style: LvlInitStyle| {
+            let args = [style as i64, -1, 0, 1, 0, 0, 0, 0];
+            let mut ops: Vec<_> = args.map(|a| push(SpOperand::Int(a))).into();
+            ops.push(op(SpOpcode::InitLevel));
+            ops
+        };
+        let err = execute(&init(LvlInitStyle::Rogue), &mut canvas, 1, &mut rng).unwrap_err();
+        assert!(matches!(
+            err,
+            SpLevError::Unsupported {
+                index: 8,
+                opcode: SpOpcode::InitLevel
+            }
+        ));
+        assert_eq!(canvas, LevelCanvas::new());
+        execute(&init(LvlInitStyle::MazeGrid), &mut canvas, 1, &mut rng).unwrap();
+    }
+
+    #[test]
+    fn features_keep_existing_furniture() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        canvas.set_typ(3, 2, LocationType::Room);
+        canvas.set_typ(5, 2, LocationType::Stairs);
+        canvas.set_typ(7, 2, LocationType::HWall);
+        let coord = |x| {
+            push(SpOperand::Coord {
+                x,
+                y: 2,
+                is_random: false,
+                flags: HumidityFlags::empty(),
+            })
+        };
+        let ops = [
+            coord(2),
+            op(SpOpcode::SelPoint),
+            op(SpOpcode::Fountain),
+            coord(4),
+            op(SpOpcode::SelPoint),
+            op(SpOpcode::Sink),
+            coord(6),
+            op(SpOpcode::SelPoint),
+            op(SpOpcode::Pool),
+        ];
+        execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+        assert_eq!(canvas.typ(3, 2), LocationType::Fountain);
+        assert_eq!(canvas.typ(5, 2), LocationType::Stairs);
+        // A wall is a bad spot but still gets the pool, as in C.
+        assert_eq!(canvas.typ(7, 2), LocationType::Pool);
+    }
+
+    fn compile(src: &str) -> Vec<SpLevOpcode> {
+        let des = nethack_data::des_parser::parse_des_file(src).unwrap();
+        des.levels.into_iter().next().unwrap().opcodes
+    }
+
+    #[test]
+    fn compiled_features_run() {
+        let ops = compile("MAZE:\"test\",' '\nFOUNTAIN:(3,2)\nSINK:(5,2)\nPOOL:(7,2)\n");
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+        assert_eq!(canvas.typ(4, 2), LocationType::Fountain);
+        assert_eq!(canvas.typ(6, 2), LocationType::Sink);
+        assert_eq!(canvas.typ(8, 2), LocationType::Pool);
+    }
+
+    #[test]
+    fn graves_get_their_epitaphs() {
+        let ops = compile("MAZE:\"test\",' '\nGRAVE:(3,2),\"Here lies Dudley\"\nGRAVE:(5,2)\n");
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(3);
+        execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+        assert_eq!(canvas.typ(4, 2), LocationType::Grave);
+        assert_eq!(canvas.typ(6, 2), LocationType::Grave);
+        assert_eq!(canvas.engraving_at(4, 2).unwrap().text, "Here lies Dudley");
+        // An epitaph-less GRAVE draws one of grave.rs's epitaphs.
+        let mut expected = NhRng::new(3);
+        assert_eq!(
+            canvas.engraving_at(6, 2).unwrap().text,
+            grave::random_epitaph(&mut expected)
+        );
+        assert_eq!(canvas.engravings.len(), 2);
+        assert_eq!(rng.rn2(1000), expected.rn2(1000));
+    }
+
+    #[test]
+    fn switch_case_with_if_else_and_break() {
+        // CASE 2 breaks from inside the IF but runs on after the ELSE;
+        // CASE 3 falls through into DEFAULT.
+        let features = |value: i64, cond: i64| {
+            let ops = compile(&format!(
+                "MAZE:\"test\",' '\n\
+                 SWITCH [{value}] {{\n\
+                 CASE 1: TERRAIN:(1,1),'.'\nBREAK\n\
+                 CASE 2: IF [{cond} == 1] {{ TERRAIN:(2,2),'.'\nBREAK\n}} ELSE {{ TERRAIN:(3,3),'.'\n}}\n\
+                 TERRAIN:(4,4),'.'\nBREAK\n\
+                 CASE 3: TERRAIN:(5,5),'.'\n\
+                 DEFAULT: TERRAIN:(6,6),'.'\n\
+                 }}\n\
+                 TERRAIN:(7,7),'.'\n"
+            ));
+            let mut canvas = LevelCanvas::new();
+            let mut rng = NhRng::new(1);
+            execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+            // The map origin is at column 1.
+            (1..=7)
+                .filter(|&i| canvas.typ(i + 1, i) == LocationType::Room)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(features(1, 0), [1, 7]);
+        assert_eq!(features(2, 1), [2, 7]);
+        assert_eq!(features(2, 0), [3, 4, 7]);
+        assert_eq!(features(3, 0), [5, 6, 7]);
+        assert_eq!(features(9, 0), [6, 7]);
+    }
+
+    #[test]
+    fn percent_terrain_applies_about_half_the_time() {
+        let ops = compile("MAZE:\"test\",' '\n[50%]: TERRAIN:(5,5),'.'\n");
+        let runs = 1000;
+        let hits = (0..runs)
+            .filter(|&seed| {
+                let mut canvas = LevelCanvas::new();
+                let mut rng = NhRng::new(seed);
+                execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+                assert!(canvas.flags.contains(LevelFlags::MAZELEVEL));
+                // (5,5) is relative to the map origin at column 1.
+                canvas.typ(6, 5) == LocationType::Room
+            })
+            .count();
+        assert!((400..600).contains(&hits), "{hits}/{runs} terrain changes");
+    }
+
+    #[test]
+    fn weapon_shop_region_is_stocked_with_weapons() {
+        let ops = compile("MAZE:\"test\",' '\nREGION:(10,5,14,8),lit,\"weapon shop\"\n");
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+        let [shop] = canvas.rooms[..] else {
+            panic!("expected one room, got {:?}", canvas.rooms);
+        };
+        assert_eq!((shop.x1, shop.y1, shop.x2, shop.y2), (11, 5, 15, 8));
+        assert_eq!(shop.rtype.shop_class(), Some(ObjectClass::Weapon));
+        assert!(shop.needs_fill && shop.joined && !shop.irregular);
+        assert!(canvas.get(10, 4).unwrap().lit);
+        assert!(!canvas.get(17, 4).unwrap().lit);
+
+        let ops = compile("MAZE:\"test\",' '\nREGION:(10,5,14,8),unlit,\"zoo\",unfilled\n");
+        let mut canvas = LevelCanvas::new();
+        execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+        assert_eq!(canvas.rooms[0].rtype, RoomType::Zoo);
+        assert!(!canvas.rooms[0].needs_fill);
+        assert!(!canvas.get(12, 6).unwrap().lit);
+    }
+
+    #[test]
+    fn random_region_lighting_draws_like_c() {
+        let ops = compile("MAZE:\"test\",' '\nREGION:(10,5,14,8),random,\"ordinary\"\n");
+        for (depth, seeds) in [(1, 0..50), (40, 0..200)] {
+            for seed in seeds {
+                let mut canvas = LevelCanvas::new();
+                let mut rng = NhRng::new(seed);
+                execute(&ops, &mut canvas, depth, &mut rng).unwrap();
+                // rnd(1 + depth) < 11 && rn2(77): the second draw only
+                // happens when the first passes, always at depth 1.
+                let mut c = NhRng::new(seed);
+                let lit = c.rnd(1 + depth) < 11 && c.rn2(77) != 0;
+                assert_eq!(rng.core_draws(), c.core_draws(), "seed {seed}");
+                if depth == 1 {
+                    assert_eq!(rng.core_draws(), 2);
+                }
+                assert_eq!(canvas.get(12, 6).unwrap().lit, lit, "seed {seed}");
+            }
+        }
+    }
+
+    #[test]
+    fn region_contents_are_room_relative() {
+        let ops = compile(
+            "MAZE:\"test\",' '\n\
+             REGION:(4,3,6,5),lit,\"delphi\" {\nFOUNTAIN:(0,1)\n}\n\
+             FOUNTAIN:(0,1)\n",
+        );
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+        assert_eq!(canvas.rooms[0].rtype, RoomType::Delphi);
+        // Inside the region its corner (5,3) is the origin; after ENDROOM
+        // the map's (1,0) is again.
+        assert_eq!(canvas.typ(5, 4), LocationType::Fountain);
+        assert_eq!(canvas.typ(1, 1), LocationType::Fountain);
+    }
+
+    #[test]
+    fn comparison_jumps() {
+        // if (2 < 3) is taken, if (3 < 2) is not.
+        for (a, b, taken) in [(2, 3, true), (3, 2, false), (3, 3, false)] {
+            let mut canvas = LevelCanvas::new();
+            let mut rng = NhRng::new(1);
+            let ops = [
+                push(SpOperand::Int(a)),
+                push(SpOperand::Int(b)),
+                op(SpOpcode::Cmp),
+                push(SpOperand::Int(4)),
+                op(SpOpcode::Jl),
+                region(0, 0, 3, 3),
+                op(SpOpcode::NonDiggable),
+            ];
+            let result = execute(&ops, &mut canvas, 1, &mut rng);
+            // A taken jump lands past the end, before the region is pushed.
+            assert!(result.is_ok());
+            let flagged = !canvas.get(2, 2).unwrap().wall_info.is_empty();
+            assert_eq!(flagged, !taken, "{a} < {b}");
+        }
+    }
+
+    #[test]
+    fn cmp_pushes_flags_for_the_jump() {
+        let run = |a: SpOperand, b: SpOperand, jump: SpOpcode| {
+            let mut canvas = LevelCanvas::new();
+            let mut rng = NhRng::new(1);
+            let ops = [
+                push(a),
+                push(b),
+                op(SpOpcode::Cmp),
+                push(SpOperand::Int(4)),
+                op(jump),
+                region(0, 0, 3, 3),
+                op(SpOpcode::NonDiggable),
+            ];
+            execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+            canvas.get(2, 2).unwrap().wall_info.is_empty()
+        };
+        let (one, two) = (SpOperand::Int(1), SpOperand::Int(2));
+        assert!(run(one.clone(), two.clone(), SpOpcode::Jne));
+        assert!(run(two.clone(), one.clone(), SpOpcode::Jge));
+        assert!(!run(one.clone(), one.clone(), SpOpcode::Jne));
+        // Strings only compare equal or not; unequal ones set no flag.
+        let s = |s: &str| SpOperand::String(s.into());
+        assert!(run(s("a"), s("a"), SpOpcode::Je));
+        assert!(!run(s("a"), s("b"), SpOpcode::Je));
+        assert!(!run(s("a"), s("b"), SpOpcode::Jne));
+
+        // A jump not taken still pops the result along with its offset.
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        let ops = [
+            push(one.clone()),
+            push(two),
+            op(SpOpcode::Cmp),
+            push(SpOperand::Int(2)),
+            op(SpOpcode::Jg),
+            op(SpOpcode::Pop),
+        ];
+        let err = execute(&ops, &mut canvas, 1, &mut rng).unwrap_err();
+        assert!(
+            matches!(err, SpLevError::StackUnderflow { index: 5, .. }),
+            "{err}"
+        );
+    }
+
+    /// A lit ordinary room of `w` x `h` centred in the middle of the map.
+    fn centred_room(w: i64, h: i64) -> Vec<SpLevOpcode> {
+        room_lit(1, w, h)
+    }
+
+    /// [`centred_room`] with lighting `lit`, negative for random.
+    fn room_lit(lit: i64, w: i64, h: i64) -> Vec<SpLevOpcode> {
+        [0, 100, lit, 1, 3, 3, 3, 3, w, h]
+            .into_iter()
+            .map(|n| push(SpOperand::Int(n)))
+            .chain([op(SpOpcode::Room)])
+            .collect()
+    }
+
+    fn room_door(wall: i64, pos: i64) -> Vec<SpLevOpcode> {
+        [pos, DoorState::LOCKED.bits().into(), 0, wall]
+            .into_iter()
+            .map(|n| push(SpOperand::Int(n)))
+            .chain([op(SpOpcode::RoomDoor)])
+            .collect()
+    }
+
+    #[test]
+    fn fixed_room_is_walled_and_placed_on_the_grid() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        let mut ops = centred_room(11, 9);
+        ops.extend([
+            push(SpOperand::Coord {
+                x: 0,
+                y: 0,
+                is_random: false,
+                flags: HumidityFlags::empty(),
+            }),
+            op(SpOpcode::SelPoint),
+            op(SpOpcode::Fountain),
+            op(SpOpcode::EndRoom),
+        ]);
+        execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+
+        // Oracle's centre room: grid cell (3,3), centred.
+        let [room] = canvas.rooms[..] else {
+            panic!("expected one room, got {:?}", canvas.rooms);
+        };
+        assert_eq!((room.x1, room.y1, room.x2, room.y2), (35, 7, 45, 15));
+        assert_eq!(canvas.typ(34, 6), LocationType::TlCorner);
+        assert_eq!(canvas.typ(46, 16), LocationType::BrCorner);
+        assert_eq!(canvas.typ(40, 6), LocationType::HWall);
+        assert_eq!(canvas.typ(34, 10), LocationType::VWall);
+        assert_eq!(canvas.typ(40, 10), LocationType::Room);
+        assert!(canvas.get(34, 6).unwrap().lit);
+        // Coordinates inside the room are relative to its corner.
+        assert_eq!(canvas.typ(35, 7), LocationType::Fountain);
+    }
+
+    #[test]
+    fn random_room_lighting_draws_like_c() {
+        // rn2(100) for the chance, then rnd(1 + depth) and, when that is
+        // below 11, rn2(77).
+        for (lit, depth, draws) in [(1, 1, 1), (0, 30, 1), (-1, 1, 3)] {
+            let mut canvas = LevelCanvas::new();
+            let mut rng = NhRng::new(1);
+            execute(&room_lit(lit, 5, 5), &mut canvas, depth, &mut rng).unwrap();
+            assert_eq!(rng.core_draws(), draws, "lit {lit} depth {depth}");
+        }
+        for seed in 0..200 {
+            let mut canvas = LevelCanvas::new();
+            let mut rng = NhRng::new(seed);
+            execute(&room_lit(-1, 5, 5), &mut canvas, 40, &mut rng).unwrap();
+            let mut c = NhRng::new(seed);
+            c.rn2(100);
+            let lit = c.rnd(41) < 11 && c.rn2(77) != 0;
+            assert_eq!(rng.core_draws(), c.core_draws(), "seed {seed}");
+            let room = canvas.rooms[0];
+            assert_eq!(canvas.get(room.x1, room.y1).unwrap().lit, lit);
+        }
+    }
+
+    #[test]
+    fn room_door_on_the_wall() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        let mut ops = centred_room(3, 3);
+        ops.extend(room_door(WALL_EAST, 1));
+        execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+
+        let room = canvas.rooms[0];
+        let door = canvas.get(room.x2 + 1, room.y1 + 1).unwrap();
+        assert_eq!(door.typ, LocationType::Door);
+        assert_eq!(door.doormask, DoorState::LOCKED);
+    }
+
+    #[test]
+    fn room_door_past_the_wall_is_skipped() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        let mut ops = centred_room(3, 3);
+        ops.extend(room_door(WALL_NORTH, 5));
+        execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+
+        // C would have put the door on stone two cells past the corner.
+        let room = canvas.rooms[0];
+        assert_eq!(canvas.typ(room.x1 + 5, room.y1 - 1), LocationType::Stone);
+        for x in 0..COLNO as i32 {
+            for y in 0..ROWNO as i32 {
+                assert_ne!(canvas.typ(x, y), LocationType::Door, "door at ({x},{y})");
+            }
+        }
+    }
+
+    #[test]
+    fn room_door_outside_a_room_is_dropped() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        execute(&room_door(WALL_NORTH, 0), &mut canvas, 1, &mut rng).unwrap();
+        assert_eq!(canvas, LevelCanvas::new());
+    }
+
+    fn drawbridge(x: i16, y: i16, open: i64, dir: i64) -> [SpLevOpcode; 4] {
+        [
+            push(SpOperand::Coord {
+                x,
+                y,
+                is_random: false,
+                flags: HumidityFlags::empty(),
+            }),
+            push(SpOperand::Int(open)),
+            push(SpOperand::Int(dir)),
+            op(SpOpcode::Drawbridge),
+        ]
+    }
+
+    #[test]
+    fn drawbridge_open_and_closed() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        // Spans at columns 5 and 9 (map x 4 and 8), walls to their east.
+        for x in [6, 10] {
+            canvas.set_typ(x, 3, LocationType::VWall);
+        }
+        let mut ops = drawbridge(4, 3, 1, DB_EAST).to_vec();
+        ops.extend(drawbridge(8, 3, 0, DB_EAST));
+        execute(&ops, &mut canvas, 1, &mut rng).unwrap();
+
+        assert_eq!(canvas.typ(5, 3), LocationType::DrawbridgeDown);
+        let gate = canvas.get(6, 3).unwrap();
+        assert_eq!(
+            (gate.typ, gate.doormask),
+            (LocationType::Door, DoorState::NODOOR)
+        );
+        assert_eq!(canvas.typ(9, 3), LocationType::DrawbridgeUp);
+        let wall = canvas.get(10, 3).unwrap();
+        assert_eq!(wall.typ, LocationType::DbWall);
+        assert_eq!(wall.wall_info, WallInfo::NONDIGGABLE);
+        // An east-facing bridge spans north-south with its gate east-west.
+        for x in [5, 9] {
+            let span = canvas.get(x, 3).unwrap();
+            assert_eq!(
+                (span.drawbridgemask, span.horizontal),
+                (DB_EAST as u8, true)
+            );
+            assert!(!canvas.get(x + 1, 3).unwrap().horizontal);
+        }
+    }
+
+    #[test]
+    fn drawbridge_over_lava() {
+        let mut canvas = LevelCanvas::new();
+        let mut rng = NhRng::new(1);
+        canvas.set_typ(5, 3, LocationType::LavaPool);
+        canvas.set_typ(5, 2, LocationType::HWall);
+        execute(&drawbridge(4, 3, 0, DB_NORTH), &mut canvas, 1, &mut rng).unwrap();
+        let span = canvas.get(5, 3).unwrap();
+        assert_eq!(span.typ, LocationType::DrawbridgeUp);
+        assert_eq!(span.drawbridgemask, DB_NORTH as u8 | DB_LAVA);
+        assert!(!span.horizontal);
+        assert!(canvas.get(5, 2).unwrap().horizontal);
+    }
+
+    #[test]
+    fn drawbridge_needs_a_wall() {
+        let mut canvas = LevelCanvas::new();
+        canvas.set_typ(5, 3, LocationType::Moat);
+        let before = canvas.clone();
+        let mut rng = NhRng::new(1);
+        execute(&drawbridge(4, 3, 1, DB_NORTH), &mut canvas, 1, &mut rng).unwrap();
+        assert_eq!(canvas, before);
+    }
+}
@@ -0,0 +1,110 @@
+//! Dice strings such as `2d6+1`, as written in data files.
+
+use crate::NhRng;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    #[error("empty dice spec")]
+    Empty,
+    #[error("invalid number {0:?} in dice spec")]
+    InvalidNumber(String),
+    #[error("dice spec {0:?} needs a positive count and number of sides")]
+    NotPositive(String),
+    #[error("dice spec {0:?} can roll past the i32 range")]
+    Overflow(String),
+}
+
+fn number(s: &str) -> Result<i32, ParseError> {
+    s.trim()
+        .parse()
+        .map_err(|_| ParseError::InvalidNumber(s.trim().to_string()))
+}
+
+/// Roll a dice spec: `NdM`, `NdM+K`, `NdM-K`, or a flat integer `K`.
+/// `dM` is shorthand for `1dM`. `N` and `M` must be positive, and every
+/// possible result must fit in an `i32`; nothing is drawn for a bad spec.
+pub fn parse_and_roll(spec: &str, rng: &mut NhRng) -> Result<i32, ParseError> {
+    let spec = spec.trim();
+    if spec.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let Some((n, rest)) = spec.split_once(['d', 'D']) else {
+        return number(spec);
+    };
+    let (m, k) = match rest.find(['+', '-']) {
+        Some(i) => (&rest[..i], number(&rest[i..])?),
+        None => (rest, 0),
+    };
+    let n = if n.trim().is_empty() { 1 } else { number(n)? };
+    let m = number(m)?;
+    if n <= 0 || m <= 0 {
+        return Err(ParseError::NotPositive(spec.to_string()));
+    }
+    if n.checked_mul(m)
+        .and_then(|max| max.checked_add(k))
+        .is_none()
+    {
+        return Err(ParseError::Overflow(spec.to_string()));
+    }
+    let roll = if n == 1 { rng.rnd(m) } else { rng.d(n, m) };
+    Ok(roll + k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dice_with_bonus_stays_in_range() {
+        let mut rng = NhRng::new(42);
+        let rolls: Vec<i32> = (0..1000)
+            .map(|_| parse_and_roll("2d6+1", &mut rng).unwrap())
+            .collect();
+        assert!(rolls.iter().all(|r| (3..=13).contains(r)));
+        assert!(rolls.contains(&3) && rolls.contains(&13));
+    }
+
+    #[test]
+    fn flat_and_malformed_specs() {
+        let mut rng = NhRng::new(42);
+        assert_eq!(parse_and_roll("5", &mut rng), Ok(5));
+        assert!((1..=4).contains(&parse_and_roll("d4", &mut rng).unwrap()));
+        assert!((0..=2).contains(&parse_and_roll("1d3-1", &mut rng).unwrap()));
+        assert_eq!(parse_and_roll(" ", &mut rng), Err(ParseError::Empty));
+        assert_eq!(
+            parse_and_roll("2dx", &mut rng),
+            Err(ParseError::InvalidNumber("x".into()))
+        );
+    }
+
+    #[test]
+    fn non_positive_dice_are_rejected() {
+        let mut rng = NhRng::new(42);
+        let mut untouched = rng.clone();
+        for spec in ["-2d6", "0d6", "2d0", "d0"] {
+            assert_eq!(
+                parse_and_roll(spec, &mut rng),
+                Err(ParseError::NotPositive(spec.into())),
+                "{spec}"
+            );
+        }
+        assert_eq!(rng.rn2(1000), untouched.rn2(1000));
+        assert_eq!(parse_and_roll("-3", &mut rng), Ok(-3));
+    }
+
+    #[test]
+    fn overflowing_dice_are_rejected() {
+        let mut rng = NhRng::new(42);
+        for spec in ["65536d65536", "1d2147483647+1", "3d1000000000-1"] {
+            assert_eq!(
+                parse_and_roll(spec, &mut rng),
+                Err(ParseError::Overflow(spec.into())),
+                "{spec}"
+            );
+        }
+        assert_eq!(
+            parse_and_roll("1d2147483646+1", &mut rng).map(|r| r > 0),
+            Ok(true)
+        );
+    }
+}
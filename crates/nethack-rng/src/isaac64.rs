@@ -9,7 +9,10 @@ const SZ_LOG: u32 = 8;
 const SZ: usize = 1 << SZ_LOG; // 256
 const SEED_SZ_MAX: usize = SZ << 3; // 2048
 
-#[derive(Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "Isaac64State", into = "Isaac64State")]
 pub struct Isaac64Ctx {
     n: usize,
     r: [u64; SZ],
@@ -21,16 +24,21 @@ pub struct Isaac64Ctx {
 
 impl Isaac64Ctx {
     pub fn new(seed: &[u8]) -> Self {
-        let mut ctx = Self {
+        let mut ctx = Self::zeroed();
+        ctx.reseed(seed);
+        ctx
+    }
+
+    /// The all-zero context `isaac64_init()` clears before seeding.
+    pub fn zeroed() -> Self {
+        Self {
             n: 0,
             r: [0; SZ],
             m: [0; SZ],
             a: 0,
             b: 0,
             c: 0,
-        };
-        ctx.reseed(seed);
-        ctx
+        }
     }
 
     pub fn reseed(&mut self, seed: &[u8]) {
@@ -135,6 +143,66 @@ impl Isaac64Ctx {
         self.n -= 1;
         self.r[self.n]
     }
+
+    /// Advance as `count` calls to [`Self::next_u64`] would, dropping the
+    /// rest of each block at once instead of reading it.
+    pub fn skip(&mut self, mut count: u64) {
+        while count > 0 {
+            if self.n == 0 {
+                self.update();
+            }
+            let take = count.min(self.n as u64);
+            self.n -= take as usize;
+            count -= take;
+        }
+    }
+}
+
+/// Serialized form of [`Isaac64Ctx`]: serde has no impls for 256-element
+/// arrays, so the tables travel as sequences and are checked on the way in.
+#[derive(Serialize, Deserialize)]
+struct Isaac64State {
+    n: usize,
+    r: Vec<u64>,
+    m: Vec<u64>,
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+impl From<Isaac64Ctx> for Isaac64State {
+    fn from(ctx: Isaac64Ctx) -> Self {
+        Self {
+            n: ctx.n,
+            r: ctx.r.to_vec(),
+            m: ctx.m.to_vec(),
+            a: ctx.a,
+            b: ctx.b,
+            c: ctx.c,
+        }
+    }
+}
+
+impl TryFrom<Isaac64State> for Isaac64Ctx {
+    type Error = String;
+
+    fn try_from(state: Isaac64State) -> Result<Self, Self::Error> {
+        let table = |v: Vec<u64>, name| {
+            <[u64; SZ]>::try_from(v)
+                .map_err(|v| format!("ISAAC64 {name} has {} entries, not {SZ}", v.len()))
+        };
+        if state.n > SZ {
+            return Err(format!("ISAAC64 index {} is past {SZ}", state.n));
+        }
+        Ok(Self {
+            n: state.n,
+            r: table(state.r, "r")?,
+            m: table(state.m, "m")?,
+            a: state.a,
+            b: state.b,
+            c: state.c,
+        })
+    }
 }
 
 fn lower_bits(x: u64) -> usize {
@@ -168,6 +236,38 @@ mod tests {
         seed.to_le_bytes()
     }
 
+    #[test]
+    fn state_tables_are_checked() {
+        let mut ctx = Isaac64Ctx::new(&seed_from_u64(42));
+        ctx.next_u64();
+        let mut state = Isaac64State::from(ctx.clone());
+        let mut restored = Isaac64Ctx::try_from(state).unwrap();
+        assert_eq!(restored.next_u64(), ctx.next_u64());
+
+        state = Isaac64State::from(ctx.clone());
+        state.m.pop();
+        let err = Isaac64Ctx::try_from(state).err().unwrap();
+        assert_eq!(err, "ISAAC64 m has 255 entries, not 256");
+        state = Isaac64State::from(ctx);
+        state.n = SZ + 1;
+        assert!(Isaac64Ctx::try_from(state).is_err());
+    }
+
+    #[test]
+    fn skip_across_blocks() {
+        for count in [0, 1, 255, 256, 257, 600] {
+            let mut skipped = Isaac64Ctx::new(&seed_from_u64(9));
+            let mut stepped = skipped.clone();
+            skipped.next_u64();
+            stepped.next_u64();
+            skipped.skip(count);
+            for _ in 0..count {
+                stepped.next_u64();
+            }
+            assert_eq!(skipped.next_u64(), stepped.next_u64(), "skip {count}");
+        }
+    }
+
     #[test]
     fn raw_values_seed_42() {
         let mut ctx = Isaac64Ctx::new(&seed_from_u64(42));
@@ -260,4 +360,93 @@ mod tests {
             assert_eq!(val, e, "mismatch at index {i}");
         }
     }
+
+    /// Seed bytes for the partial-word tests; a seed of length `n` uses the
+    /// first `n`. Expected values come from `tools/isaac64_ref.c`.
+    const PARTIAL_SEED: [u8; 7] = [0x2A, 0x17, 0xC3, 0x5E, 0x91, 0x04, 0xFF];
+
+    fn check_partial_seed(len: usize, expected: [u64; 8]) {
+        let mut ctx = Isaac64Ctx::new(&PARTIAL_SEED[..len]);
+        for (i, &e) in expected.iter().enumerate() {
+            assert_eq!(ctx.next_u64(), e, "{len}-byte seed, index {i}");
+        }
+    }
+
+    #[test]
+    fn raw_values_1_byte_seed() {
+        check_partial_seed(
+            1,
+            [
+                13535040523913025898,
+                11186036148076763066,
+                17457813421150709648,
+                14433197483349118045,
+                7996039696826744184,
+                8587010431704612506,
+                11495013891180058003,
+                6278830536540527714,
+            ],
+        );
+    }
+
+    #[test]
+    fn raw_values_3_byte_seed() {
+        check_partial_seed(
+            3,
+            [
+                12580405299236997320,
+                2207915445457228834,
+                9111553948710830586,
+                17391114998125829814,
+                13793198288991772942,
+                10242138928891243718,
+                2900391600437223965,
+                7553205077270563404,
+            ],
+        );
+    }
+
+    #[test]
+    fn raw_values_5_byte_seed() {
+        check_partial_seed(
+            5,
+            [
+                10036084989716966351,
+                17376367320354050703,
+                16676007516779402729,
+                3905795663183518466,
+                11000556272613775307,
+                7054952758600021420,
+                10739399181730809773,
+                11354828124435570858,
+            ],
+        );
+    }
+
+    #[test]
+    fn raw_values_7_byte_seed() {
+        check_partial_seed(
+            7,
+            [
+                16676327428092819098,
+                5326863603449258879,
+                13794002104206564182,
+                3381757828561241537,
+                309448769685971318,
+                14143853899591364034,
+                16227853633418389060,
+                14385042582800506977,
+            ],
+        );
+    }
+
+    #[test]
+    fn one_byte_seed_matches_zero_padded_word() {
+        // A short seed is zero-extended, so 0x2A alone equals u64 seed 42.
+        let mut short = Isaac64Ctx::new(&[42]);
+        let mut full = Isaac64Ctx::new(&seed_from_u64(42));
+        for _ in 0..20 {
+            assert_eq!(short.next_u64(), full.next_u64());
+        }
+    }
 }
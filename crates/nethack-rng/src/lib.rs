@@ -1,6 +1,13 @@
+mod dice;
 mod isaac64;
+mod replay;
 
+pub use dice::{ParseError, parse_and_roll};
 use isaac64::Isaac64Ctx;
+pub use replay::{ReplayCall, ReplayError, ReplayLog, RngFn};
+use std::borrow::BorrowMut;
+
+use serde::{Deserialize, Serialize};
 
 /// Dual-stream RNG matching NetHack's ISAAC64-based random number generation.
 ///
@@ -12,6 +19,36 @@ use isaac64::Isaac64Ctx;
 pub struct NhRng {
     core: Isaac64Ctx,
     display: Isaac64Ctx,
+    /// Level for reports of invalid arguments; `None` silences them.
+    guard_level: Option<log::Level>,
+    /// Values drawn from each stream so far.
+    core_draws: u64,
+    display_draws: u64,
+}
+
+/// An unseeded generator: both streams hold the all-zero state
+/// `isaac64_init()` starts from. Call [`NhRng::reseed`] before drawing.
+impl Default for NhRng {
+    fn default() -> Self {
+        Self {
+            core: Isaac64Ctx::zeroed(),
+            display: Isaac64Ctx::zeroed(),
+            guard_level: Some(log::Level::Warn),
+            core_draws: 0,
+            display_draws: 0,
+        }
+    }
+}
+
+/// The full ISAAC64 context of both [`NhRng`] streams, from
+/// [`NhRng::save_state`]. Deserializing checks the table sizes, so any
+/// state that loads can be restored.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RngState {
+    core: Isaac64Ctx,
+    display: Isaac64Ctx,
+    core_draws: u64,
+    display_draws: u64,
 }
 
 impl NhRng {
@@ -24,6 +61,9 @@ impl NhRng {
         Self {
             core: Isaac64Ctx::new(&seed_bytes),
             display: Isaac64Ctx::new(&seed_bytes),
+            guard_level: Some(log::Level::Warn),
+            core_draws: 0,
+            display_draws: 0,
         }
     }
 
@@ -32,46 +72,198 @@ impl NhRng {
         Self {
             core: Isaac64Ctx::new(&core_seed.to_le_bytes()),
             display: Isaac64Ctx::new(&display_seed.to_le_bytes()),
+            guard_level: Some(log::Level::Warn),
+            core_draws: 0,
+            display_draws: 0,
+        }
+    }
+
+    /// Reseed both streams from the seed's 8 little-endian bytes, as
+    /// [`NhRng::new`] does.
+    ///
+    /// Like `isaac64_reseed()`, this does not clear the streams first: the
+    /// seed is XORed into the current state, so only an unseeded
+    /// [`NhRng::default`] ends up matching `new(seed)`. The draw counts
+    /// restart from zero.
+    pub fn reseed(&mut self, seed: u64) {
+        self.reseed_dual(seed, seed);
+    }
+
+    /// [`NhRng::reseed`] with separate seeds for core and display streams.
+    pub fn reseed_dual(&mut self, core_seed: u64, display_seed: u64) {
+        self.core.reseed(&core_seed.to_le_bytes());
+        self.display.reseed(&display_seed.to_le_bytes());
+        self.core_draws = 0;
+        self.display_draws = 0;
+    }
+
+    /// Snapshot both streams, with their draw counts, for saving mid-game.
+    pub fn save_state(&self) -> RngState {
+        RngState {
+            core: self.core.clone(),
+            display: self.display.clone(),
+            core_draws: self.core_draws,
+            display_draws: self.display_draws,
+        }
+    }
+
+    /// Put both streams back to a [`NhRng::save_state`] snapshot, so the
+    /// following draws repeat exactly. The guard level is left as it is.
+    pub fn restore_state(&mut self, state: &RngState) {
+        self.core = state.core.clone();
+        self.display = state.display.clone();
+        self.core_draws = state.core_draws;
+        self.display_draws = state.display_draws;
+    }
+
+    /// A new generator seeded from the next core value, for work that must
+    /// not disturb this one's stream beyond that single draw. Not part of
+    /// NetHack: a forked stream matches nothing in C. The guard level is
+    /// inherited.
+    pub fn fork(&mut self) -> NhRng {
+        let mut child = NhRng::new(self.next_core_u64());
+        child.guard_level = self.guard_level;
+        child
+    }
+
+    /// `n` generators from successive [`NhRng::fork`]s, advancing this one
+    /// by `n` core draws. Each is independent and can move to its own
+    /// thread; the same parent state always yields the same forks.
+    pub fn split_n(&mut self, n: usize) -> Vec<NhRng> {
+        (0..n).map(|_| self.fork()).collect()
+    }
+
+    /// Set the log level for invalid arguments such as `rn2(0)`, which
+    /// default to warnings. `None` silences them, for tools that probe edge
+    /// cases on purpose. Return values are the same either way.
+    pub fn set_guard_level(&mut self, level: Option<log::Level>) {
+        self.guard_level = level;
+    }
+
+    /// Number of values drawn from the core stream since creation, for
+    /// lining up consumption against a C trace. Calls rejected for invalid
+    /// arguments draw nothing and are not counted.
+    pub fn core_draws(&self) -> u64 {
+        self.core_draws
+    }
+
+    /// Number of values drawn from the display stream since creation.
+    pub fn display_draws(&self) -> u64 {
+        self.display_draws
+    }
+
+    /// Discard the next `n` core values, exactly as `n` draws would, and
+    /// count them in [`NhRng::core_draws`]. For fast-forwarding a replay.
+    pub fn skip_core(&mut self, n: u64) {
+        self.core_draws += n;
+        self.core.skip(n);
+    }
+
+    /// Discard the next `n` display values, as [`NhRng::skip_core`] does
+    /// for the core stream.
+    pub fn skip_display(&mut self, n: u64) {
+        self.display_draws += n;
+        self.display.skip(n);
+    }
+
+    /// Borrow the core stream as an [`NhRngCore`], so the same `NhRng` can
+    /// go on to `rn2()` afterwards.
+    pub fn core_rng(&mut self) -> NhRngCore<&mut NhRng> {
+        NhRngCore(self)
+    }
+
+    /// The next raw 64-bit value from the core stream, for building other
+    /// distributions. Every core helper draws through this, so raw and
+    /// helper calls share one deterministic order.
+    pub fn next_core_u64(&mut self) -> u64 {
+        self.core_draws += 1;
+        self.core.next_u64()
+    }
+
+    /// The next raw 64-bit value from the display stream.
+    pub fn next_display_u64(&mut self) -> u64 {
+        self.display_draws += 1;
+        self.display.next_u64()
+    }
+
+    fn guard(&self, args: std::fmt::Arguments<'_>) {
+        if let Some(level) = self.guard_level {
+            log::log!(level, "{args} attempted");
         }
     }
 
     /// `0 <= rn2(x) < x` — uniform random integer on the core stream.
+    ///
+    /// Like C's `RND()`, this reduces a 64-bit draw with `% x`, so values
+    /// below `2^64 % x` are very slightly more likely. The bias is kept for
+    /// compatibility; see [`NhRng::rn2_unbiased`] for exact uniformity.
     pub fn rn2(&mut self, x: i32) -> i32 {
         if x <= 0 {
-            log::warn!("rn2({x}) attempted");
+            self.guard(format_args!("rn2({x})"));
             return 0;
         }
-        (self.core.next_u64() % x as u64) as i32
+        (self.next_core_u64() % x as u64) as i32
+    }
+
+    /// `0 <= rn2_unbiased(x) < x` by rejection sampling, for tools that
+    /// want exactly uniform draws.
+    ///
+    /// **Does not match NetHack**: rejected draws consume extra values from
+    /// the core stream, so later rolls diverge from C.
+    pub fn rn2_unbiased(&mut self, x: i32) -> i32 {
+        if x <= 0 {
+            self.guard(format_args!("rn2_unbiased({x})"));
+            return 0;
+        }
+        uniform_below(x as u64, u64::MAX, || self.next_core_u64()) as i32
     }
 
     /// `0 <= rn2_on_display_rng(x) < x` — uniform random on the display stream.
     pub fn rn2_on_display_rng(&mut self, x: i32) -> i32 {
         if x <= 0 {
-            log::warn!("rn2_on_display_rng({x}) attempted");
+            self.guard(format_args!("rn2_on_display_rng({x})"));
             return 0;
         }
-        (self.display.next_u64() % x as u64) as i32
+        (self.next_display_u64() % x as u64) as i32
+    }
+
+    /// A `pct` percent chance on the core stream: `rn2(100) < pct`.
+    ///
+    /// `pct` is clamped to `0..=100`, so 0 or less is never true and 100 or
+    /// more always is. One value is drawn either way.
+    pub fn percent(&mut self, pct: i32) -> bool {
+        self.rn2(100) < pct.clamp(0, 100)
+    }
+
+    /// [`NhRng::percent`] on the display stream, for cosmetic choices.
+    pub fn percent_display(&mut self, pct: i32) -> bool {
+        self.rn2_on_display_rng(100) < pct.clamp(0, 100)
+    }
+
+    /// `y <= rn1(x, y) < x + y` — C's `rn1()` macro, `rn2(x) + y`.
+    pub fn rn1(&mut self, x: i32, y: i32) -> i32 {
+        self.rn2(x) + y
     }
 
     /// `1 <= rnd(x) <= x` — uniform random integer.
     pub fn rnd(&mut self, x: i32) -> i32 {
         if x <= 0 {
-            log::warn!("rnd({x}) attempted");
+            self.guard(format_args!("rnd({x})"));
             return 1;
         }
-        (self.core.next_u64() % x as u64) as i32 + 1
+        (self.next_core_u64() % x as u64) as i32 + 1
     }
 
     /// `n <= d(n, x) <= n*x` — sum of n rolls of a d-x die.
     pub fn d(&mut self, n: i32, x: i32) -> i32 {
         if x < 0 || n < 0 || (x == 0 && n != 0) {
-            log::warn!("d({n},{x}) attempted");
+            self.guard(format_args!("d({n},{x})"));
             return 1;
         }
         // C implementation: tmp = n; while(n--) tmp += RND(x); return tmp;
         let mut tmp = n;
         for _ in 0..n {
-            tmp += (self.core.next_u64() % x as u64) as i32;
+            tmp += (self.next_core_u64() % x as u64) as i32;
         }
         tmp
     }
@@ -79,7 +271,7 @@ impl NhRng {
     /// Luck-adjusted random: good luck biases toward 0, bad luck toward x-1.
     pub fn rnl(&mut self, x: i32, luck: i32) -> i32 {
         if x <= 0 {
-            log::warn!("rnl({x}) attempted");
+            self.guard(format_args!("rnl({x})"));
             return 0;
         }
 
@@ -102,6 +294,42 @@ impl NhRng {
         i
     }
 
+    /// Index into a probability-weighted table, drawing `rn2(total)` once and
+    /// walking the cumulative sum the way `mkobj()` walks `oc_prob`.
+    /// Zero-weight entries are never chosen.
+    ///
+    /// If every weight is zero nothing is drawn and 0 is returned.
+    ///
+    /// # Panics
+    ///
+    /// If `weights` is empty.
+    pub fn weighted_index(&mut self, weights: &[u32]) -> usize {
+        assert!(!weights.is_empty(), "weighted_index of an empty table");
+        let total: u64 = weights.iter().map(|&w| u64::from(w)).sum();
+        if total == 0 {
+            self.guard(format_args!("weighted_index with all-zero weights"));
+            return 0;
+        }
+        let mut roll = self.next_core_u64() % total;
+        for (i, &w) in weights.iter().enumerate() {
+            if roll < u64::from(w) {
+                return i;
+            }
+            roll -= u64::from(w);
+        }
+        unreachable!("roll is below the total weight")
+    }
+
+    /// Shuffle `items` in place as `spo_shuffle_array()` does for `SHUFFLE:`:
+    /// for `i` from the last index down to 1, swap with `rn2(i + 1)`. A
+    /// slice of `n` items takes `n - 1` core draws.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.rn2(i as i32 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+
     /// Experience-scaled random: `1 <= rne(x) <= max(ulevel/3, 5)`.
     pub fn rne(&mut self, x: i32, ulevel: i32) -> i32 {
         let utmp = if ulevel < 15 { 5 } else { ulevel / 3 };
@@ -112,11 +340,12 @@ impl NhRng {
         tmp
     }
 
-    /// Timeout-scaling random (rnz).
-    pub fn rnz(&mut self, i: i32) -> i32 {
+    /// Timeout-scaling random (rnz), for a hero of experience level
+    /// `ulevel`: `i` scaled up or down by a factor from `rne(4)`.
+    pub fn rnz(&mut self, i: i32, ulevel: i32) -> i32 {
         let mut x = i as i64;
         let mut tmp = 1000i64 + self.rn2(1000) as i64;
-        tmp *= self.rne(4, 1) as i64; // rne uses ulevel; C code uses u.ulevel
+        tmp *= self.rne(4, ulevel) as i64;
         if self.rn2(2) != 0 {
             x = x * tmp / 1000;
         } else {
@@ -126,6 +355,117 @@ impl NhRng {
     }
 }
 
+/// The first seed in `range` whose fresh [`NhRng::new`] satisfies
+/// `predicate`, for finding a game that starts with some observed rolls.
+///
+/// Seeds are tried in order, each with its own generator, so the predicate
+/// sees the stream from its start.
+pub fn find_seed(
+    mut predicate: impl FnMut(&mut NhRng) -> bool,
+    range: std::ops::Range<u64>,
+) -> Option<u64> {
+    range
+        .into_iter()
+        .find(|&seed| predicate(&mut NhRng::new(seed)))
+}
+
+/// Raw 64-bit access to an [`NhRng`]'s core stream, for code written
+/// against the `rand` ecosystem. It owns the generator (`NhRngCore<NhRng>`)
+/// or borrows it from [`NhRng::core_rng`]. With the `rand_core` feature it
+/// implements `rand_core::TryRng` (and so `Rng`, formerly `RngCore`), and
+/// the owned form `SeedableRng` with an 8-byte seed.
+///
+/// **Not seed-compatible with NetHack's consumption order**: callers decide
+/// how many values each result takes, so mixing these draws with `rn2()`
+/// and friends leaves the core stream somewhere C would never be. Each
+/// 64-bit value counts as one [`NhRng::core_draws`].
+#[derive(Clone)]
+pub struct NhRngCore<R = NhRng>(R);
+
+impl<R: BorrowMut<NhRng>> NhRngCore<R> {
+    pub fn new(rng: R) -> Self {
+        Self(rng)
+    }
+
+    pub fn into_inner(self) -> R {
+        self.0
+    }
+
+    /// The next core value.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0.borrow_mut().next_core_u64()
+    }
+
+    /// The low half of the next core value. A whole 64-bit value is used
+    /// up, as every C `RND()` call does, so the draw count stays in step.
+    pub fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    /// Fill `dest` with little-endian core values, one per 8 bytes; the
+    /// unused bytes of a final partial value are dropped.
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<R: BorrowMut<NhRng>> rand_core::TryRng for NhRngCore<R> {
+    type Error = std::convert::Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        Ok(self.next_u32())
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        Ok(self.next_u64())
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        self.fill_bytes(dst);
+        Ok(())
+    }
+}
+
+/// Seeds are [`NhRng::new`]'s `u64` as 8 little-endian bytes, so
+/// `seed_from_u64(n)` is `NhRng::new(n)`.
+#[cfg(feature = "rand_core")]
+impl rand_core::SeedableRng for NhRngCore {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(NhRng::new(u64::from_le_bytes(seed)))
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        Self::new(NhRng::new(state))
+    }
+}
+
+impl From<NhRng> for NhRngCore {
+    fn from(rng: NhRng) -> Self {
+        Self::new(rng)
+    }
+}
+
+/// Reduce draws in `0..=max` to `0..x`, rejecting the top `(max + 1) % x`
+/// values so every result is equally likely.
+fn uniform_below(x: u64, max: u64, mut draw: impl FnMut() -> u64) -> u64 {
+    // Count of values past the last whole multiple of x, computed without
+    // overflowing when max is u64::MAX.
+    let excess = (max % x + 1) % x;
+    let limit = max - excess;
+    loop {
+        let v = draw();
+        if v <= limit {
+            return v % x;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,12 +503,212 @@ mod tests {
         }
     }
 
+    #[test]
+    fn draw_counters() {
+        let mut rng = NhRng::new(42);
+        let mut plain = NhRng::new(42);
+        assert_eq!((rng.core_draws(), rng.display_draws()), (0, 0));
+        assert_eq!(rng.rn2(100), plain.rn2(100));
+        assert_eq!((rng.core_draws(), rng.display_draws()), (1, 0));
+        rng.d(3, 6);
+        rng.rn2_on_display_rng(10);
+        rng.set_guard_level(None);
+        rng.rn2(0);
+        assert_eq!((rng.core_draws(), rng.display_draws()), (4, 1));
+
+        // One draw per die, whatever the die size.
+        let before = rng.core_draws();
+        rng.d(3, 6);
+        assert_eq!(rng.core_draws() - before, 3);
+        rng.d(2, 100);
+        assert_eq!(rng.core_draws() - before, 5);
+    }
+
+    #[test]
+    fn save_and_restore_state() {
+        let mut rng = NhRng::new(42);
+        for _ in 0..37 {
+            rng.rn2(1000);
+        }
+        rng.rn2_on_display_rng(10);
+        let state = rng.save_state();
+        let first: Vec<_> = (0..100).map(|_| rng.rn2(1000)).collect();
+        let display = rng.rn2_on_display_rng(1000);
+
+        rng.restore_state(&state);
+        assert_eq!(rng.core_draws(), 37);
+        let again: Vec<_> = (0..100).map(|_| rng.rn2(1000)).collect();
+        assert_eq!(again, first);
+        assert_eq!(rng.rn2_on_display_rng(1000), display);
+
+        // A generator with another seed picks up the same streams.
+        let mut other = NhRng::new(7);
+        other.restore_state(&state);
+        assert_eq!((0..100).map(|_| other.rn2(1000)).collect::<Vec<_>>(), first);
+    }
+
+    #[test]
+    fn state_survives_serde_round_trip() {
+        let mut rng = NhRng::new(42);
+        // Past the first 256-value block, so the saved index is mid-table.
+        for _ in 0..300 {
+            rng.rn2(1000);
+        }
+        rng.rn2_on_display_rng(10);
+        let json = serde_json::to_string(&rng.save_state()).unwrap();
+        let state: RngState = serde_json::from_str(&json).unwrap();
+
+        let mut loaded = NhRng::new(7);
+        loaded.restore_state(&state);
+        assert_eq!(loaded.core_draws(), rng.core_draws());
+        assert_eq!(loaded.display_draws(), rng.display_draws());
+        let expected: Vec<_> = (0..600).map(|_| rng.rn2(1000)).collect();
+        let got: Vec<_> = (0..600).map(|_| loaded.rn2(1000)).collect();
+        assert_eq!(got, expected);
+        assert_eq!(
+            loaded.rn2_on_display_rng(1000),
+            rng.rn2_on_display_rng(1000)
+        );
+
+        // A table of the wrong size does not load.
+        let padded = json.replacen("\"r\":[", "\"r\":[1,", 1);
+        assert!(serde_json::from_str::<RngState>(&padded).is_err());
+    }
+
+    #[test]
+    fn skip_matches_drawing() {
+        let mut rng = NhRng::new(42);
+        let mut plain = NhRng::new(42);
+        rng.skip_core(5);
+        let sixth = (0..6).map(|_| plain.rn2(100)).last().unwrap();
+        assert_eq!(rng.rn2(100), sixth);
+        assert_eq!(rng.core_draws(), 6);
+
+        // Across the 256-value refill, on the display stream too.
+        rng.skip_display(300);
+        for _ in 0..300 {
+            plain.rn2_on_display_rng(2);
+        }
+        assert_eq!(rng.rn2_on_display_rng(1000), plain.rn2_on_display_rng(1000));
+        rng.skip_core(1000);
+        for _ in 0..1000 {
+            plain.rn2(2);
+        }
+        assert_eq!(rng.rn2(1000), plain.rn2(1000));
+        assert_eq!(rng.display_draws(), plain.display_draws());
+    }
+
+    #[test]
+    fn raw_core_stream() {
+        let mut plain = NhRng::new(42);
+        let expected: Vec<_> = (0..3).map(|_| plain.core.next_u64()).collect();
+
+        let mut core = NhRngCore::new(NhRng::new(42));
+        assert_eq!(core.next_u64(), expected[0]);
+        let mut buf = [0u8; 12];
+        core.fill_bytes(&mut buf);
+        assert_eq!(buf[..8], expected[1].to_le_bytes());
+        assert_eq!(buf[8..], expected[2].to_le_bytes()[..4]);
+        assert_eq!(core.into_inner().core_draws(), 3);
+
+        // Equal seeds give equal streams through the borrowed wrapper, and
+        // next_u32 uses up a whole value.
+        let mut a = NhRng::new(42);
+        let mut b = NhRng::new(42);
+        assert_eq!(a.core_rng().next_u32(), expected[0] as u32);
+        b.core_rng().next_u64();
+        assert_eq!(a.rn2(1000), b.rn2(1000));
+        assert_eq!(a.core_draws(), 2);
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn rand_core_traits() {
+        use rand::seq::SliceRandom;
+        use rand_core::Rng;
+
+        let mut plain = NhRng::new(42);
+        let mut core = NhRngCore::new(NhRng::new(42));
+        assert_eq!(Rng::next_u64(&mut core), plain.next_core_u64());
+        assert_eq!(Rng::next_u32(&mut core), plain.next_core_u64() as u32);
+        let mut buf = [0u8; 8];
+        Rng::fill_bytes(&mut core, &mut buf);
+        assert_eq!(buf, plain.next_core_u64().to_le_bytes());
+
+        // A seeded shuffle comes out the same every run.
+        let shuffled = |seed| {
+            let mut items: Vec<u32> = (0..10).collect();
+            items.shuffle(&mut NhRngCore::new(NhRng::new(seed)));
+            items
+        };
+        assert_eq!(shuffled(42), shuffled(42));
+        assert_eq!(shuffled(42), [6, 5, 8, 2, 9, 1, 4, 7, 0, 3]);
+        assert_ne!(shuffled(42), shuffled(43));
+
+        // Seeding through the trait matches NhRng::new, and the borrowed
+        // form leaves the generator usable afterwards.
+        use rand_core::SeedableRng;
+        let mut seeded = NhRngCore::from_seed(42u64.to_le_bytes());
+        let mut by_u64 = NhRngCore::seed_from_u64(42);
+        let mut rng = NhRng::new(42);
+        let mut items: Vec<u32> = (0..10).collect();
+        items.shuffle(&mut rng.core_rng());
+        assert_eq!(items, shuffled(42));
+        let used = rng.core_draws();
+        let next = rng.next_core_u64();
+        let skipped = |core: &mut NhRngCore| {
+            for _ in 0..used {
+                core.next_u64();
+            }
+            core.next_u64()
+        };
+        assert_eq!(skipped(&mut seeded), next);
+        assert_eq!(skipped(&mut by_u64), next);
+    }
+
+    #[test]
+    fn rn1_range() {
+        let mut rng = NhRng::new(42);
+        let mut plain = NhRng::new(42);
+        for _ in 0..1000 {
+            let v = rng.rn1(6, 1);
+            assert!((1..=6).contains(&v), "rn1(6, 1) = {v}");
+            assert_eq!(v, plain.rn2(6) + 1);
+        }
+        assert_eq!(rng.core_draws(), 1000);
+        rng.set_guard_level(None);
+        assert_eq!(rng.rn1(0, 5), 5);
+        assert_eq!(rng.core_draws(), 1000);
+    }
+
+    #[test]
+    fn split_forks_are_distinct_and_reproducible() {
+        let mut parent = NhRng::new(42);
+        let mut forks = parent.split_n(4);
+        assert_eq!(parent.core_draws(), 4);
+        let firsts: Vec<_> = forks.iter_mut().map(|f| f.rn2(1_000_000)).collect();
+        for (i, a) in firsts.iter().enumerate() {
+            assert!(!firsts[i + 1..].contains(a), "{firsts:?}");
+        }
+
+        // The same parent seed gives the same forks, even on other threads.
+        let forks = NhRng::new(42).split_n(4);
+        let again: Vec<_> = std::thread::scope(|s| {
+            let handles: Vec<_> = forks
+                .into_iter()
+                .map(|mut f| s.spawn(move || f.rn2(1_000_000)))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert_eq!(again, firsts);
+    }
+
     #[test]
     fn rn2_range() {
         let mut rng = NhRng::new(42);
         for _ in 0..1000 {
             let v = rng.rn2(50);
-            assert!(v >= 0 && v < 50, "rn2(50) = {v} out of range [0, 50)");
+            assert!((0..50).contains(&v), "rn2(50) = {v} out of range [0, 50)");
         }
     }
 
@@ -177,7 +717,7 @@ mod tests {
         let mut rng = NhRng::new(42);
         for _ in 0..1000 {
             let v = rng.rnd(6);
-            assert!(v >= 1 && v <= 6, "rnd(6) = {v} out of range [1, 6]");
+            assert!((1..=6).contains(&v), "rnd(6) = {v} out of range [1, 6]");
         }
     }
 
@@ -186,7 +726,7 @@ mod tests {
         let mut rng = NhRng::new(42);
         for _ in 0..1000 {
             let v = rng.d(3, 6);
-            assert!(v >= 3 && v <= 18, "d(3,6) = {v} out of range [3, 18]");
+            assert!((3..=18).contains(&v), "d(3,6) = {v} out of range [3, 18]");
         }
     }
 
@@ -242,17 +782,316 @@ mod tests {
         for luck in -13..=13 {
             for _ in 0..100 {
                 let v = rng.rnl(20, luck);
-                assert!(v >= 0 && v < 20, "rnl(20, {luck}) = {v} out of range");
+                assert!((0..20).contains(&v), "rnl(20, {luck}) = {v} out of range");
             }
         }
     }
 
+    #[test]
+    fn reseed_matches_new() {
+        let mut fresh = NhRng::new(42);
+        let mut reseeded = NhRng::default();
+        reseeded.reseed(42);
+        for _ in 0..600 {
+            assert_eq!(fresh.rn2(1000), reseeded.rn2(1000));
+            assert_eq!(
+                fresh.rn2_on_display_rng(1000),
+                reseeded.rn2_on_display_rng(1000)
+            );
+        }
+
+        let mut dual = NhRng::default();
+        dual.reseed_dual(1, 2);
+        let mut expected = NhRng::new_dual(1, 2);
+        assert_eq!(dual.rn2(1000), expected.rn2(1000));
+        assert_eq!(
+            dual.rn2_on_display_rng(1000),
+            expected.rn2_on_display_rng(1000)
+        );
+
+        // Reseeding mixes into the current state rather than replacing it.
+        let mut used = NhRng::new(7);
+        used.reseed(42);
+        let mut fresh = NhRng::new(42);
+        assert_ne!(
+            (0..8).map(|_| used.rn2(1000)).collect::<Vec<_>>(),
+            (0..8).map(|_| fresh.rn2(1000)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn rnz_scales_with_level() {
+        // Values from C's rnz() (`tools/isaac64_ref.c`). Seeds 1117 and
+        // 1158 reach rne()'s level-1 cap of 5, where a level-20 hero (cap 6)
+        // keeps drawing and the rest of the roll diverges; seeds 1 and 42
+        // stop early at either level.
+        for (seed, level1, level20) in [
+            (1, 212, 212),
+            (42, 184, 184),
+            (1117, 3449, 35),
+            (1158, 68, 2142),
+        ] {
+            assert_eq!(NhRng::new(seed).rnz(350, 1), level1, "seed {seed}");
+            assert_eq!(NhRng::new(seed).rnz(350, 20), level20, "seed {seed}");
+        }
+    }
+
     #[test]
     fn rne_range() {
         let mut rng = NhRng::new(42);
         for _ in 0..1000 {
             let v = rng.rne(3, 10);
-            assert!(v >= 1 && v <= 5, "rne(3, 10) = {v} out of range [1, 5]");
+            assert!((1..=5).contains(&v), "rne(3, 10) = {v} out of range [1, 5]");
+        }
+    }
+
+    #[test]
+    fn rn2_unbiased_range() {
+        let mut rng = NhRng::new(42);
+        for _ in 0..1000 {
+            let v = rng.rn2_unbiased(7);
+            assert!((0..7).contains(&v), "rn2_unbiased(7) = {v}");
+        }
+        assert_eq!(rng.rn2_unbiased(0), 0);
+    }
+
+    #[test]
+    fn rejection_removes_modulo_bias() {
+        // At 64 bits the modulo bias is far too small to measure, so use
+        // byte-sized draws: 256 % 96 == 64, so plain modulo makes 0..64
+        // half again as likely as 64..96.
+        let x = 96;
+        let samples = 96_000;
+        let mut rng = NhRng::new(7);
+        let mut modulo = vec![0u32; x as usize];
+        let mut rejected = vec![0u32; x as usize];
+        for _ in 0..samples {
+            modulo[(rng.core.next_u64() & 0xff) as usize % x as usize] += 1;
+            let v = uniform_below(x, 0xff, || rng.core.next_u64() & 0xff);
+            rejected[v as usize] += 1;
+        }
+        let low_high_ratio = |counts: &[u32]| {
+            let low: u32 = counts[..64].iter().sum();
+            let high: u32 = counts[64..].iter().sum();
+            (low as f64 / 64.0) / (high as f64 / 32.0)
+        };
+        let biased = low_high_ratio(&modulo);
+        let unbiased = low_high_ratio(&rejected);
+        assert!((biased - 1.5).abs() < 0.05, "modulo ratio {biased}");
+        assert!((unbiased - 1.0).abs() < 0.05, "rejection ratio {unbiased}");
+    }
+
+    // ---- Randomized invariants ----
+    //
+    // A small SplitMix64 generator picks seeds and arguments so the bounds
+    // are exercised well beyond the fixed-seed cases above.
+
+    const CASES: usize = 2000;
+
+    struct SplitMix64(u64);
+
+    impl SplitMix64 {
+        fn next(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        }
+
+        /// Uniform integer in `lo..=hi`.
+        fn range(&mut self, lo: i32, hi: i32) -> i32 {
+            lo + (self.next() % (hi - lo + 1) as u64) as i32
+        }
+    }
+
+    /// Run `check` against `CASES` freshly seeded RNGs.
+    fn for_random_seeds(salt: u64, mut check: impl FnMut(&mut NhRng, &mut SplitMix64)) {
+        let mut generator = SplitMix64(salt);
+        for _ in 0..CASES {
+            let mut rng = NhRng::new(generator.next());
+            check(&mut rng, &mut generator);
+        }
+    }
+
+    #[test]
+    fn prop_rn2_bounds() {
+        for_random_seeds(1, |rng, g| {
+            let x = g.range(1, 10_000);
+            let v = rng.rn2(x);
+            assert!((0..x).contains(&v), "rn2({x}) = {v}");
+            assert_eq!(rng.rn2(1), 0);
+        });
+    }
+
+    #[test]
+    fn prop_rnd_bounds() {
+        for_random_seeds(2, |rng, g| {
+            let x = g.range(1, 10_000);
+            let v = rng.rnd(x);
+            assert!((1..=x).contains(&v), "rnd({x}) = {v}");
+            assert_eq!(rng.rnd(1), 1);
+        });
+    }
+
+    #[test]
+    fn prop_d_bounds() {
+        for_random_seeds(3, |rng, g| {
+            let n = g.range(0, 20);
+            let x = g.range(1, 100);
+            let v = rng.d(n, x);
+            assert!((n..=n * x).contains(&v), "d({n},{x}) = {v}");
+            assert_eq!(rng.d(0, 0), 0);
+        });
+    }
+
+    #[test]
+    fn prop_rnl_bounds() {
+        for_random_seeds(4, |rng, g| {
+            let x = g.range(1, 100);
+            let luck = g.range(-13, 13);
+            let v = rng.rnl(x, luck);
+            assert!((0..x).contains(&v), "rnl({x}, {luck}) = {v}");
+        });
+    }
+
+    #[test]
+    fn prop_rne_bounds() {
+        for_random_seeds(5, |rng, g| {
+            let x = g.range(1, 10);
+            let ulevel = g.range(1, 30);
+            let max = if ulevel < 15 { 5 } else { ulevel / 3 };
+            let v = rng.rne(x, ulevel);
+            assert!((1..=max).contains(&v), "rne({x}, {ulevel}) = {v}");
+        });
+    }
+
+    #[test]
+    fn prop_invalid_arguments_use_guards() {
+        for_random_seeds(6, |rng, g| {
+            let x = g.range(-1000, 0);
+            assert_eq!(rng.rn2(x), 0);
+            assert_eq!(rng.rnd(x), 1);
+            assert_eq!(rng.rnl(x, g.range(-13, 13)), 0);
+        });
+    }
+
+    #[test]
+    fn raw_draws_interleave_with_rn2() {
+        let reference: Vec<u64> = {
+            let mut rng = NhRng::new(42);
+            (0..6).map(|_| rng.next_core_u64()).collect()
+        };
+        let mut rng = NhRng::new(42);
+        assert_eq!(rng.next_core_u64(), reference[0]);
+        assert_eq!(rng.rn2(100) as u64, reference[1] % 100);
+        assert_eq!(rng.next_core_u64(), reference[2]);
+        assert_eq!(rng.d(2, 6) as u64, 2 + reference[3] % 6 + reference[4] % 6);
+        assert_eq!(rng.next_core_u64(), reference[5]);
+        assert_eq!(rng.core_draws(), 6);
+
+        // The display stream is separate and counted on its own.
+        let display = NhRng::new(42).next_display_u64();
+        assert_eq!(display, reference[0]);
+        assert_eq!(rng.next_display_u64(), display);
+        assert_eq!((rng.core_draws(), rng.display_draws()), (6, 1));
+    }
+
+    #[test]
+    fn percent_boundaries() {
+        let mut rng = NhRng::new(42);
+        for n in 1..=200 {
+            assert!(rng.percent(100));
+            assert!(!rng.percent(0));
+            assert!(rng.percent(250));
+            assert!(!rng.percent_display(-5));
+            assert_eq!(rng.core_draws(), 3 * n);
+            assert_eq!(rng.display_draws(), n);
+        }
+
+        // rn2_matches_c_seed_42 starts 98, 66, 48.
+        let mut rng = NhRng::new(42);
+        assert!(!rng.percent(98));
+        assert!(rng.percent(67));
+        assert!(!rng.percent(48));
+    }
+
+    #[test]
+    fn shuffle_matches_c_seed_42() {
+        let mut rng = NhRng::new(42);
+        let mut items = [1, 2, 3, 4, 5];
+        rng.shuffle(&mut items);
+        // The draws are rn2(5) = 3, rn2(4) = 2, rn2(3) = 2 and rn2(2) = 1,
+        // the same raw values as rn2_matches_c_seed_42's first four.
+        assert_eq!(items, [1, 2, 5, 3, 4]);
+        assert_eq!(rng.core_draws(), 4);
+
+        let mut one = ["only"];
+        rng.shuffle(&mut one);
+        rng.shuffle::<u8>(&mut []);
+        assert_eq!(rng.core_draws(), 4);
+    }
+
+    #[test]
+    fn weighted_index_follows_weights() {
+        let mut rng = NhRng::new(42);
+        let weights = [10, 0, 30, 60];
+        let mut counts = [0u32; 4];
+        for _ in 0..100_000 {
+            counts[rng.weighted_index(&weights)] += 1;
         }
+        assert_eq!(counts[1], 0);
+        for (count, weight) in counts.iter().zip(weights) {
+            let expected = weight * 1000;
+            assert!(count.abs_diff(expected) < 1000, "{counts:?}");
+        }
+        assert_eq!(rng.core_draws(), 100_000);
+
+        // A single draw, matching rn2(total) on the same stream.
+        let mut plain = NhRng::new(7);
+        let roll = plain.rn2(100);
+        let expected = if roll < 10 {
+            0
+        } else if roll < 40 {
+            2
+        } else {
+            3
+        };
+        assert_eq!(NhRng::new(7).weighted_index(&weights), expected);
+
+        rng.set_guard_level(None);
+        assert_eq!(rng.weighted_index(&[0, 0]), 0);
+        assert_eq!(rng.core_draws(), 100_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty table")]
+    fn weighted_index_rejects_empty_table() {
+        NhRng::new(42).weighted_index(&[]);
+    }
+
+    #[test]
+    fn guard_level_does_not_change_results() {
+        for level in [Some(log::Level::Warn), Some(log::Level::Debug), None] {
+            let mut rng = NhRng::new(42);
+            rng.set_guard_level(level);
+            assert_eq!(rng.rn2(0), 0);
+            assert_eq!(rng.rn2_unbiased(-1), 0);
+            assert_eq!(rng.rn2_on_display_rng(0), 0);
+            assert_eq!(rng.rnd(0), 1);
+            assert_eq!(rng.d(-1, 6), 1);
+            assert_eq!(rng.rnl(0, 3), 0);
+            // Rejected calls draw nothing, so the stream is where it started.
+            assert_eq!(rng.rn2(100), NhRng::new(42).rn2(100));
+        }
+    }
+
+    #[test]
+    fn find_seed_scans_in_order() {
+        let first_42 = |rng: &mut NhRng| rng.rn2(100) == 42;
+        let seed = find_seed(first_42, 0..10_000).expect("a seed in range");
+        assert_eq!(NhRng::new(seed).rn2(100), 42);
+        assert!((0..seed).all(|s| NhRng::new(s).rn2(100) != 42));
+        assert_eq!(find_seed(first_42, seed + 1..seed + 1), None);
     }
 }
@@ -0,0 +1,240 @@
+//! Recorded RNG call sequences for cross-checking against C traces.
+//!
+//! The text format has one call per line, `name(arg, ...) = result`, in the
+//! style of C's RNG trace output. Blank lines and `#` comments are ignored.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::NhRng;
+
+/// An `NhRng` function a log can record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RngFn {
+    Rn2,
+    Rn2OnDisplayRng,
+    Rnd,
+    D,
+    Rnl,
+    Rne,
+    Rnz,
+}
+
+impl RngFn {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Rn2 => "rn2",
+            Self::Rn2OnDisplayRng => "rn2_on_display_rng",
+            Self::Rnd => "rnd",
+            Self::D => "d",
+            Self::Rnl => "rnl",
+            Self::Rne => "rne",
+            Self::Rnz => "rnz",
+        }
+    }
+
+    /// Number of arguments the function takes.
+    pub const fn arity(self) -> usize {
+        match self {
+            Self::D | Self::Rnl | Self::Rne | Self::Rnz => 2,
+            _ => 1,
+        }
+    }
+
+    /// Call the function on `rng`. `args` must have [`RngFn::arity`] items.
+    pub fn call(self, rng: &mut NhRng, args: &[i32]) -> i32 {
+        match self {
+            Self::Rn2 => rng.rn2(args[0]),
+            Self::Rn2OnDisplayRng => rng.rn2_on_display_rng(args[0]),
+            Self::Rnd => rng.rnd(args[0]),
+            Self::D => rng.d(args[0], args[1]),
+            Self::Rnl => rng.rnl(args[0], args[1]),
+            Self::Rne => rng.rne(args[0], args[1]),
+            Self::Rnz => rng.rnz(args[0], args[1]),
+        }
+    }
+}
+
+impl FromStr for RngFn {
+    type Err = ReplayError;
+
+    fn from_str(s: &str) -> Result<Self, ReplayError> {
+        [
+            Self::Rn2,
+            Self::Rn2OnDisplayRng,
+            Self::Rnd,
+            Self::D,
+            Self::Rnl,
+            Self::Rne,
+            Self::Rnz,
+        ]
+        .into_iter()
+        .find(|f| f.name() == s)
+        .ok_or_else(|| ReplayError::UnknownFunction(s.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ReplayError {
+    #[error("line {line}: expected `name(args) = result`")]
+    Syntax { line: usize },
+    #[error("unknown RNG function {0:?}")]
+    UnknownFunction(String),
+    #[error("line {line}: {func} takes {expected} arguments, got {found}")]
+    Arity {
+        line: usize,
+        func: &'static str,
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// One recorded call and what it returned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayCall {
+    pub func: RngFn,
+    pub args: Vec<i32>,
+    pub result: i32,
+}
+
+impl fmt::Display for ReplayCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.func.name())?;
+        for (i, arg) in self.args.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{arg}")?;
+        }
+        write!(f, ") = {}", self.result)
+    }
+}
+
+/// A sequence of RNG calls, recorded from Rust or read from a C trace.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplayLog {
+    pub calls: Vec<ReplayCall>,
+}
+
+impl ReplayLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `func` on `rng` and record it.
+    ///
+    /// # Panics
+    /// If `args` does not match the function's arity.
+    pub fn call(&mut self, rng: &mut NhRng, func: RngFn, args: &[i32]) -> i32 {
+        assert_eq!(args.len(), func.arity(), "{} arguments", func.name());
+        let result = func.call(rng, args);
+        self.calls.push(ReplayCall {
+            func,
+            args: args.to_vec(),
+            result,
+        });
+        result
+    }
+
+    /// Read a log in the text format written by `Display`.
+    pub fn parse(input: &str) -> Result<Self, ReplayError> {
+        let mut calls = Vec::new();
+        for (i, raw) in input.lines().enumerate() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let syntax = || ReplayError::Syntax { line: i + 1 };
+            let (call, result) = line.split_once('=').ok_or_else(syntax)?;
+            let (name, args) = call
+                .trim()
+                .strip_suffix(')')
+                .and_then(|c| c.split_once('('))
+                .ok_or_else(syntax)?;
+            let func: RngFn = name.trim().parse()?;
+            let args = args
+                .split(',')
+                .filter(|a| !a.trim().is_empty())
+                .map(|a| a.trim().parse().map_err(|_| syntax()))
+                .collect::<Result<Vec<i32>, _>>()?;
+            if args.len() != func.arity() {
+                return Err(ReplayError::Arity {
+                    line: i + 1,
+                    func: func.name(),
+                    expected: func.arity(),
+                    found: args.len(),
+                });
+            }
+            let result = result.trim().parse().map_err(|_| syntax())?;
+            calls.push(ReplayCall { func, args, result });
+        }
+        Ok(Self { calls })
+    }
+
+    /// Replay every call against `rng`, which should be freshly seeded the
+    /// same way as the recording.
+    ///
+    /// # Panics
+    /// At the first call whose result differs from the log.
+    pub fn assert_matches(&self, rng: &mut NhRng) {
+        for (i, call) in self.calls.iter().enumerate() {
+            let got = call.func.call(rng, &call.args);
+            assert_eq!(got, call.result, "call {i}: {call}, replay returned {got}");
+        }
+    }
+}
+
+impl fmt::Display for ReplayLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for call in &self.calls {
+            writeln!(f, "{call}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_parse_and_replay() {
+        let mut rng = NhRng::new(42);
+        let mut log = ReplayLog::new();
+        log.call(&mut rng, RngFn::Rn2, &[100]);
+        log.call(&mut rng, RngFn::D, &[2, 6]);
+        log.call(&mut rng, RngFn::Rnl, &[20, -3]);
+        log.call(&mut rng, RngFn::Rn2OnDisplayRng, &[10]);
+
+        let text = log.to_string();
+        assert!(text.starts_with("rn2(100) = 98\n"), "{text}");
+        assert!(text.contains("rnl(20, -3) = "));
+        let parsed = ReplayLog::parse(&format!("# seed 42\n\n{text}")).unwrap();
+        assert_eq!(parsed, log);
+        parsed.assert_matches(&mut NhRng::new(42));
+    }
+
+    #[test]
+    #[should_panic(expected = "call 0: rn2(100) = 97")]
+    fn mismatch_panics() {
+        ReplayLog::parse("rn2(100) = 97")
+            .unwrap()
+            .assert_matches(&mut NhRng::new(42));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            ReplayLog::parse("rn2 100 = 1"),
+            Err(ReplayError::Syntax { line: 1 })
+        );
+        assert_eq!(
+            ReplayLog::parse("rn3(5) = 1"),
+            Err(ReplayError::UnknownFunction("rn3".into()))
+        );
+        assert!(matches!(
+            ReplayLog::parse("\nd(5) = 1"),
+            Err(ReplayError::Arity { line: 2, .. })
+        ));
+    }
+}
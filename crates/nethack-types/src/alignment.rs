@@ -1,3 +1,5 @@
+use std::fmt;
+
 use bitflags::bitflags;
 use serde::Serialize;
 use strum::{EnumCount, EnumIter, FromRepr};
@@ -35,6 +37,18 @@ impl Alignment {
     }
 }
 
+/// C's `align_str()`.
+impl fmt::Display for Alignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::None => "unaligned",
+            Self::Chaotic => "chaotic",
+            Self::Neutral => "neutral",
+            Self::Lawful => "lawful",
+        })
+    }
+}
+
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
     pub struct AlignmentMask: u8 {
@@ -46,6 +60,29 @@ bitflags! {
     }
 }
 
+impl AlignmentMask {
+    /// The alignments in the mask, lawful first, as the role selection
+    /// menus list them.
+    pub fn alignments(self) -> impl Iterator<Item = Alignment> {
+        [Alignment::Lawful, Alignment::Neutral, Alignment::Chaotic]
+            .into_iter()
+            .filter(move |a| self.contains(a.to_mask()))
+    }
+}
+
+/// The alignments joined with commas, e.g. `lawful, chaotic`, or `none`
+/// for an empty mask.
+impl fmt::Display for AlignmentMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut alignments = self.alignments();
+        let Some(first) = alignments.next() else {
+            return f.write_str("none");
+        };
+        write!(f, "{first}")?;
+        alignments.try_for_each(|a| write!(f, ", {a}"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,6 +115,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mask_alignments_and_display() {
+        let mask = AlignmentMask::LAWFUL | AlignmentMask::CHAOTIC;
+        let alignments: Vec<_> = mask.alignments().collect();
+        assert_eq!(alignments, [Alignment::Lawful, Alignment::Chaotic]);
+        assert_eq!(mask.to_string(), "lawful, chaotic");
+        assert_eq!(AlignmentMask::MASK.alignments().count(), 3);
+        assert_eq!(AlignmentMask::NONE.to_string(), "none");
+        assert_eq!(Alignment::None.to_string(), "unaligned");
+    }
+
     #[test]
     fn count() {
         assert_eq!(Alignment::COUNT, 4);
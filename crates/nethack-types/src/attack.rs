@@ -1,8 +1,24 @@
-use serde::Serialize;
-use strum::{EnumCount, EnumIter, FromRepr};
+use serde::{Serialize, Serializer};
+use strum::{EnumCount, EnumIter, FromRepr, IntoEnumIterator, IntoStaticStr};
+
+/// Serialize as the variant name in human-readable formats and as the C
+/// constant's value otherwise, so neither changes if variants are reordered.
+macro_rules! serialize_name_or_repr {
+    ($ty:ty) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.serialize_str(self.into())
+                } else {
+                    serializer.serialize_u8(*self as u8)
+                }
+            }
+        }
+    };
+}
 
 /// Attack types from `monattk.h` (AT_* constants).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, EnumIter, EnumCount, FromRepr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, EnumCount, FromRepr, IntoStaticStr)]
 #[repr(u8)]
 pub enum AttackType {
     None = 0,
@@ -26,8 +42,40 @@ pub enum AttackType {
     Magic = 255,
 }
 
+serialize_name_or_repr!(AttackType);
+
+impl AttackType {
+    /// The C constant name, e.g. `AT_BITE`.
+    pub fn c_name(self) -> &'static str {
+        match self {
+            Self::None => "AT_NONE",
+            Self::Claw => "AT_CLAW",
+            Self::Bite => "AT_BITE",
+            Self::Kick => "AT_KICK",
+            Self::Butt => "AT_BUTT",
+            Self::Touch => "AT_TUCH",
+            Self::Sting => "AT_STNG",
+            Self::Hugs => "AT_HUGS",
+            Self::Spit => "AT_SPIT",
+            Self::Engulf => "AT_ENGL",
+            Self::Breath => "AT_BREA",
+            Self::Explode => "AT_EXPL",
+            Self::Boom => "AT_BOOM",
+            Self::Gaze => "AT_GAZE",
+            Self::Tentacle => "AT_TENT",
+            Self::Weapon => "AT_WEAP",
+            Self::Magic => "AT_MAGC",
+        }
+    }
+
+    /// Parse a C constant name such as `AT_BITE`.
+    pub fn from_c_name(name: &str) -> Option<Self> {
+        Self::iter().find(|v| v.c_name() == name)
+    }
+}
+
 /// Damage types from `monattk.h` (AD_* constants).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, EnumIter, EnumCount, FromRepr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, EnumCount, FromRepr, IntoStaticStr)]
 #[repr(u8)]
 pub enum DamageType {
     Physical = 0,
@@ -82,10 +130,84 @@ pub enum DamageType {
     Curse = 253,
 }
 
+serialize_name_or_repr!(DamageType);
+
+impl DamageType {
+    /// The C constant name, e.g. `AD_FIRE`.
+    pub fn c_name(self) -> &'static str {
+        match self {
+            Self::Physical => "AD_PHYS",
+            Self::MagicMissile => "AD_MAGM",
+            Self::Fire => "AD_FIRE",
+            Self::Cold => "AD_COLD",
+            Self::Sleep => "AD_SLEE",
+            Self::Disintegration => "AD_DISN",
+            Self::Electric => "AD_ELEC",
+            Self::DrainStr => "AD_DRST",
+            Self::Acid => "AD_ACID",
+            Self::Spc1 => "AD_SPC1",
+            Self::Spc2 => "AD_SPC2",
+            Self::Blind => "AD_BLND",
+            Self::Stun => "AD_STUN",
+            Self::Slow => "AD_SLOW",
+            Self::Paralyze => "AD_PLYS",
+            Self::DrainLife => "AD_DRLI",
+            Self::DrainEnergy => "AD_DREN",
+            Self::Legs => "AD_LEGS",
+            Self::Stone => "AD_STON",
+            Self::Stick => "AD_STCK",
+            Self::StealGold => "AD_SGLD",
+            Self::StealItem => "AD_SITM",
+            Self::Seduce => "AD_SDUC",
+            Self::Teleport => "AD_TLPT",
+            Self::Rust => "AD_RUST",
+            Self::Confuse => "AD_CONF",
+            Self::Digest => "AD_DGST",
+            Self::Heal => "AD_HEAL",
+            Self::Wrap => "AD_WRAP",
+            Self::Were => "AD_WERE",
+            Self::DrainDex => "AD_DRDX",
+            Self::DrainCon => "AD_DRCO",
+            Self::DrainInt => "AD_DRIN",
+            Self::Disease => "AD_DISE",
+            Self::Decay => "AD_DCAY",
+            Self::SuccubusSeduction => "AD_SSEX",
+            Self::Hallucination => "AD_HALU",
+            Self::Death => "AD_DETH",
+            Self::Pestilence => "AD_PEST",
+            Self::Famine => "AD_FAMN",
+            Self::Slime => "AD_SLIM",
+            Self::Disenchant => "AD_ENCH",
+            Self::Corrode => "AD_CORR",
+            Self::Clerical => "AD_CLRC",
+            Self::Spell => "AD_SPEL",
+            Self::RandomBreath => "AD_RBRE",
+            Self::StealAmulet => "AD_SAMU",
+            Self::Curse => "AD_CURS",
+        }
+    }
+
+    /// Parse a C constant name such as `AD_FIRE`.
+    pub fn from_c_name(name: &str) -> Option<Self> {
+        Self::iter().find(|v| v.c_name() == name)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use strum::IntoEnumIterator;
+
+    #[test]
+    fn json_uses_variant_names() {
+        assert_eq!(
+            serde_json::to_string(&AttackType::Weapon).unwrap(),
+            "\"Weapon\""
+        );
+        assert_eq!(
+            serde_json::to_string(&DamageType::StealAmulet).unwrap(),
+            "\"StealAmulet\""
+        );
+    }
 
     #[test]
     fn attack_type_discriminants() {
@@ -121,6 +243,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn c_names_round_trip() {
+        for at in AttackType::iter() {
+            let name = at.c_name();
+            assert!(name.starts_with("AT_") && name.len() == 7, "{name}");
+            assert_eq!(AttackType::from_c_name(name), Some(at));
+        }
+        for dt in DamageType::iter() {
+            let name = dt.c_name();
+            assert!(name.starts_with("AD_") && name.len() == 7, "{name}");
+            assert_eq!(DamageType::from_c_name(name), Some(dt));
+        }
+        assert_eq!(AttackType::from_c_name("AT_BITE"), Some(AttackType::Bite));
+        assert_eq!(AttackType::from_c_name("AT_TUCH"), Some(AttackType::Touch));
+        assert_eq!(DamageType::from_c_name("AD_FIRE"), Some(DamageType::Fire));
+        assert_eq!(
+            DamageType::from_c_name("AD_SSEX"),
+            Some(DamageType::SuccubusSeduction)
+        );
+        assert_eq!(AttackType::from_c_name("BITE"), None);
+        assert_eq!(DamageType::from_c_name("AD_PLASMA"), None);
+    }
+
     #[test]
     fn attack_count() {
         assert_eq!(AttackType::COUNT, 17);
@@ -59,4 +59,13 @@ mod tests {
         assert_eq!(atk.dice_num, 1);
         assert_eq!(atk.dice_sides, 6);
     }
+
+    #[test]
+    fn json_names_types() {
+        let bite = Attack::new(AttackType::Bite, DamageType::Physical, 1, 4);
+        assert_eq!(
+            serde_json::to_string(&bite).unwrap(),
+            r#"{"attack_type":"Bite","damage_type":"Physical","dice_num":1,"dice_sides":4}"#
+        );
+    }
 }
@@ -1,8 +1,13 @@
-use serde::Serialize;
-use strum::{EnumCount, EnumIter, FromRepr};
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::{Serialize, Serializer};
+use strum::{EnumCount, EnumIter, FromRepr, IntoEnumIterator};
+
+use crate::{MonsterClass, ObjectClass};
 
 /// Display colors matching NetHack's IBM PC color scheme.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, EnumIter, EnumCount, FromRepr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, EnumCount, FromRepr)]
 #[repr(u8)]
 pub enum Color {
     Black = 0,
@@ -42,12 +47,192 @@ impl Color {
     pub const HI_MINERAL: Self = Self::Gray;
     pub const DRAGON_SILVER: Self = Self::BrightCyan;
     pub const HI_ZAP: Self = Self::BrightBlue;
+
+    /// The canonical name: the C constant without its `CLR_` prefix, in
+    /// lower case (`"red"`, `"bright_green"`, `"no_color"`).
+    pub const fn name(self) -> &'static str {
+        COLOR_NAMES[self as usize]
+    }
+
+    /// Parse a canonical name from [`Color::name`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::iter().find(|c| c.name() == name)
+    }
+
+    /// Whether this is one of the high-intensity colors (`Orange` through
+    /// `White`), drawn bold on an 8-color terminal.
+    pub const fn is_bright(self) -> bool {
+        (self as u8) > Self::BRIGHT
+    }
+
+    /// The 8-color ANSI base color (0-7) and whether to draw it bold, as tty
+    /// terminals without bright colors display them. `NoColor` is the
+    /// terminal's plain gray.
+    pub const fn to_8color(self) -> (u8, bool) {
+        match self {
+            Self::NoColor => (Self::Gray as u8, false),
+            c => ((c as u8) & (Self::BRIGHT - 1), c.is_bright()),
+        }
+    }
+
+    /// Default color for a bare object class symbol.
+    ///
+    /// `objects.c` colors each object individually; the class default is the
+    /// material highlight its object macros fall back to (gold coins use
+    /// `HI_GOLD`, scrolls and spellbooks `HI_PAPER`, and so on).
+    pub const fn default_for_object_class(class: ObjectClass) -> Self {
+        match class {
+            ObjectClass::Random => Self::NoColor,
+            ObjectClass::IllObj => Self::HI_OBJ,
+            ObjectClass::Weapon
+            | ObjectClass::Armor
+            | ObjectClass::Ring
+            | ObjectClass::Amulet
+            | ObjectClass::Wand
+            | ObjectClass::Ball
+            | ObjectClass::Chain => Self::HI_METAL,
+            ObjectClass::Tool => Self::HI_WOOD,
+            ObjectClass::Food | ObjectClass::Venom => Self::HI_ORGANIC,
+            ObjectClass::Potion | ObjectClass::Gem => Self::HI_GLASS,
+            ObjectClass::Scroll | ObjectClass::SpellBook => Self::HI_PAPER,
+            ObjectClass::Coin => Self::HI_GOLD,
+            ObjectClass::Rock => Self::HI_MINERAL,
+        }
+    }
+
+    /// Default color for a bare monster class symbol.
+    ///
+    /// `monst.c` colors each species individually; the class default is the
+    /// color of the first species of the class in `mons[]` order (e.g. the
+    /// baby gray dragon for `D`). Classes without species use `NoColor`.
+    pub const fn default_for_monster_class(class: MonsterClass) -> Self {
+        match class {
+            MonsterClass::Ant
+            | MonsterClass::Cockatrice
+            | MonsterClass::Dog
+            | MonsterClass::Kobold
+            | MonsterClass::Mimic
+            | MonsterClass::Quadruped
+            | MonsterClass::Rodent
+            | MonsterClass::Unicorn
+            | MonsterClass::Worm
+            | MonsterClass::Zruty
+            | MonsterClass::Bat
+            | MonsterClass::Centaur
+            | MonsterClass::Gnome
+            | MonsterClass::Lich
+            | MonsterClass::Mummy
+            | MonsterClass::Ogre
+            | MonsterClass::RustMonster
+            | MonsterClass::Troll
+            | MonsterClass::Umber
+            | MonsterClass::Xorn
+            | MonsterClass::Zombie
+            | MonsterClass::WormTail => Self::Brown,
+            MonsterClass::Blob
+            | MonsterClass::Gremlin
+            | MonsterClass::Humanoid
+            | MonsterClass::Leprechaun
+            | MonsterClass::Nymph
+            | MonsterClass::Angel
+            | MonsterClass::Snake => Self::Green,
+            MonsterClass::Eye
+            | MonsterClass::Orc
+            | MonsterClass::Piercer
+            | MonsterClass::Spider
+            | MonsterClass::Trapper
+            | MonsterClass::Vortex
+            | MonsterClass::Dragon
+            | MonsterClass::Pudding
+            | MonsterClass::Wraith
+            | MonsterClass::Yeti
+            | MonsterClass::Ghost => Self::Gray,
+            MonsterClass::Feline | MonsterClass::Elemental | MonsterClass::Human => Self::White,
+            MonsterClass::Imp
+            | MonsterClass::Giant
+            | MonsterClass::Naga
+            | MonsterClass::Vampire => Self::Red,
+            MonsterClass::Jelly | MonsterClass::Kop | MonsterClass::Demon | MonsterClass::Eel => {
+                Self::Blue
+            }
+            MonsterClass::Xan => Self::Magenta,
+            MonsterClass::Light | MonsterClass::Golem | MonsterClass::Lizard => Self::Yellow,
+            MonsterClass::Fungus => Self::BrightGreen,
+            MonsterClass::Jabberwock => Self::Orange,
+            MonsterClass::QuantumMechanic => Self::Cyan,
+            MonsterClass::Invisible | MonsterClass::MimicDef => Self::NoColor,
+        }
+    }
 }
 
+/// Serialize as the canonical name in human-readable formats and as the
+/// color number otherwise.
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.name())
+        } else {
+            serializer.serialize_u8(*self as u8)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ColorVisitor;
+
+        impl Visitor<'_> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a color name or number")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Color, E> {
+                Color::from_name(v).ok_or_else(|| E::unknown_variant(v, COLOR_NAMES))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Color, E> {
+                u8::try_from(v)
+                    .ok()
+                    .and_then(Color::from_repr)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(ColorVisitor)
+        } else {
+            deserializer.deserialize_u8(ColorVisitor)
+        }
+    }
+}
+
+/// [`Color::name`] for each color number.
+const COLOR_NAMES: &[&str] = &[
+    "black",
+    "red",
+    "green",
+    "brown",
+    "blue",
+    "magenta",
+    "cyan",
+    "gray",
+    "no_color",
+    "orange",
+    "bright_green",
+    "yellow",
+    "bright_blue",
+    "bright_magenta",
+    "bright_cyan",
+    "white",
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use strum::IntoEnumIterator;
+    use serde::de::IntoDeserializer;
+    use serde::de::value::Error;
 
     #[test]
     fn count() {
@@ -71,4 +256,76 @@ mod tests {
             assert_eq!(Color::from_repr(c as u8), Some(c));
         }
     }
+
+    #[test]
+    fn serde_round_trip() {
+        for c in Color::iter() {
+            let named: Result<Color, Error> = Color::deserialize(c.name().into_deserializer());
+            assert_eq!(named.unwrap(), c);
+            let numbered: Result<Color, Error> = Color::deserialize((c as u8).into_deserializer());
+            assert_eq!(numbered.unwrap(), c);
+        }
+        assert_eq!(Color::NoColor.name(), "no_color");
+        let bad: Result<Color, Error> = Color::deserialize("pink".into_deserializer());
+        assert!(bad.is_err());
+        let bad: Result<Color, Error> = Color::deserialize(16u8.into_deserializer());
+        assert!(bad.is_err());
+    }
+
+    #[test]
+    fn json_uses_names() {
+        for c in Color::iter() {
+            assert_eq!(
+                serde_json::to_string(&c).unwrap(),
+                format!("\"{}\"", c.name())
+            );
+        }
+        assert_eq!(
+            serde_json::to_string(&Color::BrightGreen).unwrap(),
+            "\"bright_green\""
+        );
+    }
+
+    #[test]
+    fn eight_color_fallback() {
+        assert_eq!(Color::BrightGreen.to_8color(), (Color::Green as u8, true));
+        assert_eq!(Color::Black.to_8color(), (Color::Black as u8, false));
+        assert_eq!(Color::Orange.to_8color(), (Color::Red as u8, true));
+        assert_eq!(Color::NoColor.to_8color(), (Color::Gray as u8, false));
+        assert!(Color::White.is_bright());
+        assert!(!Color::Gray.is_bright());
+        assert!(!Color::NoColor.is_bright());
+    }
+
+    #[test]
+    fn object_class_defaults() {
+        assert_eq!(
+            Color::default_for_object_class(ObjectClass::Coin),
+            Color::Yellow
+        );
+        assert_eq!(
+            Color::default_for_object_class(ObjectClass::Gem),
+            Color::HI_GLASS
+        );
+        assert_eq!(
+            Color::default_for_object_class(ObjectClass::Scroll),
+            Color::HI_PAPER
+        );
+    }
+
+    #[test]
+    fn monster_class_defaults() {
+        assert_eq!(
+            Color::default_for_monster_class(MonsterClass::Dragon),
+            Color::Gray
+        );
+        assert_eq!(
+            Color::default_for_monster_class(MonsterClass::Ant),
+            Color::Brown
+        );
+        assert_eq!(
+            Color::default_for_monster_class(MonsterClass::Invisible),
+            Color::NoColor
+        );
+    }
 }
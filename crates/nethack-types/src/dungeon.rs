@@ -1,5 +1,7 @@
 use serde::Serialize;
 
+use crate::Alignment;
+
 /// Complete dungeon topology parsed from `dungeon.def`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct DungeonTopology {
@@ -70,6 +72,19 @@ pub enum DungeonAlignment {
     Noalign,
 }
 
+impl DungeonAlignment {
+    /// The hero alignment a dungeon or level is co-aligned with, if any.
+    /// `Unaligned` and `Noalign` have no counterpart.
+    pub const fn to_alignment(self) -> Option<Alignment> {
+        match self {
+            Self::Lawful => Some(Alignment::Lawful),
+            Self::Neutral => Some(Alignment::Neutral),
+            Self::Chaotic => Some(Alignment::Chaotic),
+            Self::Unaligned | Self::Noalign => None,
+        }
+    }
+}
+
 /// Branch connection type matching C's `TBR_*` constants in `dgn_file.h`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
 pub enum BranchType {
@@ -86,3 +101,30 @@ pub enum BranchDirection {
     Up,
     Down,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aligned_dungeons_map_to_alignment() {
+        assert_eq!(
+            DungeonAlignment::Lawful.to_alignment(),
+            Some(Alignment::Lawful)
+        );
+        assert_eq!(
+            DungeonAlignment::Neutral.to_alignment(),
+            Some(Alignment::Neutral)
+        );
+        assert_eq!(
+            DungeonAlignment::Chaotic.to_alignment(),
+            Some(Alignment::Chaotic)
+        );
+    }
+
+    #[test]
+    fn unaligned_dungeons_have_no_alignment() {
+        assert_eq!(DungeonAlignment::Unaligned.to_alignment(), None);
+        assert_eq!(DungeonAlignment::Noalign.to_alignment(), None);
+    }
+}
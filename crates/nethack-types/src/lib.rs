@@ -16,10 +16,13 @@ pub mod monster_sound;
 pub mod monster_type;
 pub mod object_class;
 pub mod object_id;
+pub mod object_instance;
 pub mod object_type;
 pub mod property;
 pub mod resistance;
 pub mod role;
+pub mod room_type;
+pub mod selection;
 pub mod sp_lev;
 pub mod worn;
 
@@ -40,6 +43,7 @@ pub use monster_sound::MonsterSound;
 pub use monster_type::MonsterType;
 pub use object_class::ObjectClass;
 pub use object_id::ObjectId;
+pub use object_instance::{Buc, ObjectInstance};
 pub use object_type::{ObjectType, ObjectTypeFlags};
 pub use property::Property;
 pub use resistance::Resistance;
@@ -47,6 +51,8 @@ pub use role::{
     AlignDefinition, Gender, GenderDefinition, RaceDefinition, RaceKind, RoleAdvance,
     RoleDefinition, RoleKind, RoleName,
 };
+pub use room_type::RoomType;
+pub use selection::{COLNO, ROWNO, Selection};
 pub use worn::WornMask;
 
 pub use dungeon::{
@@ -54,6 +60,6 @@ pub use dungeon::{
     DungeonTopology, LevelDef,
 };
 pub use sp_lev::{
-    DesFile, LevelFlags, LvlInitStyle, SpLevOpcode, SpMonVarFlag, SpObjVarFlag, SpOpcode,
-    SpOperand, SpecialLevel,
+    DesFile, HumidityFlags, LevelFlags, LvlInitStyle, SpLevOpcode, SpMonVarFlag, SpObjVarFlag,
+    SpOpcode, SpOperand, SpecialLevel, StackEffect, StackError,
 };
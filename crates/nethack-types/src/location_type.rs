@@ -85,6 +85,28 @@ impl LocationType {
     pub const fn is_drawbridge(self) -> bool {
         matches!(self, Self::DrawbridgeUp | Self::DrawbridgeDown)
     }
+
+    /// The other state of a drawbridge span, as `open_drawbridge()` and
+    /// `close_drawbridge()` switch it; `None` for anything else.
+    pub const fn drawbridge_toggle(self) -> Option<LocationType> {
+        match self {
+            Self::DrawbridgeUp => Some(Self::DrawbridgeDown),
+            Self::DrawbridgeDown => Some(Self::DrawbridgeUp),
+            _ => None,
+        }
+    }
+
+    /// Open ground a fountain, sink or pool can replace: plain floor,
+    /// corridor or ice, never a wall, door or existing furniture.
+    pub const fn can_place_furniture(self) -> bool {
+        matches!(self, Self::Room | Self::Corr | Self::Ice)
+    }
+
+    /// Solid rock a pick can dig through (`dig_check()`): stone, walls and
+    /// secret passages, but not trees or drawbridge walls.
+    pub const fn can_dig_through(self) -> bool {
+        self.is_rock() && !matches!(self, Self::Tree | Self::DbWall)
+    }
 }
 
 #[cfg(test)]
@@ -128,10 +150,36 @@ mod tests {
         assert!(!LocationType::Room.is_furniture());
     }
 
+    #[test]
+    fn placement_and_digging() {
+        assert!(LocationType::Room.can_place_furniture());
+        assert!(LocationType::Corr.can_place_furniture());
+        assert!(!LocationType::VWall.can_place_furniture());
+        assert!(!LocationType::Door.can_place_furniture());
+        assert!(!LocationType::Altar.can_place_furniture());
+
+        assert!(LocationType::HWall.can_dig_through());
+        assert!(LocationType::Stone.can_dig_through());
+        assert!(LocationType::SCorr.can_dig_through());
+        assert!(!LocationType::Room.can_dig_through());
+        assert!(!LocationType::Tree.can_dig_through());
+        assert!(!LocationType::DbWall.can_dig_through());
+    }
+
     #[test]
     fn round_trip() {
         for lt in LocationType::iter() {
             assert_eq!(LocationType::from_repr(lt as u8), Some(lt));
         }
     }
+
+    #[test]
+    fn drawbridge_toggles_both_ways() {
+        use LocationType::{DrawbridgeDown, DrawbridgeUp};
+        assert_eq!(DrawbridgeUp.drawbridge_toggle(), Some(DrawbridgeDown));
+        assert_eq!(DrawbridgeDown.drawbridge_toggle(), Some(DrawbridgeUp));
+        for t in LocationType::iter().filter(|t| !t.is_drawbridge()) {
+            assert_eq!(t.drawbridge_toggle(), None, "{t:?}");
+        }
+    }
 }
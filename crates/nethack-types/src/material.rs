@@ -44,6 +44,52 @@ impl Material {
     pub fn is_corrodeable(self) -> bool {
         self == Self::Copper || self == Self::Iron
     }
+
+    /// Relative density in tenths of g/cm³.
+    ///
+    /// NetHack stores a fixed weight per object type, so this scale is for
+    /// material substitution (crafting, melting down); values follow real
+    /// materials, with mithril taken as a light metal well below iron.
+    pub const fn density(self) -> u32 {
+        match self {
+            Self::Liquid => 10,
+            Self::Wax => 9,
+            Self::Veggy => 7,
+            Self::Flesh => 10,
+            Self::Paper => 8,
+            Self::Cloth => 5,
+            Self::Leather => 9,
+            Self::Wood => 6,
+            Self::Bone => 19,
+            Self::DragonHide => 12,
+            Self::Iron => 79,
+            Self::Metal => 78,
+            Self::Copper => 90,
+            Self::Silver => 105,
+            Self::Gold => 193,
+            Self::Platinum => 214,
+            Self::Mithril => 40,
+            Self::Plastic => 12,
+            Self::Glass => 25,
+            Self::Gemstone => 35,
+            Self::Mineral => 27,
+        }
+    }
+
+    /// Weight of an object whose type weighs `base_weight` in `base`, when
+    /// made of `actual` instead. Never rounds a non-zero weight down to 0,
+    /// and saturates at `u32::MAX` rather than wrapping.
+    pub const fn effective_weight(base_weight: u32, base: Material, actual: Material) -> u32 {
+        let scaled = (base_weight as u64 * actual.density() as u64 + base.density() as u64 / 2)
+            / base.density() as u64;
+        if scaled == 0 && base_weight > 0 {
+            1
+        } else if scaled > u32::MAX as u64 {
+            u32::MAX
+        } else {
+            scaled as u32
+        }
+    }
 }
 
 #[cfg(test)]
@@ -78,6 +124,51 @@ mod tests {
         assert!(!Material::Wood.is_metallic());
     }
 
+    #[test]
+    fn density_ordering() {
+        assert!(Material::Iron.density() > Material::Mithril.density());
+        assert!(Material::Gold.density() > Material::Silver.density());
+        assert!(Material::Mithril.density() > Material::Wood.density());
+        for m in Material::iter() {
+            assert!(m.density() > 0, "{m:?} has no density");
+        }
+    }
+
+    #[test]
+    fn effective_weight() {
+        // Same material: unchanged.
+        assert_eq!(
+            Material::effective_weight(40, Material::Iron, Material::Iron),
+            40
+        );
+        // A mithril version of an iron object is lighter.
+        let mithril = Material::effective_weight(250, Material::Iron, Material::Mithril);
+        assert!(mithril < 250);
+        assert_eq!(mithril, 127);
+        // Tiny objects never weigh nothing.
+        assert_eq!(
+            Material::effective_weight(1, Material::Gold, Material::Cloth),
+            1
+        );
+        assert_eq!(
+            Material::effective_weight(0, Material::Iron, Material::Gold),
+            0
+        );
+        // Cloth to platinum scales by 214/5 and would overflow u32.
+        assert_eq!(
+            Material::effective_weight(u32::MAX / 10, Material::Cloth, Material::Platinum),
+            u32::MAX
+        );
+        assert_eq!(
+            Material::effective_weight(u32::MAX, Material::Platinum, Material::Platinum),
+            u32::MAX
+        );
+        assert_eq!(
+            Material::effective_weight(100_000_000, Material::Cloth, Material::Platinum),
+            4_280_000_000
+        );
+    }
+
     #[test]
     fn round_trip() {
         for m in Material::iter() {
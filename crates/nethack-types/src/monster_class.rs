@@ -1,8 +1,21 @@
 use serde::Serialize;
 use strum::{EnumCount, EnumIter, FromRepr};
 
-/// Monster class symbols from `monsym.h`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, EnumIter, EnumCount, FromRepr)]
+/// Monster class symbols from `monsym.h`. Classes order as `def_monsyms`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    EnumIter,
+    EnumCount,
+    FromRepr,
+)]
 #[repr(u8)]
 pub enum MonsterClass {
     Ant = 1,
@@ -135,6 +148,14 @@ impl MonsterClass {
             Self::MimicDef => ']',
         }
     }
+
+    /// Position of this class in monster menus (0 = first).
+    ///
+    /// C lists classes in `def_monsyms` order, which is the `S_*` order these
+    /// discriminants follow.
+    pub const fn menu_order(self) -> u8 {
+        self as u8 - 1
+    }
 }
 
 #[cfg(test)]
@@ -168,4 +189,16 @@ mod tests {
         assert_eq!(MonsterClass::Demon.default_symbol(), '&');
         assert_eq!(MonsterClass::Ghost.default_symbol(), ' ');
     }
+
+    #[test]
+    fn menu_order_is_permutation() {
+        let mut seen = [false; MonsterClass::COUNT];
+        for mc in MonsterClass::iter() {
+            let pos = mc.menu_order() as usize;
+            assert!(!seen[pos], "{mc:?} shares menu position {pos}");
+            seen[pos] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!(MonsterClass::Ant.menu_order(), 0);
+    }
 }
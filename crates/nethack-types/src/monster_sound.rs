@@ -54,6 +54,41 @@ impl MonsterSound {
 
     /// MS_ORC is an alias for MS_GRUNT.
     pub const ORC: Self = Self::Grunt;
+
+    /// The noise a content pet with this sound makes when it answers a
+    /// whistle, as a verb phrase (`"yips"`), following the tame cases of
+    /// `domonnoise()`. Sounds that are speech rather than noise, whose lines
+    /// depend on the monster, all give `"talks"`; silent monsters give `""`.
+    pub fn pet_whistle_response(self) -> &'static str {
+        match self {
+            Self::Silent => "",
+            Self::Bark => "yips",
+            Self::Mew => "purrs",
+            Self::Roar | Self::Growl => "snarls",
+            Self::Squeak => "squeaks",
+            Self::Squawk => "squawks",
+            Self::Hiss => "hisses",
+            Self::Buzz => "drones",
+            Self::Grunt => "grunts",
+            Self::Neigh => "neighs",
+            Self::Wail => "wails mournfully",
+            Self::Gurgle => "gurgles",
+            Self::Burble => "burbles",
+            Self::Shriek => "shrieks",
+            Self::Bones => "rattles noisily",
+            Self::Laugh => "giggles",
+            Self::Mumble => "mumbles incomprehensibly",
+            Self::Imitate => "imitates you",
+            Self::Were => "howls",
+            _ => "talks",
+        }
+    }
+
+    /// Whether the monster makes any sound at all (everything but
+    /// MS_SILENT).
+    pub fn is_vocal(self) -> bool {
+        self != Self::Silent
+    }
 }
 
 #[cfg(test)]
@@ -79,6 +114,17 @@ mod tests {
         assert_eq!(MonsterSound::ORC, MonsterSound::Grunt);
     }
 
+    #[test]
+    fn whistle_responses() {
+        assert!(MonsterSound::Bark.is_vocal());
+        assert_eq!(MonsterSound::Bark.pet_whistle_response(), "yips");
+        assert!(!MonsterSound::Silent.is_vocal());
+        assert_eq!(MonsterSound::Silent.pet_whistle_response(), "");
+        for ms in MonsterSound::iter() {
+            assert_eq!(ms.is_vocal(), !ms.pet_whistle_response().is_empty());
+        }
+    }
+
     #[test]
     fn round_trip() {
         for ms in MonsterSound::iter() {
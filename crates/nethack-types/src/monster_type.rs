@@ -34,14 +34,118 @@ pub struct MonsterType {
     pub color: Color,
 }
 
+impl MonsterType {
+    /// One-line summary for inspection tools, e.g.
+    /// `giant ant (a) lvl 2 spd 18 AC 3 MR 0 attacks: Bite 1d4 Physical`.
+    pub fn summary(&self) -> String {
+        let attacks: Vec<String> = self
+            .attacks
+            .iter()
+            .filter(|a| !a.is_none())
+            .map(|a| {
+                format!(
+                    "{:?} {}d{} {:?}",
+                    a.attack_type, a.dice_num, a.dice_sides, a.damage_type
+                )
+            })
+            .collect();
+        let attacks = if attacks.is_empty() {
+            "none".to_string()
+        } else {
+            attacks.join(", ")
+        };
+        format!(
+            "{} ({}) lvl {} spd {} AC {} MR {} attacks: {}",
+            self.name,
+            self.symbol,
+            self.level,
+            self.move_speed,
+            self.ac,
+            self.magic_resistance,
+            attacks
+        )
+    }
+
+    /// Alignment credit for sacrificing a fresh corpse of this species, as
+    /// computed by `dosacrifice()` in `pray.c`.
+    ///
+    /// `hero_align` is the hero's alignment, and the altar is taken to match
+    /// it (the case where the value counts towards prayer); `None` stands for
+    /// an unaligned altar. The base value is `difficulty + 1`; undead add 1
+    /// unless the altar is chaotic, and unicorns are -5 on an altar of their
+    /// own alignment and +3 otherwise. Same-race and former-pet penalties
+    /// depend on the individual corpse and are left to the caller.
+    pub fn sacrifice_value(&self, hero_align: Option<Alignment>) -> i32 {
+        let altar = hero_align.unwrap_or(Alignment::None);
+        let mut value = self.difficulty as i32 + 1;
+        if self.flags2.contains(MonsterFlags2::UNDEAD) {
+            if altar != Alignment::Chaotic {
+                value += 1;
+            }
+        } else if self.is_unicorn() {
+            if self.alignment == altar {
+                value = -5;
+            } else {
+                value += 3;
+            }
+        }
+        value
+    }
+
+    /// C's `lays_eggs()`: the species is `M1_OVIPAROUS`.
+    pub fn lays_eggs(&self) -> bool {
+        self.flags1.contains(MonsterFlags1::OVIPAROUS)
+    }
+
+    /// Whether the species multiplies by cloning itself: gremlins in water
+    /// and brown or black puddings hit with iron. C checks these species by
+    /// `PM_*` index rather than by flag.
+    pub fn breeds(&self) -> bool {
+        matches!(self.name, "gremlin" | "brown pudding" | "black pudding")
+    }
+
+    /// C's `pm_invisible()`: stalkers and black lights are always invisible.
+    /// Like [`breeds`](Self::breeds), C checks these by species, not flag.
+    pub fn is_invisible(&self) -> bool {
+        matches!(self.name, "stalker" | "black light")
+    }
+
+    /// C's `infravisible()`: the species is `M3_INFRAVISIBLE`, so a hero
+    /// with infravision sees it in the dark.
+    pub fn is_infravisible(&self) -> bool {
+        self.flags3.contains(MonsterFlags3::INFRAVISIBLE)
+    }
+
+    /// C's `is_displacer()`: the species is `M3_DISPLACES`.
+    pub fn displaces(&self) -> bool {
+        self.flags3.contains(MonsterFlags3::DISPLACES)
+    }
+
+    /// C's `can_saddle()`: a steed class (`q u A C D J`) of at least
+    /// medium size with a solid, non-humanoid body; centaurs are the one
+    /// humanoid exception. Flyers can be ridden too.
+    pub fn can_be_ridden(&self) -> bool {
+        matches!(self.symbol, 'q' | 'u' | 'A' | 'C' | 'D' | 'J')
+            && self.size as u8 >= MonsterSize::Medium as u8
+            && (!self.flags1.contains(MonsterFlags1::HUMANOID) || self.symbol == 'C')
+            && !self
+                .flags1
+                .intersects(MonsterFlags1::AMORPHOUS | MonsterFlags1::UNSOLID)
+    }
+
+    /// C's `is_unicorn()`: a gem-loving `u`.
+    fn is_unicorn(&self) -> bool {
+        self.symbol == 'u' && self.flags2.contains(MonsterFlags2::JEWELS)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::attack::{AttackType, DamageType};
 
-    #[test]
-    fn size_check() {
-        // Ensure the struct can be constructed
-        let _mon = MonsterType {
+    fn test_monster() -> MonsterType {
+        MonsterType {
             name: "test monster",
             symbol: 'T',
             level: 5,
@@ -62,6 +166,27 @@ mod tests {
             flags3: MonsterFlags3::empty(),
             difficulty: 5,
             color: Color::Brown,
-        };
+        }
+    }
+
+    #[test]
+    fn size_check() {
+        // Ensure the struct can be constructed
+        let _mon = test_monster();
+    }
+
+    #[test]
+    fn summary_fields() {
+        let mut mon = test_monster();
+        assert_eq!(
+            mon.summary(),
+            "test monster (T) lvl 5 spd 12 AC 5 MR 0 attacks: none"
+        );
+        mon.attacks[0] = Attack::new(AttackType::Bite, DamageType::Physical, 1, 4);
+        mon.attacks[1] = Attack::new(AttackType::Claw, DamageType::Fire, 2, 6);
+        assert!(
+            mon.summary()
+                .ends_with("attacks: Bite 1d4 Physical, Claw 2d6 Fire")
+        );
     }
 }
@@ -51,6 +51,33 @@ impl ObjectClass {
             Self::Venom => '.',
         }
     }
+
+    /// Position of this class in inventory menus (0 = first).
+    ///
+    /// Follows C's `def_inv_order` (coins, amulets, weapons, armor, food, ...);
+    /// classes missing from it are listed afterwards in numeric order.
+    pub const fn menu_order(self) -> u8 {
+        match self {
+            Self::Coin => 0,
+            Self::Amulet => 1,
+            Self::Weapon => 2,
+            Self::Armor => 3,
+            Self::Food => 4,
+            Self::Scroll => 5,
+            Self::SpellBook => 6,
+            Self::Potion => 7,
+            Self::Ring => 8,
+            Self::Wand => 9,
+            Self::Tool => 10,
+            Self::Gem => 11,
+            Self::Rock => 12,
+            Self::Ball => 13,
+            Self::Chain => 14,
+            Self::Random => 15,
+            Self::IllObj => 16,
+            Self::Venom => 17,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -83,4 +110,17 @@ mod tests {
             assert_eq!(ObjectClass::from_repr(oc as u8), Some(oc));
         }
     }
+
+    #[test]
+    fn menu_order_is_permutation() {
+        let mut seen = [false; ObjectClass::MAX];
+        for c in ObjectClass::iter() {
+            let pos = c.menu_order() as usize;
+            assert!(!seen[pos], "{c:?} shares menu position {pos}");
+            seen[pos] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!(ObjectClass::Coin.menu_order(), 0);
+        assert!(ObjectClass::Weapon.menu_order() < ObjectClass::Armor.menu_order());
+    }
 }
@@ -0,0 +1,45 @@
+use serde::Serialize;
+
+use crate::{MonsterId, ObjectId};
+
+/// Blessed/uncursed/cursed status (C's `blessed` and `cursed` bits).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+pub enum Buc {
+    Blessed,
+    #[default]
+    Uncursed,
+    Cursed,
+}
+
+/// The per-object state that decides whether two stacks merge: the subset
+/// of C's `struct obj` that `mergable()` compares.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ObjectInstance {
+    pub id: ObjectId,
+    /// Enchantment or charges (`spe`).
+    pub enchant: i8,
+    /// Rust/burn (`oeroded`) and corrosion/rot (`oeroded2`) levels.
+    pub erosion: (u8, u8),
+    pub buc: Buc,
+    /// Whether the hero knows the BUC status (`bknown`).
+    pub buc_known: bool,
+    /// Individual name given with `#name` (`ONAME`).
+    pub name: Option<String>,
+    /// Species of a corpse, tin, egg, statue or figurine (`corpsenm`).
+    pub corpsenm: Option<MonsterId>,
+}
+
+impl ObjectInstance {
+    /// A plain, unnamed, uncursed instance of `id`.
+    pub fn new(id: ObjectId) -> Self {
+        Self {
+            id,
+            enchant: 0,
+            erosion: (0, 0),
+            buc: Buc::Uncursed,
+            buc_known: false,
+            name: None,
+            corpsenm: None,
+        }
+    }
+}
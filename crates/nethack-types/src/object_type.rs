@@ -3,10 +3,13 @@ use serde::Serialize;
 use crate::color::Color;
 use crate::material::Material;
 use crate::object_class::ObjectClass;
+use crate::object_id::ObjectId;
 
 /// An object type definition, matching C's `struct objclass`.
 #[derive(Debug, Clone, Serialize)]
 pub struct ObjectType {
+    /// This entry's index in the object table.
+    pub id: ObjectId,
     pub name: &'static str,
     pub description: Option<&'static str>,
     pub class: ObjectClass,
@@ -26,6 +29,73 @@ pub struct ObjectType {
     pub flags: ObjectTypeFlags,
 }
 
+impl ObjectType {
+    /// One-line summary for inspection tools, e.g.
+    /// `long sword ()) Weapon Iron wt 40 cost 15 dmg d8/d12`.
+    pub fn summary(&self) -> String {
+        let mut s = format!("{} ({})", self.display_name(), self.class.symbol());
+        if let Some(desc) = self.description {
+            s.push_str(&format!(" [{desc}]"));
+        }
+        s.push_str(&format!(
+            " {:?} {:?} wt {} cost {}",
+            self.class, self.material, self.weight, self.cost
+        ));
+        if self.damage_small != 0 || self.damage_large != 0 {
+            s.push_str(&format!(
+                " dmg d{}/d{}",
+                self.damage_small, self.damage_large
+            ));
+        }
+        s
+    }
+
+    /// Radius of the light a lit object of this type sheds, as
+    /// `begin_burn()` sets it: lamps and lanterns light radius 3 and a
+    /// burning potion of oil radius 1. A single candle and a Candelabrum
+    /// with few candles light radius 2; C's `candle_light_range()` widens
+    /// that for bigger stacks and more attached candles. 0 for objects
+    /// that cannot be lit.
+    pub fn light_radius(&self) -> u8 {
+        match (self.class, self.name) {
+            (ObjectClass::Tool, "oil lamp" | "magic lamp" | "brass lantern") => 3,
+            (ObjectClass::Tool, "tallow candle" | "wax candle" | "Candelabrum of Invocation") => 2,
+            (ObjectClass::Potion, "oil") => 1,
+            _ => 0,
+        }
+    }
+
+    /// Effect constant for a magic consumable (scroll, potion, spellbook or
+    /// wand).
+    ///
+    /// C names these effects by object index (`SCR_IDENTIFY`, `POT_WATER`,
+    /// `SPE_FORCE_BOLT`, `WAN_DIGGING`, ...), so the effect id is the
+    /// `ObjectId` discriminant. Returns `None` for other classes and for the
+    /// unnamed extra appearances (e.g. the spare scroll labels).
+    pub fn effect_id(&self) -> Option<u16> {
+        match self.class {
+            ObjectClass::Scroll
+            | ObjectClass::Potion
+            | ObjectClass::SpellBook
+            | ObjectClass::Wand
+                if !self.name.is_empty() =>
+            {
+                Some(self.id as u16)
+            }
+            _ => None,
+        }
+    }
+
+    /// The object's name, falling back to its description for the unnamed
+    /// "extra" appearances (e.g. the random scroll labels).
+    fn display_name(&self) -> &'static str {
+        match (self.name, self.description) {
+            ("", Some(desc)) => desc,
+            (name, _) => name,
+        }
+    }
+}
+
 /// Boolean flags for object types, collapsed from the C bitfield members.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct ObjectTypeFlags {
@@ -17,6 +17,22 @@ bitflags! {
     }
 }
 
+impl Resistance {
+    /// Parse a single C constant name such as `MR_FIRE`.
+    pub fn from_c_name(name: &str) -> Option<Self> {
+        Self::from_name(name.strip_prefix("MR_")?)
+    }
+
+    /// The C constant name (`MR_FIRE`, ...) for a single resistance flag.
+    pub fn c_name(self) -> Option<String> {
+        let mut names = self.iter_names();
+        match (names.next(), names.next()) {
+            (Some((name, _)), None) => Some(format!("MR_{name}")),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -35,4 +51,29 @@ mod tests {
         assert!(fire_cold.contains(Resistance::COLD));
         assert!(!fire_cold.contains(Resistance::ELEC));
     }
+
+    #[test]
+    fn c_names_round_trip() {
+        let mut count = 0;
+        for (_, flag) in Resistance::all().iter_names() {
+            let name = flag.c_name().expect("single flag has a C name");
+            assert!(name.starts_with("MR_"));
+            assert_eq!(Resistance::from_c_name(&name), Some(flag));
+            count += 1;
+        }
+        assert_eq!(count, 8);
+        assert_eq!(Resistance::from_c_name("MR_FIRE"), Some(Resistance::FIRE));
+        assert_eq!(
+            Resistance::from_c_name("MR_DISINT"),
+            Some(Resistance::DISINT)
+        );
+    }
+
+    #[test]
+    fn c_name_rejects_invalid() {
+        assert_eq!(Resistance::from_c_name("FIRE"), None);
+        assert_eq!(Resistance::from_c_name("MR_PLASMA"), None);
+        assert_eq!((Resistance::FIRE | Resistance::COLD).c_name(), None);
+        assert_eq!(Resistance::empty().c_name(), None);
+    }
 }
@@ -0,0 +1,122 @@
+use serde::Serialize;
+use strum::{EnumCount, EnumIter, FromRepr};
+
+use crate::ObjectClass;
+
+/// Special room types from `mkroom.h` (enum roomtype_types).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, EnumIter, EnumCount, FromRepr)]
+#[repr(u8)]
+pub enum RoomType {
+    Ordinary = 0,
+    Court = 2,
+    Swamp = 3,
+    Vault = 4,
+    Beehive = 5,
+    Morgue = 6,
+    Barracks = 7,
+    Zoo = 8,
+    Delphi = 9,
+    Temple = 10,
+    LepreHall = 11,
+    CockNest = 12,
+    AntHole = 13,
+    /// General store; every type from here on is a shop.
+    ShopBase = 14,
+    ArmorShop = 15,
+    ScrollShop = 16,
+    PotionShop = 17,
+    WeaponShop = 18,
+    FoodShop = 19,
+    RingShop = 20,
+    WandShop = 21,
+    ToolShop = 22,
+    BookShop = 23,
+    FodderShop = 24,
+    CandleShop = 25,
+}
+
+impl RoomType {
+    /// `MAXRTYPE`: the highest room type.
+    pub const MAX: u8 = Self::CandleShop as u8;
+
+    /// The room type for a `.des` room type string, as `lev_comp` names them.
+    pub fn from_des_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "ordinary" => Self::Ordinary,
+            "throne" => Self::Court,
+            "swamp" => Self::Swamp,
+            "vault" => Self::Vault,
+            "beehive" => Self::Beehive,
+            "morgue" => Self::Morgue,
+            "barracks" => Self::Barracks,
+            "zoo" => Self::Zoo,
+            "delphi" => Self::Delphi,
+            "temple" => Self::Temple,
+            "anthole" => Self::AntHole,
+            "cocknest" => Self::CockNest,
+            "leprehall" => Self::LepreHall,
+            "shop" => Self::ShopBase,
+            "armor shop" => Self::ArmorShop,
+            "scroll shop" => Self::ScrollShop,
+            "potion shop" => Self::PotionShop,
+            "weapon shop" => Self::WeaponShop,
+            "food shop" => Self::FoodShop,
+            "ring shop" => Self::RingShop,
+            "wand shop" => Self::WandShop,
+            "tool shop" => Self::ToolShop,
+            "book shop" => Self::BookShop,
+            "health food shop" => Self::FodderShop,
+            "candle shop" => Self::CandleShop,
+            _ => return None,
+        })
+    }
+
+    pub const fn is_shop(self) -> bool {
+        (self as u8) >= Self::ShopBase as u8
+    }
+
+    /// The object class a shop is stocked from (`shtypes[].symb`), or
+    /// `Random` for a general store. `None` for rooms that are not shops.
+    pub const fn shop_class(self) -> Option<ObjectClass> {
+        Some(match self {
+            Self::ShopBase => ObjectClass::Random,
+            Self::ArmorShop => ObjectClass::Armor,
+            Self::ScrollShop => ObjectClass::Scroll,
+            Self::PotionShop => ObjectClass::Potion,
+            Self::WeaponShop => ObjectClass::Weapon,
+            Self::FoodShop | Self::FodderShop => ObjectClass::Food,
+            Self::RingShop => ObjectClass::Ring,
+            Self::WandShop => ObjectClass::Wand,
+            Self::ToolShop | Self::CandleShop => ObjectClass::Tool,
+            Self::BookShop => ObjectClass::SpellBook,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn discriminants() {
+        assert_eq!(RoomType::Court as u8, 2);
+        assert_eq!(RoomType::ShopBase as u8, 14);
+        assert_eq!(RoomType::WeaponShop as u8, 18);
+        assert_eq!(RoomType::MAX, 25);
+    }
+
+    #[test]
+    fn shops_have_stock_classes() {
+        for rt in RoomType::iter() {
+            assert_eq!(rt.is_shop(), rt.shop_class().is_some(), "{rt:?}");
+        }
+        assert_eq!(
+            RoomType::from_des_name("weapon shop").and_then(RoomType::shop_class),
+            Some(ObjectClass::Weapon)
+        );
+        assert_eq!(RoomType::from_des_name("throne"), Some(RoomType::Court));
+        assert_eq!(RoomType::from_des_name("closet"), None);
+    }
+}
@@ -0,0 +1,158 @@
+use serde::Serialize;
+
+/// Map width in columns (`COLNO` in `global.h`).
+pub const COLNO: usize = 80;
+/// Map height in rows (`ROWNO` in `global.h`).
+pub const ROWNO: usize = 21;
+
+/// A set of map locations, the value of a `SPOVAR_SEL` special-level variable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Selection {
+    /// Row-major membership bits, indexed by `y * COLNO + x`.
+    cells: Vec<bool>,
+}
+
+impl Default for Selection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Selection {
+    /// An empty selection.
+    pub fn new() -> Self {
+        Self {
+            cells: vec![false; COLNO * ROWNO],
+        }
+    }
+
+    fn index(x: i32, y: i32) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= COLNO || y as usize >= ROWNO {
+            None
+        } else {
+            Some(y as usize * COLNO + x as usize)
+        }
+    }
+
+    /// Whether `(x, y)` is selected. Out-of-map points never are.
+    pub fn get(&self, x: i32, y: i32) -> bool {
+        Self::index(x, y).is_some_and(|i| self.cells[i])
+    }
+
+    /// Add or remove `(x, y)`. Out-of-map points are ignored, as in C's
+    /// `selection_setpoint()`.
+    pub fn set(&mut self, x: i32, y: i32, selected: bool) {
+        if let Some(i) = Self::index(x, y) {
+            self.cells[i] = selected;
+        }
+    }
+
+    /// Decode the bytes of a `SPOVAR_SEL` operand.
+    ///
+    /// C keeps a selection as a string with one byte per map cell, not a
+    /// bitmap or run lengths: `selection_opvar()` allocates `COLNO * ROWNO`
+    /// bytes set to `1`, and `selection_setpoint()` stores the cell's value
+    /// plus one at `y * COLNO + x`, so `1` is unselected and `2` selected.
+    /// The string never contains a zero, so it is written to a `.lev` file
+    /// like any other string: an `i32` length of `COLNO * ROWNO`, then the
+    /// bytes. Any byte above `1` counts as selected, and missing trailing
+    /// bytes as unselected.
+    pub fn from_lev_bytes(raw: &[u8]) -> Self {
+        let mut sel = Self::new();
+        for (cell, &b) in sel.cells.iter_mut().zip(raw) {
+            *cell = b > 1;
+        }
+        sel
+    }
+
+    /// Encode as the bytes of a `SPOVAR_SEL` operand, the inverse of
+    /// [`Selection::from_lev_bytes`].
+    pub fn to_lev_bytes(&self) -> Vec<u8> {
+        self.cells.iter().map(|&c| if c { 2 } else { 1 }).collect()
+    }
+
+    /// Number of selected points.
+    pub fn count(&self) -> usize {
+        self.cells.iter().filter(|&&c| c).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.cells.contains(&true)
+    }
+
+    /// Selected points in C's scan order (`x` outer, `y` inner), which the
+    /// selection opcodes rely on when drawing random points.
+    pub fn iter(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        (0..COLNO as i32).flat_map(move |x| {
+            (0..ROWNO as i32)
+                .filter(move |&y| self.get(x, y))
+                .map(move |y| (x, y))
+        })
+    }
+
+    /// Add every point of `other` (`SPO_SEL_ADD`).
+    pub fn union_with(&mut self, other: &Selection) {
+        for (a, &b) in self.cells.iter_mut().zip(&other.cells) {
+            *a |= b;
+        }
+    }
+
+    /// Invert membership of every point (`SPO_SEL_COMPLEMENT`).
+    pub fn complement(&mut self) {
+        for c in &mut self.cells {
+            *c = !*c;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_and_get() {
+        let mut sel = Selection::new();
+        assert!(sel.is_empty());
+        sel.set(3, 4, true);
+        sel.set(79, 20, true);
+        assert!(sel.get(3, 4));
+        assert!(sel.get(79, 20));
+        assert!(!sel.get(4, 3));
+        assert_eq!(sel.count(), 2);
+        sel.set(3, 4, false);
+        assert_eq!(sel.count(), 1);
+    }
+
+    #[test]
+    fn out_of_bounds_ignored() {
+        let mut sel = Selection::new();
+        sel.set(-1, 0, true);
+        sel.set(COLNO as i32, 0, true);
+        sel.set(0, ROWNO as i32, true);
+        assert!(sel.is_empty());
+        assert!(!sel.get(-1, 0));
+    }
+
+    #[test]
+    fn iter_is_column_major() {
+        let mut sel = Selection::new();
+        sel.set(2, 0, true);
+        sel.set(1, 5, true);
+        sel.set(1, 2, true);
+        let points: Vec<_> = sel.iter().collect();
+        assert_eq!(points, vec![(1, 2), (1, 5), (2, 0)]);
+    }
+
+    #[test]
+    fn union_and_complement() {
+        let mut a = Selection::new();
+        a.set(1, 1, true);
+        let mut b = Selection::new();
+        b.set(2, 2, true);
+        a.union_with(&b);
+        assert_eq!(a.count(), 2);
+        a.complement();
+        assert_eq!(a.count(), COLNO * ROWNO - 2);
+        assert!(!a.get(1, 1));
+    }
+}
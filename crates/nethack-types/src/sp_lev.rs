@@ -2,6 +2,8 @@ use bitflags::bitflags;
 use serde::Serialize;
 use strum::FromRepr;
 
+use crate::selection::Selection;
+
 /// Opcodes for the special level bytecode interpreter.
 /// Values match C's `enum opcode_defs` in `sp_lev.h:60-139`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, FromRepr)]
@@ -85,9 +87,194 @@ pub enum SpOpcode {
     SelComplement = 75,
 }
 
+/// How an opcode changes the interpreter stack in `sp_lev.c`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackEffect {
+    /// Pops `pops` operands, then pushes `pushes`.
+    Fixed { pops: usize, pushes: usize },
+    /// The count depends on operand values: monster and object parameter
+    /// lists, variable initializers, and function frames.
+    Variable,
+}
+
+impl SpOpcode {
+    /// Whether this opcode pops a relative jump offset (`SPO_JMP`, `SPO_JE`, ...).
+    pub const fn is_jump(self) -> bool {
+        matches!(
+            self,
+            Self::Jmp | Self::Jl | Self::Jle | Self::Jg | Self::Jge | Self::Je | Self::Jne
+        )
+    }
+
+    /// C's name for this opcode, `SPO_*` without the prefix, as `lev_comp`
+    /// prints it (e.g. `PUSH`, `SEL_FILLRECT`).
+    pub const fn mnemonic(self) -> &'static str {
+        match self {
+            Self::Null => "NULL",
+            Self::Message => "MESSAGE",
+            Self::Monster => "MONSTER",
+            Self::Object => "OBJECT",
+            Self::Engraving => "ENGRAVING",
+            Self::Room => "ROOM",
+            Self::Subroom => "SUBROOM",
+            Self::Door => "DOOR",
+            Self::Stair => "STAIR",
+            Self::Ladder => "LADDER",
+            Self::Altar => "ALTAR",
+            Self::Fountain => "FOUNTAIN",
+            Self::Sink => "SINK",
+            Self::Pool => "POOL",
+            Self::Trap => "TRAP",
+            Self::Gold => "GOLD",
+            Self::Corridor => "CORRIDOR",
+            Self::LevRegion => "LEVREGION",
+            Self::Drawbridge => "DRAWBRIDGE",
+            Self::MazeWalk => "MAZEWALK",
+            Self::NonDiggable => "NON_DIGGABLE",
+            Self::NonPasswall => "NON_PASSWALL",
+            Self::Wallify => "WALLIFY",
+            Self::Map => "MAP",
+            Self::RoomDoor => "ROOM_DOOR",
+            Self::Region => "REGION",
+            Self::Mineralize => "MINERALIZE",
+            Self::Cmp => "CMP",
+            Self::Jmp => "JMP",
+            Self::Jl => "JL",
+            Self::Jle => "JLE",
+            Self::Jg => "JG",
+            Self::Jge => "JGE",
+            Self::Je => "JE",
+            Self::Jne => "JNE",
+            Self::Terrain => "TERRAIN",
+            Self::ReplaceTerrain => "REPLACE_TERRAIN",
+            Self::Exit => "EXIT",
+            Self::EndRoom => "ENDROOM",
+            Self::PopContainer => "POP_CONTAINER",
+            Self::Push => "PUSH",
+            Self::Pop => "POP",
+            Self::Rn2 => "RN2",
+            Self::Dec => "DEC",
+            Self::Inc => "INC",
+            Self::MathAdd => "MATH_ADD",
+            Self::MathSub => "MATH_SUB",
+            Self::MathMul => "MATH_MUL",
+            Self::MathDiv => "MATH_DIV",
+            Self::MathMod => "MATH_MOD",
+            Self::MathSign => "MATH_SIGN",
+            Self::Copy => "COPY",
+            Self::EndMonInvent => "END_MONINVENT",
+            Self::Grave => "GRAVE",
+            Self::FramePush => "FRAME_PUSH",
+            Self::FramePop => "FRAME_POP",
+            Self::Call => "CALL",
+            Self::Return => "RETURN",
+            Self::InitLevel => "INITLEVEL",
+            Self::LevelFlags => "LEVEL_FLAGS",
+            Self::VarInit => "VAR_INIT",
+            Self::ShuffleArray => "SHUFFLE_ARRAY",
+            Self::Dice => "DICE",
+            Self::SelAdd => "SEL_ADD",
+            Self::SelPoint => "SEL_POINT",
+            Self::SelRect => "SEL_RECT",
+            Self::SelFillRect => "SEL_FILLRECT",
+            Self::SelLine => "SEL_LINE",
+            Self::SelRndLine => "SEL_RNDLINE",
+            Self::SelGrow => "SEL_GROW",
+            Self::SelFlood => "SEL_FLOOD",
+            Self::SelRndCoord => "SEL_RNDCOORD",
+            Self::SelEllipse => "SEL_ELLIPSE",
+            Self::SelFilter => "SEL_FILTER",
+            Self::SelGradient => "SEL_GRADIENT",
+            Self::SelComplement => "SEL_COMPLEMENT",
+        }
+    }
+
+    /// The opcode named `mnemonic` by [`Self::mnemonic`], for writing
+    /// opcode streams by hand. Case-sensitive.
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        (0..=u8::MAX)
+            .filter_map(Self::from_repr)
+            .find(|op| op.mnemonic() == mnemonic)
+    }
+
+    /// The operands this opcode's `spo_*` handler pops and pushes. A push
+    /// of an array variable also pops its index; that is not counted here.
+    ///
+    /// `CMP` pushes its `SP_CPUFLAG_*` result, which a conditional jump pops
+    /// from under its offset; `JMP` pops only the offset.
+    pub const fn stack_effect(self) -> StackEffect {
+        let (pops, pushes) = match self {
+            Self::Null | Self::Exit | Self::EndRoom | Self::PopContainer | Self::EndMonInvent => {
+                (0, 0)
+            }
+            Self::Push => (0, 1),
+            Self::Message
+            | Self::Fountain
+            | Self::Sink
+            | Self::Pool
+            | Self::NonDiggable
+            | Self::NonPasswall
+            | Self::Pop
+            | Self::LevelFlags
+            | Self::ShuffleArray
+            | Self::Jmp => (1, 0),
+            Self::Jl | Self::Jle | Self::Jg | Self::Jge | Self::Je | Self::Jne => (2, 0),
+            Self::Door
+            | Self::Stair
+            | Self::Ladder
+            | Self::Trap
+            | Self::Gold
+            | Self::Wallify
+            | Self::Terrain => (2, 0),
+            Self::Engraving | Self::Altar | Self::Drawbridge | Self::Grave => (3, 0),
+            Self::MazeWalk
+            | Self::RoomDoor
+            | Self::Region
+            | Self::Mineralize
+            | Self::ReplaceTerrain => (4, 0),
+            Self::Corridor | Self::Map => (6, 0),
+            Self::InitLevel => (8, 0),
+            Self::Room | Self::Subroom => (10, 0),
+            Self::LevRegion => (13, 0),
+            Self::Rn2
+            | Self::Dec
+            | Self::Inc
+            | Self::MathSign
+            | Self::SelPoint
+            | Self::SelRect
+            | Self::SelFillRect
+            | Self::SelFlood
+            | Self::SelRndCoord
+            | Self::SelComplement => (1, 1),
+            Self::MathAdd
+            | Self::MathSub
+            | Self::MathMul
+            | Self::MathDiv
+            | Self::MathMod
+            | Self::Dice
+            | Self::SelAdd
+            | Self::SelLine
+            | Self::SelGrow
+            | Self::Cmp => (2, 1),
+            Self::SelRndLine | Self::SelFilter => (3, 1),
+            Self::SelEllipse => (4, 1),
+            Self::SelGradient => (6, 1),
+            Self::Copy => (1, 2),
+            Self::Monster
+            | Self::Object
+            | Self::VarInit
+            | Self::FramePush
+            | Self::FramePop
+            | Self::Call
+            | Self::Return => return StackEffect::Variable,
+        };
+        StackEffect::Fixed { pops, pushes }
+    }
+}
+
 /// Typed operand pushed onto the stack with `SPO_PUSH`.
 /// Variants match C's `SPOVAR_*` constants in `sp_lev.h:206-221`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub enum SpOperand {
     Int(i64),
     String(String),
@@ -96,8 +283,8 @@ pub enum SpOperand {
         x: i16,
         y: i16,
         is_random: bool,
-        /// Humidity/location flags for random coords.
-        flags: u32,
+        /// Location requirements for random coords.
+        flags: HumidityFlags,
     },
     Region {
         x1: i16,
@@ -117,9 +304,56 @@ pub enum SpOperand {
         class: i16,
         id: i16,
     },
-    Sel(Vec<u8>),
+    /// A selection. `raw` holds the byte-per-cell `.lev` encoding it was
+    /// decoded from, kept as read so a `.lev` file round-trips exactly;
+    /// selections built at run time have none.
+    Sel {
+        sel: Selection,
+        raw: Option<Vec<u8>>,
+    },
+}
+
+/// Selections compare by the decoded [`Selection`] only, so one read from a
+/// `.lev` file equals the same selection built without raw bytes.
+impl PartialEq for SpOperand {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Int(a), Self::Int(b)) => a == b,
+            (Self::String(a), Self::String(b)) | (Self::Variable(a), Self::Variable(b)) => a == b,
+            (
+                Self::Coord {
+                    x,
+                    y,
+                    is_random,
+                    flags,
+                },
+                Self::Coord {
+                    x: x2,
+                    y: y2,
+                    is_random: is_random2,
+                    flags: flags2,
+                },
+            ) => (x, y, is_random, flags) == (x2, y2, is_random2, flags2),
+            (
+                Self::Region { x1, y1, x2, y2 },
+                Self::Region {
+                    x1: ox1,
+                    y1: oy1,
+                    x2: ox2,
+                    y2: oy2,
+                },
+            ) => (x1, y1, x2, y2) == (ox1, oy1, ox2, oy2),
+            (Self::MapChar { typ, lit }, Self::MapChar { typ: t, lit: l }) => (typ, lit) == (t, l),
+            (Self::Monst { class, id }, Self::Monst { class: c, id: i })
+            | (Self::Obj { class, id }, Self::Obj { class: c, id: i }) => (class, id) == (c, i),
+            (Self::Sel { sel, .. }, Self::Sel { sel: other, .. }) => sel == other,
+            _ => false,
+        }
+    }
 }
 
+impl Eq for SpOperand {}
+
 /// A single instruction in the special level bytecode.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct SpLevOpcode {
@@ -147,6 +381,37 @@ bitflags! {
     }
 }
 
+impl LevelFlags {
+    /// `premapped`: the whole level starts out mapped, as in Sokoban.
+    pub const fn is_premapped(self) -> bool {
+        self.contains(Self::PREMAPPED)
+    }
+
+    /// `shroud`: nothing out of sight is remembered.
+    pub const fn is_shrouded(self) -> bool {
+        self.contains(Self::SHROUD)
+    }
+}
+
+bitflags! {
+    /// Location requirements for a random coordinate, matching C's humidity
+    /// flags in `sp_lev.h`. `lev_comp` packs them into the low byte of a
+    /// random `SPOVAR_COORD`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+    pub struct HumidityFlags: u8 {
+        const DRY         = 0x01;
+        const WET         = 0x02;
+        const HOT         = 0x04;
+        const SOLID       = 0x08;
+        /// Anywhere, even outside the map area.
+        const ANY_LOC     = 0x10;
+        /// Return (-1, -1) rather than complaining when nothing fits.
+        const NO_LOC_WARN = 0x20;
+        /// Like `DRY`, but furniture is acceptable too.
+        const SPACELOC    = 0x40;
+    }
+}
+
 /// Level initialization style matching C's `enum lvlinit_types`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, FromRepr)]
 #[repr(u8)]
@@ -210,8 +475,544 @@ pub struct SpecialLevel {
     pub opcodes: Vec<SpLevOpcode>,
 }
 
+impl SpecialLevel {
+    /// Compare opcode streams with [`structurally_equal`], ignoring names.
+    pub fn structurally_equal(&self, other: &SpecialLevel) -> bool {
+        structurally_equal(&self.opcodes, &other.opcodes)
+    }
+
+    /// The flags the level sets: every `LEVEL_FLAGS` opcode OR-ed together,
+    /// each taking the integer pushed just before it. Flags pushed any
+    /// other way (from a variable, say) are not followed.
+    pub fn level_flags(&self) -> LevelFlags {
+        self.opcodes
+            .windows(2)
+            .filter_map(|pair| match pair {
+                [
+                    SpLevOpcode {
+                        opcode: SpOpcode::Push,
+                        operand: Some(SpOperand::Int(bits)),
+                    },
+                    SpLevOpcode {
+                        opcode: SpOpcode::LevelFlags,
+                        ..
+                    },
+                ] => Some(LevelFlags::from_bits_truncate(*bits as u32)),
+                _ => None,
+            })
+            .fold(LevelFlags::empty(), |acc, flags| acc | flags)
+    }
+
+    /// Check that every path through the opcode stream keeps the stack
+    /// balanced and leaves it empty at `EXIT`, reporting the first problem.
+    ///
+    /// Execution is followed symbolically, taking both sides of each
+    /// conditional jump. Integer constants are tracked so jump offsets and
+    /// the parameter lists of `MONSTER`, `OBJECT` and `VAR_INIT` can be
+    /// sized. Function calls are not followed.
+    pub fn validate_stack(&self) -> Result<(), StackError> {
+        StackCheck::new(&self.opcodes).run()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum StackError {
+    #[error("stack underflow at opcode {index} ({opcode:?})")]
+    Underflow { index: usize, opcode: SpOpcode },
+    #[error("{depth} operands left on the stack at exit (opcode {index})")]
+    Leftover { index: usize, depth: usize },
+    #[error("opcode {index} reached with stack depth {found} and {expected} on another path")]
+    Mismatch {
+        index: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("opcode {index} ({opcode:?}) needs a constant integer operand")]
+    NotConstant { index: usize, opcode: SpOpcode },
+    #[error("jump at opcode {index} to {target} leaves the stream")]
+    BadJump { index: usize, target: i64 },
+    #[error("cannot check opcode {index} ({opcode:?})")]
+    Unsupported { index: usize, opcode: SpOpcode },
+}
+
+/// What the stack checker knows about a stack slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Sym {
+    Int(i64),
+    Str(String),
+    Unknown,
+}
+
+/// Symbolic interpreter behind [`SpecialLevel::validate_stack`].
+struct StackCheck<'a> {
+    ops: &'a [SpLevOpcode],
+    /// Stack depth each visited opcode was first reached with.
+    seen: std::collections::HashMap<usize, usize>,
+    /// Paths still to follow: next opcode and stack.
+    pending: Vec<(usize, Vec<Sym>)>,
+    /// Variables initialized as arrays, whose pushes pop an index.
+    arrays: std::collections::HashSet<String>,
+}
+
+impl<'a> StackCheck<'a> {
+    fn new(ops: &'a [SpLevOpcode]) -> Self {
+        Self {
+            ops,
+            seen: Default::default(),
+            pending: vec![(0, Vec::new())],
+            arrays: Default::default(),
+        }
+    }
+
+    fn run(mut self) -> Result<(), StackError> {
+        while let Some((mut pc, mut stack)) = self.pending.pop() {
+            loop {
+                let Some(op) = self.ops.get(pc) else {
+                    // Running off the end exits like EXIT.
+                    Self::check_empty(pc, &stack)?;
+                    break;
+                };
+                match self.seen.get(&pc) {
+                    Some(&depth) if depth == stack.len() => break,
+                    Some(&depth) => {
+                        return Err(StackError::Mismatch {
+                            index: pc,
+                            expected: depth,
+                            found: stack.len(),
+                        });
+                    }
+                    None => {
+                        self.seen.insert(pc, stack.len());
+                    }
+                }
+                match self.step(pc, op, &mut stack)? {
+                    Flow::Next => pc += 1,
+                    Flow::Exit => {
+                        Self::check_empty(pc, &stack)?;
+                        break;
+                    }
+                    Flow::Jump(target) => pc = target,
+                    Flow::Branch(target) => {
+                        self.pending.push((target, stack.clone()));
+                        pc += 1;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_empty(index: usize, stack: &[Sym]) -> Result<(), StackError> {
+        if stack.is_empty() {
+            Ok(())
+        } else {
+            Err(StackError::Leftover {
+                index,
+                depth: stack.len(),
+            })
+        }
+    }
+
+    fn step(
+        &mut self,
+        pc: usize,
+        op: &SpLevOpcode,
+        stack: &mut Vec<Sym>,
+    ) -> Result<Flow, StackError> {
+        let opcode = op.opcode;
+        let underflow = || StackError::Underflow { index: pc, opcode };
+        let pop = |stack: &mut Vec<Sym>| stack.pop().ok_or_else(underflow);
+        let pop_int = |sym: Sym| match sym {
+            Sym::Int(n) => Ok(n),
+            _ => Err(StackError::NotConstant { index: pc, opcode }),
+        };
+        match opcode {
+            SpOpcode::Exit => return Ok(Flow::Exit),
+            SpOpcode::Push => {
+                let sym = match &op.operand {
+                    Some(SpOperand::Int(n)) => Sym::Int(*n),
+                    Some(SpOperand::String(s)) => Sym::Str(s.clone()),
+                    Some(SpOperand::Variable(name)) => {
+                        if self.arrays.contains(name) {
+                            pop(stack)?;
+                        }
+                        Sym::Unknown
+                    }
+                    _ => Sym::Unknown,
+                };
+                stack.push(sym);
+            }
+            opcode if opcode.is_jump() => {
+                let offset = pop_int(pop(stack)?)?;
+                if opcode != SpOpcode::Jmp {
+                    pop(stack)?; // CMP result
+                }
+                let target = pc as i64 + offset;
+                if target < 0 || target > self.ops.len() as i64 {
+                    return Err(StackError::BadJump { index: pc, target });
+                }
+                let target = target as usize;
+                return Ok(if opcode == SpOpcode::Jmp {
+                    Flow::Jump(target)
+                } else {
+                    Flow::Branch(target)
+                });
+            }
+            SpOpcode::Monster | SpOpcode::Object => {
+                let end = if opcode == SpOpcode::Monster {
+                    SpMonVarFlag::End as i64
+                } else {
+                    SpObjVarFlag::End as i64
+                };
+                pop(stack)?; // has_inv / containment
+                // Each parameter is a value under its flag, down to End. A
+                // monster's appearance is two values: the kind and the name.
+                loop {
+                    match pop_int(pop(stack)?)? {
+                        flag if (0..end).contains(&flag) => {
+                            pop(stack)?;
+                            if opcode == SpOpcode::Monster && flag == SpMonVarFlag::Appear as i64 {
+                                pop(stack)?;
+                            }
+                        }
+                        _ => break,
+                    }
+                }
+                if opcode == SpOpcode::Monster {
+                    pop(stack)?; // coord
+                }
+                pop(stack)?; // monster or object
+            }
+            SpOpcode::VarInit => {
+                let name = match pop(stack)? {
+                    Sym::Str(name) => name,
+                    _ => return Err(StackError::NotConstant { index: pc, opcode }),
+                };
+                let len = pop_int(pop(stack)?)?;
+                for _ in 0..len.max(1) {
+                    pop(stack)?;
+                }
+                if len > 0 {
+                    self.arrays.insert(name);
+                } else {
+                    self.arrays.remove(&name);
+                }
+            }
+            opcode => match opcode.stack_effect() {
+                StackEffect::Fixed { pops, pushes } => {
+                    let mut popped = Vec::with_capacity(pops);
+                    for _ in 0..pops {
+                        popped.push(pop(stack)?);
+                    }
+                    // COPY duplicates what it popped; results are unknown.
+                    if opcode == SpOpcode::Copy {
+                        stack.extend([popped[0].clone(), popped[0].clone()]);
+                    } else {
+                        stack.extend(std::iter::repeat_n(Sym::Unknown, pushes));
+                    }
+                }
+                StackEffect::Variable => {
+                    return Err(StackError::Unsupported { index: pc, opcode });
+                }
+            },
+        }
+        Ok(Flow::Next)
+    }
+}
+
+/// Where the stack checker goes after an opcode.
+enum Flow {
+    Next,
+    Exit,
+    Jump(usize),
+    /// A conditional jump: both the target and the next opcode.
+    Branch(usize),
+}
+
+/// The offset operand of the jump at `idx`, if it is pushed immediately before.
+fn jump_offset(ops: &[SpLevOpcode], idx: usize) -> Option<i64> {
+    if !ops.get(idx)?.opcode.is_jump() {
+        return None;
+    }
+    match ops.get(idx.checked_sub(1)?)? {
+        SpLevOpcode {
+            opcode: SpOpcode::Push,
+            operand: Some(SpOperand::Int(offset)),
+        } => Some(*offset),
+        _ => None,
+    }
+}
+
+/// The instruction the jump at `idx` logically lands on.
+///
+/// `sp_lev.c` adds the offset to the jump's own index. Targets are threaded
+/// through unconditional `PUSH n; JMP` trampolines, and anything past the
+/// end of the stream is normalized to `ops.len()`. Returns `None` for a
+/// negative target or a trampoline cycle.
+fn logical_target(ops: &[SpLevOpcode], idx: usize) -> Option<usize> {
+    let mut target = idx as i64 + jump_offset(ops, idx)?;
+    for _ in 0..=ops.len() {
+        if target < 0 {
+            return None;
+        }
+        let t = target as usize;
+        if t >= ops.len() {
+            return Some(ops.len());
+        }
+        match jump_offset(ops, t + 1) {
+            Some(offset) if ops[t + 1].opcode == SpOpcode::Jmp => {
+                target = (t + 1) as i64 + offset;
+            }
+            _ => return Some(t),
+        }
+    }
+    None
+}
+
+/// Compare two opcode streams, treating jump offsets as equal when they land
+/// on the same logical instruction (see [`logical_target`]).
+///
+/// Useful when diffing compiler output whose jump patching differs only in
+/// encoding. All other opcodes and operands must match exactly.
+pub fn structurally_equal(a: &[SpLevOpcode], b: &[SpLevOpcode]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    (0..a.len()).all(|i| {
+        if a[i].opcode != b[i].opcode {
+            return false;
+        }
+        if jump_offset(a, i + 1).is_some() && jump_offset(b, i + 1).is_some() {
+            return logical_target(a, i + 1) == logical_target(b, i + 1);
+        }
+        a[i].operand == b[i].operand
+    })
+}
+
 /// A parsed `.des` file containing one or more level definitions.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct DesFile {
     pub levels: Vec<SpecialLevel>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selections_compare_without_raw_bytes() {
+        let mut sel = Selection::new();
+        sel.set(3, 2, true);
+        let read = SpOperand::Sel {
+            sel: sel.clone(),
+            raw: Some(sel.to_lev_bytes()),
+        };
+        let built = SpOperand::Sel {
+            sel: sel.clone(),
+            raw: None,
+        };
+        assert_eq!(read, built);
+        sel.set(4, 2, true);
+        assert_ne!(read, SpOperand::Sel { sel, raw: None });
+        assert_ne!(built, SpOperand::Int(0));
+        assert_eq!(
+            SpOperand::Monst { class: 1, id: 2 },
+            SpOperand::Monst { class: 1, id: 2 }
+        );
+        assert_ne!(
+            SpOperand::Monst { class: 1, id: 2 },
+            SpOperand::Obj { class: 1, id: 2 }
+        );
+    }
+
+    fn push(val: i64) -> SpLevOpcode {
+        SpLevOpcode {
+            opcode: SpOpcode::Push,
+            operand: Some(SpOperand::Int(val)),
+        }
+    }
+
+    fn op(opcode: SpOpcode) -> SpLevOpcode {
+        SpLevOpcode {
+            opcode,
+            operand: None,
+        }
+    }
+
+    /// `if (1 == 1) { message } ; trampoline ; exit`
+    fn stream(first_jump: i64, trampoline: i64) -> Vec<SpLevOpcode> {
+        vec![
+            push(1),               // 0
+            push(1),               // 1
+            op(SpOpcode::Cmp),     // 2
+            push(first_jump),      // 3
+            op(SpOpcode::Jne),     // 4
+            push(7),               // 5
+            op(SpOpcode::Message), // 6
+            push(trampoline),      // 7
+            op(SpOpcode::Jmp),     // 8
+            op(SpOpcode::Exit),    // 9
+        ]
+    }
+
+    #[test]
+    fn jump_detection() {
+        assert!(SpOpcode::Jmp.is_jump());
+        assert!(SpOpcode::Jne.is_jump());
+        assert!(!SpOpcode::Cmp.is_jump());
+        assert!(!SpOpcode::Push.is_jump());
+    }
+
+    #[test]
+    fn cmp_result_is_popped_by_conditional_jumps() {
+        let fixed = |pops, pushes| StackEffect::Fixed { pops, pushes };
+        assert_eq!(SpOpcode::Cmp.stack_effect(), fixed(2, 1));
+        assert_eq!(SpOpcode::Jmp.stack_effect(), fixed(1, 0));
+        assert_eq!(SpOpcode::Jne.stack_effect(), fixed(2, 0));
+        assert_eq!(SpOpcode::SelGradient.stack_effect(), fixed(6, 1));
+        assert_eq!(level(stream(5, 1)).validate_stack(), Ok(()));
+    }
+
+    #[test]
+    fn mnemonics_round_trip() {
+        let ops: Vec<_> = (0..=u8::MAX).filter_map(SpOpcode::from_repr).collect();
+        assert_eq!(ops.len(), SpOpcode::SelComplement as usize + 1);
+        for op in ops {
+            assert_eq!(SpOpcode::from_mnemonic(op.mnemonic()), Some(op));
+        }
+        assert_eq!(SpOpcode::Push.mnemonic(), "PUSH");
+        assert_eq!(SpOpcode::SelFillRect.mnemonic(), "SEL_FILLRECT");
+        assert_eq!(SpOpcode::from_mnemonic("push"), None);
+    }
+
+    #[test]
+    fn level_flags_are_folded() {
+        let flags = |bits: LevelFlags| push(bits.bits().into());
+        let level = SpecialLevel {
+            name: "test".into(),
+            opcodes: vec![
+                flags(LevelFlags::NOTELEPORT),
+                op(SpOpcode::LevelFlags),
+                push(7),
+                op(SpOpcode::Message),
+                flags(LevelFlags::PREMAPPED | LevelFlags::HARDFLOOR),
+                op(SpOpcode::LevelFlags),
+            ],
+        };
+        assert_eq!(
+            level.level_flags(),
+            LevelFlags::NOTELEPORT | LevelFlags::PREMAPPED | LevelFlags::HARDFLOOR
+        );
+        assert!(level.level_flags().is_premapped());
+    }
+
+    #[test]
+    fn identical_streams_are_equal() {
+        let a = stream(5, 1);
+        assert!(structurally_equal(&a, &a.clone()));
+    }
+
+    #[test]
+    fn offsets_through_trampoline_are_equal() {
+        // Jumping straight to EXIT (4 + 5 = 9) vs. via the PUSH/JMP at 7..8.
+        let direct = stream(5, 1);
+        let via_trampoline = stream(3, 1);
+        assert_ne!(direct, via_trampoline);
+        assert!(structurally_equal(&direct, &via_trampoline));
+    }
+
+    #[test]
+    fn offsets_past_end_are_equal() {
+        let a = stream(5, 2);
+        let b = stream(5, 40);
+        assert!(structurally_equal(&a, &b));
+    }
+
+    #[test]
+    fn different_targets_are_not_equal() {
+        let a = stream(5, 1);
+        let b = stream(2, 1); // lands on MESSAGE instead
+        assert!(!structurally_equal(&a, &b));
+    }
+
+    #[test]
+    fn operand_differences_still_matter() {
+        let a = stream(5, 1);
+        let mut b = a.clone();
+        b[5] = push(8);
+        assert!(!structurally_equal(&a, &b));
+        assert!(!structurally_equal(&a, &a[..9]));
+    }
+
+    fn level(opcodes: Vec<SpLevOpcode>) -> SpecialLevel {
+        SpecialLevel {
+            name: "test".into(),
+            opcodes,
+        }
+    }
+
+    #[test]
+    fn balanced_stack_validates() {
+        // MAZE header, a 50% fountain, a monster with one parameter, EXIT.
+        let mut ops: Vec<_> = [1, 127, 0, 0, 0, 0, 0, 0].map(push).to_vec();
+        ops.push(op(SpOpcode::InitLevel));
+        ops.extend([push(50), push(100), op(SpOpcode::Rn2), op(SpOpcode::Cmp)]);
+        ops.extend([push(3), op(SpOpcode::Jle), push(0), op(SpOpcode::Fountain)]);
+        ops.extend([push(0), push(0), push(SpMonVarFlag::End as i64)]);
+        ops.extend([push(1), push(SpMonVarFlag::Asleep as i64), push(0)]);
+        ops.extend([op(SpOpcode::Monster), op(SpOpcode::Exit)]);
+        assert_eq!(level(ops).validate_stack(), Ok(()));
+    }
+
+    #[test]
+    fn unbalanced_stack_fails() {
+        // A fountain with no coordinate.
+        let ops = vec![op(SpOpcode::Fountain)];
+        assert_eq!(
+            level(ops).validate_stack(),
+            Err(StackError::Underflow {
+                index: 0,
+                opcode: SpOpcode::Fountain
+            })
+        );
+
+        // An extra push left behind.
+        let ops = vec![push(1), push(2), op(SpOpcode::Fountain), op(SpOpcode::Exit)];
+        assert_eq!(
+            level(ops).validate_stack(),
+            Err(StackError::Leftover { index: 3, depth: 1 })
+        );
+
+        // Only the fall-through path pushes what the POP removes.
+        let ops = vec![
+            push(1),
+            push(2),
+            op(SpOpcode::Cmp),
+            push(2),
+            op(SpOpcode::Je),
+            push(7),
+            op(SpOpcode::Pop),
+        ];
+        assert_eq!(
+            level(ops).validate_stack(),
+            Err(StackError::Mismatch {
+                index: 6,
+                expected: 1,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn special_level_wrapper() {
+        let a = SpecialLevel {
+            name: "a".into(),
+            opcodes: stream(5, 1),
+        };
+        let b = SpecialLevel {
+            name: "b".into(),
+            opcodes: stream(3, 1),
+        };
+        assert!(a.structurally_equal(&b));
+    }
+}
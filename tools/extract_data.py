@@ -4,8 +4,8 @@
 Parses monst.c and objects.c to generate:
   - crates/nethack-types/src/monster_id.rs  (MonsterId enum)
   - crates/nethack-types/src/object_id.rs   (ObjectId enum)
-  - crates/nethack-data/src/monsters.rs     (MONSTERS array)
-  - crates/nethack-data/src/objects.rs      (OBJECTS array)
+  - crates/nethack-data/src/monsters/table.rs (MONSTERS array)
+  - crates/nethack-data/src/objects/table.rs (OBJECTS array)
 """
 
 import argparse
@@ -1649,8 +1649,8 @@ def write_monster_id(monsters, path):
         f.write("}\n")
 
 
-def write_object_id(objects, path):
-    """Write the ObjectId enum file."""
+def object_id_names(objects):
+    """The ObjectId variant name of each object, in table order."""
     # Build name-class pairs for deduplication
     class_prefixes = {
         "Scroll": "ScrollOf", "SpellBook": "SpellOf", "Wand": "WandOf",
@@ -1674,7 +1674,12 @@ def write_object_id(objects, path):
                 name = f"Unknown{cls}"
         names_ctx.append((name, class_prefixes.get(cls, "")))
 
-    names = deduplicate_names(names_ctx)
+    return deduplicate_names(names_ctx)
+
+
+def write_object_id(objects, path):
+    """Write the ObjectId enum file."""
+    names = object_id_names(objects)
 
     with open(path, 'w') as f:
         f.write(GENERATED_HEADER)
@@ -1695,7 +1700,7 @@ def write_object_id(objects, path):
 
 
 def write_monsters_rs(monsters, path):
-    """Write the monsters.rs data file."""
+    """Write the monsters/table.rs data file."""
     n = len(monsters)
     with open(path, 'w') as f:
         f.write(GENERATED_HEADER)
@@ -1747,7 +1752,7 @@ def write_monsters_rs(monsters, path):
 
 
 def write_objects_rs(objects, path):
-    """Write the objects.rs data file."""
+    """Write the objects/table.rs data file."""
     n = len(objects)
     with open(path, 'w') as f:
         f.write(GENERATED_HEADER)
@@ -1757,7 +1762,7 @@ def write_objects_rs(objects, path):
         f.write("use nethack_types::*;\n")
         f.write("\n")
         f.write(f"pub static OBJECTS: [ObjectType; {n}] = [\n")
-        for obj in objects:
+        for obj, id_name in zip(objects, object_id_names(objects)):
             name = obj['name'] if obj['name'] else ""
             desc = obj['description']
             cls = objclass_variant(obj['class'])
@@ -1768,6 +1773,7 @@ def write_objects_rs(objects, path):
             desc_str = f'Some("{desc}")' if desc else "None"
 
             f.write("    ObjectType {\n")
+            f.write(f'        id: ObjectId::{id_name},\n')
             f.write(f'        name: "{name}",\n')
             f.write(f'        description: {desc_str},\n')
             f.write(f'        class: ObjectClass::{cls},\n')
@@ -1856,11 +1862,11 @@ def main():
     write_object_id(objects, types_dir / "object_id.rs")
     print(f"Wrote {types_dir / 'object_id.rs'}")
 
-    write_monsters_rs(monsters, data_dir / "monsters.rs")
-    print(f"Wrote {data_dir / 'monsters.rs'}")
+    write_monsters_rs(monsters, data_dir / "monsters" / "table.rs")
+    print(f"Wrote {data_dir / 'monsters' / 'table.rs'}")
 
-    write_objects_rs(objects, data_dir / "objects.rs")
-    print(f"Wrote {data_dir / 'objects.rs'}")
+    write_objects_rs(objects, data_dir / "objects" / "table.rs")
+    print(f"Wrote {data_dir / 'objects' / 'table.rs'}")
 
     print("Done!")
 
@@ -165,6 +165,83 @@ static void init_like_nethack(isaac64_ctx *ctx, unsigned long seed) {
     isaac64_init(ctx, buf, (int)sizeof(seed));
 }
 
+// Seeds that are not a multiple of 8 bytes exercise reseed's partial-word path.
+static void print_partial_seeds(void) {
+    static const unsigned char bytes[] = {0x2A, 0x17, 0xC3, 0x5E, 0x91, 0x04, 0xFF};
+    int lens[] = {1, 3, 5, 7};
+
+    for (int l = 0; l < 4; l++) {
+        isaac64_ctx ctx;
+        isaac64_init(&ctx, bytes, lens[l]);
+        printf("=== %d-byte seed ===\n", lens[l]);
+        for (int i = 0; i < 8; i++)
+            printf("  %llu\n", (unsigned long long)isaac64_next_uint64(&ctx));
+        printf("\n");
+    }
+}
+
+// rnd.c's RND(), rn2(), rne() and rnz() over a single core context, with
+// u.ulevel passed in.
+static isaac64_ctx core;
+
+static int rn2(int x) {
+    return (int) (isaac64_next_uint64(&core) % x);
+}
+
+static int rne(int x, int ulevel) {
+    int tmp, utmp;
+
+    utmp = (ulevel < 15) ? 5 : ulevel / 3;
+    tmp = 1;
+    while (tmp < utmp && !rn2(x))
+        tmp++;
+    return tmp;
+}
+
+static int rnz(int i, int ulevel) {
+    long x = (long) i;
+    long tmp = 1000L;
+
+    tmp += rn2(1000);
+    tmp *= rne(4, ulevel);
+    if (rn2(2)) {
+        x *= tmp;
+        x /= 1000;
+    } else {
+        x *= 1000;
+        x /= tmp;
+    }
+    return (int) x;
+}
+
+static void print_rnz(void) {
+    unsigned long seeds[] = {1, 42, 1117, 1158};
+    int levels[] = {1, 20};
+
+    printf("=== rnz(350) ===\n");
+    for (int s = 0; s < 4; s++) {
+        printf("  seed %lu:", seeds[s]);
+        for (int l = 0; l < 2; l++) {
+            init_like_nethack(&core, seeds[s]);
+            printf(" ulevel %d -> %d", levels[l], rnz(350, levels[l]));
+        }
+        printf("\n");
+    }
+    printf("\n");
+}
+
+// The draws of rndmonnum()'s Plan B loop, rn1(SPECIAL_PM - LOW_PM, LOW_PM)
+// with SPECIAL_PM == PM_LONG_WORM_TAIL == 327 and LOW_PM == 0. Which of
+// them the loop accepts depends on mons[].geno, so only the raw draws are
+// printed here.
+static void print_rndmonnum_draws(void) {
+    init_like_nethack(&core, 42);
+    printf("=== rn1(327, 0), seed 42 ===\n");
+    for (int i = 0; i < 20; i++)
+        printf("  %d\n", rn2(327) + 0);
+    printf("\n");
+}
+
 int main(void) {
     unsigned long seeds[] = {42, 0, 12345};
     int num_seeds = sizeof(seeds) / sizeof(seeds[0]);
@@ -195,5 +272,8 @@ int main(void) {
         printf("\n");
     }
 
+    print_partial_seeds();
+    print_rnz();
+    print_rndmonnum_draws();
     return 0;
 }